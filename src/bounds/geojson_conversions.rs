@@ -0,0 +1,24 @@
+//! Provides some `Bounds` conversion methods for the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::bounds::Bounds;
+use geojson::Bbox;
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Converts a `Bounds` struct into a GeoJSON bounding box, ordered west,
+    /// south, east, north. As allowed by
+    /// [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946#section-5.2),
+    /// the west value is greater than the east value for boxes that cross the
+    /// antimeridian.
+    pub fn to_geojson_bbox(&self) -> Bbox {
+        vec![
+            self.southwest.lng.to_f64().unwrap_or_default(),
+            self.southwest.lat.to_f64().unwrap_or_default(),
+            self.northeast.lng.to_f64().unwrap_or_default(),
+            self.northeast.lat.to_f64().unwrap_or_default(),
+        ] // vec!
+    } // fn
+} // impl
@@ -0,0 +1,427 @@
+//! Provides geometric operations for the `Bounds` struct: building a bounding
+//! box from points, testing points against it, and merging or intersecting
+//! boxes.
+//!
+//! Longitudes are treated as arcs on a circle rather than as a linear range,
+//! so boxes whose `southwest` longitude is greater than their `northeast`
+//! longitude are understood to cross the antimeridian (±180°).
+
+use crate::bounds::Bounds;
+use crate::error::Error;
+use crate::latlng::{geodesy::EARTH_RADIUS_METERS, LatLng};
+use rust_decimal::{Decimal, prelude::FromPrimitive, prelude::ToPrimitive};
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns the smallest bounding box that contains all of the given
+    /// points, or `None` if the iterator is empty.
+    ///
+    /// The box is allowed to cross the antimeridian if that produces a
+    /// narrower box. For example, points at 179° E and 179° W produce a box
+    /// 2° wide rather than one that spans 358° of the globe.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let bounds = Bounds::from_points(&route_points)
+    ///     .expect("route has at least one point");
+    /// ```
+    pub fn from_points<'a, I>(points: I) -> Option<Bounds>
+    where
+        I: IntoIterator<Item = &'a LatLng>,
+    {
+        let points: Vec<&LatLng> = points.into_iter().collect();
+
+        let south = points.iter().map(|point| point.lat).min()?;
+        let north = points.iter().map(|point| point.lat).max()?;
+
+        let mut longitudes: Vec<Decimal> = points
+            .iter()
+            .map(|point| normalize_lng(point.lng))
+            .collect();
+        longitudes.sort();
+        longitudes.dedup();
+
+        // The box is the complement of the widest empty gap between adjacent
+        // longitudes. The gap that wraps around from the last longitude back
+        // to the first is checked first, so that a box which does not cross
+        // the antimeridian is preferred when gaps are equal:
+        let first = longitudes[0];
+        let last = longitudes[longitudes.len() - 1];
+        let mut widest_gap = first - last + dec!(360.0);
+        let (mut west, mut east) = (first, last);
+
+        for pair in longitudes.windows(2) {
+            let gap = pair[1] - pair[0];
+            if gap > widest_gap {
+                widest_gap = gap;
+                (west, east) = (pair[1], pair[0]);
+            } // if
+        } // for
+
+        Some(Bounds {
+            southwest: LatLng { lat: south, lng: west },
+            northeast: LatLng { lat: north, lng: east },
+        }) // Bounds
+    } // fn
+
+    /// Returns `true` if the bounding box crosses the antimeridian (±180°),
+    /// that is, if its `southwest` longitude is east of its `northeast`
+    /// longitude.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.southwest.lng > self.northeast.lng
+    } // fn
+
+    /// Returns `true` if the point is inside of the bounding box or on its
+    /// edge.
+    pub fn contains(&self, point: &LatLng) -> bool {
+        point.lat >= self.southwest.lat
+            && point.lat <= self.northeast.lat
+            && self.contains_lng(point.lng)
+    } // fn
+
+    /// Extends the bounding box so that it contains the given point. If the
+    /// point is outside of the box's longitudes, the box grows in whichever
+    /// direction (east or west) adds the least width.
+    pub fn extend(&mut self, point: &LatLng) -> &mut Bounds {
+        self.southwest.lat = self.southwest.lat.min(point.lat);
+        self.northeast.lat = self.northeast.lat.max(point.lat);
+
+        if !self.contains_lng(point.lng) {
+            let eastward = degrees_east(self.northeast.lng, point.lng);
+            let westward = degrees_east(point.lng, self.southwest.lng);
+            if eastward <= westward {
+                self.northeast.lng = normalize_lng(point.lng);
+            } else {
+                self.southwest.lng = normalize_lng(point.lng);
+            } // if
+        } // if
+
+        self
+    } // fn
+
+    /// Returns the smallest bounding box that contains both this box and
+    /// another box.
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        let (self_span, other_span) = (self.lng_span(), other.lng_span());
+
+        // Starting at either box's western edge, the union must be wide enough
+        // to reach the far side of the other box. The narrower is the union:
+        let from_self = self_span
+            .max(degrees_east(self.southwest.lng, other.southwest.lng) + other_span);
+        let from_other = other_span
+            .max(degrees_east(other.southwest.lng, self.southwest.lng) + self_span);
+
+        let (west, span) = if from_self <= from_other {
+            (self.southwest.lng, from_self)
+        } else {
+            (other.southwest.lng, from_other)
+        }; // if
+
+        let (west, east) = lng_arc(west, span);
+
+        Bounds {
+            southwest: LatLng {
+                lat: self.southwest.lat.min(other.southwest.lat),
+                lng: west,
+            }, // LatLng
+            northeast: LatLng {
+                lat: self.northeast.lat.max(other.northeast.lat),
+                lng: east,
+            }, // LatLng
+        } // Bounds
+    } // fn
+
+    /// Returns the area shared by this box and another box, or `None` if the
+    /// boxes do not overlap.
+    ///
+    /// Two boxes that both cross the antimeridian, or that are each wider than
+    /// 180°, may overlap in two separate areas. In that case the wider of the
+    /// two areas is returned.
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let south = self.southwest.lat.max(other.southwest.lat);
+        let north = self.northeast.lat.min(other.northeast.lat);
+
+        if south > north {
+            return None;
+        } // if
+
+        let (self_span, other_span) = (self.lng_span(), other.lng_span());
+
+        // An overlapping area always starts at one of the two western edges,
+        // and continues until either box ends:
+        let from_self = if other.contains_lng(self.southwest.lng) {
+            let remaining = other_span - degrees_east(other.southwest.lng, self.southwest.lng);
+            Some((self.southwest.lng, self_span.min(remaining)))
+        } else {
+            None
+        }; // if
+
+        let from_other = if self.contains_lng(other.southwest.lng) {
+            let remaining = self_span - degrees_east(self.southwest.lng, other.southwest.lng);
+            Some((other.southwest.lng, other_span.min(remaining)))
+        } else {
+            None
+        }; // if
+
+        let (west, span) = match (from_self, from_other) {
+            (Some(a), Some(b)) => if b.1 > a.1 { b } else { a },
+            (Some(a), None) => a,
+            (None, Some(b)) => b,
+            (None, None) => return None,
+        }; // match
+
+        let (west, east) = lng_arc(west, span);
+
+        Some(Bounds {
+            southwest: LatLng { lat: south, lng: west },
+            northeast: LatLng { lat: north, lng: east },
+        }) // Bounds
+    } // fn
+
+    /// Returns the center of the bounding box. For boxes that cross the
+    /// antimeridian, the center's longitude is wrapped into the -180° to
+    /// +180° range.
+    pub fn center(&self) -> LatLng {
+        let lat = (self.southwest.lat + self.northeast.lat) / dec!(2.0);
+        let lng = normalize_lng(self.southwest.lng + self.lng_span() / dec!(2.0));
+        LatLng { lat: lat.normalize(), lng: lng.normalize() }
+    } // fn
+
+    /// Returns the height and width of the bounding box in degrees, as a
+    /// `(latitude, longitude)` tuple. The width accounts for boxes that cross
+    /// the antimeridian and may be up to 360°.
+    pub fn span(&self) -> (Decimal, Decimal) {
+        (self.northeast.lat - self.southwest.lat, self.lng_span())
+    } // fn
+
+    /// Returns a copy of the bounding box grown by `meters` on every side.
+    /// This is useful for adding a margin around a viewport, or a tolerance
+    /// around a geofence. Negative distances are treated as zero.
+    ///
+    /// Latitudes are clamped to the poles. If the box becomes wider than the
+    /// whole globe, or reaches a pole, its longitudes span from -180° to
+    /// +180°.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::FloatToDecimalConversionError` if `meters` is
+    /// infinite.
+    pub fn expand_by_meters(&self, meters: f64) -> Result<Bounds, Error> {
+        let meters = meters.max(0.0);
+        let lat_degrees = (meters / EARTH_RADIUS_METERS).to_degrees();
+        let lat_delta: Decimal = Decimal::from_f64(lat_degrees)
+            .ok_or_else(|| Error::FloatToDecimalConversionError(meters.to_string()))?
+            .round_dp(8);
+
+        let south = (self.southwest.lat - lat_delta).max(dec!(-90.0));
+        let north = (self.northeast.lat + lat_delta).min(dec!(90.0));
+
+        // Meridians converge towards the poles, so a degree of longitude is
+        // shortest at the latitude furthest from the equator:
+        let widest_lat = south.abs().max(north.abs()).to_f64().unwrap_or(90.0);
+        let lng_degrees = lat_degrees / widest_lat.to_radians().cos();
+        let lng_delta = Decimal::from_f64(lng_degrees)
+            .map(|delta| delta.round_dp(8))
+            .filter(|delta| dec!(2.0) * delta + self.lng_span() < dec!(360.0));
+
+        let (west, east) = match lng_delta {
+            Some(lng_delta) => (
+                normalize_lng(self.southwest.lng - lng_delta),
+                normalize_lng(self.northeast.lng + lng_delta),
+            ), // Some
+            None => (dec!(-180.0), dec!(180.0)),
+        }; // match
+
+        Ok(Bounds {
+            southwest: LatLng { lat: south.normalize(), lng: west.normalize() },
+            northeast: LatLng { lat: north.normalize(), lng: east.normalize() },
+        }) // Bounds
+    } // fn
+
+    /// Returns the width of the bounding box in degrees of longitude, from 0°
+    /// to 360°.
+    fn lng_span(&self) -> Decimal {
+        if self.crosses_antimeridian() {
+            self.northeast.lng - self.southwest.lng + dec!(360.0)
+        } else {
+            self.northeast.lng - self.southwest.lng
+        } // if
+    } // fn
+
+    /// Returns `true` if the longitude is within the bounding box's arc of
+    /// longitudes.
+    fn contains_lng(&self, lng: Decimal) -> bool {
+        degrees_east(self.southwest.lng, lng) <= self.lng_span()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns how many degrees east one must travel from the `from` longitude to
+/// reach the `to` longitude, from 0° up to (but not including) 360°.
+
+fn degrees_east(from: Decimal, to: Decimal) -> Decimal {
+    let degrees = (to - from) % dec!(360.0);
+    if degrees < dec!(0.0) { degrees + dec!(360.0) } else { degrees }
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Wraps a longitude into the -180° to +180° range.
+
+fn normalize_lng(lng: Decimal) -> Decimal {
+    if lng > dec!(180.0) {
+        lng - dec!(360.0)
+    } else if lng < dec!(-180.0) {
+        lng + dec!(360.0)
+    } else {
+        lng
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the western and eastern longitudes of an arc that starts at `west`
+/// and is `span` degrees wide. An arc that covers the whole globe is returned
+/// as -180° to +180°.
+
+fn lng_arc(west: Decimal, span: Decimal) -> (Decimal, Decimal) {
+    if span >= dec!(360.0) {
+        (dec!(-180.0), dec!(180.0))
+    } else {
+        (normalize_lng(west).normalize(), normalize_lng(west + span).normalize())
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: Decimal, lng: Decimal) -> LatLng {
+        LatLng { lat, lng }
+    } // fn
+
+    fn bounds(south: Decimal, west: Decimal, north: Decimal, east: Decimal) -> Bounds {
+        Bounds { southwest: point(south, west), northeast: point(north, east) }
+    } // fn
+
+    /// Fiji's main islands, which straddle the antimeridian.
+    fn fiji() -> Bounds {
+        bounds(dec!(-21), dec!(177), dec!(-12), dec!(-178))
+    } // fn
+
+    /// The western Aleutian Islands, which are entirely east of the
+    /// antimeridian.
+    fn near_islands() -> Bounds {
+        bounds(dec!(51), dec!(172), dec!(53), dec!(179))
+    } // fn
+
+    #[test]
+    fn contains_across_the_antimeridian() {
+        let fiji = fiji();
+        assert!(fiji.crosses_antimeridian());
+        assert!(fiji.contains(&point(dec!(-17), dec!(178))));
+        assert!(fiji.contains(&point(dec!(-17), dec!(180))));
+        assert!(fiji.contains(&point(dec!(-17), dec!(-180))));
+        assert!(fiji.contains(&point(dec!(-17), dec!(-179))));
+        assert!(fiji.contains(&point(dec!(-21), dec!(-178))));
+        assert!(!fiji.contains(&point(dec!(-17), dec!(0))));
+        assert!(!fiji.contains(&point(dec!(-17), dec!(176))));
+        assert!(!fiji.contains(&point(dec!(-17), dec!(-177))));
+        assert!(!fiji.contains(&point(dec!(-25), dec!(179))));
+
+        assert!(!near_islands().crosses_antimeridian());
+        assert!(!near_islands().contains(&point(dec!(52), dec!(-179))));
+    } // fn
+
+    #[test]
+    fn extends_the_short_way_around() {
+        let mut fiji = fiji();
+        fiji.extend(&point(dec!(-22), dec!(-175)));
+        assert_eq!(fiji, bounds(dec!(-22), dec!(177), dec!(-12), dec!(-175)));
+        fiji.extend(&point(dec!(-18), dec!(175)));
+        assert_eq!(fiji, bounds(dec!(-22), dec!(175), dec!(-12), dec!(-175)));
+
+        // Attu is in the eastern hemisphere and Adak in the western. Adding
+        // Adak makes the box cross the antimeridian rather than span the
+        // globe:
+        let mut aleutians = near_islands();
+        aleutians.extend(&point(dec!(51.88), dec!(-176.66)));
+        assert!(aleutians.crosses_antimeridian());
+        assert_eq!(aleutians, bounds(dec!(51), dec!(172), dec!(53), dec!(-176.66)));
+    } // fn
+
+    #[test]
+    fn unions_across_the_antimeridian() {
+        let andreanof = bounds(dec!(51), dec!(-180), dec!(55), dec!(-165));
+        let aleutians = near_islands().union(&andreanof);
+        assert_eq!(aleutians, bounds(dec!(51), dec!(172), dec!(55), dec!(-165)));
+        assert_eq!(andreanof.union(&near_islands()), aleutians);
+
+        let west = bounds(dec!(0), dec!(170), dec!(1), dec!(175));
+        let east = bounds(dec!(0), dec!(-175), dec!(1), dec!(-170));
+        assert_eq!(west.union(&east), bounds(dec!(0), dec!(170), dec!(1), dec!(-170)));
+
+        // Boxes far from the antimeridian do not wrap:
+        let (left, right) = (bounds(dec!(0), dec!(0), dec!(1), dec!(10)), bounds(dec!(0), dec!(20), dec!(1), dec!(30)));
+        assert_eq!(left.union(&right), bounds(dec!(0), dec!(0), dec!(1), dec!(30)));
+        assert_eq!(fiji().union(&fiji()), fiji());
+    } // fn
+
+    #[test]
+    fn intersects_across_the_antimeridian() {
+        let fiji = fiji();
+        let east = bounds(dec!(-20), dec!(179), dec!(-10), dec!(-170));
+        assert_eq!(fiji.intersection(&east), Some(bounds(dec!(-20), dec!(179), dec!(-12), dec!(-178))));
+
+        let west = bounds(dec!(-30), dec!(170), dec!(-10), dec!(178));
+        assert_eq!(fiji.intersection(&west), Some(bounds(dec!(-21), dec!(177), dec!(-12), dec!(178))));
+
+        let inside = bounds(dec!(-20), dec!(178), dec!(-15), dec!(-179));
+        assert_eq!(fiji.intersection(&inside), Some(inside.clone()));
+        assert_eq!(inside.intersection(&fiji), Some(inside));
+    } // fn
+
+    #[test]
+    fn disjoint_boxes_do_not_intersect() {
+        let square = bounds(dec!(0), dec!(0), dec!(1), dec!(1));
+        assert_eq!(square.intersection(&bounds(dec!(2), dec!(0), dec!(3), dec!(1))), None);
+        assert_eq!(square.intersection(&bounds(dec!(0), dec!(2), dec!(1), dec!(3))), None);
+        assert_eq!(fiji().intersection(&bounds(dec!(-20), dec!(0), dec!(-10), dec!(10))), None);
+        assert_eq!(fiji().intersection(&near_islands()), None);
+
+        // Boxes that only share an edge intersect along it:
+        let edge = square.intersection(&bounds(dec!(1), dec!(0), dec!(2), dec!(1))).unwrap();
+        assert_eq!(edge.span(), (dec!(0), dec!(1)));
+    } // fn
+
+    #[test]
+    fn centers_wrap_into_range() {
+        assert_eq!(fiji().center(), point(dec!(-16.5), dec!(179.5)));
+        let aleutians = bounds(dec!(51), dec!(172), dec!(55), dec!(-165));
+        assert_eq!(aleutians.center(), point(dec!(53), dec!(-176.5)));
+        assert_eq!(aleutians.span(), (dec!(4), dec!(23)));
+        assert_eq!(near_islands().center(), point(dec!(52), dec!(175.5)));
+    } // fn
+
+    #[test]
+    fn builds_from_points_on_both_sides_of_the_dateline() {
+        let points = [
+            point(dec!(-16), dec!(179.5)),
+            point(dec!(-18), dec!(-179.5)),
+            point(dec!(-17), dec!(178)),
+        ]; // points
+        let fiji = Bounds::from_points(&points).unwrap();
+        assert_eq!(fiji, bounds(dec!(-18), dec!(178), dec!(-16), dec!(-179.5)));
+        assert!(points.iter().all(|point| fiji.contains(point)));
+
+        let points = [point(dec!(0), dec!(10)), point(dec!(1), dec!(20))];
+        assert_eq!(Bounds::from_points(&points), Some(bounds(dec!(0), dec!(10), dec!(1), dec!(20))));
+        assert_eq!(Bounds::from_points(&[]), None);
+    } // fn
+} // mod
@@ -4,6 +4,9 @@
 
 #[cfg(feature = "geo")]
 mod geo_conversions;
+#[cfg(feature = "geojson")]
+mod geojson_conversions;
+mod geometry;
 
 // -----------------------------------------------------------------------------
 
@@ -20,8 +23,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Bounds {
     /// South-west or bottom-left corner of the bounding box.
+    #[serde(alias = "sw")]
     pub southwest: LatLng,
     /// North-east or top-right corner of the bounding box.
+    #[serde(alias = "ne")]
     pub northeast: LatLng,
 } // struct
 
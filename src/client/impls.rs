@@ -1,11 +1,11 @@
 use crate::client::GoogleMapsClient;
 #[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
-#[cfg(feature = "directions")]
+#[cfg(any(feature = "directions", feature = "embed"))]
 use crate::directions::request::location::Location;
 #[cfg(feature = "distance_matrix")]
 use crate::directions::request::waypoint::Waypoint;
-#[cfg(any(feature = "geocoding", feature = "time_zone"))]
+#[cfg(any(feature = "embed", feature = "geocoding", feature = "solar", feature = "time_zone", feature = "weather"))]
 use crate::latlng::LatLng;
 #[cfg(feature = "time_zone")]
 use chrono::{DateTime, Utc};
@@ -339,6 +339,30 @@ impl GoogleMapsClient {
         crate::places::place_details::request::Request::new(self, place_id)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Starts a Places API **autocomplete session**. The session generates a
+    /// session token and applies it to each _Place Autocomplete_ request made
+    /// as the user types. Calling `place_details` on the session concludes it,
+    /// so that the token is never reused.
+    ///
+    /// ```rust
+    /// let session = google_maps_client.autocomplete_session();
+    ///
+    /// let predictions = session.place_autocomplete("Edmon".to_string())
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// if let Some(place_id) = predictions.predictions[0].place_id.clone() {
+    ///     let details = session.place_details(place_id).execute().await?;
+    /// }
+    /// ```
+
+    #[cfg(all(feature = "autocomplete", feature = "places"))]
+    pub fn autocomplete_session(&self) -> crate::places::autocomplete_session::AutocompleteSession {
+        crate::places::autocomplete_session::AutocompleteSession::new(self)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Roads API **Snap To Roads** service takes up to 100 GPS points
@@ -407,4 +431,267 @@ impl GoogleMapsClient {
         crate::roads::snap_to_roads::request::Request::new(self, path)
     } // fn
 
-} // impl
\ No newline at end of file
+    // -------------------------------------------------------------------------
+    //
+    /// The Solar API **Building Insights** service locates the building
+    /// closest to a query point, and returns its solar potential: roof segment
+    /// statistics, possible solar panel configurations, and (in supported
+    /// regions) financial analyses for each configuration.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let building_insights = google_maps_client.building_insights(
+    ///     LatLng::try_from_dec(dec!(37.4450), dec!(-122.1390))?,
+    ///     ImageryQuality::High,
+    /// ).execute().await?;
+    /// ```
+
+    #[cfg(feature = "solar")]
+    pub fn building_insights(
+        &self,
+        location: LatLng,
+        required_quality: crate::solar::imagery_quality::ImageryQuality,
+    ) -> crate::solar::building_insights::request::Request {
+        crate::solar::building_insights::request::Request::new(self, location, required_quality)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Solar API **Data Layers** service gets raw solar information for a
+    /// region surrounding a location. The response contains URLs to GeoTIFF
+    /// files (DSM, RGB, mask, annual & monthly flux, hourly shade) that may be
+    /// downloaded with the `geo_tiff` method.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let data_layers = google_maps_client.data_layers(
+    ///     LatLng::try_from_dec(dec!(37.4450), dec!(-122.1390))?,
+    ///     100.0,
+    ///     DataLayerView::ImageryAndAllFluxLayers,
+    /// ).execute().await?;
+    /// ```
+
+    #[cfg(feature = "solar")]
+    pub fn data_layers(
+        &self,
+        location: LatLng,
+        radius_meters: f64,
+        view: crate::solar::data_layers::request::data_layer_view::DataLayerView,
+    ) -> crate::solar::data_layers::request::Request {
+        crate::solar::data_layers::request::Request::new(self, location, radius_meters, view)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Solar API **GeoTIFF** service downloads one of the GeoTIFF files
+    /// referenced in a _Data Layers_ response, and returns it as raw bytes.
+    /// These URLs expire a few hours after the _Data Layers_ request.
+    ///
+    /// ```rust
+    /// if let Some(dsm_url) = &data_layers.dsm_url {
+    ///     let dsm: Vec<u8> = google_maps_client
+    ///         .geo_tiff(dsm_url.to_string())
+    ///         .execute()
+    ///         .await?;
+    ///     std::fs::write("dsm.tiff", dsm)?;
+    /// }
+    /// ```
+
+    #[cfg(feature = "solar")]
+    pub fn geo_tiff(
+        &self,
+        id: String,
+    ) -> crate::solar::geo_tiff::request::Request {
+        crate::solar::geo_tiff::request::Request::new(self, id)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Embed API **Place** mode displays a map pin at a particular
+    /// place or address. The resulting URL is meant to be used as the `src` of
+    /// an `<iframe>`.
+    ///
+    /// ```rust
+    /// let iframe_src = google_maps_client.embed_place(
+    ///     Location::Address(String::from("Eiffel Tower, Paris, France"))
+    /// ).with_zoom(17).query_url()?;
+    /// ```
+
+    #[cfg(feature = "embed")]
+    pub fn embed_place(
+        &self,
+        q: Location,
+    ) -> crate::embed::place::Request {
+        crate::embed::place::Request::new(self, q)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Embed API **View** mode returns a map with no markers or
+    /// directions.
+    ///
+    /// ```rust
+    /// let iframe_src = google_maps_client.embed_view(
+    ///     LatLng::try_from_dec(dec!(-33.8569), dec!(151.2152))?
+    /// ).with_zoom(18).with_map_type(MapType::Satellite).query_url()?;
+    /// ```
+
+    #[cfg(feature = "embed")]
+    pub fn embed_view(
+        &self,
+        center: LatLng,
+    ) -> crate::embed::view::Request {
+        crate::embed::view::Request::new(self, center)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Embed API **Directions** mode displays the path between two or
+    /// more specified points on the map, as well as the distance and travel
+    /// time.
+    ///
+    /// ```rust
+    /// let iframe_src = google_maps_client.embed_directions(
+    ///     Location::Address(String::from("Oslo Norway")),
+    ///     Location::Address(String::from("Telemark Norway")),
+    /// ).with_travel_mode(TravelMode::Bicycling).query_url()?;
+    /// ```
+
+    #[cfg(feature = "embed")]
+    pub fn embed_directions(
+        &self,
+        origin: Location,
+        destination: Location,
+    ) -> crate::embed::directions::Request {
+        crate::embed::directions::Request::new(self, origin, destination)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Embed API **Street View** mode shows an interactive panoramic
+    /// view from a designated location.
+    ///
+    /// ```rust
+    /// let iframe_src = google_maps_client.embed_street_view(
+    ///     LatLng::try_from_dec(dec!(46.414382), dec!(10.013988))?
+    /// ).with_heading(210).with_pitch(10).with_field_of_view(35).query_url()?;
+    /// ```
+
+    #[cfg(feature = "embed")]
+    pub fn embed_street_view(
+        &self,
+        location: LatLng,
+    ) -> crate::embed::street_view::Request {
+        crate::embed::street_view::Request::new(self, location)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Embed API **Search** mode shows results for a search across the
+    /// visible map region.
+    ///
+    /// ```rust
+    /// let iframe_src = google_maps_client.embed_search(
+    ///     String::from("record stores in Seattle")
+    /// ).query_url()?;
+    /// ```
+
+    #[cfg(feature = "embed")]
+    pub fn embed_search(
+        &self,
+        q: String,
+    ) -> crate::embed::search::Request {
+        crate::embed::search::Request::new(self, q)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Weather API **Current Conditions** service returns the current
+    /// weather conditions at a location.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let current_conditions = google_maps_client.current_conditions(
+    ///     LatLng::try_from_dec(dec!(37.4220), dec!(-122.0841))?,
+    /// ).with_unit_system(UnitSystem::Imperial).execute().await?;
+    /// ```
+
+    #[cfg(feature = "weather")]
+    pub fn current_conditions(
+        &self,
+        location: LatLng,
+    ) -> crate::weather::current_conditions::request::Request {
+        crate::weather::current_conditions::request::Request::new(self, location)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Weather API **Forecast Hours** service returns up to 240 hours of
+    /// hourly forecasts for a location. Use the request's `stream` method to
+    /// iterate over every hour without handling pagination.
+    ///
+    /// ```rust
+    /// let forecast = google_maps_client.forecast_hours(
+    ///     LatLng::try_from_dec(dec!(37.4220), dec!(-122.0841))?,
+    /// ).with_hours(48).execute().await?;
+    /// ```
+
+    #[cfg(feature = "weather")]
+    pub fn forecast_hours(
+        &self,
+        location: LatLng,
+    ) -> crate::weather::forecast_hours::request::Request {
+        crate::weather::forecast_hours::request::Request::new(self, location)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Weather API **Forecast Days** service returns up to 10 days of
+    /// daily forecasts for a location.
+    ///
+    /// ```rust
+    /// let forecast = google_maps_client.forecast_days(
+    ///     LatLng::try_from_dec(dec!(37.4220), dec!(-122.0841))?,
+    /// ).with_days(7).with_page_size(7).execute().await?;
+    /// ```
+
+    #[cfg(feature = "weather")]
+    pub fn forecast_days(
+        &self,
+        location: LatLng,
+    ) -> crate::weather::forecast_days::request::Request {
+        crate::weather::forecast_days::request::Request::new(self, location)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Weather API **History Hours** service returns up to 24 hours of
+    /// hourly weather history for a location.
+    ///
+    /// ```rust
+    /// let history = google_maps_client.history_hours(
+    ///     LatLng::try_from_dec(dec!(37.4220), dec!(-122.0841))?,
+    /// ).with_hours(12).execute().await?;
+    /// ```
+
+    #[cfg(feature = "weather")]
+    pub fn history_hours(
+        &self,
+        location: LatLng,
+    ) -> crate::weather::history_hours::request::Request {
+        crate::weather::history_hours::request::Request::new(self, location)
+    } // fn
+
+} // impl
@@ -0,0 +1,25 @@
+//! Helpers for writing [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180)
+//! comma-separated values, which several response types can be exported as.
+
+// -----------------------------------------------------------------------------
+//
+/// Appends one CSV record, terminated by a line break, to the output. Fields
+/// that contain a comma, a quote or a line break are quoted.
+
+pub(crate) fn push_record(csv: &mut String, fields: impl Iterator<Item = String>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            csv.push(',');
+        } // if
+
+        if field.contains([',', '"', '\r', '\n']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(&field);
+        } // if
+    } // for
+
+    csv.push_str("\r\n");
+} // fn
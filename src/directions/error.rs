@@ -91,6 +91,8 @@ pub enum Error {
     SerdeJson(serde_json::error::Error),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
+    /// A traffic model may only be specified with a departure time.
+    TrafficModelRequiresDepartureTime(String),
     /// Transit mode may only be specified in Transit travel mode.
     TransitModeIsForTransitOnly(String, String),
     /// Transit routing preference may only be specified in Transit travel mode.
@@ -253,6 +255,11 @@ impl std::fmt::Display for Error {
                 Try again with {} fewer waypoint(s).",
                 waypoint_count,
                 waypoint_count - 25),
+            Error::TrafficModelRequiresDepartureTime(traffic_model) => write!(f,
+                "Google Maps Directions API client: \
+                The with_traffic_model() method may only be used when with_departure_time() has been set. \
+                The traffic model is set to `{traffic_model}`. \
+                Try again either with a departure time or no traffic model."),
             Error::TransitModeIsForTransitOnly(travel_mode, transit_modes) => write!(f,
                 "Google Maps Directions API client: \
                 The with_transit_modes() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
@@ -301,6 +308,7 @@ impl std::error::Error for Error {
             Error::ReqwestMessage(_error) => None,
             Error::SerdeJson(error) => Some(error),
             Error::TooManyWaypoints(_waypoint_count) => None,
+            Error::TrafficModelRequiresDepartureTime(_traffic_model) => None,
             Error::TransitModeIsForTransitOnly(_travel_mode, _transit_modes) => None,
             Error::TransitRoutePreferenceIsForTransitOnly(
                 _travel_mode,
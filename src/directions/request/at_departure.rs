@@ -0,0 +1,32 @@
+use crate::directions::request::{departure_time::DepartureTime, traffic_model::TrafficModel};
+use crate::directions::request::Request;
+use chrono::{DateTime, Utc};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns a copy of this request, with the same options, that departs at
+    /// the given time and uses the given traffic model. This is used by
+    /// `DepartureSweep` to send one request per departure time and traffic
+    /// model.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `departure` ‧ When to depart.
+    ///
+    /// * `traffic_model` ‧ The traffic model to predict the duration in
+    /// traffic with.
+    pub(crate) fn at_departure(
+        &self,
+        departure: DateTime<Utc>,
+        traffic_model: TrafficModel,
+    ) -> Request<'a> {
+        Request {
+            departure_time: Some(DepartureTime::At(departure.naive_utc())),
+            traffic_model: Some(traffic_model),
+            query: None,
+            validated: false,
+            ..self.clone()
+        } // Request
+    } // fn
+} // impl
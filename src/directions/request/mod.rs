@@ -3,6 +3,8 @@
 //! builder pattern. This module contains the tools (enums, structs, methods)
 //! for building your Google Maps Platform request.
 
+#[cfg(feature = "enable-reqwest")]
+mod at_departure;
 mod build;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
@@ -59,7 +61,7 @@ use crate::{
 /// _Directions API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request<'a> {
 
     // Required parameters:
@@ -71,11 +73,11 @@ pub struct Request<'a> {
 
     /// The address, latitude/longitude, or place ID to which you wish to
     /// calculate directions.
-    destination: Location,
+    pub(crate) destination: Location,
 
     /// The address, latitude/longitude, or place ID from which you wish to
     /// calculate directions.
-    origin: Location,
+    pub(crate) origin: Location,
 
     // Optional parameters:
     // --------------------
@@ -102,7 +104,7 @@ pub struct Request<'a> {
 
     /// Features that routes should avoid. See file `avoid.rs` and method
     /// `with_restrictions()` for more information.
-    restrictions: Option<Vec<Avoid>>,
+    pub(crate) restrictions: Option<Vec<Avoid>>,
 
     /// Assumptions to use when calculating time in traffic. See file
     /// `traffic_model.rs` and method `with_traffic_model()` for more
@@ -119,7 +121,7 @@ pub struct Request<'a> {
 
     /// Mode of transportation. See file `travel_mode.rs` and method
     /// `with_travel_mode()` for more information.
-    travel_mode: Option<TravelMode>,
+    pub(crate) travel_mode: Option<TravelMode>,
 
     /// Unit system to use when displaying results. See file `unit_system.rs`
     /// and method `with_unit_system()` for more information.
@@ -131,7 +133,7 @@ pub struct Request<'a> {
 
     /// Pass throughs or stopovers at intermediate locations. See file
     /// `waypoint.rs` and method `with_waypoints()` for more information.
-    waypoints: Option<Vec<Waypoint>>,
+    pub(crate) waypoints: Option<Vec<Waypoint>>,
 
     // Internal use only:
     // ------------------
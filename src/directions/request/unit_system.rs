@@ -2,6 +2,7 @@
 //! whether imperial or metric units are used in Directions responses.
 
 use crate::directions::error::Error;
+use crate::distance::{display::DisplayDistance, Distance};
 use phf::phf_map;
 use serde::{Deserialize, Serialize, Deserializer};
 
@@ -23,7 +24,8 @@ use serde::{Deserialize, Serialize, Deserializer};
 ///
 /// **Note**: this unit system setting only affects the `text` displayed within
 /// `distance` fields. The `distance` fields also contain `values` which are
-/// always expressed in meters.
+/// always expressed in meters. Use `Distance::display_in` to format those
+/// values, or any other distance, in a unit system.
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum UnitSystem {
@@ -122,4 +124,36 @@ impl std::fmt::Display for UnitSystem {
             UnitSystem::Metric => write!(f, "Metric"),
         } // match
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Distance {
+    /// Returns a formatter for the distance in the units of a `UnitSystem`:
+    /// meters and kilometers for `UnitSystem::Metric`, or feet and miles for
+    /// `UnitSystem::Imperial`.
+    pub fn display_in(&self, unit_system: &UnitSystem) -> DisplayDistance {
+        match unit_system {
+            UnitSystem::Imperial => self.display_imperial(),
+            UnitSystem::Metric => self.display_metric(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_distances_in_each_unit_system() {
+        let short = Distance::from_meters(150.0);
+        assert_eq!(short.display_in(&UnitSystem::Metric).to_string(), "150 m");
+        assert_eq!(short.display_in(&UnitSystem::Imperial).to_string(), "492 ft");
+
+        let long = Distance::from_kilometers(42.195);
+        assert_eq!(long.display_in(&UnitSystem::Metric).to_string(), "42.2 km");
+        assert_eq!(long.display_in(&UnitSystem::Imperial).to_string(), "26.2 mi");
+    } // fn
+} // mod
@@ -107,6 +107,14 @@ impl<'a> Request<'a> {
 
         } // if
 
+        // Google only models traffic for a given departure time, so a traffic
+        // model cannot be set without one:
+        if let Some(traffic_model) = &self.traffic_model {
+            if self.departure_time.is_none() {
+                return Err(Error::TrafficModelRequiresDepartureTime(traffic_model.to_string()));
+            } // if
+        } // if
+
         // Indicate that the request passed validation.
         self.validated = true;
 
@@ -132,4 +132,15 @@ impl std::convert::From<&Waypoint> for String {
 
         } // match
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Waypoint {
+    /// Encodes a slice of `LatLng` coordinates into a `Waypoint::Polyline`
+    /// variant. The points are encoded with 5 decimal places of precision, as
+    /// expected by the Directions API.
+    pub fn from_points(points: &[LatLng]) -> Self {
+        Waypoint::Polyline(crate::polyline::encode(points, crate::polyline::Precision::Five))
+    } // fn
 } // impl
\ No newline at end of file
@@ -1,5 +1,6 @@
 //! A representation of distance as a numeric value and a display string.
 
+use crate::distance::Distance;
 use serde::{Deserialize, Serialize};
 
 /// A representation of distance as a numeric value and a display string.
@@ -9,6 +10,7 @@ pub struct DirectionsDistance {
     /// A string representation of the distance value, using the
     /// `with_unit_system()` specified in the request.
     pub text: String,
-    /// Indicates the distance in meters.
-    pub value: u32,
+    /// The distance. Google reports distances in whole meters, regardless of
+    /// the unit system of the `text`.
+    pub value: Distance,
 } // struct
\ No newline at end of file
@@ -1,5 +1,7 @@
 //! A representation of duration as a numeric value and a display string.
 
+use crate::duration::DisplayDuration;
+use crate::language::Language;
 use crate::serde::duration_to_seconds::duration_to_seconds;
 use crate::serde::seconds_to_duration::seconds_to_duration;
 use chrono::Duration;
@@ -14,4 +16,15 @@ pub struct DirectionsDuration {
     /// The duration in seconds.
     #[serde(deserialize_with = "seconds_to_duration", serialize_with = "duration_to_seconds")]
     pub value: Duration,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsDuration {
+    /// Returns a formatter for the duration in the given language. Google's
+    /// `text` is already in the language of the request, so this is for
+    /// showing a response in another language.
+    pub fn display(&self, language: Language) -> DisplayDuration {
+        DisplayDuration::new(self.value, language)
+    } // fn
+} // impl
@@ -0,0 +1,115 @@
+//! Provides GeoJSON export for the `Route`, `Leg` and `Step` structs, for use
+//! with the [geojson](https://crates.io/crates/geojson) crate. Each is
+//! exported as a `LineString` feature, built from the decoded step polylines,
+//! so that it can be rendered directly by web maps such as MapLibre.
+
+use crate::directions::response::{leg::Leg, route::Route, step::Step};
+use crate::distance::Distance;
+use crate::latlng::{geojson_conversions::to_line_string, LatLng};
+use crate::polyline::Error;
+use geojson::Feature;
+
+// -----------------------------------------------------------------------------
+
+impl Step {
+    /// Converts this step into a GeoJSON `LineString` feature.
+    ///
+    /// The feature has the following properties: `distance` in meters,
+    /// `duration` in seconds, `instructions` (as HTML), `travel_mode`, and
+    /// `maneuver` when Google provides one.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the step's polyline could not be decoded.
+    pub fn to_geojson(&self) -> Result<Feature, Error> {
+        let mut feature = Feature::from(to_line_string(&self.polyline.decode()?));
+        feature.set_property("distance", self.distance.value.meters());
+        feature.set_property("duration", self.duration.value.num_seconds());
+        feature.set_property("instructions", self.html_instructions.clone());
+        feature.set_property("travel_mode", String::from(&self.travel_mode));
+        if let Some(maneuver) = self.get_maneuver() {
+            feature.set_property("maneuver", maneuver);
+        } // if
+        Ok(feature)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Converts this leg into a GeoJSON `LineString` feature, made up of the
+    /// geometry of all of its steps.
+    ///
+    /// The feature has the following properties: `distance` in meters,
+    /// `duration` in seconds, `start_address`, `end_address`, and
+    /// `duration_in_traffic` in seconds when Google provides one.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn to_geojson(&self) -> Result<Feature, Error> {
+        let mut feature = Feature::from(to_line_string(&self.decode_steps()?));
+        feature.set_property("distance", self.distance.value.meters());
+        feature.set_property("duration", self.duration.value.num_seconds());
+        feature.set_property("start_address", self.start_address.clone());
+        feature.set_property("end_address", self.end_address.clone());
+        if let Some(duration_in_traffic) = &self.duration_in_traffic {
+            feature.set_property("duration_in_traffic", duration_in_traffic.value.num_seconds());
+        } // if
+        Ok(feature)
+    } // fn
+
+    /// Decodes and joins the polylines of every step in this leg. Each step
+    /// starts where the previous one ended, so shared points are skipped.
+    fn decode_steps(&self) -> Result<Vec<LatLng>, Error> {
+        let mut points: Vec<LatLng> = Vec::new();
+        for step in &self.steps {
+            for point in step.polyline.decode()? {
+                if points.last() != Some(&point) {
+                    points.push(point);
+                } // if
+            } // for
+        } // for
+        Ok(points)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Converts this route into a GeoJSON `LineString` feature, made up of the
+    /// geometry of all of its legs. The feature's bounding box is taken from
+    /// the route's `bounds`.
+    ///
+    /// The feature has the following properties: `summary`, total `distance`
+    /// in meters, total `duration` in seconds, `copyrights` and `warnings`.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn to_geojson(&self) -> Result<Feature, Error> {
+        let mut points: Vec<LatLng> = Vec::new();
+        for leg in &self.legs {
+            for point in leg.decode_steps()? {
+                if points.last() != Some(&point) {
+                    points.push(point);
+                } // if
+            } // for
+        } // for
+
+        let mut feature = Feature::from(to_line_string(&points));
+        feature.bbox = Some(self.bounds.to_geojson_bbox());
+        feature.set_property("summary", self.summary.clone());
+        feature.set_property(
+            "distance",
+            self.legs.iter().map(|leg| leg.distance.value).sum::<Distance>().meters(),
+        ); // set_property
+        feature.set_property(
+            "duration",
+            self.legs.iter().map(|leg| leg.duration.value.num_seconds()).sum::<i64>(),
+        ); // set_property
+        feature.set_property("copyrights", self.copyrights.clone());
+        feature.set_property("warnings", self.warnings.clone());
+        Ok(feature)
+    } // fn
+} // impl
@@ -0,0 +1,513 @@
+//! Contains the `Instruction` struct, which generates turn-by-turn
+//! instructions from a driving maneuver and road names, as text or as
+//! [SSML](https://www.w3.org/TR/speech-synthesis11/) for text-to-speech.
+
+use crate::directions::response::{
+    driving_maneuver::DrivingManeuver,
+    leg::Leg,
+    plain_instructions::{parse_html, Segment},
+    step::Step,
+}; // crate::directions::response
+use crate::language::Language;
+
+// -----------------------------------------------------------------------------
+
+/// A turn-by-turn instruction built from its parts rather than taken from
+/// Google's HTML. This gives consistent wording that is suitable for voice
+/// guidance.
+///
+/// Instructions can be worded in English, French, German and Spanish. Other
+/// languages are worded in English. Use `Step::plain_instructions` for text in
+/// any language that Google supports.
+///
+/// ## Example:
+///
+/// ```rust
+/// let instruction = Instruction {
+///     maneuver: Some(DrivingManeuver::TurnLeft),
+///     road: Some(String::from("Main St")),
+///     distance: Some(String::from("400 m")),
+///     ..Instruction::default()
+/// };
+///
+/// assert_eq!(instruction.to_text(&Language::English), "In 400 m, turn left onto Main St.");
+/// assert_eq!(instruction.to_text(&Language::German), "Nach 400 m links abbiegen auf Main St.");
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Instruction {
+    /// The action to take. `None` means to continue along the current road,
+    /// which is what Google's first step of a leg usually is.
+    pub maneuver: Option<DrivingManeuver>,
+
+    /// The road to turn onto or continue on.
+    pub road: Option<String>,
+
+    /// The road or place that the maneuver heads toward, for example on a
+    /// highway exit sign.
+    pub toward: Option<String>,
+
+    /// How far ahead the maneuver is, as display text such as "400 m" or
+    /// "0.2 mi". `None` announces the maneuver as happening now.
+    pub distance: Option<String>,
+
+    /// Notes to read after the instruction, such as "Destination will be on
+    /// the right".
+    pub notes: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Instruction {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the instruction as plain text, for example "In 400 m, turn left
+    /// onto Main St. Destination will be on the right."
+    ///
+    /// ## Arguments:
+    ///
+    /// * `language` ‧ The language to word the instruction in. Languages
+    /// other than English, French, German and Spanish are worded in English.
+    /// The road names and notes are used as they are.
+
+    pub fn to_text(&self, language: &Language) -> String {
+        let wording = Wording::for_language(language);
+        let (action, road, toward) = self.parts(wording);
+        let (before_distance, after_distance) = wording.distance();
+
+        let mut sentence = match &self.distance {
+            Some(distance) => format!("{before_distance}{distance}{after_distance}{}", lowercase_first(action)),
+            None => action.to_string(),
+        }; // match
+        if let Some((preposition, road)) = road {
+            sentence.push_str(&format!(" {preposition} {road}"));
+        } // if
+        if let Some(toward) = toward {
+            sentence.push_str(&format!(" {} {toward}", wording.toward()));
+        } // if
+
+        std::iter::once(sentence)
+            .chain(self.notes.iter().cloned())
+            .map(|sentence| end_sentence(&sentence))
+            .collect::<Vec<String>>()
+            .join(" ")
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the instruction as an SSML document for text-to-speech engines
+    /// such as Google Cloud Text-to-Speech and Amazon Polly. Road names are
+    /// read as addresses, and there is a short pause before each note.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `language` ‧ The language to word the instruction in. This is also
+    /// set as the document's `xml:lang`. Languages other than English, French,
+    /// German and Spanish are worded in English.
+
+    pub fn to_ssml(&self, language: &Language) -> String {
+        let wording = Wording::for_language(language);
+        let (action, road, toward) = self.parts(wording);
+        let (before_distance, after_distance) = wording.distance();
+
+        let mut ssml = format!("<speak xml:lang=\"{}\"><s>", wording.code());
+        match &self.distance {
+            Some(distance) => ssml.push_str(&format!(
+                "{}<say-as interpret-as=\"unit\">{}</say-as>{}{}",
+                escape(before_distance),
+                escape(distance),
+                escape(after_distance),
+                escape(&lowercase_first(action)),
+            )), // Some
+            None => ssml.push_str(&escape(action)),
+        } // match
+        if let Some((preposition, road)) = road {
+            ssml.push_str(&format!(" {preposition} <say-as interpret-as=\"address\">{}</say-as>", escape(road)));
+        } // if
+        if let Some(toward) = toward {
+            ssml.push_str(&format!(
+                " {} <say-as interpret-as=\"address\">{}</say-as>",
+                escape(wording.toward()),
+                escape(toward),
+            )); // push_str
+        } // if
+        ssml.push_str(".</s>");
+
+        for note in &self.notes {
+            ssml.push_str(&format!("<break time=\"500ms\"/><s>{}</s>", escape(&end_sentence(note))));
+        } // for
+
+        ssml.push_str("</speak>");
+        ssml
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the wording of the maneuver, the road along with the
+    /// preposition that joins it to the maneuver, and the place it heads
+    /// toward.
+
+    fn parts(&self, wording: Wording) -> (&'static str, Option<(&'static str, &String)>, Option<&String>) {
+        let (action, preposition) = wording.maneuver(self.maneuver.as_ref());
+        let road = self.road.as_ref().map(|road| (preposition, road));
+        (action, road, self.toward.as_ref())
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Instruction {
+    /// Formats the instruction as plain text in English. See
+    /// `Instruction::to_text`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_text(&Language::English))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The languages that instructions can be worded in.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Wording {
+    English,
+    French,
+    German,
+    Spanish,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Wording {
+    /// Returns the wording for a language, or English for languages that
+    /// aren't supported.
+    fn for_language(language: &Language) -> Self {
+        match language {
+            Language::French | Language::FrenchCanada => Wording::French,
+            Language::German => Wording::German,
+            Language::Spanish | Language::SpanishLatinAmerica => Wording::Spanish,
+            _ => Wording::English,
+        } // match
+    } // fn
+
+    /// Returns the language code for SSML's `xml:lang` attribute.
+    fn code(self) -> &'static str {
+        match self {
+            Wording::English => "en",
+            Wording::French => "fr",
+            Wording::German => "de",
+            Wording::Spanish => "es",
+        } // match
+    } // fn
+
+    /// Returns the text that goes before and after the distance to a
+    /// maneuver, as in "In 400 m, turn left".
+    fn distance(self) -> (&'static str, &'static str) {
+        match self {
+            Wording::English => ("In ", ", "),
+            Wording::French => ("Dans ", ", "),
+            Wording::German => ("Nach ", " "),
+            Wording::Spanish => ("En ", ", "),
+        } // match
+    } // fn
+
+    /// Returns the word that joins the place a maneuver heads toward.
+    fn toward(self) -> &'static str {
+        match self {
+            Wording::English => "toward",
+            Wording::French => "en direction de",
+            Wording::German => "Richtung",
+            Wording::Spanish => "en dirección a",
+        } // match
+    } // fn
+
+    /// Returns the wording of a maneuver and the preposition that joins a
+    /// road to it. `None` means to continue along the current road.
+    fn maneuver(self, maneuver: Option<&DrivingManeuver>) -> (&'static str, &'static str) {
+        match self {
+            Wording::English => match maneuver {
+                None => ("Continue", "on"),
+                Some(DrivingManeuver::Ferry) => ("Take the ferry", "to"),
+                Some(DrivingManeuver::FerryTrain) => ("Take the train ferry", "to"),
+                Some(DrivingManeuver::ForkLeft) => ("Keep left at the fork", "onto"),
+                Some(DrivingManeuver::ForkRight) => ("Keep right at the fork", "onto"),
+                Some(DrivingManeuver::KeepLeft) => ("Keep left", "onto"),
+                Some(DrivingManeuver::KeepRight) => ("Keep right", "onto"),
+                Some(DrivingManeuver::Merge) => ("Merge", "onto"),
+                Some(DrivingManeuver::Ramp) => ("Take the ramp", "onto"),
+                Some(DrivingManeuver::RampLeft) => ("Take the ramp on the left", "onto"),
+                Some(DrivingManeuver::RampRight) => ("Take the ramp on the right", "onto"),
+                Some(DrivingManeuver::RoundaboutLeft | DrivingManeuver::RoundaboutRight) =>
+                    ("At the roundabout, take the exit", "onto"),
+                Some(DrivingManeuver::Straight) => ("Continue straight", "onto"),
+                Some(DrivingManeuver::TurnLeft) => ("Turn left", "onto"),
+                Some(DrivingManeuver::TurnRight) => ("Turn right", "onto"),
+                Some(DrivingManeuver::TurnSharpLeft) => ("Turn sharp left", "onto"),
+                Some(DrivingManeuver::TurnSharpRight) => ("Turn sharp right", "onto"),
+                Some(DrivingManeuver::TurnSlightLeft) => ("Turn slightly left", "onto"),
+                Some(DrivingManeuver::TurnSlightRight) => ("Turn slightly right", "onto"),
+                Some(DrivingManeuver::UturnLeft | DrivingManeuver::UturnRight) => ("Make a U-turn", "onto"),
+            }, // English
+            Wording::French => match maneuver {
+                None => ("Continuer", "sur"),
+                Some(DrivingManeuver::Ferry) => ("Prendre le ferry", "vers"),
+                Some(DrivingManeuver::FerryTrain) => ("Prendre le train-ferry", "vers"),
+                Some(DrivingManeuver::ForkLeft) => ("Rester à gauche à l'embranchement", "sur"),
+                Some(DrivingManeuver::ForkRight) => ("Rester à droite à l'embranchement", "sur"),
+                Some(DrivingManeuver::KeepLeft) => ("Rester à gauche", "sur"),
+                Some(DrivingManeuver::KeepRight) => ("Rester à droite", "sur"),
+                Some(DrivingManeuver::Merge) => ("S'insérer", "sur"),
+                Some(DrivingManeuver::Ramp) => ("Prendre la bretelle", "vers"),
+                Some(DrivingManeuver::RampLeft) => ("Prendre la bretelle à gauche", "vers"),
+                Some(DrivingManeuver::RampRight) => ("Prendre la bretelle à droite", "vers"),
+                Some(DrivingManeuver::RoundaboutLeft | DrivingManeuver::RoundaboutRight) =>
+                    ("Au rond-point, prendre la sortie", "sur"),
+                Some(DrivingManeuver::Straight) => ("Continuer tout droit", "sur"),
+                Some(DrivingManeuver::TurnLeft) => ("Tourner à gauche", "sur"),
+                Some(DrivingManeuver::TurnRight) => ("Tourner à droite", "sur"),
+                Some(DrivingManeuver::TurnSharpLeft) => ("Tourner franchement à gauche", "sur"),
+                Some(DrivingManeuver::TurnSharpRight) => ("Tourner franchement à droite", "sur"),
+                Some(DrivingManeuver::TurnSlightLeft) => ("Tourner légèrement à gauche", "sur"),
+                Some(DrivingManeuver::TurnSlightRight) => ("Tourner légèrement à droite", "sur"),
+                Some(DrivingManeuver::UturnLeft | DrivingManeuver::UturnRight) => ("Faire demi-tour", "sur"),
+            }, // French
+            // German actions are lowercased after a distance, as in "Nach
+            // 400 m links abbiegen", so none of them may start with a noun:
+            Wording::German => match maneuver {
+                None => ("Weiterfahren", "auf"),
+                Some(DrivingManeuver::Ferry) => ("Die Fähre nehmen", "nach"),
+                Some(DrivingManeuver::FerryTrain) => ("Den Autozug nehmen", "nach"),
+                Some(DrivingManeuver::ForkLeft) => ("An der Gabelung links halten", "auf"),
+                Some(DrivingManeuver::ForkRight) => ("An der Gabelung rechts halten", "auf"),
+                Some(DrivingManeuver::KeepLeft) => ("Links halten", "auf"),
+                Some(DrivingManeuver::KeepRight) => ("Rechts halten", "auf"),
+                Some(DrivingManeuver::Merge) => ("Einfädeln", "auf"),
+                Some(DrivingManeuver::Ramp) => ("Die Ausfahrt nehmen", "auf"),
+                Some(DrivingManeuver::RampLeft) => ("Die linke Ausfahrt nehmen", "auf"),
+                Some(DrivingManeuver::RampRight) => ("Die rechte Ausfahrt nehmen", "auf"),
+                Some(DrivingManeuver::RoundaboutLeft | DrivingManeuver::RoundaboutRight) =>
+                    ("Im Kreisverkehr die Ausfahrt nehmen", "auf"),
+                Some(DrivingManeuver::Straight) => ("Geradeaus weiterfahren", "auf"),
+                Some(DrivingManeuver::TurnLeft) => ("Links abbiegen", "auf"),
+                Some(DrivingManeuver::TurnRight) => ("Rechts abbiegen", "auf"),
+                Some(DrivingManeuver::TurnSharpLeft) => ("Scharf links abbiegen", "auf"),
+                Some(DrivingManeuver::TurnSharpRight) => ("Scharf rechts abbiegen", "auf"),
+                Some(DrivingManeuver::TurnSlightLeft) => ("Leicht links abbiegen", "auf"),
+                Some(DrivingManeuver::TurnSlightRight) => ("Leicht rechts abbiegen", "auf"),
+                Some(DrivingManeuver::UturnLeft | DrivingManeuver::UturnRight) => ("Wenden", "auf"),
+            }, // German
+            Wording::Spanish => match maneuver {
+                None => ("Continúa", "por"),
+                Some(DrivingManeuver::Ferry) => ("Toma el ferry", "hacia"),
+                Some(DrivingManeuver::FerryTrain) => ("Toma el tren transbordador", "hacia"),
+                Some(DrivingManeuver::ForkLeft) => ("Mantente a la izquierda en la bifurcación", "hacia"),
+                Some(DrivingManeuver::ForkRight) => ("Mantente a la derecha en la bifurcación", "hacia"),
+                Some(DrivingManeuver::KeepLeft) => ("Mantente a la izquierda", "hacia"),
+                Some(DrivingManeuver::KeepRight) => ("Mantente a la derecha", "hacia"),
+                Some(DrivingManeuver::Merge) => ("Incorpórate", "a"),
+                Some(DrivingManeuver::Ramp) => ("Toma la salida", "hacia"),
+                Some(DrivingManeuver::RampLeft) => ("Toma la salida de la izquierda", "hacia"),
+                Some(DrivingManeuver::RampRight) => ("Toma la salida de la derecha", "hacia"),
+                Some(DrivingManeuver::RoundaboutLeft | DrivingManeuver::RoundaboutRight) =>
+                    ("En la rotonda, toma la salida", "hacia"),
+                Some(DrivingManeuver::Straight) => ("Sigue recto", "por"),
+                Some(DrivingManeuver::TurnLeft) => ("Gira a la izquierda", "hacia"),
+                Some(DrivingManeuver::TurnRight) => ("Gira a la derecha", "hacia"),
+                Some(DrivingManeuver::TurnSharpLeft) => ("Gira bruscamente a la izquierda", "hacia"),
+                Some(DrivingManeuver::TurnSharpRight) => ("Gira bruscamente a la derecha", "hacia"),
+                Some(DrivingManeuver::TurnSlightLeft) => ("Gira ligeramente a la izquierda", "hacia"),
+                Some(DrivingManeuver::TurnSlightRight) => ("Gira ligeramente a la derecha", "hacia"),
+                Some(DrivingManeuver::UturnLeft | DrivingManeuver::UturnRight) => ("Cambia de sentido", "hacia"),
+            }, // Spanish
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Step {
+    /// Builds an `Instruction` for this step from its maneuver and the road
+    /// names in its HTML instructions.
+    ///
+    /// Road names are found by the words that precede them, such as "onto"
+    /// and "toward", in English, French, German and Spanish. For directions
+    /// requested in other languages the road names are usually not found, and
+    /// the instruction has the maneuver and notes only.
+    ///
+    /// The instruction has no distance, since a maneuver is announced ahead
+    /// of time using the length of the _previous_ step. `Leg::instructions`
+    /// fills this in.
+    pub fn instruction(&self) -> Instruction {
+        let segments = parse_html(&self.html_instructions);
+
+        Instruction {
+            maneuver: self.maneuver.clone(),
+            road: bold_after(&segments, &["onto", "on", "sur", "auf", "por", "hacia"]),
+            toward: bold_after(&segments, &["toward", "towards", "en direction de", "richtung", "en dirección a", "dirección"]),
+            distance: None,
+            notes: self.plain_instructions().notes,
+        } // Instruction
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Builds an `Instruction` for each step of the leg. Each instruction's
+    /// distance is the length of the step before it, which is how far ahead
+    /// the maneuver is when the previous maneuver has just been made. The
+    /// first instruction has no distance.
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut previous_distance: Option<String> = None;
+
+        self.steps
+            .iter()
+            .map(|step| {
+                let instruction = Instruction {
+                    distance: previous_distance.take(),
+                    ..step.instruction()
+                }; // Instruction
+                previous_distance = Some(step.distance.text.clone());
+                instruction
+            }) // map
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the first bold text of the main instruction that directly follows
+/// one of the given words.
+
+fn bold_after(segments: &[Segment], words: &[&str]) -> Option<String> {
+    segments
+        .iter()
+        .filter(|segment| segment.note.is_none())
+        .collect::<Vec<&Segment>>()
+        .windows(2)
+        .find(|pair| {
+            let preceding = pair[0].text.trim_end().to_lowercase();
+            !pair[0].bold && pair[1].bold && words.iter().any(|word|
+                preceding == *word || preceding.ends_with(&format!(" {word}"))
+            ) // any
+        }) // find
+        .map(|pair| pair[1].text.split_whitespace().collect::<Vec<&str>>().join(" "))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Lowercases the first letter of a sentence so that it may follow a
+/// distance, as in "In 400 m, turn left".
+
+fn lowercase_first(text: &str) -> String {
+    let mut characters = text.chars();
+    match characters.next() {
+        Some(first) => first.to_lowercase().chain(characters).collect(),
+        None => String::new(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Adds a full stop to a sentence, unless it already ends with punctuation.
+
+fn end_sentence(sentence: &str) -> String {
+    if sentence.ends_with(['.', '!', '?']) {
+        sentence.to_string()
+    } else {
+        format!("{sentence}.")
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Escapes the characters that have a special meaning in SSML.
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn_left() -> Instruction {
+        Instruction {
+            maneuver: Some(DrivingManeuver::TurnLeft),
+            road: Some(String::from("Main St")),
+            toward: Some(String::from("Downtown")),
+            distance: Some(String::from("400 m")),
+            notes: vec![String::from("Destination will be on the right")],
+        } // Instruction
+    } // fn
+
+    #[test]
+    fn words_instructions_in_english() {
+        assert_eq!(
+            turn_left().to_text(&Language::English),
+            "In 400 m, turn left onto Main St toward Downtown. Destination will be on the right.",
+        );
+        assert_eq!(turn_left().to_string(), turn_left().to_text(&Language::English));
+        assert_eq!(Instruction::default().to_text(&Language::EnglishGreatBritain), "Continue.");
+    } // fn
+
+    #[test]
+    fn words_instructions_in_other_languages() {
+        assert_eq!(
+            turn_left().to_text(&Language::French),
+            "Dans 400 m, tourner à gauche sur Main St en direction de Downtown. Destination will be on the right.",
+        );
+        assert_eq!(
+            turn_left().to_text(&Language::German),
+            "Nach 400 m links abbiegen auf Main St Richtung Downtown. Destination will be on the right.",
+        );
+        let instruction = Instruction { distance: None, notes: vec![], ..turn_left() };
+        assert_eq!(
+            instruction.to_text(&Language::SpanishLatinAmerica),
+            "Gira a la izquierda hacia Main St en dirección a Downtown.",
+        );
+    } // fn
+
+    #[test]
+    fn words_unsupported_languages_in_english() {
+        assert_eq!(turn_left().to_text(&Language::Japanese), turn_left().to_text(&Language::English));
+    } // fn
+
+    #[test]
+    fn writes_ssml() {
+        let instruction = Instruction {
+            road: Some(String::from("Smith & Sons Rd")),
+            ..turn_left()
+        }; // Instruction
+        assert_eq!(
+            instruction.to_ssml(&Language::English),
+            "<speak xml:lang=\"en\"><s>In <say-as interpret-as=\"unit\">400 m</say-as>, turn left onto \
+            <say-as interpret-as=\"address\">Smith &amp; Sons Rd</say-as> toward \
+            <say-as interpret-as=\"address\">Downtown</say-as>.</s><break time=\"500ms\"/>\
+            <s>Destination will be on the right.</s></speak>",
+        );
+        assert_eq!(
+            Instruction::default().to_ssml(&Language::German),
+            "<speak xml:lang=\"de\"><s>Weiterfahren.</s></speak>",
+        );
+    } // fn
+
+    #[test]
+    fn finds_road_names_in_html() {
+        let segments = parse_html("Turn <b>left</b> onto <b>Main St</b> toward <b>Downtown</b>");
+        assert_eq!(bold_after(&segments, &["onto", "on"]), Some(String::from("Main St")));
+        assert_eq!(bold_after(&segments, &["toward"]), Some(String::from("Downtown")));
+
+        let segments = parse_html("Links abbiegen auf <b>Hauptstraße</b> Richtung <b>Zentrum</b>");
+        assert_eq!(bold_after(&segments, &["auf"]), Some(String::from("Hauptstraße")));
+        assert_eq!(bold_after(&segments, &["richtung"]), Some(String::from("Zentrum")));
+    } // fn
+} // mod
@@ -0,0 +1,214 @@
+//! Renders an itinerary in the style of a
+//! [GTFS](https://gtfs.org/schedule/reference/) feed, for tools that already
+//! understand transit schedules.
+
+use crate::csv::push_record;
+use crate::directions::{
+    response::{
+        itinerary::{segment::Ride, Itinerary},
+        transit_stop::TransitStop,
+    }, // crate::directions::response
+    vehicle_type::VehicleType,
+}; // crate::directions
+use chrono::{DateTime, NaiveDateTime};
+use chrono_tz::Tz;
+
+// -----------------------------------------------------------------------------
+
+/// The columns of the CSV produced by `Itinerary::to_gtfs_csv`. The names are
+/// taken from GTFS's `routes.txt`, `trips.txt`, `stops.txt` and
+/// `stop_times.txt` files.
+
+const COLUMNS: [&str; 16] = [
+    "trip_id",
+    "route_short_name",
+    "route_long_name",
+    "route_type",
+    "route_color",
+    "route_text_color",
+    "agency_name",
+    "trip_headsign",
+    "trip_short_name",
+    "stop_sequence",
+    "stop_name",
+    "stop_lat",
+    "stop_lon",
+    "arrival_time",
+    "departure_time",
+    "stop_timezone",
+]; // COLUMNS
+
+// -----------------------------------------------------------------------------
+
+impl Itinerary {
+    /// Formats the itinerary's rides as
+    /// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) CSV, using the
+    /// field names and formats of a GTFS feed flattened into one table.
+    ///
+    /// Each ride has two rows: one for the stop where the passenger boards,
+    /// and one for the stop where they get off. Rides are numbered from 1 in
+    /// the `trip_id` column. Walks, transfers and waits are implied by the
+    /// gaps between rides.
+    ///
+    /// As in GTFS, times are local to each stop and measured from midnight at
+    /// the start of the service day, which is the date of the first ride.
+    /// Times after midnight of the following day are written as 24:00:00 or
+    /// later.
+    pub fn to_gtfs_csv(&self) -> String {
+        let mut csv = String::new();
+        push_record(&mut csv, COLUMNS.iter().map(ToString::to_string));
+
+        let Some(service_day) = self.rides().next().map(|ride| ride
+            .departure
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default()
+        ) else {
+            return csv;
+        }; // let
+
+        for (index, ride) in self.rides().enumerate() {
+            let trip_id = (index + 1).to_string();
+            let stops = [
+                (1, &ride.departure_stop, ride.departure),
+                (u16::from(ride.num_stops) + 1, &ride.arrival_stop, ride.arrival),
+            ]; // stops
+            for (stop_sequence, stop, time) in stops {
+                push_record(&mut csv, stop_time(&trip_id, ride, stop_sequence, stop, time, service_day).into_iter());
+            } // for
+        } // for
+
+        csv
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl VehicleType {
+    /// Returns the GTFS
+    /// [`route_type`](https://gtfs.org/schedule/reference/#routestxt) for the
+    /// vehicle type. Types with no equivalent in the basic GTFS list use the
+    /// extended route types, such as 1700 (miscellaneous service) for
+    /// `VehicleType::Other`.
+    pub fn gtfs_route_type(&self) -> u16 {
+        match self {
+            VehicleType::Tram | VehicleType::MetroRail => 0,
+            VehicleType::Subway => 1,
+            VehicleType::Rail
+            | VehicleType::HeavyRail
+            | VehicleType::CommuterTrain
+            | VehicleType::HighSpeedTrain
+            | VehicleType::LongDistanceTrain => 2,
+            VehicleType::Bus | VehicleType::IntercityBus | VehicleType::ShareTaxi => 3,
+            VehicleType::Ferry => 4,
+            VehicleType::CableCar => 5,
+            VehicleType::GondolaLift => 6,
+            VehicleType::Funicular => 7,
+            VehicleType::Trolleybus => 11,
+            VehicleType::Monorail => 12,
+            VehicleType::Other => 1700,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the fields of one row of the GTFS CSV, in the order of `COLUMNS`.
+
+fn stop_time(
+    trip_id: &str,
+    ride: &Ride,
+    stop_sequence: u16,
+    stop: &TransitStop,
+    time: DateTime<Tz>,
+    service_day: NaiveDateTime,
+) -> [String; 16] {
+    let line = &ride.line;
+    let time_of_day = gtfs_time(time, service_day);
+
+    [
+        trip_id.to_string(),
+        line.short_name.clone().unwrap_or_default(),
+        line.name.clone().unwrap_or_default(),
+        line.vehicle.vehicle_type.gtfs_route_type().to_string(),
+        gtfs_color(line.color.as_deref()),
+        gtfs_color(line.text_color.as_deref()),
+        line.agencies.first().map(|agency| agency.name.clone()).unwrap_or_default(),
+        ride.headsign.clone(),
+        ride.trip_short_name.clone().unwrap_or_default(),
+        stop_sequence.to_string(),
+        stop.name.clone(),
+        stop.location.lat.to_string(),
+        stop.location.lng.to_string(),
+        time_of_day.clone(),
+        time_of_day,
+        time.timezone().name().to_string(),
+    ] // array
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Formats a time as GTFS's HH:MM:SS, counting hours from the start of the
+/// service day so that times after midnight are 24:00:00 or later.
+
+fn gtfs_time(time: DateTime<Tz>, service_day: NaiveDateTime) -> String {
+    let seconds = time.naive_local().signed_duration_since(service_day).num_seconds().max(0);
+    format!("{:02}:{:02}:{:02}", seconds / 3_600, seconds / 60 % 60, seconds % 60)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a colour such as "#1a2b3c" from Google into GTFS's six uppercase
+/// hexadecimal digits with no leading `#`.
+
+fn gtfs_color(color: Option<&str>) -> String {
+    color
+        .map(|color| color.trim_start_matches('#').to_ascii_uppercase())
+        .unwrap_or_default()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directions::response::itinerary::tests::{at, routes};
+    use chrono::Duration;
+
+    #[test]
+    fn writes_two_rows_for_each_ride() {
+        let csv = routes()[0].itinerary().to_gtfs_csv();
+        let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+        assert_eq!(rows, [
+            COLUMNS.join(","),
+            String::from("1,M1,Broadway Local,3,0039A6,FFFFFF,City Transit,Uptown,,1,Main St Station,40.72,-74,08:07:00,08:07:00,America/New_York"),
+            String::from("1,M1,Broadway Local,3,0039A6,FFFFFF,City Transit,Uptown,,7,Central Station,40.75,-73.99,08:22:00,08:22:00,America/New_York"),
+            String::from("2,A,Eighth Avenue Express,1,2850AD,FFFFFF,City Transit,Inwood,,1,Central Station,40.75,-73.99,08:22:00,08:22:00,America/New_York"),
+            String::from("2,A,Eighth Avenue Express,1,2850AD,FFFFFF,City Transit,Inwood,,5,Harbour,40.77,-73.98,08:35:00,08:35:00,America/New_York"),
+        ]); // assert_eq!
+    } // fn
+
+    #[test]
+    fn writes_only_the_header_without_rides() {
+        let mut route = routes()[0].clone();
+        route.legs[0].steps.retain(|step| step.transit_details.is_none());
+        assert_eq!(route.itinerary().to_gtfs_csv(), format!("{}\r\n", COLUMNS.join(",")));
+    } // fn
+
+    #[test]
+    fn counts_hours_past_midnight_from_the_service_day() {
+        let service_day = at(0, 0).naive_local();
+        assert_eq!(gtfs_time(at(8, 7), service_day), "08:07:00");
+        assert_eq!(gtfs_time(at(23, 50) + Duration::minutes(80), service_day), "25:10:00");
+    } // fn
+
+    #[test]
+    fn converts_colors_and_route_types() {
+        assert_eq!(gtfs_color(Some("#1a2b3c")), "1A2B3C");
+        assert_eq!(gtfs_color(None), "");
+        assert_eq!(VehicleType::Subway.gtfs_route_type(), 1);
+        assert_eq!(VehicleType::Tram.gtfs_route_type(), 0);
+        assert_eq!(VehicleType::Other.gtfs_route_type(), 1700);
+    } // fn
+} // mod
@@ -0,0 +1,299 @@
+//! Contains the `Itinerary` struct, which turns the transit details nested in
+//! a route's steps into an ordered list of walks, rides, transfers and waits.
+
+mod gtfs;
+pub mod segment;
+
+// -----------------------------------------------------------------------------
+
+use crate::directions::{
+    response::{
+        itinerary::segment::{Ride, Segment, Transfer, Wait, Walk},
+        route::Route,
+        transit_stop::TransitStop,
+        transit_time::TransitTime,
+    }, // crate::directions::response
+    travel_mode::TravelMode,
+}; // crate::directions
+use chrono::{DateTime, Duration};
+use chrono_tz::Tz;
+
+// -----------------------------------------------------------------------------
+
+/// A passenger's journey along a transit route, in the order that it happens.
+/// See `Route::itinerary`.
+///
+/// ## Example:
+///
+/// ```rust
+/// let itinerary = directions.routes[0].itinerary();
+///
+/// for segment in &itinerary.segments {
+///     match segment {
+///         Segment::Walk(walk) => println!("{}", walk.instructions),
+///         Segment::Ride(ride) => println!("{} towards {}", ride.line.short_name.as_deref().unwrap_or_default(), ride.headsign),
+///         Segment::Transfer(transfer) => println!("Transfer ({} min)", transfer.dwell().num_minutes()),
+///         Segment::Wait(wait) => println!("Wait {} min", wait.duration().num_minutes()),
+///     }
+/// }
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Itinerary {
+    /// The segments of the journey, in order.
+    pub segments: Vec<Segment>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Itinerary {
+    /// Returns the rides of the itinerary, in order.
+    pub fn rides(&self) -> impl Iterator<Item = &Ride> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Ride(ride) => Some(ride.as_ref()),
+            _ => None,
+        }) // filter_map
+    } // fn
+
+    /// Returns the total time spent waiting at stops, including the wait
+    /// before the first ride.
+    pub fn total_wait_time(&self) -> Duration {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Wait(wait) => Some(wait.duration()),
+                _ => None,
+            }) // filter_map
+            .fold(Duration::zero(), |total, duration| total + duration)
+    } // fn
+
+    /// Returns when the journey starts, which is usually when the passenger
+    /// starts walking to the first stop. Use `rides().next()` for the first
+    /// vehicle's departure.
+    pub fn first_departure(&self) -> Option<DateTime<Tz>> {
+        self.segments.iter().find_map(Segment::start)
+    } // fn
+
+    /// Returns when the journey ends, which is usually when the passenger
+    /// finishes walking from the last stop.
+    pub fn last_arrival(&self) -> Option<DateTime<Tz>> {
+        self.segments.iter().rev().find_map(Segment::end)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Builds the route's itinerary: the walks, rides, transfers and waits of
+    /// a transit route, in order, with the time of each.
+    ///
+    /// Walk times are worked out from the leg's departure time and the
+    /// duration of each step. A wait is added wherever a vehicle departs
+    /// after the passenger reaches its stop. A transfer is added after every
+    /// ride that is followed by another.
+    ///
+    /// Steps that are neither walking nor transit, which transit routes don't
+    /// have, are left out.
+    pub fn itinerary(&self) -> Itinerary {
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut clock: Option<DateTime<Tz>> = None;
+        // The index, arrival stop and arrival time of the most recent ride:
+        let mut previous_ride: Option<(usize, TransitStop, DateTime<Tz>)> = None;
+
+        for leg in &self.legs {
+            // Google provides a departure time for transit legs. Otherwise,
+            // count back from the first ride:
+            clock = leg.departure_time.as_ref().map(TransitTime::local_time).or(clock).or_else(|| {
+                let (index, details) = leg.steps
+                    .iter()
+                    .enumerate()
+                    .find_map(|(index, step)| step.transit_details.as_ref().map(|details| (index, details)))?;
+                Some(leg.steps[..index]
+                    .iter()
+                    .fold(details.departure_time.local_time(), |time, step| time - step.duration.value))
+            }); // or_else
+
+            for step in &leg.steps {
+                let Some(details) = &step.transit_details else {
+                    let arrival = clock.map(|time| time + step.duration.value);
+                    if step.travel_mode == TravelMode::Walking {
+                        segments.push(Segment::Walk(Walk {
+                            start_location: step.start_location.clone(),
+                            end_location: step.end_location.clone(),
+                            distance: step.distance.value,
+                            duration: step.duration.value,
+                            instructions: step.plain_instructions(),
+                            departure: clock,
+                            arrival,
+                        })); // push
+                    } // if
+                    clock = arrival;
+                    continue;
+                }; // let
+
+                let departure = details.departure_time.local_time();
+                let arrival = details.arrival_time.local_time();
+
+                if let Some((index, from_stop, previous_arrival)) = previous_ride.take() {
+                    segments.insert(index + 1, Segment::Transfer(Transfer {
+                        from_stop,
+                        to_stop: details.departure_stop.clone(),
+                        arrival: previous_arrival,
+                        departure,
+                    })); // insert
+                } // if
+
+                if let Some(start) = clock.filter(|time| *time < departure) {
+                    segments.push(Segment::Wait(Wait {
+                        stop: details.departure_stop.clone(),
+                        start,
+                        end: departure,
+                    })); // push
+                } // if
+
+                segments.push(Segment::Ride(Box::new(Ride {
+                    line: details.line.clone(),
+                    headsign: details.headsign.clone(),
+                    trip_short_name: details.trip_short_name.clone(),
+                    departure_stop: details.departure_stop.clone(),
+                    arrival_stop: details.arrival_stop.clone(),
+                    departure,
+                    arrival,
+                    num_stops: details.num_stops,
+                    headway: details.headway,
+                    distance: step.distance.value,
+                }))); // push
+
+                previous_ride = Some((segments.len() - 1, details.arrival_stop.clone(), arrival));
+                clock = Some(arrival);
+            } // for
+        } // for
+
+        Itinerary { segments }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directions::response::Response;
+    use chrono::TimeZone;
+    use chrono_tz::America::New_York;
+
+    /// The transit routes from `fixtures/transit.json`. The first has one leg
+    /// with a bus and a subway. The second has two legs with a bus, a tram and
+    /// a subway.
+    pub(super) fn routes() -> Vec<Route> {
+        include_str!("../fixtures/transit.json").parse::<Response>().unwrap().routes
+    } // fn
+
+    /// Returns a time on the morning of the fixture's trips.
+    pub(super) fn at(hour: u32, minute: u32) -> DateTime<Tz> {
+        New_York.with_ymd_and_hms(2026, 3, 20, hour, minute, 0).unwrap()
+    } // fn
+
+    fn kinds(itinerary: &Itinerary) -> Vec<&'static str> {
+        itinerary.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Walk(_) => "Walk",
+                Segment::Ride(_) => "Ride",
+                Segment::Transfer(_) => "Transfer",
+                Segment::Wait(_) => "Wait",
+            }) // map
+            .collect()
+    } // fn
+
+    /// When a segment starts and ends.
+    type Span = (Option<DateTime<Tz>>, Option<DateTime<Tz>>);
+
+    fn times(itinerary: &Itinerary) -> Vec<Span> {
+        itinerary.segments.iter().map(|segment| (segment.start(), segment.end())).collect()
+    } // fn
+
+    #[test]
+    fn orders_the_segments_of_a_route() {
+        let itinerary = routes()[0].itinerary();
+
+        assert_eq!(kinds(&itinerary), ["Walk", "Wait", "Ride", "Transfer", "Ride", "Walk"]);
+        assert_eq!(times(&itinerary), [
+            (Some(at(8, 0)), Some(at(8, 5))),
+            (Some(at(8, 5)), Some(at(8, 7))),
+            (Some(at(8, 7)), Some(at(8, 22))),
+            (Some(at(8, 22)), Some(at(8, 22))),
+            (Some(at(8, 22)), Some(at(8, 35))),
+            (Some(at(8, 35)), Some(at(8, 40))),
+        ]); // assert_eq!
+
+        let Segment::Transfer(transfer) = &itinerary.segments[3] else {
+            panic!("expected a transfer, found {:?}", itinerary.segments[3]);
+        }; // let
+        assert!(transfer.is_same_stop());
+        assert_eq!(transfer.dwell(), Duration::zero());
+
+        let rides: Vec<&str> = itinerary.rides().map(|ride| ride.headsign.as_str()).collect();
+        assert_eq!(rides, ["Uptown", "Inwood"]);
+        assert_eq!(itinerary.total_wait_time(), Duration::minutes(2));
+        assert_eq!(itinerary.first_departure(), Some(at(8, 0)));
+        assert_eq!(itinerary.last_arrival(), Some(at(8, 40)));
+    } // fn
+
+    #[test]
+    fn lists_the_walk_and_wait_of_a_transfer_after_it() {
+        let itinerary = routes()[1].itinerary();
+
+        assert_eq!(
+            kinds(&itinerary),
+            ["Walk", "Ride", "Transfer", "Walk", "Wait", "Ride", "Transfer", "Ride", "Walk"],
+        );
+
+        // The walk between the bus and the tram:
+        let Segment::Transfer(transfer) = &itinerary.segments[2] else {
+            panic!("expected a transfer, found {:?}", itinerary.segments[2]);
+        }; // let
+        assert!(!transfer.is_same_stop());
+        assert_eq!((transfer.arrival, transfer.departure), (at(8, 20), at(8, 25)));
+        assert_eq!(times(&itinerary)[3..5], [
+            (Some(at(8, 20)), Some(at(8, 22))),
+            (Some(at(8, 22)), Some(at(8, 25))),
+        ]); // assert_eq!
+
+        // The change from the tram to the subway happens between the legs:
+        let Segment::Transfer(transfer) = &itinerary.segments[6] else {
+            panic!("expected a transfer, found {:?}", itinerary.segments[6]);
+        }; // let
+        assert!(transfer.is_same_stop());
+        assert_eq!(transfer.dwell(), Duration::minutes(3));
+
+        assert_eq!(itinerary.total_wait_time(), Duration::minutes(3));
+        assert_eq!(itinerary.first_departure(), Some(at(8, 2)));
+        assert_eq!(itinerary.last_arrival(), Some(at(8, 45)));
+    } // fn
+
+    #[test]
+    fn counts_back_from_the_first_ride_without_a_departure_time() {
+        let mut route = routes()[0].clone();
+        route.legs[0].departure_time = None;
+        let itinerary = route.itinerary();
+
+        // The walk ends as the bus departs, so there is no wait:
+        assert_eq!(kinds(&itinerary), ["Walk", "Ride", "Transfer", "Ride", "Walk"]);
+        assert_eq!(times(&itinerary)[0], (Some(at(8, 2)), Some(at(8, 7))));
+        assert_eq!(itinerary.last_arrival(), Some(at(8, 40)));
+    } // fn
+
+    #[test]
+    fn leaves_walks_untimed_without_a_departure_time_or_rides() {
+        let mut route = routes()[0].clone();
+        route.legs[0].departure_time = None;
+        route.legs[0].steps.retain(|step| step.transit_details.is_none());
+        let itinerary = route.itinerary();
+
+        assert_eq!(kinds(&itinerary), ["Walk", "Walk"]);
+        assert_eq!(times(&itinerary), [(None, None), (None, None)]);
+        assert_eq!(itinerary.first_departure(), None);
+        assert_eq!(itinerary.total_wait_time(), Duration::zero());
+    } // fn
+} // mod
@@ -0,0 +1,209 @@
+//! Contains the `Segment` enum and the `Walk`, `Ride`, `Transfer` and `Wait`
+//! structs that make up an itinerary.
+
+use crate::directions::response::{
+    plain_instructions::PlainInstructions,
+    transit_line::TransitLine,
+    transit_stop::TransitStop,
+}; // crate::directions::response
+use crate::distance::Distance;
+use crate::latlng::LatLng;
+use chrono::{DateTime, Duration};
+use chrono_tz::Tz;
+
+// -----------------------------------------------------------------------------
+
+/// Walking between two places, for example from the origin to the first stop
+/// or between the stops of a transfer.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Walk {
+    /// Where the walk starts.
+    pub start_location: LatLng,
+
+    /// Where the walk ends.
+    pub end_location: LatLng,
+
+    /// The walking distance.
+    pub distance: Distance,
+
+    /// How long Google expects the walk to take.
+    pub duration: Duration,
+
+    /// The walking instructions, for example "Walk to Union Station".
+    pub instructions: PlainInstructions,
+
+    /// When the walk starts. This is `None` only when the route has no
+    /// departure time and no ride to estimate one from.
+    pub departure: Option<DateTime<Tz>>,
+
+    /// When the walk ends. This is `None` when `departure` is.
+    pub arrival: Option<DateTime<Tz>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// Riding a transit vehicle from one stop to another.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ride {
+    /// The transit line, including its name, colour, agencies and vehicle
+    /// type.
+    pub line: TransitLine,
+
+    /// The direction to travel in, as it is marked on the vehicle or at the
+    /// departure stop. This is often the terminus station.
+    pub headsign: String,
+
+    /// The text that appears in schedules and sign boards to identify a
+    /// transit trip to passengers, for example a train number.
+    pub trip_short_name: Option<String>,
+
+    /// The stop to board at.
+    pub departure_stop: TransitStop,
+
+    /// The stop to get off at.
+    pub arrival_stop: TransitStop,
+
+    /// When the vehicle departs, in the time zone of the departure stop.
+    pub departure: DateTime<Tz>,
+
+    /// When the vehicle arrives, in the time zone of the arrival stop.
+    pub arrival: DateTime<Tz>,
+
+    /// The number of stops from the departure stop to the arrival stop. This
+    /// includes the arrival stop, but not the departure stop.
+    pub num_stops: u8,
+
+    /// The expected time between departures from the departure stop, in
+    /// seconds.
+    pub headway: Option<u16>,
+
+    /// The distance travelled on the vehicle.
+    pub distance: Distance,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Ride {
+    /// Returns the time spent on the vehicle.
+    pub fn duration(&self) -> Duration {
+        self.arrival.signed_duration_since(self.departure)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Changing from one transit vehicle to another. A transfer is listed
+/// directly after the ride that it changes from, and is followed by the walk
+/// and wait segments that make it up, if there are any.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    /// The stop where the previous ride ends.
+    pub from_stop: TransitStop,
+
+    /// The stop where the next ride starts. This is often the same stop as
+    /// `from_stop`.
+    pub to_stop: TransitStop,
+
+    /// When the previous ride arrives.
+    pub arrival: DateTime<Tz>,
+
+    /// When the next ride departs.
+    pub departure: DateTime<Tz>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Transfer {
+    /// Returns the dwell time: the time between getting off the previous
+    /// vehicle and boarding the next one, including any walking.
+    pub fn dwell(&self) -> Duration {
+        self.departure.signed_duration_since(self.arrival)
+    } // fn
+
+    /// Returns whether the passenger stays at the same stop, as opposed to
+    /// walking to another one.
+    pub fn is_same_stop(&self) -> bool {
+        self.from_stop == self.to_stop
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Waiting at a stop for a transit vehicle to depart.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Wait {
+    /// The stop that the passenger waits at.
+    pub stop: TransitStop,
+
+    /// When the wait starts.
+    pub start: DateTime<Tz>,
+
+    /// When the wait ends, which is when the next ride departs.
+    pub end: DateTime<Tz>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Wait {
+    /// Returns how long the wait is.
+    pub fn duration(&self) -> Duration {
+        self.end.signed_duration_since(self.start)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// One part of an itinerary.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Segment {
+    /// Walking between two places.
+    Walk(Walk),
+    /// Riding a transit vehicle. The ride is boxed because it is much larger
+    /// than the other segments.
+    Ride(Box<Ride>),
+    /// Changing from one transit vehicle to another.
+    Transfer(Transfer),
+    /// Waiting at a stop.
+    Wait(Wait),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Segment {
+    /// Returns when the segment starts, if it is known. A transfer starts when
+    /// the previous ride arrives.
+    pub fn start(&self) -> Option<DateTime<Tz>> {
+        match self {
+            Segment::Walk(walk) => walk.departure,
+            Segment::Ride(ride) => Some(ride.departure),
+            Segment::Transfer(transfer) => Some(transfer.arrival),
+            Segment::Wait(wait) => Some(wait.start),
+        } // match
+    } // fn
+
+    /// Returns when the segment ends, if it is known. A transfer ends when the
+    /// next ride departs.
+    pub fn end(&self) -> Option<DateTime<Tz>> {
+        match self {
+            Segment::Walk(walk) => walk.arrival,
+            Segment::Ride(ride) => Some(ride.arrival),
+            Segment::Transfer(transfer) => Some(transfer.departure),
+            Segment::Wait(wait) => Some(wait.end),
+        } // match
+    } // fn
+
+    /// Returns how long the segment takes.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Segment::Walk(walk) => walk.duration,
+            Segment::Ride(ride) => ride.duration(),
+            Segment::Transfer(transfer) => transfer.dwell(),
+            Segment::Wait(wait) => wait.duration(),
+        } // match
+    } // fn
+} // impl
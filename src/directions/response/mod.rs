@@ -6,11 +6,18 @@ pub mod directions_distance;
 pub mod directions_duration;
 pub mod driving_maneuver;
 pub mod geocoded_waypoint;
+#[cfg(feature = "geojson")]
+mod geojson_conversions;
 pub mod geocoder_status;
+pub mod instruction;
+pub mod itinerary;
 pub mod leg;
 pub mod overview_polyline;
+pub mod plain_instructions;
 pub mod polyline;
 pub mod route;
+pub mod route_path;
+pub mod route_totals;
 pub mod status;
 pub mod step;
 pub mod transit_agency;
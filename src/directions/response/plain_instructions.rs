@@ -0,0 +1,324 @@
+//! Contains the `PlainInstructions` struct, which holds a step's instructions
+//! with the HTML markup removed.
+
+use crate::directions::response::step::Step;
+
+// -----------------------------------------------------------------------------
+
+/// A step's instructions as plain text, for places that can't display HTML
+/// such as SMS messages and GPS devices.
+///
+/// Google appends notes such as "Destination will be on the right" or "Toll
+/// road" to the instructions in `<div>` elements. These are split out into
+/// `notes` so that they can be shown, or spoken, separately.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PlainInstructions {
+    /// The main instruction, for example "Turn left onto Main St".
+    pub instruction: String,
+
+    /// Any notes that follow the main instruction, for example "Destination
+    /// will be on the right".
+    pub notes: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PlainInstructions {
+    /// Formats the instruction and its notes as a single line of text, with
+    /// each note as a separate sentence.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let sentences: Vec<&str> = std::iter::once(self.instruction.as_str())
+            .chain(self.notes.iter().map(String::as_str))
+            .filter(|sentence| !sentence.is_empty())
+            .collect();
+        write!(f, "{}", sentences.join(". "))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Step {
+    /// Returns the step's instructions with the HTML markup removed and the
+    /// notes split out. HTML entities such as `&amp;` are decoded. The text is
+    /// in the language that was requested from Google.
+    pub fn plain_instructions(&self) -> PlainInstructions {
+        let segments = parse_html(&self.html_instructions);
+
+        let instruction = collapse_whitespace(
+            &segments
+                .iter()
+                .filter(|segment| segment.note.is_none())
+                .map(|segment| segment.text.as_str())
+                .collect::<String>()
+        ); // collapse_whitespace
+
+        let note_count = segments
+            .iter()
+            .filter_map(|segment| segment.note)
+            .max()
+            .map_or(0, |note| note + 1);
+
+        let notes = (0..note_count)
+            .map(|note| collapse_whitespace(
+                &segments
+                    .iter()
+                    .filter(|segment| segment.note == Some(note))
+                    .map(|segment| segment.text.as_str())
+                    .collect::<String>()
+            )) // map
+            .filter(|note| !note.is_empty())
+            .collect();
+
+        PlainInstructions { instruction, notes }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// A run of text from Google's HTML instructions that has the same
+/// formatting throughout.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Segment {
+    /// The text, with HTML entities decoded.
+    pub(crate) text: String,
+    /// Whether the text was in a `<b>` element. Google uses these for
+    /// directions and road names.
+    pub(crate) bold: bool,
+    /// The index of the `<div>` note that the text belongs to, or `None` for
+    /// the main instruction.
+    pub(crate) note: Option<usize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Splits Google's HTML instructions into runs of text. Only the handful of
+/// elements that Google uses are understood: `<b>` marks bold text, `<div>`
+/// starts a note, and `<wbr>` is dropped. Any other tag is removed.
+
+pub(crate) fn parse_html(html: &str) -> Vec<Segment> {
+
+    let mut segments = vec![Segment::default()];
+    let mut bold_depth = 0_usize;
+    let mut div_depth = 0_usize;
+    let mut note_count = 0_usize;
+    let mut characters = html.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '<' {
+            if let Some(segment) = segments.last_mut() {
+                segment.text.push(character);
+            } // if
+            continue;
+        } // if
+
+        // Read the tag's name, ignoring its attributes:
+        let tag: String = characters.by_ref().take_while(|character| *character != '>').collect();
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|character: char| !character.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("b" | "strong", false) => bold_depth += 1,
+            ("b" | "strong", true) => bold_depth = bold_depth.saturating_sub(1),
+            ("div", false) => {
+                div_depth += 1;
+                // Only a top-level `<div>` starts a new note. Nested ones are
+                // part of the same note, but still separate words:
+                if div_depth == 1 {
+                    note_count += 1;
+                } else if let Some(segment) = segments.last_mut() {
+                    segment.text.push(' ');
+                } // if
+            }, // div
+            ("div", true) => {
+                div_depth = div_depth.saturating_sub(1);
+                if let Some(segment) = segments.last_mut() {
+                    segment.text.push(' ');
+                } // if
+            }, // div
+            ("br" | "p", _) => if let Some(segment) = segments.last_mut() {
+                segment.text.push(' ');
+            }, // br
+            _ => {},
+        } // match
+
+        let bold = bold_depth > 0;
+        let note = (div_depth > 0).then(|| note_count - 1);
+        if segments.last().is_some_and(|segment| segment.bold != bold || segment.note != note) {
+            segments.push(Segment { text: String::new(), bold, note });
+        } // if
+    } // while
+
+    segments
+        .into_iter()
+        .filter(|segment| !segment.text.is_empty())
+        .map(|segment| Segment { text: decode_entities(&segment.text), ..segment })
+        .collect()
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes the named HTML entities that appear in Google's instructions, and
+/// all numeric entities. Unrecognized entities are left as they are.
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .map(|end| &rest[1..end])
+            .filter(|entity| entity.len() <= 8);
+
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        }); // and_then
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }, // Some
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }, // _
+        } // match
+    } // while
+
+    decoded.push_str(rest);
+    decoded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Trims the text and replaces each run of whitespace with a single space.
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directions::response::Response;
+
+    /// The first step of the driving fixture, with its instructions replaced.
+    fn step(html_instructions: &str) -> Step {
+        let mut step = include_str!("fixtures/driving.json")
+            .parse::<Response>()
+            .unwrap()
+            .routes
+            .remove(0)
+            .legs
+            .remove(0)
+            .steps
+            .remove(0);
+        step.html_instructions = html_instructions.to_string();
+        step
+    } // fn
+
+    fn plain(instruction: &str, notes: &[&str]) -> PlainInstructions {
+        PlainInstructions {
+            instruction: instruction.to_string(),
+            notes: notes.iter().map(ToString::to_string).collect(),
+        } // PlainInstructions
+    } // fn
+
+    #[test]
+    fn splits_out_notes() {
+        let instructions = step(
+            "Turn <b>left</b> onto <b>Main St</b>\
+            <div style=\"font-size:0.9em\">Destination will be on the right</div>"
+        ).plain_instructions();
+        assert_eq!(instructions, plain("Turn left onto Main St", &["Destination will be on the right"]));
+        assert_eq!(instructions.to_string(), "Turn left onto Main St. Destination will be on the right");
+
+        let instructions = step(
+            "Take the ramp onto <b>I-90 E</b>\
+            <div style=\"font-size:0.9em\">Toll road</div>\
+            <div style=\"font-size:0.9em\">Entering Indiana</div>"
+        ).plain_instructions();
+        assert_eq!(instructions, plain("Take the ramp onto I-90 E", &["Toll road", "Entering Indiana"]));
+
+        assert_eq!(step("Head <b>north</b>").plain_instructions(), plain("Head north", &[]));
+        assert_eq!(step("").plain_instructions().to_string(), "");
+    } // fn
+
+    #[test]
+    fn nested_divs_belong_to_one_note() {
+        let instructions = step(
+            "Continue onto <b>I-65 S</b>\
+            <div style=\"font-size:0.9em\">Partial toll road<div>Entering Indiana</div></div>"
+        ).plain_instructions();
+        assert_eq!(instructions, plain("Continue onto I-65 S", &["Partial toll road Entering Indiana"]));
+    } // fn
+
+    #[test]
+    fn decodes_entities_and_drops_tags() {
+        let instructions = step(
+            "Turn <b>right</b> onto <b>Church St</b> &amp; <b>O&#39;Farrell&nbsp;St</b>"
+        ).plain_instructions();
+        assert_eq!(instructions.instruction, "Turn right onto Church St & O'Farrell St");
+
+        let instructions = step(
+            "Take exit <b>41</b> toward <b>I-95/<wbr/>Downtown</b> at \
+            <span class=\"location\">Pier&nbsp;39</span><br>then <i>merge</i>"
+        ).plain_instructions();
+        assert_eq!(instructions.instruction, "Take exit 41 toward I-95/Downtown at Pier 39 then merge");
+    } // fn
+
+    #[test]
+    fn parses_bold_runs() {
+        let segments = parse_html("Turn <b>left</b> onto <B>Main&nbsp;St</B><div>Toll <b>road</b></div>");
+        let runs: Vec<(&str, bool, Option<usize>)> = segments
+            .iter()
+            .map(|segment| (segment.text.as_str(), segment.bold, segment.note))
+            .collect();
+        assert_eq!(runs, vec![
+            ("Turn ", false, None),
+            ("left", true, None),
+            (" onto ", false, None),
+            ("Main St", true, None),
+            ("Toll ", false, Some(0)),
+            ("road", true, Some(0)),
+            (" ", false, Some(0)),
+        ]); // assert_eq!
+    } // fn
+
+    #[test]
+    fn decodes_known_entities_only() {
+        assert_eq!(decode_entities("Fish &amp; Chips"), "Fish & Chips");
+        assert_eq!(decode_entities("&lt;b&gt; &quot;A&quot; &apos;B&#39;"), "<b> \"A\" 'B'");
+        assert_eq!(decode_entities("&#x2192; &#X2192; &#8594;"), "→ → →");
+        assert_eq!(decode_entities("AT&T"), "AT&T");
+        assert_eq!(decode_entities("&amp"), "&amp");
+        assert_eq!(decode_entities("&copy; &#99999999;"), "&copy; &#99999999;");
+        assert_eq!(decode_entities("a &verylongentity; b"), "a &verylongentity; b");
+    } // fn
+} // mod
@@ -1,12 +1,68 @@
 //! An [encoded polyline representation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
 //! of the route.
 
+use crate::latlng::LatLng;
+use crate::polyline::{Error, Precision};
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+
 /// An [encoded polyline representation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
 /// of the route.
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Polyline {
     pub points: String,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Polyline {
+    /// Decodes the encoded polyline into a `Vec` of `LatLng` coordinates. The
+    /// Directions API always encodes polylines with 5 decimal places of
+    /// precision.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the `points` string is not a valid encoded
+    /// polyline.
+    pub fn decode(&self) -> Result<Vec<LatLng>, Error> {
+        crate::polyline::decode(&self.points, Precision::Five)
+    } // fn
+
+    /// Decodes the encoded polyline into a `Vec` of `LatLng` coordinates using
+    /// the specified precision.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the `points` string is not a valid encoded
+    /// polyline.
+    pub fn decode_with_precision(&self, precision: Precision) -> Result<Vec<LatLng>, Error> {
+        crate::polyline::decode(&self.points, precision)
+    } // fn
+
+    /// Encodes a slice of `LatLng` coordinates into a `Polyline` using 5
+    /// decimal places of precision.
+    pub fn encode(points: &[LatLng]) -> Self {
+        Polyline { points: crate::polyline::encode(points, Precision::Five) }
+    } // fn
+
+    /// Encodes a slice of `LatLng` coordinates into a `Polyline` using the
+    /// specified precision.
+    pub fn encode_with_precision(points: &[LatLng], precision: Precision) -> Self {
+        Polyline { points: crate::polyline::encode(points, precision) }
+    } // fn
+
+    /// Returns a simplified copy of this polyline, where every removed point
+    /// was within `tolerance` meters of the simplified path. See
+    /// `google_maps::polyline::douglas_peucker` for more information.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the `points` string is not a valid encoded
+    /// polyline.
+    pub fn simplify(&self, tolerance: f64) -> Result<Self, Error> {
+        let points = crate::polyline::douglas_peucker(&self.decode()?, tolerance);
+        Ok(Polyline::encode(&points))
+    } // fn
+} // impl
\ No newline at end of file
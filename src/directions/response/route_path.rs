@@ -0,0 +1,456 @@
+//! Contains the `RoutePath` struct and its associated methods. A `RoutePath`
+//! flattens the step-by-step polylines of a Directions API `Route` into a
+//! single path that can be measured and queried by distance or time, for
+//! example to track a driver's progress and update their ETA.
+
+use crate::directions::response::{leg::Leg, route::Route};
+use crate::distance::Distance;
+use crate::latlng::LatLng;
+use crate::polyline::Error;
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// A single vertex of a `RoutePath`, along with how far along the route and
+/// how long into the trip it is reached.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathVertex {
+    /// The location of this vertex.
+    pub location: LatLng,
+    /// The distance travelled from the start of the route to this vertex.
+    pub distance: Distance,
+    /// The time elapsed from the start of the route to this vertex.
+    pub elapsed: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The point on a `RoutePath` that is closest to some other location, such as
+/// a vehicle's last reported position.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutePosition {
+    /// The closest location on the route.
+    pub location: LatLng,
+    /// How far the location is from the route.
+    pub distance_off_route: Distance,
+    /// The distance from the start of the route to `location`.
+    pub distance_along: Distance,
+    /// The expected time elapsed from the start of the route to `location`.
+    pub elapsed: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The distance and time that remain between some position and the end of a
+/// `RoutePath`.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteRemaining {
+    /// The distance left to travel.
+    pub distance: Distance,
+    /// The expected time left to travel.
+    pub duration: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A `Route`'s step polylines decoded into one continuous path, with the
+/// cumulative distance and expected elapsed time at every vertex.
+///
+/// Distances are measured along the decoded geometry using the haversine
+/// formula. Each step's duration is spread over its vertices in proportion to
+/// distance. When a leg reports a `duration_in_traffic`, its steps' durations
+/// are scaled so that the leg's total matches it.
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RoutePath {
+    vertices: Vec<PathVertex>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RoutePath {
+    /// Builds a `RoutePath` by decoding the polyline of every step of every
+    /// leg in the route.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn try_from_route(route: &Route) -> Result<RoutePath, Error> {
+        let mut path = RoutePath::default();
+        for leg in &route.legs {
+            path.push_leg(leg)?;
+        } // for
+        Ok(path)
+    } // fn
+
+    /// Builds a `RoutePath` by decoding the polyline of every step in a single
+    /// leg.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn try_from_leg(leg: &Leg) -> Result<RoutePath, Error> {
+        let mut path = RoutePath::default();
+        path.push_leg(leg)?;
+        Ok(path)
+    } // fn
+
+    /// Appends a leg's steps to the end of the path.
+    fn push_leg(&mut self, leg: &Leg) -> Result<(), Error> {
+        // Steps only report durations without traffic. Scale them so the leg
+        // adds up to its duration in traffic, when Google provides one:
+        let leg_seconds = duration_to_seconds(leg.duration.value);
+        let traffic_scale = match &leg.duration_in_traffic {
+            Some(in_traffic) if leg_seconds > 0.0 =>
+                duration_to_seconds(in_traffic.value) / leg_seconds,
+            _ => 1.0,
+        }; // match
+
+        for step in &leg.steps {
+            let points = step.polyline.decode()?;
+            let step_seconds = duration_to_seconds(step.duration.value) * traffic_scale;
+            self.push_step(&points, step_seconds);
+        } // for
+
+        Ok(())
+    } // fn
+
+    /// Appends a step's decoded points to the end of the path, spreading the
+    /// step's duration over its segments in proportion to their length.
+    fn push_step(&mut self, points: &[LatLng], step_seconds: f64) {
+        let (mut distance, mut seconds) = self.vertices
+            .last()
+            .map_or((0.0, 0.0), |last| (last.distance.meters(), duration_to_seconds(last.elapsed)));
+
+        // Each step starts where the previous one ended, so the shared vertex
+        // is skipped:
+        let skip = match (self.vertices.last(), points.first()) {
+            (Some(last), Some(first)) if last.location == *first => 1,
+            _ => 0,
+        }; // match
+
+        let step_length: f64 = points
+            .windows(2)
+            .map(|pair| pair[0].haversine_distance(&pair[1]))
+            .sum();
+
+        let mut previous = self.vertices.last().map(|last| last.location.clone());
+
+        for (index, point) in points.iter().enumerate().skip(skip) {
+            if let Some(previous) = &previous {
+                let segment_length = previous.haversine_distance(point);
+                distance += segment_length;
+                // A gap between this step and the previous one is not part of
+                // the step's length, so it is not given any of its time:
+                if index > 0 && step_length > 0.0 {
+                    seconds += step_seconds * segment_length / step_length;
+                } // if
+            } // if
+            self.vertices.push(PathVertex {
+                location: point.clone(),
+                distance: Distance::from_meters(distance),
+                elapsed: seconds_to_duration(seconds),
+            }); // push
+            previous = Some(point.clone());
+        } // for
+
+        // A step with no length (for example, a turn in place) still takes
+        // time. That time is added at its final vertex:
+        if step_length <= 0.0 && step_seconds > 0.0 {
+            if let Some(last) = self.vertices.last_mut() {
+                last.elapsed = seconds_to_duration(seconds + step_seconds);
+            } // if
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RoutePath {
+    /// Returns the vertices of the path, in order.
+    pub fn vertices(&self) -> &[PathVertex] {
+        &self.vertices
+    } // fn
+
+    /// Returns the total length of the path.
+    pub fn length(&self) -> Distance {
+        self.vertices.last().map_or(Distance::ZERO, |last| last.distance)
+    } // fn
+
+    /// Returns the total expected time to travel the path.
+    pub fn duration(&self) -> Duration {
+        self.vertices.last().map_or_else(Duration::zero, |last| last.elapsed)
+    } // fn
+
+    /// Returns the location `distance` along the path. Distances before the
+    /// start or past the end of the path are clamped to its ends.
+    ///
+    /// Returns `None` if the path is empty or `distance` is not a number.
+    pub fn position_at(&self, distance: Distance) -> Option<LatLng> {
+        let meters = distance.meters();
+        if meters.is_nan() {
+            return None;
+        } // if
+        let index = self.vertices.partition_point(|vertex| vertex.distance.meters() < meters);
+        self.interpolate_at(index, |vertex| vertex.distance.meters(), meters)
+    } // fn
+
+    /// Returns the expected location after `elapsed` time on the path. Times
+    /// before the start or past the end of the trip are clamped to its ends.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn position_at_time(&self, elapsed: Duration) -> Option<LatLng> {
+        let seconds = duration_to_seconds(elapsed);
+        let index = self.vertices
+            .partition_point(|vertex| duration_to_seconds(vertex.elapsed) < seconds);
+        self.interpolate_at(index, |vertex| duration_to_seconds(vertex.elapsed), seconds)
+    } // fn
+
+    /// Returns the point on the path that is closest to `location`, how far
+    /// `location` is from the path, and how far along the path that point is.
+    /// This can be used to detect a vehicle that has left its route.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn closest_point(&self, location: &LatLng) -> Option<RoutePosition> {
+        let first = self.vertices.first()?;
+
+        let mut closest = RoutePosition {
+            location: first.location.clone(),
+            distance_off_route: Distance::from_meters(location.haversine_distance(&first.location)),
+            distance_along: first.distance,
+            elapsed: first.elapsed,
+        }; // RoutePosition
+
+        for pair in self.vertices.windows(2) {
+            let (start, end) = (&pair[0], &pair[1]);
+            let fraction = project_onto_segment(location, &start.location, &end.location);
+            let Ok(candidate) = start.location.interpolate(&end.location, fraction) else {
+                continue;
+            }; // let
+            let distance_off_route = Distance::from_meters(location.haversine_distance(&candidate));
+            if distance_off_route < closest.distance_off_route {
+                let start_seconds = duration_to_seconds(start.elapsed);
+                let end_seconds = duration_to_seconds(end.elapsed);
+                closest = RoutePosition {
+                    location: candidate,
+                    distance_off_route,
+                    distance_along: start.distance + (end.distance - start.distance) * fraction,
+                    elapsed: seconds_to_duration(
+                        start_seconds + (end_seconds - start_seconds) * fraction
+                    ),
+                }; // RoutePosition
+            } // if
+        } // for
+
+        Some(closest)
+    } // fn
+
+    /// Returns the distance and expected time remaining from the point on the
+    /// path closest to `location` to the end of the path.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn remaining_from(&self, location: &LatLng) -> Option<RouteRemaining> {
+        let position = self.closest_point(location)?;
+        Some(RouteRemaining {
+            distance: (self.length() - position.distance_along).max(Distance::ZERO),
+            duration: (self.duration() - position.elapsed).max(Duration::zero()),
+        }) // RouteRemaining
+    } // fn
+
+    /// Interpolates between the vertex before `index` and the vertex at
+    /// `index`, using `measure` (distance or time) to find how far `target` is
+    /// between them.
+    fn interpolate_at(
+        &self,
+        index: usize,
+        measure: impl Fn(&PathVertex) -> f64,
+        target: f64,
+    ) -> Option<LatLng> {
+        let last = self.vertices.last()?;
+        if index == 0 {
+            return self.vertices.first().map(|first| first.location.clone());
+        } // if
+        let Some(end) = self.vertices.get(index) else {
+            return Some(last.location.clone());
+        }; // let
+        let start = &self.vertices[index - 1];
+        let span = measure(end) - measure(start);
+        let fraction = if span > 0.0 { (target - measure(start)) / span } else { 1.0 };
+        start.location.interpolate(&end.location, fraction.clamp(0.0, 1.0)).ok()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Decodes this route's step polylines into a `RoutePath`. See `RoutePath`
+    /// for how distances and times are assigned to each vertex.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn path(&self) -> Result<RoutePath, Error> {
+        RoutePath::try_from_route(self)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns how far along the segment from `start` to `end` the point closest
+/// to `location` is, from `0.0` to `1.0`. The segment is projected onto a
+/// flat plane centered on `location`, which is accurate for the short
+/// segments that make up a route.
+
+fn project_onto_segment(location: &LatLng, start: &LatLng, end: &LatLng) -> f64 {
+    let scale = location.lat_radians().cos();
+    let project = |point: &LatLng| {
+        let mut delta_lng = point.lng_radians() - location.lng_radians();
+        // Segments that cross the antimeridian must not wrap the long way
+        // around the globe:
+        if delta_lng > std::f64::consts::PI {
+            delta_lng -= 2.0 * std::f64::consts::PI;
+        } else if delta_lng < -std::f64::consts::PI {
+            delta_lng += 2.0 * std::f64::consts::PI;
+        } // if
+        (delta_lng * scale, point.lat_radians() - location.lat_radians())
+    }; // project
+    let (ax, ay) = project(start);
+    let (bx, by) = project(end);
+    let (dx, dy) = (bx - ax, by - ay);
+    let length_squared = dx * dx + dy * dy;
+    if length_squared > 0.0 {
+        ((-ax * dx - ay * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Converts a `chrono::Duration` into fractional seconds.
+fn duration_to_seconds(duration: Duration) -> f64 {
+    duration.num_milliseconds() as f64 / 1_000.0
+} // fn
+
+/// Converts fractional seconds into a `chrono::Duration`, to the nearest
+/// millisecond.
+fn seconds_to_duration(seconds: f64) -> Duration {
+    Duration::milliseconds((seconds * 1_000.0).round() as i64)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directions::response::{polyline::Polyline, Response};
+
+    /// A point on the equator. Each 0.01° of longitude is about 1,112 meters.
+    fn east(lng: f64) -> LatLng {
+        LatLng::try_from_f64(0.0, lng).unwrap()
+    } // fn
+
+    fn path(steps: &[(&[f64], i64)]) -> RoutePath {
+        let mut path = RoutePath::default();
+        for (lngs, seconds) in steps {
+            let points: Vec<LatLng> = lngs.iter().map(|lng| east(*lng)).collect();
+            path.push_step(&points, *seconds as f64);
+        } // for
+        path
+    } // fn
+
+    fn elapsed(path: &RoutePath) -> Vec<i64> {
+        path.vertices().iter().map(|vertex| vertex.elapsed.num_seconds()).collect()
+    } // fn
+
+    fn assert_meters(actual: Distance, expected: f64) {
+        assert!((actual.meters() - expected).abs() < 1.0, "{} is not {expected} m", actual.meters());
+    } // fn
+
+    #[test]
+    fn contiguous_steps_share_a_vertex() {
+        let path = path(&[(&[0.0, 0.01, 0.02], 100), (&[0.02, 0.03], 60)]);
+        assert_eq!(path.vertices().len(), 4);
+        assert_eq!(elapsed(&path), vec![0, 50, 100, 160]);
+        assert_meters(path.length(), 3_335.8);
+        assert_eq!(path.duration(), Duration::seconds(160));
+    } // fn
+
+    #[test]
+    fn gaps_between_steps_take_no_time() {
+        let path = path(&[(&[0.0, 0.01], 100), (&[0.02, 0.03], 60)]);
+        assert_eq!(path.vertices().len(), 4);
+        // The gap from 0.01° to 0.02° is measured, but takes no time:
+        assert_eq!(elapsed(&path), vec![0, 100, 100, 160]);
+        assert_meters(path.length(), 3_335.8);
+        assert_eq!(path.duration(), Duration::seconds(160));
+    } // fn
+
+    #[test]
+    fn zero_length_steps_still_take_time() {
+        let path = path(&[(&[0.0, 0.01], 100), (&[0.01, 0.01], 30), (&[0.01], 15), (&[0.01, 0.02], 100)]);
+        // The time spent turning in place is added to a second vertex at the
+        // same location, so the vehicle waits there:
+        assert_eq!(elapsed(&path), vec![0, 100, 145, 245]);
+        assert_eq!(path.position_at_time(Duration::seconds(120)), Some(east(0.01)));
+        assert_meters(path.length(), 2_223.9);
+        assert_eq!(path.duration(), Duration::seconds(245));
+    } // fn
+
+    #[test]
+    fn finds_positions_by_distance_and_time() {
+        let path = path(&[(&[0.0, 0.01], 100), (&[0.01, 0.02], 300)]);
+
+        let halfway = path.position_at(path.length() / 2.0).unwrap();
+        assert!(halfway.haversine_distance(&east(0.01)) < 1.0);
+        assert_eq!(path.position_at(Distance::from_meters(-5.0)), Some(east(0.0)));
+        assert_eq!(path.position_at(Distance::from_meters(1.0e9)), Some(east(0.02)));
+        assert_eq!(path.position_at(Distance::from_meters(f64::NAN)), None);
+
+        // Half of the second step's time is spent on the first half of it:
+        let later = path.position_at_time(Duration::seconds(250)).unwrap();
+        assert!(later.haversine_distance(&east(0.015)) < 1.0);
+        assert_eq!(path.position_at_time(Duration::hours(1)), Some(east(0.02)));
+        assert_eq!(RoutePath::default().position_at_time(Duration::zero()), None);
+    } // fn
+
+    #[test]
+    fn measures_progress_from_a_nearby_location() {
+        let path = path(&[(&[0.0, 0.01], 100), (&[0.01, 0.02], 300)]);
+
+        // About 111 meters north of the middle of the second step:
+        let driver = LatLng::try_from_f64(0.001, 0.015).unwrap();
+        let position = path.closest_point(&driver).unwrap();
+        assert!(position.location.haversine_distance(&east(0.015)) < 1.0);
+        assert_meters(position.distance_off_route, 111.2);
+        assert_meters(position.distance_along, 1_667.9);
+        assert_eq!(position.elapsed.num_seconds(), 250);
+
+        let remaining = path.remaining_from(&driver).unwrap();
+        assert_meters(remaining.distance, 556.0);
+        assert_eq!(remaining.duration.num_seconds(), 150);
+
+        assert_eq!(RoutePath::default().remaining_from(&driver), None);
+    } // fn
+
+    #[test]
+    fn scales_steps_to_the_duration_in_traffic() {
+        let mut route = include_str!("fixtures/driving.json")
+            .parse::<Response>()
+            .unwrap()
+            .routes
+            .remove(0);
+        for step in &mut route.legs[0].steps {
+            step.polyline = Polyline::encode(&[step.start_location.clone(), step.end_location.clone()]);
+        } // for
+
+        // The leg takes 3 hours without traffic and 3 hours 36 minutes with
+        // it, so its 30 minute first step takes 36 minutes:
+        let path = route.path().unwrap();
+        assert_eq!(elapsed(&path), vec![0, 36 * 60, 216 * 60]);
+        assert_eq!(path.duration(), Duration::minutes(216));
+    } // fn
+} // mod
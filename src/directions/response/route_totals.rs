@@ -0,0 +1,319 @@
+//! Contains the `RouteTotals` struct, which adds up a route's legs and steps,
+//! and the `RouteCriterion` enum used to choose between alternative routes.
+
+use crate::directions::{
+    response::{route::Route, transit_fare::TransitFare, Response},
+    travel_mode::TravelMode,
+}; // crate::directions
+use crate::distance::Distance;
+use chrono::Duration;
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+
+/// The distance and time spent using one travel mode along a route, for
+/// example the walking portion of a transit route.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ModeTotals {
+    /// The distance travelled in this mode.
+    pub distance: Distance,
+
+    /// The time spent travelling in this mode.
+    pub duration: Duration,
+
+    /// The number of steps that use this mode.
+    pub steps: usize,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for ModeTotals {
+    /// Returns a reasonable default value for the `ModeTotals` struct.
+    fn default() -> Self {
+        ModeTotals {
+            distance: Distance::ZERO,
+            duration: Duration::zero(),
+            steps: 0,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The totals for a whole route, across all of its legs.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouteTotals {
+    /// The total distance of the route.
+    pub distance: Distance,
+
+    /// The total duration of the route, without traffic.
+    pub duration: Duration,
+
+    /// The total duration of the route in traffic. This is `None` unless at
+    /// least one leg has a traffic estimate. Legs without one count with
+    /// their regular duration.
+    pub duration_in_traffic: Option<Duration>,
+
+    /// The distance and duration for each travel mode used by the route's
+    /// steps. Driving routes only have `TravelMode::Driving`, while transit
+    /// routes usually have both `TravelMode::Walking` and
+    /// `TravelMode::Transit`.
+    pub modes: BTreeMap<TravelMode, ModeTotals>,
+
+    /// The number of transit vehicles boarded.
+    pub rides: usize,
+
+    /// The number of times a passenger changes from one transit vehicle to
+    /// another within a leg. This is one less than the number of rides in
+    /// each leg, added up across the legs. A leg boundary is a planned stop at
+    /// a waypoint, so it isn't counted as a transfer.
+    pub transfers: usize,
+
+    /// The total fare for the route. Google provides this for the whole
+    /// route, and only for transit routes where every fare is known.
+    pub fare: Option<TransitFare>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RouteTotals {
+    /// Returns the totals for one travel mode. Modes that the route doesn't
+    /// use have zero distance and duration.
+    pub fn mode(&self, travel_mode: &TravelMode) -> ModeTotals {
+        self.modes.get(travel_mode).cloned().unwrap_or_default()
+    } // fn
+
+    /// Returns the total duration in traffic if there is one, and the regular
+    /// duration otherwise.
+    pub fn duration_in_traffic_or_duration(&self) -> Duration {
+        self.duration_in_traffic.unwrap_or(self.duration)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Adds up the route's legs and steps into distance, duration, travel mode
+    /// and transfer totals.
+    pub fn totals(&self) -> RouteTotals {
+        let mut modes: BTreeMap<TravelMode, ModeTotals> = BTreeMap::new();
+
+        for step in self.legs.iter().flat_map(|leg| &leg.steps) {
+            let mode = modes.entry(step.travel_mode.clone()).or_default();
+            mode.distance += step.distance.value;
+            mode.duration += step.duration.value;
+            mode.steps += 1;
+        } // for
+
+        let rides = modes.get(&TravelMode::Transit).map_or(0, |transit| transit.steps);
+
+        let transfers = self.legs
+            .iter()
+            .map(|leg| leg.steps
+                .iter()
+                .filter(|step| step.travel_mode == TravelMode::Transit)
+                .count()
+                .saturating_sub(1)
+            ) // map
+            .sum();
+
+        let duration_in_traffic = self.legs
+            .iter()
+            .any(|leg| leg.duration_in_traffic.is_some())
+            .then(|| self.legs
+                .iter()
+                .map(|leg| leg.duration_in_traffic.as_ref().unwrap_or(&leg.duration).value)
+                .fold(Duration::zero(), |total, duration| total + duration)
+            ); // then
+
+        RouteTotals {
+            distance: self.legs.iter().map(|leg| leg.distance.value).sum(),
+            duration: self.legs
+                .iter()
+                .fold(Duration::zero(), |total, leg| total + leg.duration.value),
+            duration_in_traffic,
+            modes,
+            rides,
+            transfers,
+            fare: self.fare.clone(),
+        } // RouteTotals
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// How to choose the best of several alternative routes. See
+/// `Response::best_route_by`.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RouteCriterion {
+    /// The route with the shortest duration. Durations in traffic are used
+    /// when Google provided them.
+    Fastest,
+    /// The route with the shortest distance. Ties go to the fastest of the
+    /// tied routes.
+    Shortest,
+    /// The route with the fewest transit transfers. Ties go to the fastest of
+    /// the tied routes.
+    FewestTransfers,
+    /// The route with the shortest walking distance. Ties go to the fastest of
+    /// the tied routes.
+    LeastWalking,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the best route by the given criterion, or `None` if there are
+    /// no routes. Request alternatives with `with_alternatives(true)` to give
+    /// this more than one route to choose from.
+    ///
+    /// When routes tie, the one that Google listed first is returned, since
+    /// Google lists its recommended route first.
+    pub fn best_route_by(&self, criterion: RouteCriterion) -> Option<&Route> {
+        self.routes
+            .iter()
+            .min_by_key(|route| {
+                let totals = route.totals();
+                let fastest = totals.duration_in_traffic_or_duration();
+                match criterion {
+                    RouteCriterion::Fastest => (0, Distance::ZERO, fastest),
+                    RouteCriterion::Shortest => (0, totals.distance, fastest),
+                    RouteCriterion::FewestTransfers => (totals.transfers, Distance::ZERO, fastest),
+                    RouteCriterion::LeastWalking => (0, totals.mode(&TravelMode::Walking).distance, fastest),
+                } // match
+            }) // min_by_key
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directions::response::{directions_duration::DirectionsDuration, transit_currency::TransitCurrency};
+    use rust_decimal::Decimal;
+
+    /// Two transit alternatives from the same origin to the same destination,
+    /// which take 40 minutes each. The first has one leg with a bus and a
+    /// subway. The second has two legs with a bus, a tram and a subway.
+    fn response() -> Response {
+        include_str!("fixtures/transit.json").parse().unwrap()
+    } // fn
+
+    #[test]
+    fn adds_up_a_single_leg_route() {
+        let totals = response().routes[0].totals();
+
+        assert_eq!(totals.distance, Distance::from_meters(9_550.0));
+        assert_eq!(totals.duration, Duration::minutes(40));
+        assert_eq!(totals.duration_in_traffic, None);
+        assert_eq!(totals.mode(&TravelMode::Walking), ModeTotals {
+            distance: Distance::from_meters(750.0),
+            duration: Duration::minutes(10),
+            steps: 2,
+        }); // assert_eq!
+        assert_eq!(totals.mode(&TravelMode::Transit), ModeTotals {
+            distance: Distance::from_meters(8_800.0),
+            duration: Duration::minutes(28),
+            steps: 2,
+        }); // assert_eq!
+        assert_eq!(totals.mode(&TravelMode::Driving), ModeTotals::default());
+        assert_eq!((totals.rides, totals.transfers), (2, 1));
+
+        let fare = totals.fare.unwrap();
+        assert_eq!(fare.currency, TransitCurrency::UnitedStatesDollar);
+        assert_eq!(fare.value, Decimal::new(290, 2));
+    } // fn
+
+    #[test]
+    fn counts_transfers_across_legs() {
+        let totals = response().routes[1].totals();
+
+        assert_eq!(totals.distance, Distance::from_meters(9_950.0));
+        assert_eq!(totals.duration, Duration::minutes(40));
+        assert_eq!(totals.mode(&TravelMode::Walking).distance, Distance::from_meters(450.0));
+        assert_eq!(totals.mode(&TravelMode::Transit).steps, 3);
+        // The change from the tram to the subway happens at the waypoint
+        // between the legs, so only the bus to the tram is a transfer:
+        assert_eq!((totals.rides, totals.transfers), (3, 1));
+        assert_eq!(totals.fare, None);
+    } // fn
+
+    #[test]
+    fn counts_no_transfers_without_rides() {
+        let mut route = response().routes[0].clone();
+        route.legs[0].steps.retain(|step| step.travel_mode == TravelMode::Walking);
+        let totals = route.totals();
+        assert_eq!((totals.rides, totals.transfers), (0, 0));
+    } // fn
+
+    #[test]
+    fn uses_the_duration_in_traffic_where_there_is_one() {
+        let mut route = response().routes[1].clone();
+        assert_eq!(route.totals().duration_in_traffic_or_duration(), Duration::minutes(40));
+
+        // The second leg has no duration in traffic, so its regular duration
+        // is added to the first leg's:
+        route.legs[0].duration_in_traffic = Some(DirectionsDuration {
+            text: String::from("41 mins"),
+            value: Duration::minutes(41),
+        }); // Some
+        let totals = route.totals();
+        assert_eq!(totals.duration, Duration::minutes(40));
+        assert_eq!(totals.duration_in_traffic, Some(Duration::minutes(50)));
+        assert_eq!(totals.duration_in_traffic_or_duration(), Duration::minutes(50));
+    } // fn
+
+    #[test]
+    fn picks_the_best_route_by_each_criterion() {
+        let response = response();
+        let best = |criterion| response.best_route_by(criterion).map(|route| route.legs.len());
+
+        assert_eq!(best(RouteCriterion::Shortest), Some(1));
+        // Both routes have one transfer and take 40 minutes, so the first
+        // route wins:
+        assert_eq!(best(RouteCriterion::FewestTransfers), Some(1));
+        assert_eq!(best(RouteCriterion::LeastWalking), Some(2));
+    } // fn
+
+    #[test]
+    fn picks_the_first_route_when_the_key_ties() {
+        // Both routes take 40 minutes:
+        let mut response = response();
+        assert_eq!(response.best_route_by(RouteCriterion::Fastest), Some(&response.routes[0]));
+
+        response.routes.reverse();
+        assert_eq!(response.best_route_by(RouteCriterion::Fastest), Some(&response.routes[0]));
+    } // fn
+
+    #[test]
+    fn breaks_transfer_ties_by_duration() {
+        let mut response = response();
+        let mut slower = response.routes[0].clone();
+        slower.legs[0].duration.value = Duration::minutes(45);
+        response.routes = vec![slower, response.routes[0].clone()];
+
+        assert_eq!(response.best_route_by(RouteCriterion::FewestTransfers), Some(&response.routes[1]));
+        assert_eq!(response.best_route_by(RouteCriterion::Fastest), Some(&response.routes[1]));
+    } // fn
+
+    #[test]
+    fn breaks_distance_ties_by_duration() {
+        let mut response = response();
+        let mut slower = response.routes[0].clone();
+        slower.legs[0].duration.value = Duration::minutes(45);
+        response.routes = vec![slower, response.routes[0].clone()];
+
+        assert_eq!(response.best_route_by(RouteCriterion::Shortest), Some(&response.routes[1]));
+    } // fn
+
+    #[test]
+    fn returns_no_route_without_routes() {
+        let mut response = response();
+        response.routes.clear();
+        assert_eq!(response.best_route_by(RouteCriterion::Fastest), None);
+    } // fn
+} // mod
@@ -1,7 +1,7 @@
 //! A representation of time as a Date object, a localized string, and a time
 //! zone.
 
-use chrono::{naive::serde::ts_seconds, NaiveDateTime};
+use chrono::{naive::serde::ts_seconds, DateTime, NaiveDateTime, TimeZone};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
@@ -20,4 +20,31 @@ pub struct TransitTime {
     /// The time of this departure or arrival.
     #[serde(with = "ts_seconds")]
     pub value: NaiveDateTime,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TransitTime {
+    /// Returns the time in the time zone of the transit stop, which is the
+    /// time shown on its timetable.
+    pub fn local_time(&self) -> DateTime<Tz> {
+        self.time_zone.from_utc_datetime(&self.value)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_to_local_time() {
+        let time: TransitTime = serde_json::from_str(
+            r#"{"text":"8:07 AM","time_zone":"America/New_York","value":1774008420}"#
+        ).unwrap();
+        let local = time.local_time();
+        assert_eq!(local.format("%Y-%m-%d %H:%M %Z").to_string(), "2026-03-20 08:07 EDT");
+        assert_eq!(local.timestamp(), 1_774_008_420);
+    } // fn
+} // mod
@@ -0,0 +1,136 @@
+//! Arithmetic operators for the `Distance` struct.
+
+use crate::distance::Distance;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+// -----------------------------------------------------------------------------
+
+impl Add for Distance {
+    type Output = Distance;
+    /// Adds two distances.
+    fn add(self, other: Distance) -> Distance {
+        Distance::from_meters(self.meters() + other.meters())
+    } // fn
+} // impl
+
+impl AddAssign for Distance {
+    /// Adds a distance to this one.
+    fn add_assign(&mut self, other: Distance) {
+        *self = *self + other;
+    } // fn
+} // impl
+
+impl Sub for Distance {
+    type Output = Distance;
+    /// Subtracts one distance from another. The result is negative if the
+    /// other distance is longer.
+    fn sub(self, other: Distance) -> Distance {
+        Distance::from_meters(self.meters() - other.meters())
+    } // fn
+} // impl
+
+impl SubAssign for Distance {
+    /// Subtracts a distance from this one.
+    fn sub_assign(&mut self, other: Distance) {
+        *self = *self - other;
+    } // fn
+} // impl
+
+impl Neg for Distance {
+    type Output = Distance;
+    /// Negates the distance.
+    fn neg(self) -> Distance {
+        Distance::from_meters(-self.meters())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Mul<f64> for Distance {
+    type Output = Distance;
+    /// Scales the distance by a factor.
+    fn mul(self, factor: f64) -> Distance {
+        Distance::from_meters(self.meters() * factor)
+    } // fn
+} // impl
+
+impl Mul<Distance> for f64 {
+    type Output = Distance;
+    /// Scales the distance by a factor.
+    fn mul(self, distance: Distance) -> Distance {
+        distance * self
+    } // fn
+} // impl
+
+impl Div<f64> for Distance {
+    type Output = Distance;
+    /// Divides the distance by a divisor.
+    fn div(self, divisor: f64) -> Distance {
+        Distance::from_meters(self.meters() / divisor)
+    } // fn
+} // impl
+
+impl Div for Distance {
+    type Output = f64;
+    /// Returns the ratio of two distances.
+    fn div(self, other: Distance) -> f64 {
+        self.meters() / other.meters()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::iter::Sum for Distance {
+    /// Adds up the distances.
+    fn sum<I: Iterator<Item = Distance>>(iter: I) -> Distance {
+        iter.fold(Distance::ZERO, Add::add)
+    } // fn
+} // impl
+
+impl<'a> std::iter::Sum<&'a Distance> for Distance {
+    /// Adds up the distances.
+    fn sum<I: Iterator<Item = &'a Distance>>(iter: I) -> Distance {
+        iter.copied().sum()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meters(meters: f64) -> Distance {
+        Distance::from_meters(meters)
+    } // fn
+
+    #[test]
+    fn adds_and_subtracts() {
+        assert_eq!(meters(1_000.0) + meters(250.0), meters(1_250.0));
+        assert_eq!(meters(1_000.0) - meters(1_250.0), meters(-250.0));
+        assert_eq!(-meters(3.0), meters(-3.0));
+        assert_eq!(-Distance::ZERO, Distance::ZERO);
+
+        let mut total = meters(10.0);
+        total += meters(5.0);
+        total -= meters(20.0);
+        assert_eq!(total, meters(-5.0));
+    } // fn
+
+    #[test]
+    fn scales_and_divides() {
+        assert_eq!(meters(10.0) * 2.5, meters(25.0));
+        assert_eq!(2.5 * meters(10.0), meters(25.0));
+        assert_eq!(meters(10.0) / 4.0, meters(2.5));
+        assert!((meters(10.0) / meters(4.0) - 2.5).abs() < f64::EPSILON);
+        assert_eq!(Distance::ZERO * -1.0, Distance::ZERO);
+    } // fn
+
+    #[test]
+    fn sums() {
+        let distances = [meters(1.0), meters(2.0), meters(3.5)];
+        assert_eq!(distances.iter().sum::<Distance>(), meters(6.5));
+        assert_eq!(distances.into_iter().sum::<Distance>(), meters(6.5));
+        assert_eq!(Vec::<Distance>::new().into_iter().sum::<Distance>(), Distance::ZERO);
+    } // fn
+} // mod
@@ -0,0 +1,132 @@
+//! Contains the `DisplayDistance` struct, which formats a `Distance` in a
+//! chosen unit.
+
+use crate::distance::{unit::DistanceUnit, Distance};
+
+// -----------------------------------------------------------------------------
+
+/// Formats a distance in a unit, for example "1.2 km" or "850 ft". Create one
+/// with `Distance::display`, `Distance::display_metric` or
+/// `Distance::display_imperial`.
+///
+/// Short units (meters and feet) are shown as whole numbers. Long units are
+/// shown to one decimal place under 100, and as whole numbers above it, which
+/// is how Google formats distances. A precision given in the format string,
+/// as in `format!("{:.2}", distance)`, overrides this.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DisplayDistance {
+    /// The distance to format.
+    pub distance: Distance,
+
+    /// The unit to format it in.
+    pub unit: DistanceUnit,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for DisplayDistance {
+    /// Formats the distance as a number followed by the unit's symbol.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let value = self.distance.to_unit(self.unit);
+        let precision = f.precision().unwrap_or(match self.unit {
+            DistanceUnit::Meters | DistanceUnit::Feet => 0,
+            _ if value.abs() >= 100.0 => 0,
+            _ => 1,
+        }); // unwrap_or
+        write!(f, "{value:.precision$} {}", self.unit)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Distance {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a formatter for the distance in the given unit.
+
+    pub fn display(&self, unit: DistanceUnit) -> DisplayDistance {
+        DisplayDistance { distance: *self, unit }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a formatter for the distance in meters, or in kilometers for
+    /// distances that round to a kilometer or more.
+
+    pub fn display_metric(&self) -> DisplayDistance {
+        if self.meters().abs().round() < DistanceUnit::Kilometers.meters() {
+            self.display(DistanceUnit::Meters)
+        } else {
+            self.display(DistanceUnit::Kilometers)
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a formatter for the distance in feet, or in miles for distances
+    /// of a tenth of a mile or more.
+
+    pub fn display_imperial(&self) -> DisplayDistance {
+        if self.miles().abs() < 0.1 {
+            self.display(DistanceUnit::Feet)
+        } else {
+            self.display(DistanceUnit::Miles)
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Distance {
+    /// Formats the distance in metric units. See `Distance::display_metric`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.display_metric(), f)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_metric_units() {
+        assert_eq!(Distance::ZERO.to_string(), "0 m");
+        assert_eq!(Distance::from_meters(850.4).to_string(), "850 m");
+        assert_eq!(Distance::from_meters(999.4).to_string(), "999 m");
+        // 999.5 meters would round to "1000 m", so kilometers are used:
+        assert_eq!(Distance::from_meters(999.5).to_string(), "1.0 km");
+        assert_eq!(Distance::from_kilometers(12.34).to_string(), "12.3 km");
+        assert_eq!(Distance::from_kilometers(123.4).to_string(), "123 km");
+        assert_eq!(Distance::from_meters(-1_500.0).to_string(), "-1.5 km");
+    } // fn
+
+    #[test]
+    fn displays_imperial_units() {
+        assert_eq!(Distance::from_feet(500.0).display_imperial().to_string(), "500 ft");
+        assert_eq!(Distance::from_miles(0.1).display_imperial().to_string(), "0.1 mi");
+        assert_eq!(Distance::from_miles(26.2).display_imperial().to_string(), "26.2 mi");
+        assert_eq!(Distance::from_miles(250.0).display_imperial().to_string(), "250 mi");
+    } // fn
+
+    #[test]
+    fn displays_each_unit() {
+        let distance = Distance::from_nautical_miles(1.0);
+        assert_eq!(distance.display(DistanceUnit::Meters).to_string(), "1852 m");
+        assert_eq!(distance.display(DistanceUnit::Kilometers).to_string(), "1.9 km");
+        assert_eq!(distance.display(DistanceUnit::Feet).to_string(), "6076 ft");
+        assert_eq!(distance.display(DistanceUnit::Miles).to_string(), "1.2 mi");
+        assert_eq!(distance.display(DistanceUnit::NauticalMiles).to_string(), "1.0 nmi");
+    } // fn
+
+    #[test]
+    fn format_precision_overrides_the_default() {
+        let distance = Distance::from_meters(1_234.5);
+        assert_eq!(format!("{distance:.2}"), "1.23 km");
+        assert_eq!(format!("{:.1}", distance.display(DistanceUnit::Meters)), "1234.5 m");
+    } // fn
+} // mod
@@ -0,0 +1,243 @@
+//! Contains the `Distance` struct, which holds a length in meters and converts
+//! it to other units. Google's APIs report distances, elevations and
+//! resolutions in meters, and this type keeps the unit with the number.
+
+mod arithmetic;
+pub(crate) mod display;
+pub(crate) mod unit;
+#[cfg(feature = "uom")]
+mod uom;
+
+// -----------------------------------------------------------------------------
+
+use crate::distance::unit::DistanceUnit;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+
+// -----------------------------------------------------------------------------
+
+/// A distance or length, such as the length of a route or the elevation of a
+/// point.
+///
+/// Distances are ordered and compared with `f64::total_cmp`, so that they may
+/// be sorted and used as keys. Negative zero is stored as zero, so that
+/// `Distance::from_meters(-0.0) == Distance::ZERO`. Distances are serialized
+/// as a number of meters, which is how Google's APIs send them.
+///
+/// ## Example:
+///
+/// ```rust
+/// let distance = Distance::from_kilometers(1.5) + Distance::from_meters(100.0);
+/// assert_eq!(distance.meters(), 1_600.0);
+/// assert_eq!(distance.to_string(), "1.6 km");
+/// assert_eq!(distance.display(DistanceUnit::Miles).to_string(), "1.0 mi");
+/// ```
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Distance {
+    /// The distance in meters.
+    meters: f64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Distance {
+
+    /// A distance of zero.
+    pub const ZERO: Distance = Distance { meters: 0.0 };
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a distance from a value in the given unit.
+
+    pub fn new(value: f64, unit: DistanceUnit) -> Self {
+        Distance::from_meters(value * unit.meters())
+    } // fn
+
+    /// Creates a distance from a number of meters.
+    pub const fn from_meters(meters: f64) -> Self {
+        // Adding zero turns negative zero into zero, and leaves every other
+        // value unchanged:
+        Distance { meters: meters + 0.0 }
+    } // fn
+
+    /// Creates a distance from a number of kilometers.
+    pub fn from_kilometers(kilometers: f64) -> Self {
+        Distance::new(kilometers, DistanceUnit::Kilometers)
+    } // fn
+
+    /// Creates a distance from a number of international miles.
+    pub fn from_miles(miles: f64) -> Self {
+        Distance::new(miles, DistanceUnit::Miles)
+    } // fn
+
+    /// Creates a distance from a number of international feet.
+    pub fn from_feet(feet: f64) -> Self {
+        Distance::new(feet, DistanceUnit::Feet)
+    } // fn
+
+    /// Creates a distance from a number of nautical miles.
+    pub fn from_nautical_miles(nautical_miles: f64) -> Self {
+        Distance::new(nautical_miles, DistanceUnit::NauticalMiles)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the distance in the given unit.
+
+    pub fn to_unit(&self, unit: DistanceUnit) -> f64 {
+        self.meters / unit.meters()
+    } // fn
+
+    /// Returns the distance in meters.
+    pub const fn meters(&self) -> f64 {
+        self.meters
+    } // fn
+
+    /// Returns the distance in kilometers.
+    pub fn kilometers(&self) -> f64 {
+        self.to_unit(DistanceUnit::Kilometers)
+    } // fn
+
+    /// Returns the distance in international miles.
+    pub fn miles(&self) -> f64 {
+        self.to_unit(DistanceUnit::Miles)
+    } // fn
+
+    /// Returns the distance in international feet.
+    pub fn feet(&self) -> f64 {
+        self.to_unit(DistanceUnit::Feet)
+    } // fn
+
+    /// Returns the distance in nautical miles.
+    pub fn nautical_miles(&self) -> f64 {
+        self.to_unit(DistanceUnit::NauticalMiles)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the distance is zero.
+
+    pub fn is_zero(&self) -> bool {
+        self.meters == 0.0
+    } // fn
+
+    /// Returns the absolute value of the distance. Distances are only negative
+    /// when one is subtracted from a shorter one, such as when comparing
+    /// elevations.
+    pub fn abs(&self) -> Self {
+        Distance { meters: self.meters.abs() }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::cmp::PartialEq for Distance {
+    /// Compares two distances with `f64::total_cmp`.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    } // fn
+} // impl
+
+impl std::cmp::Eq for Distance {}
+
+impl std::cmp::PartialOrd for Distance {
+    /// Orders two distances with `f64::total_cmp`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    } // fn
+} // impl
+
+impl std::cmp::Ord for Distance {
+    /// Orders two distances with `f64::total_cmp`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters.total_cmp(&other.meters)
+    } // fn
+} // impl
+
+impl std::hash::Hash for Distance {
+    /// Hashes the bits of the distance, which agrees with `f64::total_cmp`.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.meters.to_bits().hash(state);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Distance {
+    /// Serializes the distance as a number of meters. Whole numbers of meters
+    /// are written as integers, as Google does.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.meters.fract() == 0.0 && self.meters.abs() < 9_007_199_254_740_992.0 {
+            serializer.serialize_i64(self.meters as i64)
+        } else {
+            serializer.serialize_f64(self.meters)
+        } // if
+    } // fn
+} // impl
+
+impl<'de> Deserialize<'de> for Distance {
+    /// Deserializes a number of meters into a distance.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Distance::from_meters)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} is not {expected}");
+    } // fn
+
+    #[test]
+    fn converts_between_units() {
+        let mile = Distance::from_miles(1.0);
+        assert_close(mile.meters(), 1_609.344);
+        assert_close(mile.kilometers(), 1.609_344);
+        assert_close(mile.feet(), 5_280.0);
+        assert_close(Distance::from_feet(3.0).meters(), 0.914_4);
+        assert_close(Distance::from_kilometers(1.852).nautical_miles(), 1.0);
+        assert_close(Distance::from_nautical_miles(1.0).meters(), 1_852.0);
+        assert_close(Distance::new(2.5, DistanceUnit::Kilometers).to_unit(DistanceUnit::Meters), 2_500.0);
+    } // fn
+
+    #[test]
+    fn negative_zero_equals_zero() {
+        assert_eq!(Distance::from_meters(-0.0), Distance::ZERO);
+        assert_eq!(Distance::new(-0.0, DistanceUnit::Miles), Distance::ZERO);
+        assert!(Distance::from_meters(-0.0).meters().is_sign_positive());
+        assert!(Distance::from_meters(-0.0).is_zero());
+        assert_eq!(Distance::from_meters(-1.0).abs(), Distance::from_meters(1.0));
+    } // fn
+
+    #[test]
+    fn orders_distances() {
+        let mut distances = vec![Distance::from_meters(5.0), Distance::from_meters(-2.0), Distance::ZERO];
+        distances.sort();
+        assert_eq!(distances, vec![Distance::from_meters(-2.0), Distance::ZERO, Distance::from_meters(5.0)]);
+        assert!(Distance::from_kilometers(1.0) > Distance::from_feet(3_000.0));
+        assert_eq!(Distance::default(), Distance::ZERO);
+    } // fn
+
+    #[test]
+    fn serializes_as_meters() {
+        assert_eq!(serde_json::to_string(&Distance::from_meters(1_500.0)).unwrap(), "1500");
+        assert_eq!(serde_json::to_string(&Distance::from_meters(12.5)).unwrap(), "12.5");
+        assert_eq!(serde_json::to_string(&Distance::from_meters(-0.0)).unwrap(), "0");
+
+        assert_eq!(serde_json::from_str::<Distance>("1500").unwrap(), Distance::from_meters(1_500.0));
+        assert_eq!(serde_json::from_str::<Distance>("12.5").unwrap(), Distance::from_meters(12.5));
+        assert!(serde_json::from_str::<Distance>("\"12 m\"").is_err());
+
+        for meters in [0.0, 1.0, 1_234.5, -3.25, 1.0e20] {
+            let distance = Distance::from_meters(meters);
+            let json = serde_json::to_string(&distance).unwrap();
+            assert_eq!(serde_json::from_str::<Distance>(&json).unwrap(), distance);
+        } // for
+    } // fn
+} // mod
@@ -0,0 +1,64 @@
+//! Contains the `DistanceUnit` enum, which lists the units that a `Distance`
+//! may be converted to and displayed in.
+
+// -----------------------------------------------------------------------------
+
+/// A unit of length.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DistanceUnit {
+    /// Meters.
+    Meters,
+    /// Kilometers, or 1,000 meters.
+    Kilometers,
+    /// International feet, or 0.3048 meters.
+    Feet,
+    /// International miles, or 5,280 feet.
+    Miles,
+    /// Nautical miles, or 1,852 meters.
+    NauticalMiles,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl DistanceUnit {
+    /// Returns the number of meters in one of this unit.
+    pub const fn meters(&self) -> f64 {
+        match self {
+            DistanceUnit::Meters => 1.0,
+            DistanceUnit::Kilometers => 1_000.0,
+            DistanceUnit::Feet => 0.304_8,
+            DistanceUnit::Miles => 1_609.344,
+            DistanceUnit::NauticalMiles => 1_852.0,
+        } // match
+    } // fn
+
+    /// Returns the unit's symbol, for example "km" or "mi".
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            DistanceUnit::Meters => "m",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::NauticalMiles => "nmi",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for DistanceUnit {
+    /// Returns a reasonable default variant for the `DistanceUnit` enum type.
+    fn default() -> Self {
+        DistanceUnit::Meters
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for DistanceUnit {
+    /// Formats the unit as its symbol, for example "km".
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+//! Provides `Distance` conversions for the [uom](https://crates.io/crates/uom)
+//! crate's `Length` quantity.
+
+use crate::distance::Distance;
+use uom::si::{f64::Length, length::meter};
+
+// -----------------------------------------------------------------------------
+
+impl From<Distance> for Length {
+    /// Converts a `google_maps::Distance` struct to a `uom::si::f64::Length`
+    /// quantity.
+    fn from(distance: Distance) -> Self {
+        Length::new::<meter>(distance.meters())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<Length> for Distance {
+    /// Converts a `uom::si::f64::Length` quantity to a `google_maps::Distance`
+    /// struct.
+    fn from(length: Length) -> Self {
+        Distance::from_meters(length.get::<meter>())
+    } // fn
+} // impl
@@ -1,6 +1,8 @@
 //! Distance Matrix API error types and error messages.
 
-use crate::distance_matrix::response::status::Status;
+use crate::distance_matrix::{
+    response::status::Status, MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS,
+}; // use crate::distance_matrix
 
 /// Errors that may be produced by the Google Maps Distance Matrix API client.
 #[derive(Debug)]
@@ -81,8 +83,19 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// Too many destinations specified. Use `LargeDistanceMatrix` to split
+    /// larger matrices into several requests.
+    TooManyDestinations(usize),
+    /// Too many origin-destination pairings specified. Use
+    /// `LargeDistanceMatrix` to split larger matrices into several requests.
+    TooManyElements(usize, usize),
+    /// Too many origins specified. Use `LargeDistanceMatrix` to split larger
+    /// matrices into several requests.
+    TooManyOrigins(usize),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
+    /// A traffic model may only be specified with a departure time.
+    TrafficModelRequiresDepartureTime(String),
     /// Transit mode may only be specified in Transit travel mode.
     TransitModeIsForTransitOnly(String, String),
     /// Transit routing preference may only be specified in Transit travel mode.
@@ -227,6 +240,22 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Error::SerdeJson(error) => write!(f, "Google Maps Distance Matrix API client in the Serde JSON library: {error}"),
+            Error::TooManyDestinations(destination_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of destinations is {MAX_DESTINATIONS} per request. \
+                {destination_count} destinations are set. \
+                Try again with fewer destinations, or use `LargeDistanceMatrix` to split the matrix into several requests."),
+            Error::TooManyElements(origin_count, destination_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of elements (origins × destinations) is {MAX_ELEMENTS} per request. \
+                {origin_count} origins and {destination_count} destinations make {} elements. \
+                Try again with fewer origins or destinations, or use `LargeDistanceMatrix` to split the matrix into several requests.",
+                origin_count * destination_count),
+            Error::TooManyOrigins(origin_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of origins is {MAX_ORIGINS} per request. \
+                {origin_count} origins are set. \
+                Try again with fewer origins, or use `LargeDistanceMatrix` to split the matrix into several requests."),
             Error::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -234,6 +263,11 @@ impl std::fmt::Display for Error {
                 Try again with {} fewer waypoint(s).",
                 waypoint_count,
                 waypoint_count - 25),
+            Error::TrafficModelRequiresDepartureTime(traffic_model) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The with_traffic_model() method may only be used when with_departure_time() has been set. \
+                The traffic model is set to `{traffic_model}`. \
+                Try again either with a departure time or no traffic model."),
             Error::TransitModeIsForTransitOnly(travel_mode, transit_modes) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The with_transit_modes() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
@@ -279,7 +313,11 @@ impl std::error::Error for Error {
             #[cfg(feature = "enable-reqwest")]
             Error::ReqwestMessage(_error) => None,
             Error::SerdeJson(error) => Some(error),
+            Error::TooManyDestinations(_destination_count) => None,
+            Error::TooManyElements(_origin_count, _destination_count) => None,
+            Error::TooManyOrigins(_origin_count) => None,
             Error::TooManyWaypoints(_waypoint_count) => None,
+            Error::TrafficModelRequiresDepartureTime(_traffic_model) => None,
             Error::TransitModeIsForTransitOnly(_travel_mode, _transit_modes) => None,
             Error::TransitRoutePreferenceIsForTransitOnly(
                 _travel_mode,
@@ -0,0 +1,75 @@
+//! Isochrone error types and error messages.
+
+use crate::distance::Distance;
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when building an isochrone.
+
+#[derive(Debug)]
+pub enum Error {
+    /// An error returned by the Distance Matrix API client.
+    DistanceMatrix(crate::distance_matrix::error::Error),
+    /// A sample location could not be calculated.
+    LatLng(crate::error::Error),
+    /// The maximum radius is zero, negative or not a finite number.
+    InvalidMaxRadius(Distance),
+    /// The time budget is zero or negative.
+    InvalidTimeBudget(Duration),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::DistanceMatrix(error) => write!(f, "{error}"),
+            Error::LatLng(error) => write!(f, "{error}"),
+            Error::InvalidMaxRadius(max_radius) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                the isochrone's maximum radius of {max_radius} is invalid. \
+                Try again with a positive radius."),
+            Error::InvalidTimeBudget(budget) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                the isochrone's time budget of {seconds} seconds is invalid. \
+                Try again with a positive time budget.",
+                seconds=budget.num_seconds()),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DistanceMatrix(error) => error.source(),
+            Error::LatLng(error) => error.source(),
+            Error::InvalidMaxRadius(_max_radius) => None,
+            Error::InvalidTimeBudget(_budget) => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<crate::distance_matrix::error::Error> for Error {
+    /// Wraps a Distance Matrix API client error.
+    fn from(error: crate::distance_matrix::error::Error) -> Error {
+        Error::DistanceMatrix(error)
+    } // fn
+} // impl
+
+impl From<crate::error::Error> for Error {
+    /// Wraps an error from calculating a sample location.
+    fn from(error: crate::error::Error) -> Error {
+        Error::LatLng(error)
+    } // fn
+} // impl
@@ -0,0 +1,527 @@
+//! Contains the `Isochrone` executor. It approximates the area that can be
+//! reached from an origin within a time budget, using the Distance Matrix API.
+//!
+//! Google has no isochrone API, so the area is found by sampling. The origin
+//! is surrounded by evenly spaced rays, and the reachable distance along each
+//! ray is found with a binary search. Every step of the search sends one
+//! batch of Distance Matrix API requests, with one destination for each ray
+//! that hasn't been resolved yet. The batches are split into requests that
+//! are within Google's limits, and are sent observing the client's
+//! `RequestRate`.
+//!
+//! The first batch probes every ray at the maximum radius. Rays that are
+//! reachable all the way are done; the rest are searched, halving the
+//! uncertainty with each batch. At most `rays × (iterations + 1)` elements
+//! are requested. The defaults of 16 rays and 6 iterations use at most 112
+//! elements, and find each edge to within 1/64 of the maximum radius.
+//!
+//! The distances are in a straight line from the origin. Sample points that
+//! Google can't route to, such as points in lakes, count as out of reach.
+//!
+//! ## Example:
+//!
+//! ```rust
+//! let isochrone = Isochrone::new(
+//!     &google_maps_client,
+//!     LatLng::try_from_dec(dec!(45.5019), dec!(-73.5674))?,
+//!     TravelMode::Driving,
+//!     Duration::minutes(30),
+//! )
+//! .with_rays(24)
+//! .execute()
+//! .await?;
+//!
+//! for vertex in isochrone.polygon() {
+//!     println!("{vertex}");
+//! }
+//! ```
+
+pub mod error;
+mod response;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::distance_matrix::isochrone::response::{IsochroneRay, IsochroneResponse};
+
+use crate::client::GoogleMapsClient;
+use crate::directions::{
+    request::{departure_time::DepartureTime, waypoint::Waypoint},
+    travel_mode::TravelMode,
+}; // crate::directions
+use crate::distance::Distance;
+use crate::distance_matrix::{
+    isochrone::error::Error,
+    large_distance_matrix::LargeDistanceMatrix,
+    response::element_status::ElementStatus,
+}; // crate::distance_matrix
+use crate::latlng::LatLng;
+use crate::request_rate::DEFAULT_MAX_CONCURRENT_REQUESTS;
+use chrono::Duration;
+use std::future::Future;
+
+// -----------------------------------------------------------------------------
+
+/// The number of rays that are sampled, unless changed with `with_rays`.
+const DEFAULT_RAYS: usize = 16;
+
+/// The number of binary search steps per ray, unless changed with
+/// `with_iterations`.
+const DEFAULT_ITERATIONS: usize = 6;
+
+// -----------------------------------------------------------------------------
+//
+/// Approximates the area that can be reached from an origin within a time
+/// budget, as a polygon.
+
+#[derive(Clone, Debug)]
+pub struct Isochrone<'a> {
+    /// The client used to query the Distance Matrix API.
+    client: &'a GoogleMapsClient,
+
+    /// Where the traveller starts.
+    origin: LatLng,
+
+    /// How the traveller gets around.
+    travel_mode: TravelMode,
+
+    /// The longest the traveller may take.
+    budget: Duration,
+
+    /// When the traveller departs.
+    departure_time: DepartureTime,
+
+    /// The number of evenly spaced rays to sample.
+    rays: usize,
+
+    /// The number of binary search steps per ray.
+    iterations: usize,
+
+    /// The furthest distance from the origin that is sampled. If this is
+    /// `None`, it is estimated from the travel mode and time budget.
+    max_radius: Option<Distance>,
+
+    /// The maximum number of requests in flight at once.
+    max_concurrent_requests: usize,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Isochrone<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets up an isochrone query with 16 rays, 6 iterations, a departure
+    /// time of now, and a maximum radius estimated from the travel mode.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `origin` ‧ Where the traveller starts.
+    ///
+    /// * `travel_mode` ‧ How the traveller gets around.
+    ///
+    /// * `budget` ‧ The longest the traveller may take. For driving, this is
+    /// compared to the duration in traffic when Google returns one.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        origin: LatLng,
+        travel_mode: TravelMode,
+        budget: Duration,
+    ) -> Isochrone<'a> {
+        Isochrone {
+            client,
+            origin,
+            travel_mode,
+            budget,
+            departure_time: DepartureTime::Now,
+            rays: DEFAULT_RAYS,
+            iterations: DEFAULT_ITERATIONS,
+            max_radius: None,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        } // Isochrone
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets when the traveller departs. The default is now.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `departure_time` ‧ The departure time, for traffic and transit
+    /// schedules. It must not be in the past.
+
+    pub fn with_departure_time(&mut self, departure_time: DepartureTime) -> &mut Isochrone<'a> {
+        self.departure_time = departure_time;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the number of evenly spaced rays to sample. More rays give a more
+    /// detailed polygon, but use more quota.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `rays` ‧ The number of rays. Values below 3 are treated as 3.
+
+    pub fn with_rays(&mut self, rays: usize) -> &mut Isochrone<'a> {
+        self.rays = rays.max(3);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the number of binary search steps per ray. Each step halves the
+    /// uncertainty in the edge of the isochrone, but sends another batch of
+    /// requests.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `iterations` ‧ The number of steps. Values below 1 are treated as 1.
+
+    pub fn with_iterations(&mut self, iterations: usize) -> &mut Isochrone<'a> {
+        self.iterations = iterations.max(1);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the furthest distance from the origin that is sampled. By default
+    /// this is how far a fast trip could go within the time budget: 100 km/h
+    /// for driving, 60 km/h for transit, 25 km/h for bicycling and 6 km/h for
+    /// walking. A smaller radius gives a more precise edge for the same number
+    /// of iterations, but cuts the isochrone off if it is too small.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `max_radius` ‧ The maximum radius.
+
+    pub fn with_max_radius(&mut self, max_radius: Distance) -> &mut Isochrone<'a> {
+        self.max_radius = Some(max_radius);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of requests that may be in flight at once. The
+    /// rays are sampled with a `LargeDistanceMatrix`, so this works like
+    /// `LargeDistanceMatrix::with_max_concurrent_requests`.
+
+    pub fn with_max_concurrent_requests(
+        &mut self,
+        max_concurrent_requests: usize,
+    ) -> &mut Isochrone<'a> {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the furthest distance from the origin that will be sampled.
+
+    pub fn max_radius(&self) -> Distance {
+        self.max_radius.unwrap_or_else(|| {
+            let kilometers_per_hour = match self.travel_mode {
+                TravelMode::Driving => 100.0,
+                TravelMode::Transit => 60.0,
+                TravelMode::Bicycling => 25.0,
+                TravelMode::Walking => 6.0,
+            }; // match
+            let hours = self.budget.num_seconds() as f64 / 3_600.0;
+            Distance::from_kilometers(kilometers_per_hour * hours)
+        }) // unwrap_or_else
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the most elements that the query may request, which is what
+    /// Google bills the Distance Matrix API by. Fewer are requested when rays
+    /// reach the maximum radius.
+
+    pub fn max_elements(&self) -> usize {
+        self.rays * (self.iterations + 1)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query, sampling every ray, and returns the isochrone.
+    ///
+    /// ## Errors:
+    ///
+    /// * `Error::InvalidTimeBudget` if the time budget isn't positive.
+    ///
+    /// * `Error::InvalidMaxRadius` if the maximum radius isn't positive.
+    ///
+    /// * Any error returned by the Distance Matrix API. The query stops at the
+    /// first failed request, since the rays can't be resolved without it.
+
+    pub async fn execute(&self) -> Result<IsochroneResponse, Error> {
+
+        if self.budget <= Duration::zero() {
+            return Err(Error::InvalidTimeBudget(self.budget));
+        } // if
+
+        let max_radius = self.max_radius();
+        if !(max_radius.meters().is_finite() && max_radius.meters() > 0.0) {
+            return Err(Error::InvalidMaxRadius(max_radius));
+        } // if
+
+        self.search(max_radius, |locations| self.travel_times(locations)).await
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches every ray for the edge of the isochrone, sending one batch of
+    /// sample locations per iteration.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `max_radius` ‧ The furthest distance from the origin to sample.
+    ///
+    /// * `travel_times` ‧ Returns the travel time from the origin to each
+    /// location in a batch, or `None` for locations that can't be reached.
+
+    async fn search<F, Fut>(
+        &self,
+        max_radius: Distance,
+        mut travel_times: F,
+    ) -> Result<IsochroneResponse, Error>
+    where
+        F: FnMut(Vec<LatLng>) -> Fut,
+        Fut: Future<Output = Result<Vec<Option<Duration>>, Error>>,
+    {
+
+        let mut rays: Vec<RaySearch> = (0..self.rays)
+            .map(|index| RaySearch {
+                bearing: 360.0 * index as f64 / self.rays as f64,
+                reachable: Distance::ZERO,
+                unreachable: max_radius,
+                duration: None,
+                resolved: false,
+            }) // map
+            .collect();
+
+        // Probe the maximum radius first, then halve the interval between the
+        // furthest reachable and nearest unreachable distances:
+        for iteration in 0..=self.iterations {
+            let searching: Vec<usize> = (0..rays.len())
+                .filter(|index| !rays[*index].resolved)
+                .collect();
+
+            if searching.is_empty() {
+                break;
+            } // if
+
+            let probes: Vec<Distance> = searching
+                .iter()
+                .map(|index| match iteration {
+                    0 => max_radius,
+                    _ => (rays[*index].reachable + rays[*index].unreachable) / 2.0,
+                }) // map
+                .collect();
+
+            let locations = searching
+                .iter()
+                .zip(&probes)
+                .map(|(index, probe)| self.origin.destination(rays[*index].bearing, probe.meters()))
+                .collect::<Result<Vec<LatLng>, crate::error::Error>>()?;
+
+            let durations = travel_times(locations).await?;
+
+            for ((index, probe), duration) in searching.into_iter().zip(probes).zip(durations) {
+                let ray = &mut rays[index];
+                match duration.filter(|duration| *duration <= self.budget) {
+                    Some(duration) => {
+                        ray.reachable = probe;
+                        ray.duration = Some(duration);
+                        // A ray that reaches the maximum radius has no edge
+                        // to search for:
+                        if iteration == 0 {
+                            ray.resolved = true;
+                        } // if
+                    }, // Some
+                    None => ray.unreachable = probe,
+                } // match
+            } // for
+        } // for
+
+        let rays = rays
+            .into_iter()
+            .map(|ray| Ok(IsochroneRay {
+                location: self.origin.destination(ray.bearing, ray.reachable.meters())?,
+                bearing: ray.bearing,
+                reachable: ray.reachable,
+                unreachable: (!ray.resolved).then_some(ray.unreachable),
+                duration: ray.duration,
+            })) // map
+            .collect::<Result<Vec<IsochroneRay>, Error>>()?;
+
+        Ok(IsochroneResponse::new(self.origin.clone(), self.travel_mode.clone(), self.budget, rays))
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends one batch of sample locations to the Distance Matrix API, and
+    /// returns the travel time from the origin to each of them. The travel
+    /// time is `None` for locations that Google couldn't route to.
+
+    async fn travel_times(&self, locations: Vec<LatLng>) -> Result<Vec<Option<Duration>>, Error> {
+
+        let destinations = locations.into_iter().map(Waypoint::LatLng).collect();
+
+        let response = LargeDistanceMatrix::new(
+            self.client
+                .distance_matrix(vec![Waypoint::LatLng(self.origin.clone())], destinations)
+                .with_travel_mode(self.travel_mode.clone())
+                .with_departure_time(self.departure_time.clone())
+                .clone()
+        ) // LargeDistanceMatrix
+        .with_max_concurrent_requests(self.max_concurrent_requests)
+        .execute()
+        .await?;
+
+        if let Some(failure) = response.failures.into_iter().next() {
+            return Err(Error::DistanceMatrix(failure.error));
+        } // if
+
+        let elements = response.rows
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        Ok(elements
+            .into_iter()
+            .map(|element| match element {
+                Some(element) if element.status == ElementStatus::Ok => element.duration_in_traffic
+                    .or(element.duration)
+                    .map(|duration| duration.value),
+                _ => None,
+            }) // map
+            .collect())
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The state of the binary search along one ray.
+
+struct RaySearch {
+    /// The direction of the ray, in degrees clockwise from true north.
+    bearing: f64,
+    /// The furthest distance found to be reachable.
+    reachable: Distance,
+    /// The nearest distance found to be out of reach.
+    unreachable: Distance,
+    /// The travel time to the `reachable` distance, once one is found.
+    duration: Option<Duration>,
+    /// Whether the ray reaches the maximum radius, so needs no more samples.
+    resolved: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn origin() -> LatLng {
+        LatLng::try_from_f64(45.5, -73.5).unwrap()
+    } // fn
+
+    /// Stands in for the Distance Matrix API. Travel to the east is at 10 m/s,
+    /// the south can't be reached at all, and everywhere else is at 1 m/s.
+    fn travel_time(location: &LatLng) -> Option<Duration> {
+        let bearing = origin().initial_bearing(location);
+        let meters = origin().haversine_distance(location);
+        let seconds = |meters: f64| Some(Duration::seconds(meters.round() as i64));
+        match bearing {
+            bearing if (45.0..135.0).contains(&bearing) => seconds(meters / 10.0),
+            bearing if (135.0..225.0).contains(&bearing) => None,
+            _ => seconds(meters),
+        } // match
+    } // fn
+
+    #[test]
+    fn searches_each_ray_for_the_edge() {
+        let client = GoogleMapsClient::new("x");
+        let mut isochrone = Isochrone::new(&client, origin(), TravelMode::Driving, Duration::seconds(1_100));
+        isochrone.with_rays(4).with_max_radius(Distance::from_meters(2_000.0));
+
+        let mut batches = Vec::new();
+        let response = block_on(isochrone.search(isochrone.max_radius(), |locations| {
+            batches.push(locations.len());
+            let durations = locations.iter().map(travel_time).collect();
+            async move { Ok(durations) }
+        })).unwrap();
+
+        // North and west are probed at 2,000 m, 1,000 m, 1,500 m, 1,250 m,
+        // 1,125 m, 1,062.5 m and 1,093.75 m:
+        let rays = response.rays();
+        for ray in [&rays[0], &rays[3]] {
+            assert_eq!(ray.reachable, Distance::from_meters(1_093.75));
+            assert_eq!(ray.unreachable, Some(Distance::from_meters(1_125.0)));
+            assert_eq!(ray.duration, Some(Duration::seconds(1_094)));
+        } // for
+
+        // East is reachable at the maximum radius, so it is resolved by the
+        // first batch:
+        assert_eq!(rays[1].reachable, Distance::from_meters(2_000.0));
+        assert_eq!(rays[1].unreachable, None);
+        assert_eq!(rays[1].duration, Some(Duration::seconds(200)));
+
+        // Nothing to the south is reachable, so its vertex is the origin:
+        assert_eq!(rays[2].reachable, Distance::ZERO);
+        assert_eq!(rays[2].unreachable, Some(Distance::from_meters(31.25)));
+        assert_eq!(rays[2].duration, None);
+        assert!(origin().haversine_distance(&rays[2].location) < 0.01);
+
+        assert_eq!(batches, [4, 3, 3, 3, 3, 3, 3]);
+        assert!(batches.iter().sum::<usize>() <= isochrone.max_elements());
+        assert_eq!(
+            rays.iter().map(|ray| ray.bearing).collect::<Vec<f64>>(),
+            [0.0, 90.0, 180.0, 270.0],
+        );
+    } // fn
+
+    #[test]
+    fn stops_at_the_first_failed_batch() {
+        let client = GoogleMapsClient::new("x");
+        let isochrone = Isochrone::new(&client, origin(), TravelMode::Walking, Duration::minutes(10));
+
+        let mut batches = 0;
+        let result = block_on(isochrone.search(isochrone.max_radius(), |_locations| {
+            batches += 1;
+            async { Err(Error::InvalidTimeBudget(Duration::zero())) }
+        })); // block_on
+
+        assert!(matches!(result, Err(Error::InvalidTimeBudget(_))));
+        assert_eq!(batches, 1);
+    } // fn
+
+    #[test]
+    fn rejects_invalid_budgets_and_radii() {
+        let client = GoogleMapsClient::new("x");
+
+        let isochrone = Isochrone::new(&client, origin(), TravelMode::Walking, Duration::zero());
+        assert!(matches!(block_on(isochrone.execute()), Err(Error::InvalidTimeBudget(_))));
+
+        let mut isochrone = Isochrone::new(&client, origin(), TravelMode::Walking, Duration::minutes(10));
+        isochrone.with_max_radius(Distance::ZERO);
+        assert!(matches!(block_on(isochrone.execute()), Err(Error::InvalidMaxRadius(_))));
+    } // fn
+
+    #[test]
+    fn estimates_the_max_radius_from_the_travel_mode() {
+        let client = GoogleMapsClient::new("x");
+        let isochrone = Isochrone::new(&client, origin(), TravelMode::Walking, Duration::minutes(30));
+        assert_eq!(isochrone.max_radius(), Distance::from_kilometers(3.0));
+        assert_eq!(isochrone.max_elements(), 16 * 7);
+    } // fn
+} // mod
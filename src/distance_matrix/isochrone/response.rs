@@ -0,0 +1,249 @@
+//! Contains the `IsochroneResponse` and `IsochroneRay` structs, which describe
+//! the area reachable from an origin within a time budget.
+
+use crate::directions::travel_mode::TravelMode;
+use crate::distance::Distance;
+use crate::latlng::LatLng;
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// How far the traveller can get in one direction from the origin.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsochroneRay {
+    /// The direction of the ray, in degrees clockwise from true north.
+    pub bearing: f64,
+
+    /// The furthest distance along the ray, in a straight line from the
+    /// origin, that was found to be reachable within the time budget.
+    pub reachable: Distance,
+
+    /// The nearest distance along the ray that was found to be out of reach.
+    /// The edge of the isochrone lies between `reachable` and this distance.
+    /// This is `None` if the ray reaches the isochrone's maximum radius.
+    pub unreachable: Option<Distance>,
+
+    /// The point at the `reachable` distance. This is a vertex of the
+    /// isochrone's polygon.
+    pub location: LatLng,
+
+    /// The travel time to `location`, as Google predicted it. This is `None`
+    /// if no reachable point was found along the ray, in which case
+    /// `location` is the origin.
+    pub duration: Option<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The result of an `Isochrone` query: an approximation of the area that can
+/// be reached from the origin within the time budget.
+///
+/// The fields are read-only, since `contains` relies on the rays being evenly
+/// spaced around the origin, starting from north.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsochroneResponse {
+    /// Where the traveller starts.
+    origin: LatLng,
+
+    /// How the traveller gets around.
+    travel_mode: TravelMode,
+
+    /// The longest the traveller may take.
+    budget: Duration,
+
+    /// One ray for each direction that was sampled, in clockwise order
+    /// starting from north.
+    rays: Vec<IsochroneRay>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl IsochroneResponse {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a response from the rays found by an `Isochrone` query. The
+    /// rays must be evenly spaced, in clockwise order starting from north.
+
+    pub(crate) fn new(
+        origin: LatLng,
+        travel_mode: TravelMode,
+        budget: Duration,
+        rays: Vec<IsochroneRay>,
+    ) -> IsochroneResponse {
+        IsochroneResponse { origin, travel_mode, budget, rays }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns where the traveller starts.
+
+    pub fn origin(&self) -> &LatLng {
+        &self.origin
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns how the traveller gets around.
+
+    pub fn travel_mode(&self) -> &TravelMode {
+        &self.travel_mode
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the longest the traveller may take.
+
+    pub fn budget(&self) -> Duration {
+        self.budget
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns one ray for each direction that was sampled, in clockwise order
+    /// starting from north.
+
+    pub fn rays(&self) -> &[IsochroneRay] {
+        &self.rays
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the vertices of the isochrone's polygon in clockwise order,
+    /// starting from north. The first vertex isn't repeated at the end.
+
+    pub fn polygon(&self) -> Vec<LatLng> {
+        self.rays.iter().map(|ray| ray.location.clone()).collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the isochrone as a `geo_types::Polygon`, with longitudes as `x`
+    /// and latitudes as `y`.
+    ///
+    /// ## Errors:
+    ///
+    /// * This method can only fail if a vertex could not be converted into a
+    /// floating-point number, which should not happen for valid points.
+
+    #[cfg(feature = "geo")]
+    pub fn to_geo_polygon(&self) -> Result<geo_types::Polygon<f64>, crate::error::Error> {
+        let vertices = self.rays
+            .iter()
+            .map(|ray| geo_types::Point::try_from(&ray.location).map(|point| point.x_y()))
+            .collect::<Result<Vec<(f64, f64)>, crate::error::Error>>()?;
+
+        Ok(geo_types::Polygon::new(geo_types::LineString::from(vertices), Vec::new()))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether a location is inside the isochrone. The edge between
+    /// two rays is interpolated by bearing, which matches the polygon closely
+    /// for isochrones with many rays.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `location` ‧ The location to check.
+
+    pub fn contains(&self, location: &LatLng) -> bool {
+        if self.rays.is_empty() {
+            return false;
+        } // if
+
+        let distance = self.origin.haversine_distance(location);
+        if distance == 0.0 {
+            return true;
+        } // if
+
+        // Find the rays on either side of the location's bearing. The rays
+        // are evenly spaced, starting from north:
+        let spacing = 360.0 / self.rays.len() as f64;
+        let bearing = self.origin.initial_bearing(location);
+        let position = bearing / spacing;
+        let before = (position.floor() as usize) % self.rays.len();
+        let after = (before + 1) % self.rays.len();
+        let fraction = position.fract();
+
+        let radius = self.rays[before].reachable.meters() * (1.0 - fraction)
+            + self.rays[after].reachable.meters() * fraction;
+
+        distance <= radius
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn origin() -> LatLng {
+        LatLng::try_from_f64(45.5, -73.5).unwrap()
+    } // fn
+
+    /// An isochrone that reaches 1 km north and west, 2 km east, and nowhere
+    /// to the south.
+    fn isochrone() -> IsochroneResponse {
+        let rays = [(0.0, 1_000.0), (90.0, 2_000.0), (180.0, 0.0), (270.0, 1_000.0)]
+            .into_iter()
+            .map(|(bearing, meters)| IsochroneRay {
+                bearing,
+                reachable: Distance::from_meters(meters),
+                unreachable: Some(Distance::from_meters(meters + 100.0)),
+                location: origin().destination(bearing, meters).unwrap(),
+                duration: (meters > 0.0).then(|| Duration::minutes(10)),
+            }) // map
+            .collect();
+        IsochroneResponse::new(origin(), TravelMode::Driving, Duration::minutes(10), rays)
+    } // fn
+
+    fn point(bearing: f64, meters: f64) -> LatLng {
+        origin().destination(bearing, meters).unwrap()
+    } // fn
+
+    #[test]
+    fn contains_points_within_each_ray() {
+        let isochrone = isochrone();
+        assert!(isochrone.contains(&origin()));
+        assert!(isochrone.contains(&point(0.0, 900.0)));
+        assert!(!isochrone.contains(&point(0.0, 1_100.0)));
+        assert!(isochrone.contains(&point(90.0, 1_900.0)));
+        assert!(!isochrone.contains(&point(90.0, 2_100.0)));
+        assert!(!isochrone.contains(&point(180.0, 10.0)));
+    } // fn
+
+    #[test]
+    fn interpolates_between_rays() {
+        let isochrone = isochrone();
+        // Halfway between the 1 km and 2 km rays, the edge is at 1.5 km:
+        assert!(isochrone.contains(&point(45.0, 1_400.0)));
+        assert!(!isochrone.contains(&point(45.0, 1_600.0)));
+        // Between the last ray and the first:
+        assert!(isochrone.contains(&point(315.0, 950.0)));
+        assert!(!isochrone.contains(&point(315.0, 1_050.0)));
+    } // fn
+
+    #[test]
+    fn contains_nothing_without_rays() {
+        let isochrone = IsochroneResponse::new(origin(), TravelMode::Walking, Duration::minutes(10), Vec::new());
+        assert!(!isochrone.contains(&origin()));
+        assert!(isochrone.polygon().is_empty());
+    } // fn
+
+    #[test]
+    fn lists_ray_locations_as_the_polygon() {
+        let isochrone = isochrone();
+        assert_eq!(isochrone.rays().len(), 4);
+        assert_eq!(isochrone.origin(), &origin());
+        assert_eq!(isochrone.travel_mode(), &TravelMode::Driving);
+        assert_eq!(isochrone.budget(), Duration::minutes(10));
+        assert_eq!(
+            isochrone.polygon(),
+            isochrone.rays().iter().map(|ray| ray.location.clone()).collect::<Vec<LatLng>>(),
+        );
+    } // fn
+} // mod
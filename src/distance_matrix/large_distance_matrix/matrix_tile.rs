@@ -0,0 +1,93 @@
+//! Contains the `MatrixTile` struct and the function that splits a large
+//! distance matrix into tiles that Google will accept.
+
+use crate::distance_matrix::{MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+// -----------------------------------------------------------------------------
+
+/// A rectangular block of a large distance matrix that is small enough to be
+/// sent to Google in a single request. The ranges are indices into the
+/// original request's origins and destinations.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct MatrixTile {
+    /// The indices of the origins covered by this tile.
+    pub origins: Range<usize>,
+
+    /// The indices of the destinations covered by this tile.
+    pub destinations: Range<usize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MatrixTile {
+    /// Returns the number of elements (origins × destinations) in the tile.
+    /// Google bills the Distance Matrix API per element.
+    pub fn element_count(&self) -> usize {
+        self.origins.len() * self.destinations.len()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MatrixTile {
+    /// Formats the tile as its origin and destination index ranges, for
+    /// example `origins 0..4, destinations 25..50`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "origins {origins:?}, destinations {destinations:?}",
+            origins=self.origins,
+            destinations=self.destinations,
+        ) // write!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Splits an `origins` × `destinations` matrix into the fewest tiles that are
+/// within Google's per-request limits. Tiles are returned row by row, and
+/// every origin-destination pair is covered by exactly one tile.
+///
+/// ## Arguments:
+///
+/// * `origins` ‧ The number of origins in the matrix.
+///
+/// * `destinations` ‧ The number of destinations in the matrix.
+
+pub(crate) fn plan_tiles(origins: usize, destinations: usize) -> Vec<MatrixTile> {
+
+    if origins == 0 || destinations == 0 {
+        return Vec::new();
+    } // if
+
+    // Try every tile height and keep the one that needs the fewest requests.
+    // Every element is billed once no matter how the matrix is split, so
+    // fewer requests is simply less overhead:
+    let (tile_origins, tile_destinations) = (1..=origins.min(MAX_ORIGINS))
+        .map(|tile_origins| {
+            let tile_destinations = destinations
+                .min(MAX_DESTINATIONS)
+                .min(MAX_ELEMENTS / tile_origins);
+            (tile_origins, tile_destinations)
+        }) // map
+        .min_by_key(|(tile_origins, tile_destinations)| {
+            origins.div_ceil(*tile_origins) * destinations.div_ceil(*tile_destinations)
+        }) // min_by_key
+        .unwrap_or((1, 1));
+
+    (0..origins)
+        .step_by(tile_origins)
+        .flat_map(|origin_start| {
+            (0..destinations)
+                .step_by(tile_destinations)
+                .map(move |destination_start| MatrixTile {
+                    origins: origin_start..(origin_start + tile_origins).min(origins),
+                    destinations: destination_start..(destination_start + tile_destinations).min(destinations),
+                }) // map
+        }) // flat_map
+        .collect()
+
+} // fn
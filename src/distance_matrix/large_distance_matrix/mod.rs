@@ -0,0 +1,210 @@
+//! Contains the `LargeDistanceMatrix` executor. It sends distance matrix
+//! queries that are larger than Google allows in a single request.
+//!
+//! Google limits each Distance Matrix API request to 25 origins, 25
+//! destinations and 100 elements (origins × destinations). `LargeDistanceMatrix`
+//! splits a larger query into tiles that are within these limits. It sends the
+//! tiles concurrently, observing the client's `RequestRate`, and stitches the
+//! results back into a single `LargeDistanceMatrixResponse`.
+//!
+//! ## Example:
+//!
+//! ```rust
+//! let request = google_maps_client.distance_matrix(warehouses, customers);
+//!
+//! let matrix = LargeDistanceMatrix::new(request)
+//!     .with_max_concurrent_requests(4)
+//!     // Stay under a quota of 1,000 elements per minute:
+//!     .with_element_rate(1_000, Duration::from_secs(60))
+//!     .execute()
+//!     .await?;
+//!
+//! for failure in &matrix.failures {
+//!     println!("{failure}");
+//! }
+//! ```
+
+mod matrix_tile;
+mod response;
+mod tile_failure;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::distance_matrix::large_distance_matrix::{
+    matrix_tile::MatrixTile,
+    response::LargeDistanceMatrixResponse,
+    tile_failure::TileFailure,
+}; // crate::distance_matrix::large_distance_matrix
+
+use crate::distance_matrix::{error::Error, request::Request, response::Response};
+use crate::request_rate::DEFAULT_MAX_CONCURRENT_REQUESTS;
+use futures::stream::{self, StreamExt};
+use std::time::Duration;
+use stream_throttle::{ThrottlePool, ThrottleRate};
+
+// -----------------------------------------------------------------------------
+//
+/// Sends a distance matrix query of any size by splitting it into requests
+/// that are within Google's per-request limits.
+
+#[derive(Clone)]
+pub struct LargeDistanceMatrix<'a> {
+    /// The full query. Its options are applied to every tile.
+    request: Request<'a>,
+
+    /// The maximum number of tile requests in flight at once.
+    max_concurrent_requests: usize,
+
+    /// Limits the number of elements requested per period of time, if set.
+    element_rate: Option<ThrottlePool>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for LargeDistanceMatrix<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LargeDistanceMatrix")
+            .field("request", &self.request)
+            .field("max_concurrent_requests", &self.max_concurrent_requests)
+            .field("element_rate", &self.element_rate.is_some())
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> LargeDistanceMatrix<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a Distance Matrix API query so that it may have any number of
+    /// origins and destinations.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The distance matrix query, built as usual with the
+    /// `GoogleMapsClient.distance_matrix()` builder. Its options, such as the
+    /// travel mode and departure time, are applied to every tile.
+
+    pub fn new(request: Request<'a>) -> LargeDistanceMatrix<'a> {
+        LargeDistanceMatrix {
+            request,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            element_rate: None,
+        } // LargeDistanceMatrix
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of tile requests that may be in flight at once.
+    /// The client's `RequestRate` still applies to every request.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `max_concurrent_requests` ‧ The number of concurrent requests. The
+    /// default is 4. Values below 1 are treated as 1.
+
+    pub fn with_max_concurrent_requests(
+        &mut self,
+        max_concurrent_requests: usize,
+    ) -> &mut LargeDistanceMatrix<'a> {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Limits the number of elements requested per period of time. Google
+    /// meters the Distance Matrix API by elements rather than requests, so
+    /// `ClientSettings.with_rate()` alone cannot keep a query under an element
+    /// quota. Each tile waits until all of its elements fit within the rate.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `elements` ‧ The number of elements allowed per `duration`. This must
+    /// be at least `MAX_ELEMENTS` (100) for a full tile to ever be sent.
+    ///
+    /// * `duration` ‧ The period of time over which `elements` is measured.
+    /// For example, `Duration::from_secs(60)` for a per-minute quota.
+
+    pub fn with_element_rate(
+        &mut self,
+        elements: usize,
+        duration: Duration,
+    ) -> &mut LargeDistanceMatrix<'a> {
+        self.element_rate = Some(ThrottlePool::new(ThrottleRate::new(elements, duration)));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the tiles that the query will be split into. Each tile is one
+    /// request to Google.
+
+    pub fn tiles(&self) -> Vec<MatrixTile> {
+        matrix_tile::plan_tiles(self.request.origin_count(), self.request.destination_count())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query, one request per tile, and assembles the results.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the query's options are invalid, for example an
+    /// arrival time with a travel mode other than transit. No requests are
+    /// sent in this case.
+    ///
+    /// * Returns the first tile's error if every tile failed, since there is
+    /// no matrix to return.
+    ///
+    /// * Otherwise, errors from individual tiles are not returned here. They
+    /// are listed in the response's `failures` field, and the successful tiles
+    /// are still returned.
+
+    pub async fn execute(&self) -> Result<LargeDistanceMatrixResponse, Error> {
+
+        let tiles = self.tiles();
+
+        // Every tile shares the same options, so validating the first tile
+        // reports option errors once instead of once per tile:
+        if let Some(tile) = tiles.first() {
+            self.request.subset(tile.origins.clone(), tile.destinations.clone()).validate()?;
+        } // if
+
+        let results: Vec<(MatrixTile, Result<Response, Error>)> = stream::iter(tiles)
+            .map(|tile| async move {
+                let result = self.execute_tile(&tile).await;
+                (tile, result)
+            }) // map
+            .buffer_unordered(self.max_concurrent_requests)
+            .collect()
+            .await;
+
+        response::assemble(
+            self.request.origin_count(),
+            self.request.destination_count(),
+            results,
+        ) // assemble
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Waits for the element rate, if one is set, and sends the request for a
+    /// single tile.
+
+    async fn execute_tile(&self, tile: &MatrixTile) -> Result<Response, Error> {
+
+        if let Some(element_rate) = &self.element_rate {
+            for _element in 0..tile.element_count() {
+                element_rate.queue().await;
+            } // for
+        } // if
+
+        let mut request = self.request.subset(tile.origins.clone(), tile.destinations.clone());
+        request.execute().await
+
+    } // fn
+
+} // impl
@@ -0,0 +1,221 @@
+//! Contains the `LargeDistanceMatrixResponse` struct, and the function that
+//! stitches the responses for the individual tiles back into one matrix.
+
+use crate::distance_matrix::{
+    error::Error,
+    large_distance_matrix::{MatrixTile, TileFailure},
+    response::{element::Element, matrix::{Cell, Matrix}, Response},
+}; // use crate::distance_matrix
+
+// -----------------------------------------------------------------------------
+
+/// The result of a `LargeDistanceMatrix` query. The addresses and rows are in
+/// the same order as the origins and destinations of the original request, no
+/// matter how the matrix was split up.
+
+#[derive(Debug)]
+pub struct LargeDistanceMatrixResponse {
+    /// The origin address for each row. Addresses that no successful tile
+    /// returned are empty strings.
+    pub origin_addresses: Vec<String>,
+
+    /// The destination address for each column. Addresses that no successful
+    /// tile returned are empty strings.
+    pub destination_addresses: Vec<String>,
+
+    /// One row per origin, with one element per destination. Elements that
+    /// belong to a failed tile are `None`.
+    pub rows: Vec<Vec<Option<Element>>>,
+
+    /// The tiles whose requests failed, and why. This is empty if the entire
+    /// matrix was retrieved.
+    pub failures: Vec<TileFailure>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl LargeDistanceMatrixResponse {
+    /// Returns whether every tile of the matrix was retrieved successfully.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    } // fn
+
+    /// Returns the response as a dense matrix with one row per origin and one
+    /// column per destination. As with `Response::matrix`, a cell is `Some`
+    /// only if its element has an `ElementStatus::Ok` status and both a
+    /// distance and a duration. Elements of failed tiles are `None`.
+    pub fn matrix(&self) -> Matrix<Option<Cell>> {
+        let cells = self.rows
+            .iter()
+            .flatten()
+            .map(|element| element.as_ref().and_then(Cell::from_element))
+            .collect();
+
+        Matrix::from_cells(self.origin_addresses.clone(), self.destination_addresses.clone(), cells)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Assembles the responses for the individual tiles into a single
+/// `origins` × `destinations` matrix. Tiles may be given in any order.
+///
+/// If some tiles failed, the matrix is still returned and the failed tiles
+/// are listed in `failures`. If every tile failed, there is no matrix to
+/// return, so the error of the first tile is returned instead.
+///
+/// ## Arguments:
+///
+/// * `origins` ‧ The number of origins in the original request.
+///
+/// * `destinations` ‧ The number of destinations in the original request.
+///
+/// * `results` ‧ Each tile along with the outcome of its request.
+
+pub(crate) fn assemble(
+    origins: usize,
+    destinations: usize,
+    results: Vec<(MatrixTile, Result<Response, Error>)>,
+) -> Result<LargeDistanceMatrixResponse, Error> {
+
+    let mut origin_addresses = vec![String::new(); origins];
+    let mut destination_addresses = vec![String::new(); destinations];
+    let mut rows: Vec<Vec<Option<Element>>> = vec![vec![None; destinations]; origins];
+
+    let tile_count = results.len();
+    let mut failures = Vec::new();
+
+    for (tile, result) in results {
+        match result {
+            Ok(tile_response) => {
+                // Copy the tile's addresses and elements into their places in
+                // the full matrix:
+                for (index, address) in tile.origins.clone().zip(tile_response.origin_addresses) {
+                    origin_addresses[index] = address;
+                } // for
+
+                for (index, address) in tile.destinations.clone().zip(tile_response.destination_addresses) {
+                    destination_addresses[index] = address;
+                } // for
+
+                for (origin, row) in tile.origins.clone().zip(tile_response.rows) {
+                    for (destination, element) in tile.destinations.clone().zip(row.elements) {
+                        rows[origin][destination] = Some(element);
+                    } // for
+                } // for
+            }, // Ok
+            // The tile's elements were already initialized as `None`, so only
+            // the error needs to be recorded:
+            Err(error) => failures.push(TileFailure { tile, error }),
+        } // match
+    } // for
+
+    // Report failures in the order the tiles appear in the matrix, rather than
+    // the order in which the requests happened to finish:
+    failures.sort_by_key(|failure| (failure.tile.origins.start, failure.tile.destinations.start));
+
+    if !failures.is_empty() && failures.len() == tile_count {
+        return Err(failures.remove(0).error);
+    } // if
+
+    Ok(LargeDistanceMatrixResponse { origin_addresses, destination_addresses, rows, failures })
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distance_matrix::response::{element_status::ElementStatus, row::Row, status::Status};
+
+    fn tile(origins: std::ops::Range<usize>, destinations: std::ops::Range<usize>) -> MatrixTile {
+        MatrixTile { origins, destinations }
+    } // fn
+
+    /// A successful response for a tile, with an address for every origin
+    /// and destination.
+    fn success(tile: &MatrixTile) -> Result<Response, Error> {
+        let element = Element {
+            distance: None,
+            duration: None,
+            duration_in_traffic: None,
+            fare: None,
+            status: ElementStatus::Ok,
+        }; // Element
+
+        Ok(Response {
+            destination_addresses: tile.destinations.clone().map(|index| format!("d{index}")).collect(),
+            error_message: None,
+            origin_addresses: tile.origins.clone().map(|index| format!("o{index}")).collect(),
+            rows: vec![Row { elements: vec![element; tile.destinations.len()] }; tile.origins.len()],
+            status: Status::Ok,
+        }) // Ok
+    } // fn
+
+    #[test]
+    fn partial_failure_is_listed_in_failures() {
+        let (left, right) = (tile(0..2, 0..1), tile(0..2, 1..3));
+        let results = vec![
+            (right.clone(), Err(Error::QueryNotBuilt)),
+            (left.clone(), success(&left)),
+        ]; // vec
+
+        let matrix = assemble(2, 3, results).unwrap();
+        assert!(!matrix.is_complete());
+        assert_eq!(matrix.failures.len(), 1);
+        assert_eq!(matrix.failures[0].tile, right);
+
+        assert_eq!(matrix.origin_addresses, vec!["o0", "o1"]);
+        assert_eq!(matrix.destination_addresses, vec!["d0", "", ""]);
+        for row in &matrix.rows {
+            let statuses: Vec<Option<&ElementStatus>> = row
+                .iter()
+                .map(|element| element.as_ref().map(|element| &element.status))
+                .collect();
+            assert_eq!(statuses, vec![Some(&ElementStatus::Ok), None, None]);
+        } // for
+    } // fn
+
+    #[test]
+    fn matrix_has_no_cells_for_failed_tiles() {
+        let (left, right) = (tile(0..1, 0..1), tile(0..1, 1..2));
+        let mut response = success(&left).unwrap();
+        response.rows[0].elements[0] = serde_json::from_str(
+            r#"{"status":"OK","distance":{"text":"1 km","value":1000},"duration":{"text":"1 min","value":60}}"#
+        ).unwrap();
+        let results = vec![(left, Ok(response)), (right, Err(Error::QueryNotBuilt))];
+
+        let matrix = assemble(1, 2, results).unwrap().matrix();
+        assert_eq!(matrix.destination_count(), 2);
+        assert!(matrix[(0, 0)].is_some());
+        assert!(matrix[(0, 1)].is_none());
+    } // fn
+
+    #[test]
+    fn total_failure_returns_first_tile_error() {
+        let results = vec![
+            (tile(25..30, 0..4), Err(Error::RequestNotValidated)),
+            (tile(0..25, 0..4), Err(Error::QueryNotBuilt)),
+        ]; // vec
+
+        assert!(matches!(assemble(30, 4, results), Err(Error::QueryNotBuilt)));
+    } // fn
+
+    #[test]
+    fn complete_matrix_has_no_failures() {
+        let tiles = [tile(0..1, 0..2), tile(1..2, 0..2)];
+        let results = tiles.iter().map(|tile| (tile.clone(), success(tile))).collect();
+
+        let matrix = assemble(2, 2, results).unwrap();
+        assert!(matrix.is_complete());
+        assert_eq!(matrix.origin_addresses, vec!["o0", "o1"]);
+    } // fn
+
+    #[test]
+    fn empty_matrix_is_not_a_failure() {
+        let matrix = assemble(0, 0, Vec::new()).unwrap();
+        assert!(matrix.is_complete());
+        assert!(matrix.rows.is_empty());
+    } // fn
+} // mod
@@ -0,0 +1,28 @@
+//! Contains the `TileFailure` struct, which describes a tile of a large
+//! distance matrix that could not be retrieved.
+
+use crate::distance_matrix::{error::Error, large_distance_matrix::MatrixTile};
+
+// -----------------------------------------------------------------------------
+
+/// A tile of a large distance matrix whose request failed. The elements that
+/// it covers are `None` in the assembled response.
+
+#[derive(Debug)]
+pub struct TileFailure {
+    /// The block of origins and destinations that could not be retrieved.
+    pub tile: MatrixTile,
+
+    /// The error that the tile's request returned.
+    pub error: Error,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for TileFailure {
+    /// Formats the failed tile and its error in a way that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{tile}: {error}", tile=self.tile, error=self.error)
+    } // fn
+} // impl
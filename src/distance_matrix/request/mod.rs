@@ -3,6 +3,8 @@
 //! builder pattern. This module contains the tools (enums, structs, methods)
 //! for building your Google Maps Platform request.
 
+#[cfg(feature = "enable-reqwest")]
+mod at_departure;
 mod build;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
@@ -10,6 +12,8 @@ mod execute;
 mod get;
 mod new;
 mod query_url;
+#[cfg(feature = "enable-reqwest")]
+mod subset;
 mod validate;
 mod with_arrival_time;
 mod with_departure_time;
@@ -49,7 +53,7 @@ use chrono::NaiveDateTime;
 /// _Distance Matrix API_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request<'a> {
 
     // Required parameters:
@@ -0,0 +1,35 @@
+use crate::distance_matrix::request::Request;
+use std::ops::Range;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns a copy of this request, with the same options, for a block of
+    /// its origins and destinations. This is used by `LargeDistanceMatrix` to
+    /// split a large matrix into requests that are within Google's limits.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `origins` ‧ The indices of the origins to include.
+    ///
+    /// * `destinations` ‧ The indices of the destinations to include.
+    pub(crate) fn subset(&self, origins: Range<usize>, destinations: Range<usize>) -> Request<'a> {
+        Request {
+            origins: self.origins[origins].to_vec(),
+            destinations: self.destinations[destinations].to_vec(),
+            query: None,
+            validated: false,
+            ..self.clone()
+        } // Request
+    } // fn
+
+    /// Returns the number of origins in the request.
+    pub(crate) fn origin_count(&self) -> usize {
+        self.origins.len()
+    } // fn
+
+    /// Returns the number of destinations in the request.
+    pub(crate) fn destination_count(&self) -> usize {
+        self.destinations.len()
+    } // fn
+} // impl
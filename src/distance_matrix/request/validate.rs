@@ -1,5 +1,7 @@
 use crate::directions::travel_mode::TravelMode;
-use crate::distance_matrix::{error::Error, request::Request};
+use crate::distance_matrix::{
+    error::Error, request::Request, MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS,
+}; // use crate::distance_matrix
 
 impl<'a> Request<'a> {
 
@@ -66,6 +68,28 @@ impl<'a> Request<'a> {
 
         } // if
 
+        // Google only models traffic for a given departure time, so a traffic
+        // model cannot be set without one:
+        if let Some(traffic_model) = &self.traffic_model {
+            if self.departure_time.is_none() {
+                return Err(Error::TrafficModelRequiresDepartureTime(traffic_model.to_string()));
+            } // if
+        } // if
+
+        // Google rejects requests with too many origins, destinations or
+        // elements, so there is no point in sending them:
+        if self.origins.len() > MAX_ORIGINS {
+            return Err(Error::TooManyOrigins(self.origins.len()));
+        } // if
+
+        if self.destinations.len() > MAX_DESTINATIONS {
+            return Err(Error::TooManyDestinations(self.destinations.len()));
+        } // if
+
+        if self.origins.len() * self.destinations.len() > MAX_ELEMENTS {
+            return Err(Error::TooManyElements(self.origins.len(), self.destinations.len()));
+        } // if
+
         // Indicate that the request passed validation.
         self.validated = true;
 
@@ -0,0 +1,72 @@
+//! Contains the `Cell` struct, which holds the travel distance, time and fare
+//! for a single origin-destination pair.
+
+use crate::directions::response::transit_fare::TransitFare;
+use crate::distance::Distance;
+use crate::distance_matrix::response::{
+    element::Element,
+    element_status::ElementStatus,
+    matrix::Metric,
+}; // use crate::distance_matrix::response
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The travel distance, time and fare between an origin and a destination.
+/// Unlike `Element`, a `Cell` always has a distance and a duration.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Cell {
+    /// The distance of the route.
+    pub distance: Distance,
+
+    /// The length of time it takes to travel the route.
+    pub duration: Duration,
+
+    /// The length of time it takes to travel the route in traffic. This is
+    /// only present for driving requests with a departure time.
+    pub duration_in_traffic: Option<Duration>,
+
+    /// The total fare of the route. This is only present for transit requests
+    /// where fare information is available.
+    pub fare: Option<TransitFare>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Cell {
+    /// Converts an `Element` into a `Cell`. Returns `None` if the element's
+    /// status is not `ElementStatus::Ok`, or if it is missing a distance or a
+    /// duration.
+    pub fn from_element(element: &Element) -> Option<Cell> {
+        if element.status != ElementStatus::Ok {
+            return None;
+        } // if
+
+        Some(Cell {
+            distance: element.distance.as_ref()?.value,
+            duration: element.duration.as_ref()?.value,
+            duration_in_traffic: element.duration_in_traffic
+                .as_ref()
+                .map(|duration_in_traffic| duration_in_traffic.value),
+            fare: element.fare.clone(),
+        }) // Cell
+    } // fn
+
+    /// Returns the duration of the route in traffic if Google provided one,
+    /// and the regular duration otherwise.
+    pub fn duration_in_traffic_or_duration(&self) -> Duration {
+        self.duration_in_traffic.unwrap_or(self.duration)
+    } // fn
+
+    /// Returns the cell's value for a metric, in whole meters for
+    /// `Metric::Distance` and in seconds for the durations. This is the value
+    /// that cells are ranked by.
+    pub fn value(&self, metric: Metric) -> i64 {
+        match metric {
+            Metric::Distance => self.distance.meters().round() as i64,
+            Metric::Duration => self.duration.num_seconds(),
+            Metric::DurationInTraffic => self.duration_in_traffic_or_duration().num_seconds(),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+//! Exports a distance matrix as comma-separated values, for use in
+//! spreadsheets and other tools.
+
+use crate::csv::push_record;
+use crate::distance_matrix::response::matrix::{Cell, Matrix, Metric};
+
+// -----------------------------------------------------------------------------
+
+impl Matrix<Option<Cell>> {
+    /// Formats one metric of the matrix as
+    /// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) CSV.
+    ///
+    /// The first row contains the destination addresses and the first column
+    /// contains the origin addresses. The top-left field names the metric and
+    /// its unit. Pairs without a route are left empty.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `metric` ‧ The value to write for each pair: the distance in meters,
+    /// or the duration or duration in traffic in seconds.
+    pub fn to_csv(&self, metric: Metric) -> String {
+        let mut csv = String::new();
+
+        let header = std::iter::once(metric.to_string())
+            .chain(self.destination_addresses.iter().cloned());
+        push_record(&mut csv, header);
+
+        for (origin_address, row) in self.origin_addresses.iter().zip(self.rows()) {
+            let values = row.iter().map(|cell| cell
+                .as_ref()
+                .map(|cell| cell.value(metric).to_string())
+                .unwrap_or_default()
+            ); // map
+            push_record(&mut csv, std::iter::once(origin_address.clone()).chain(values));
+        } // for
+
+        csv
+    } // fn
+} // impl
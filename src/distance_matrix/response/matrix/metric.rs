@@ -0,0 +1,42 @@
+//! Contains the `Metric` enum, which selects the value that matrix cells are
+//! compared by.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The value used to rank the cells of a distance matrix, for example when
+/// looking for the nearest destination.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Metric {
+    /// Ranks cells by the distance of the route.
+    Distance,
+    /// Ranks cells by the length of time it takes to travel the route.
+    Duration,
+    /// Ranks cells by the length of time it takes to travel the route in
+    /// traffic. Cells without a traffic estimate use their regular duration.
+    DurationInTraffic,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Metric {
+    /// Returns a reasonable default variant for the `Metric` enum type.
+    fn default() -> Self {
+        Metric::Duration
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Metric {
+    /// Formats the metric, with its unit, as a column heading.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Metric::Distance => write!(f, "Distance (meters)"),
+            Metric::Duration => write!(f, "Duration (seconds)"),
+            Metric::DurationInTraffic => write!(f, "Duration in Traffic (seconds)"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,207 @@
+//! Contains the `Matrix` struct, a dense, typed view over the rows and elements
+//! of a Distance Matrix API response.
+
+mod cell;
+mod csv;
+mod metric;
+mod nearest;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::distance_matrix::response::matrix::{cell::Cell, metric::Metric};
+
+use crate::distance_matrix::response::Response;
+
+// -----------------------------------------------------------------------------
+//
+/// A dense grid of values with one row per origin and one column per
+/// destination. Each row and column is labelled with the address that Google
+/// returned for it.
+///
+/// Use `Response::matrix()` to get a `Matrix<Option<Cell>>` from a Distance
+/// Matrix API response.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Matrix<T> {
+    /// The origin address for each row.
+    pub origin_addresses: Vec<String>,
+
+    /// The destination address for each column.
+    pub destination_addresses: Vec<String>,
+
+    /// The values in row-major order. The value for origin `i` and destination
+    /// `j` is at index `i * destination_addresses.len() + j`.
+    cells: Vec<T>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<T> Matrix<T> {
+    /// Creates a matrix from its addresses and its values in row-major order.
+    pub(crate) fn from_cells(
+        origin_addresses: Vec<String>,
+        destination_addresses: Vec<String>,
+        cells: Vec<T>,
+    ) -> Self {
+        Matrix { origin_addresses, destination_addresses, cells }
+    } // fn
+
+    /// Returns the number of rows (origins) in the matrix.
+    pub fn origin_count(&self) -> usize {
+        self.origin_addresses.len()
+    } // fn
+
+    /// Returns the number of columns (destinations) in the matrix.
+    pub fn destination_count(&self) -> usize {
+        self.destination_addresses.len()
+    } // fn
+
+    /// Returns the value for an origin-destination pair, or `None` if either
+    /// index is out of range.
+    pub fn get(&self, origin: usize, destination: usize) -> Option<&T> {
+        if destination < self.destination_count() {
+            self.cells.get(origin * self.destination_count() + destination)
+        } else {
+            None
+        } // if
+    } // fn
+
+    /// Returns the values from an origin to every destination, or `None` if
+    /// the origin index is out of range.
+    pub fn row(&self, origin: usize) -> Option<&[T]> {
+        // A matrix with no columns has an empty row for every origin, so the
+        // origin has to be checked before slicing the (empty) cells:
+        if origin < self.origin_count() {
+            let columns = self.destination_count();
+            self.cells.get(origin * columns..(origin + 1) * columns)
+        } else {
+            None
+        } // if
+    } // fn
+
+    /// Returns an iterator over the rows of the matrix, in origin order. A
+    /// matrix with no columns has one empty row for each origin.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.origin_count()).filter_map(|origin| self.row(origin))
+    } // fn
+
+    /// Returns an iterator over every value along with its origin and
+    /// destination indices, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let columns = self.destination_count().max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| (index / columns, index % columns, value))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    /// Returns the value for an `(origin, destination)` pair.
+    ///
+    /// ## Panics:
+    ///
+    /// * Panics if either index is out of range. Use `get` to avoid this.
+    fn index(&self, (origin, destination): (usize, usize)) -> &T {
+        match self.get(origin, destination) {
+            Some(value) => value,
+            None => panic!(
+                "matrix index ({origin}, {destination}) is out of range for a \
+                {origins}×{destinations} matrix",
+                origins=self.origin_count(),
+                destinations=self.destination_count(),
+            ), // panic!
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the response as a dense matrix with one row per origin and one
+    /// column per destination.
+    ///
+    /// A cell is `Some` only if its element has an `ElementStatus::Ok` status
+    /// and both a distance and a duration. Every other element, including
+    /// elements missing from a malformed response, is `None`, so there is no
+    /// need to check statuses or options when reading the matrix.
+    pub fn matrix(&self) -> Matrix<Option<Cell>> {
+        let origins = self.origin_addresses.len().max(self.rows.len());
+        let destinations = self.rows
+            .iter()
+            .map(|row| row.elements.len())
+            .fold(self.destination_addresses.len(), usize::max);
+
+        let mut origin_addresses = self.origin_addresses.clone();
+        origin_addresses.resize(origins, String::new());
+        let mut destination_addresses = self.destination_addresses.clone();
+        destination_addresses.resize(destinations, String::new());
+
+        let cells = (0..origins)
+            .flat_map(|origin| (0..destinations).map(move |destination| (origin, destination)))
+            .map(|(origin, destination)| self.rows
+                .get(origin)
+                .and_then(|row| row.elements.get(destination))
+                .and_then(Cell::from_element)
+            ) // map
+            .collect();
+
+        Matrix { origin_addresses, destination_addresses, cells }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(origins: usize, destinations: usize) -> Matrix<usize> {
+        Matrix {
+            origin_addresses: (0..origins).map(|origin| format!("o{origin}")).collect(),
+            destination_addresses: (0..destinations).map(|destination| format!("d{destination}")).collect(),
+            cells: (0..origins * destinations).collect(),
+        } // Matrix
+    } // fn
+
+    #[test]
+    fn row_is_none_out_of_range() {
+        let matrix = matrix(2, 3);
+        assert_eq!(matrix.row(1), Some(&[3, 4, 5][..]));
+        assert_eq!(matrix.row(2), None);
+        assert_eq!(matrix.get(1, 2), Some(&5));
+        assert_eq!(matrix.get(1, 3), None);
+        assert_eq!(matrix.get(2, 0), None);
+    } // fn
+
+    #[test]
+    fn matrix_without_destinations_has_empty_rows() {
+        let matrix = matrix(2, 0);
+        assert_eq!(matrix.row(0), Some(&[][..]));
+        assert_eq!(matrix.row(1), Some(&[][..]));
+        assert_eq!(matrix.row(2), None);
+        assert_eq!(matrix.row(usize::MAX), None);
+        assert_eq!(matrix.rows().count(), 2);
+        assert_eq!(matrix.get(0, 0), None);
+    } // fn
+
+    #[test]
+    fn matrix_without_origins_has_no_rows() {
+        let matrix = matrix(0, 3);
+        assert_eq!(matrix.row(0), None);
+        assert_eq!(matrix.rows().count(), 0);
+        assert_eq!(matrix.iter().count(), 0);
+    } // fn
+
+    #[test]
+    fn rows_and_iter_are_in_row_major_order() {
+        let matrix = matrix(2, 2);
+        let rows: Vec<&[usize]> = matrix.rows().collect();
+        assert_eq!(rows, vec![&[0, 1][..], &[2, 3][..]]);
+        let cells: Vec<(usize, usize, usize)> = matrix.iter().map(|(o, d, value)| (o, d, *value)).collect();
+        assert_eq!(cells, vec![(0, 0, 0), (0, 1, 1), (1, 0, 2), (1, 1, 3)]);
+    } // fn
+} // mod
@@ -0,0 +1,106 @@
+//! Queries for finding the nearest origins and destinations in a distance
+//! matrix. These are the building blocks for dispatch, such as sending the
+//! closest driver to a customer.
+
+use crate::distance_matrix::response::matrix::{Cell, Matrix, Metric};
+
+// -----------------------------------------------------------------------------
+
+impl Matrix<Option<Cell>> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the index of the destination that is nearest to an origin.
+    /// Destinations without a route are ignored. Ties go to the lowest index.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `origin` ‧ The index of the origin.
+    ///
+    /// * `metric` ‧ Whether nearest means the shortest distance, the shortest
+    /// duration, or the shortest duration in traffic.
+
+    pub fn nearest_destination(&self, origin: usize, metric: Metric) -> Option<usize> {
+        self.k_nearest(origin, 1, metric).first().copied()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the index of the origin that is nearest to a destination.
+    /// Origins without a route are ignored. Ties go to the lowest index.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `destination` ‧ The index of the destination.
+    ///
+    /// * `metric` ‧ Whether nearest means the shortest distance, the shortest
+    /// duration, or the shortest duration in traffic.
+
+    pub fn nearest_origin(&self, destination: usize, metric: Metric) -> Option<usize> {
+        (0..self.origin_count())
+            .filter_map(|origin| match self.get(origin, destination) {
+                Some(Some(cell)) => Some((origin, cell.value(metric))),
+                _ => None,
+            }) // filter_map
+            .min_by_key(|(_origin, value)| *value)
+            .map(|(origin, _value)| origin)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the indices of the `k` destinations that are nearest to an
+    /// origin, nearest first. Fewer than `k` indices are returned if fewer
+    /// destinations have a route. Ties are ordered by index.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `origin` ‧ The index of the origin.
+    ///
+    /// * `k` ‧ The maximum number of destinations to return.
+    ///
+    /// * `metric` ‧ Whether nearest means the shortest distance, the shortest
+    /// duration, or the shortest duration in traffic.
+
+    pub fn k_nearest(&self, origin: usize, k: usize, metric: Metric) -> Vec<usize> {
+        let mut destinations: Vec<(usize, i64)> = self.row(origin)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(|(destination, cell)| cell
+                .as_ref()
+                .map(|cell| (destination, cell.value(metric)))
+            ) // filter_map
+            .collect();
+
+        // The sort is stable, so destinations that tie stay in index order:
+        destinations.sort_by_key(|(_destination, value)| *value);
+
+        destinations
+            .into_iter()
+            .take(k)
+            .map(|(destination, _value)| destination)
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `(origin, destination)` indices of the pair with the lowest
+    /// value anywhere in the matrix. Ties go to the first pair in row-major
+    /// order.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `metric` ‧ Whether to find the shortest distance, the shortest
+    /// duration, or the shortest duration in traffic.
+
+    pub fn argmin(&self, metric: Metric) -> Option<(usize, usize)> {
+        self.iter()
+            .filter_map(|(origin, destination, cell)| cell
+                .as_ref()
+                .map(|cell| ((origin, destination), cell.value(metric)))
+            ) // filter_map
+            .min_by_key(|(_pair, value)| *value)
+            .map(|(pair, _value)| pair)
+    } // fn
+
+} // impl
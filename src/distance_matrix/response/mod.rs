@@ -7,6 +7,7 @@
 
 pub mod element;
 pub mod element_status;
+pub mod matrix;
 pub mod row;
 pub mod status;
 
@@ -0,0 +1,192 @@
+//! Contains the `DisplayDuration` struct, which formats a `chrono::Duration`
+//! for people to read, in the words of a `Language`.
+
+use crate::language::Language;
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// Formats a duration the way Google does in its `text` fields, for example
+/// "1 hour 5 mins" or "2 days 3 hours". Use this for durations that Google
+/// didn't format, such as route totals and estimated arrival times.
+///
+/// Durations are rounded to the nearest minute, and durations under a minute
+/// are shown as one minute. Minutes are left out of durations of a day or
+/// more.
+///
+/// English, Chinese, Dutch, French, German, Italian, Japanese, Korean,
+/// Portuguese and Spanish are supported. Other languages are formatted in
+/// English.
+///
+/// ## Example:
+///
+/// ```rust
+/// let duration = Duration::minutes(65);
+/// assert_eq!(DisplayDuration::new(duration, Language::English).to_string(), "1 hour 5 mins");
+/// assert_eq!(DisplayDuration::new(duration, Language::German).to_string(), "1 Std. 5 Min.");
+/// assert_eq!(DisplayDuration::new(duration, Language::Japanese).to_string(), "1時間5分");
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DisplayDuration {
+    /// The duration to format.
+    pub duration: Duration,
+
+    /// The language to format it in.
+    pub language: Language,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl DisplayDuration {
+    /// Creates a formatter for the duration in the given language.
+    pub fn new(duration: Duration, language: Language) -> Self {
+        DisplayDuration { duration, language }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for DisplayDuration {
+    /// Formats the duration as days, hours and minutes.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let words = Words::for_language(&self.language);
+
+        let seconds = self.duration.num_seconds();
+        if seconds < 0 {
+            write!(f, "-")?;
+        } // if
+
+        let seconds = seconds.unsigned_abs();
+        let minutes = match (seconds + 30) / 60 {
+            0 if seconds > 0 => 1,
+            minutes => minutes,
+        }; // match
+
+        let (days, hours, minutes) = (minutes / 1_440, minutes / 60 % 24, minutes % 60);
+        let parts: Vec<(u64, (&str, &str))> = if days > 0 {
+            vec![(days, words.day), (hours, words.hour)]
+        } else if hours > 0 {
+            vec![(hours, words.hour), (minutes, words.minute)]
+        } else {
+            vec![(minutes, words.minute)]
+        }; // if
+
+        let text: Vec<String> = parts
+            .into_iter()
+            .enumerate()
+            .filter(|(index, (count, _unit))| *index == 0 || *count > 0)
+            .map(|(_index, (count, (singular, plural)))| {
+                let unit = if count == 1 { singular } else { plural };
+                format!("{count}{}{unit}", words.unit_separator)
+            }) // map
+            .collect();
+
+        write!(f, "{}", text.join(words.part_separator))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The words used to format a duration in one language. Each unit has a
+/// singular and a plural form.
+
+struct Words {
+    day: (&'static str, &'static str),
+    hour: (&'static str, &'static str),
+    minute: (&'static str, &'static str),
+    /// The text between a number and its unit.
+    unit_separator: &'static str,
+    /// The text between the days, hours and minutes.
+    part_separator: &'static str,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Words {
+    /// Returns the words for a language, or the English words for languages
+    /// that aren't supported.
+    fn for_language(language: &Language) -> Self {
+        let (day, hour, minute, unit_separator, part_separator) = match language {
+            Language::Chinese | Language::ChineseSimplified =>
+                (("天", "天"), ("小时", "小时"), ("分钟", "分钟"), "", ""),
+            Language::ChineseHongKong | Language::ChineseTaiwan | Language::ChineseTraditional =>
+                (("天", "天"), ("小時", "小時"), ("分鐘", "分鐘"), "", ""),
+            Language::Dutch =>
+                (("dag", "dagen"), ("u", "u"), ("min.", "min."), " ", " "),
+            Language::French | Language::FrenchCanada =>
+                (("jour", "jours"), ("h", "h"), ("min", "min"), " ", " "),
+            Language::German =>
+                (("Tag", "Tage"), ("Std.", "Std."), ("Min.", "Min."), " ", " "),
+            Language::Italian =>
+                (("giorno", "giorni"), ("ora", "ore"), ("min", "min"), " ", " "),
+            Language::Japanese =>
+                (("日", "日"), ("時間", "時間"), ("分", "分"), "", ""),
+            Language::Korean =>
+                (("일", "일"), ("시간", "시간"), ("분", "분"), "", " "),
+            Language::Portuguese | Language::PortugueseBrazil | Language::PortuguesePortugal =>
+                (("dia", "dias"), ("h", "h"), ("min", "min"), " ", " "),
+            Language::Spanish | Language::SpanishLatinAmerica =>
+                (("día", "días"), ("h", "h"), ("min", "min"), " ", " "),
+            _ =>
+                (("day", "days"), ("hour", "hours"), ("min", "mins"), " ", " "),
+        }; // match
+
+        Words { day, hour, minute, unit_separator, part_separator }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english(duration: Duration) -> String {
+        DisplayDuration::new(duration, Language::English).to_string()
+    } // fn
+
+    #[test]
+    fn rounds_to_the_nearest_minute() {
+        assert_eq!(english(Duration::zero()), "0 mins");
+        assert_eq!(english(Duration::seconds(1)), "1 min");
+        assert_eq!(english(Duration::seconds(89)), "1 min");
+        assert_eq!(english(Duration::seconds(90)), "2 mins");
+        assert_eq!(english(Duration::seconds(3_569)), "59 mins");
+        assert_eq!(english(Duration::seconds(3_570)), "1 hour");
+        assert_eq!(english(Duration::seconds(-90)), "-2 mins");
+    } // fn
+
+    #[test]
+    fn uses_singular_and_plural_units() {
+        assert_eq!(english(Duration::minutes(61)), "1 hour 1 min");
+        assert_eq!(english(Duration::minutes(125)), "2 hours 5 mins");
+        assert_eq!(english(Duration::hours(24)), "1 day");
+        assert_eq!(english(Duration::hours(25)), "1 day 1 hour");
+        // Minutes are left out of durations of a day or more:
+        assert_eq!(english(Duration::minutes(2 * 1_440 + 3 * 60 + 59)), "2 days 3 hours");
+    } // fn
+
+    #[test]
+    fn formats_each_language() {
+        let duration = Duration::minutes(1_440 + 120);
+        let formatted = |language: Language| DisplayDuration::new(duration, language).to_string();
+        assert_eq!(formatted(Language::ChineseSimplified), "1天2小时");
+        assert_eq!(formatted(Language::ChineseTraditional), "1天2小時");
+        assert_eq!(formatted(Language::Dutch), "1 dag 2 u");
+        assert_eq!(formatted(Language::French), "1 jour 2 h");
+        assert_eq!(formatted(Language::German), "1 Tag 2 Std.");
+        assert_eq!(formatted(Language::Italian), "1 giorno 2 ore");
+        assert_eq!(formatted(Language::Japanese), "1日2時間");
+        assert_eq!(formatted(Language::Korean), "1일 2시간");
+        assert_eq!(formatted(Language::PortugueseBrazil), "1 dia 2 h");
+        assert_eq!(formatted(Language::Spanish), "1 día 2 h");
+        // Unsupported languages fall back to English:
+        assert_eq!(formatted(Language::Swahili), "1 day 2 hours");
+
+        let minutes = Duration::minutes(65);
+        assert_eq!(DisplayDuration::new(minutes, Language::German).to_string(), "1 Std. 5 Min.");
+        assert_eq!(DisplayDuration::new(minutes, Language::Dutch).to_string(), "1 u 5 min.");
+        assert_eq!(DisplayDuration::new(Duration::days(3), Language::Spanish).to_string(), "3 días");
+    } // fn
+} // mod
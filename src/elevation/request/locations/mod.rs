@@ -127,6 +127,17 @@ impl std::convert::From<&Locations> for String {
 
 // -----------------------------------------------------------------------------
 
+impl Locations {
+    /// Encodes a slice of `LatLng` coordinates into a `Locations::Polyline`
+    /// variant. An encoded polyline produces a much shorter URL than a list
+    /// of `LatLngs` when requesting elevation data for many points.
+    pub fn from_points(points: &[LatLng]) -> Self {
+        Locations::Polyline(crate::polyline::encode(points, crate::polyline::Precision::Five))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::default::Default for Locations {
     /// Returns a reasonable default variant for the `Locations` enum type.
     fn default() -> Self {
@@ -0,0 +1,40 @@
+//! Provides GeoJSON export for the Elevation API's `Point` struct and
+//! response, for use with the [geojson](https://crates.io/crates/geojson)
+//! crate.
+
+use crate::elevation::response::{point::Point, Response};
+use geojson::{Feature, FeatureCollection, Geometry, Value};
+
+// -----------------------------------------------------------------------------
+
+impl Point {
+    /// Converts this elevation point into a GeoJSON `Point` feature. The
+    /// elevation is included both as the position's third (altitude) value and
+    /// as an `elevation` property, in meters. The `resolution` property is
+    /// included when Google provides one.
+    pub fn to_geojson(&self) -> Feature {
+        let mut position = self.location.to_geojson_position();
+        position.push(self.elevation.meters());
+        let mut feature = Feature::from(Geometry::new(Value::Point(position)));
+        feature.set_property("elevation", self.elevation.meters());
+        if let Some(resolution) = self.resolution {
+            feature.set_property("resolution", resolution.meters());
+        } // if
+        feature
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Converts the elevation points in this response into a GeoJSON feature
+    /// collection of `Point` features.
+    pub fn to_geojson(&self) -> FeatureCollection {
+        self.results
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(Point::to_geojson)
+            .collect()
+    } // fn
+} // impl
@@ -2,6 +2,8 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
+#[cfg(feature = "geojson")]
+mod geojson_conversions;
 pub mod point;
 pub mod status;
 
@@ -0,0 +1,114 @@
+//! The Maps Embed API **Directions** mode displays the path between two or
+//! more specified points on the map, as well as the distance and travel time.
+//!
+//! # [Parameters](https://developers.google.com/maps/documentation/embed/embedding-map#directions_mode)
+//!
+//! * `origin` (required) - Defines the starting point from which to display
+//! directions.
+//!
+//! * `destination` (required) - Defines the end point of the directions.
+
+mod build;
+mod new;
+mod query_url;
+mod validate;
+mod with_center;
+mod with_language;
+mod with_map_type;
+mod with_region;
+mod with_restrictions;
+mod with_travel_mode;
+mod with_unit_system;
+mod with_waypoints;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::avoid::Avoid;
+use crate::directions::request::location::Location;
+use crate::directions::request::unit_system::UnitSystem;
+use crate::directions::request::waypoint::Waypoint;
+use crate::directions::travel_mode::TravelMode;
+use crate::embed::map_type::MapType;
+use crate::language::Language;
+use crate::latlng::LatLng;
+use crate::region::Region;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.google.com/maps/embed/v1/directions";
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Embed Directions_ URL**. The methods implemented for this struct are
+/// what's used to build your `<iframe>` URL.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// Defines the starting point from which to display directions.
+    origin: Location,
+
+    /// Defines the end point of the directions.
+    destination: Location,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Specifies one or more intermediary places to route directions through
+    /// between the origin and destination.
+    waypoints: Option<Vec<Waypoint>>,
+
+    /// Defines the method of travel. If no mode is specified, the Embed API
+    /// will show one or more of the most relevant modes for the specified
+    /// route.
+    travel_mode: Option<TravelMode>,
+
+    /// Specifies features to avoid in directions. Only ferries, highways and
+    /// tolls are supported by the Embed API.
+    restrictions: Option<Vec<Avoid>>,
+
+    /// Specifies the method to use when displaying distances in the results.
+    /// If units are not specified, the origin country of the query determines
+    /// the units to use.
+    unit_system: Option<UnitSystem>,
+
+    /// Defines the center of the map view. Accepts comma-separated latitude
+    /// and longitude values.
+    center: Option<LatLng>,
+
+    /// Sets the initial zoom level of the map. Accepted values range from 0
+    /// (the whole world) to 21 (individual buildings).
+    zoom: Option<u8>,
+
+    /// Defines the type of map tiles to load.
+    map_type: Option<MapType>,
+
+    /// Defines the language to use for UI elements and for the display of
+    /// labels on map tiles. By default, visitors will see a map in their own
+    /// language.
+    language: Option<Language>,
+
+    /// Defines the appropriate borders and labels to display, based on
+    /// geo-political sensitivities.
+    region: Option<Region>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be embedded in the `<iframe>` URL.
+    query: Option<String>,
+
+    /// Has the request been validated?
+    validated: bool,
+
+} // struct
@@ -0,0 +1,49 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::request::location::Location;
+use crate::embed::directions::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Embed Directions URL with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `origin` ‧ The starting point from which to display directions.
+    /// * `destination` ‧ The end point of the directions.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        origin: Location,
+        destination: Location,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            origin,
+            destination,
+            // Optional parameters:
+            waypoints: None,
+            travel_mode: None,
+            restrictions: None,
+            unit_system: None,
+            center: None,
+            zoom: None,
+            map_type: None,
+            language: None,
+            region: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+
+    } // fn
+
+} // impl
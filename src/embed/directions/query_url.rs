@@ -0,0 +1,76 @@
+use crate::embed::directions::{SERVICE_URL, Request};
+use crate::embed::error::Error;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `<iframe>` URL that represents the map you've built.
+    ///
+    /// ## Description:
+    ///
+    /// The returned URL may be used directly as the `src` attribute of an
+    /// `<iframe>` element. Note that the URL contains your API key, so the key
+    /// should be restricted to the Maps Embed API and to your website's
+    /// domain(s).
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.validate()?.build()?.query.as_ref().unwrap(),
+        }; // match
+        Ok(format!("{SERVICE_URL}?{query_string}"))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use crate::directions::request::{avoid::Avoid, location::Location, waypoint::Waypoint};
+    use crate::directions::travel_mode::TravelMode;
+    use crate::embed::error::Error;
+
+    #[test]
+    fn query_url_has_the_trip() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let mut request = client.embed_directions(
+            Location::Address("Oslo".to_string()),
+            Location::Address("Telemark".to_string()),
+        ); // embed_directions
+        let url = request
+            .with_waypoints(vec![Waypoint::Address("Drammen".to_string())])
+            .with_travel_mode(TravelMode::Driving)
+            .with_restrictions(vec![Avoid::Tolls, Avoid::Highways])
+            .query_url();
+        assert_eq!(
+            url.unwrap(),
+            "https://www.google.com/maps/embed/v1/directions\
+            ?key=YOUR_GOOGLE_API_KEY_HERE&origin=Oslo&destination=Telemark\
+            &waypoints=Drammen&mode=driving&avoid=tolls%7Chighways"
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn query_url_rejects_polyline_waypoints() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let mut request = client.embed_directions(
+            Location::Address("Oslo".to_string()),
+            Location::Address("Telemark".to_string()),
+        ); // embed_directions
+        let url = request
+            .with_waypoints(vec![Waypoint::Polyline("_p~iF~ps|U".to_string())])
+            .query_url();
+        assert_eq!(url, Err(Error::PolylineWaypoint("_p~iF~ps|U".to_string())));
+    } // fn
+} // mod
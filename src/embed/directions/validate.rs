@@ -0,0 +1,70 @@
+use crate::directions::{
+    request::{avoid::Avoid, waypoint::Waypoint},
+    travel_mode::TravelMode,
+}; // crate::directions
+use crate::embed::{directions::Request, error::Error};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that the
+    /// Maps Embed API will accept them - i.e. it will not allow a zoom level
+    /// outside of 0 to 21. Waypoints are checked the same way the Directions
+    /// API request builder checks them, with the Embed API's lower limit of
+    /// 20 waypoints.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        if let Some(waypoints) = &self.waypoints {
+
+            // If the travel mode has been set to TravelMode::Transit,
+            // waypoints cannot be set:
+            if self.travel_mode == Some(TravelMode::Transit) {
+                return Err(Error::EitherWaypointsOrTransitMode(waypoints.len()));
+            } // if
+
+            // Ensure that the number of waypoints is equal to or less than 20:
+            if waypoints.len() > 20 {
+                return Err(Error::TooManyWaypoints(waypoints.len()));
+            } // if
+
+            // Encoded polylines are not accepted by the Embed API:
+            if let Some(Waypoint::Polyline(polyline)) = waypoints
+                .iter()
+                .find(|waypoint| matches!(waypoint, Waypoint::Polyline(_))) {
+                return Err(Error::PolylineWaypoint(polyline.to_string()));
+            } // if
+
+        } // if
+
+        // The Embed API can only avoid ferries, highways and tolls:
+        if let Some(restrictions) = &self.restrictions {
+            if restrictions.contains(&Avoid::Indoor) {
+                return Err(Error::AvoidIndoor);
+            } // if
+        } // if
+
+        // If a zoom level has been set, ensure that it is between 0 and 21:
+        if let Some(zoom) = self.zoom {
+            if zoom > 21 {
+                return Err(Error::InvalidZoom(zoom));
+            } // if
+        } // if
+
+        // Indicate that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
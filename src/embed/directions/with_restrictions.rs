@@ -0,0 +1,35 @@
+use crate::directions::request::avoid::Avoid;
+use crate::embed::directions::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify features that routes should avoid.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `restrictions` ‧ Features to avoid. Note that this doesn't preclude
+    /// routes that include the restricted feature(s); it biases the result to
+    /// more favorable routes. `Avoid::Indoor` is not supported by the Embed
+    /// API.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_restrictions(vec![Avoid::Tolls, Avoid::Highways])
+    /// ```
+
+    pub fn with_restrictions(&'a mut self, restrictions: Vec<Avoid>) -> &'a mut Request {
+
+        // Set restrictions in Request struct.
+        self.restrictions = Some(restrictions);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,33 @@
+use crate::directions::travel_mode::TravelMode;
+use crate::embed::directions::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the mode of transportation.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `travel_mode` ‧ The method of travel used to compute the embedded
+    /// route. Defaults to the most relevant modes for the route.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_travel_mode(TravelMode::Walking)
+    /// ```
+
+    pub fn with_travel_mode(&'a mut self, travel_mode: TravelMode) -> &'a mut Request {
+
+        // Set travel mode in Request struct.
+        self.travel_mode = Some(travel_mode);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,33 @@
+use crate::directions::request::unit_system::UnitSystem;
+use crate::embed::directions::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the unit system used to display distances in the results.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `unit_system` ‧ Either `UnitSystem::Metric` or
+    /// `UnitSystem::Imperial`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_unit_system(UnitSystem::Imperial)
+    /// ```
+
+    pub fn with_unit_system(&'a mut self, unit_system: UnitSystem) -> &'a mut Request {
+
+        // Set unit system in Request struct.
+        self.unit_system = Some(unit_system);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
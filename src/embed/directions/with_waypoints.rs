@@ -0,0 +1,38 @@
+use crate::directions::request::waypoint::Waypoint;
+use crate::embed::directions::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify pass throughs or stopovers at intermediate locations.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `waypoints` ‧ Intermediate locations to route the directions
+    /// through. Up to 20 waypoints may be given as an address, place ID or
+    /// latitude & longitude. Waypoints are not supported in transit mode and
+    /// encoded polylines are not supported by the Embed API.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_waypoints(vec![
+    ///     Waypoint::Address(String::from("Montreal, QC")),
+    ///     Waypoint::PlaceId(String::from("ChIJDbdkHFQayUwR7-8fITgxTmU")),
+    /// ])
+    /// ```
+
+    pub fn with_waypoints(&'a mut self, waypoints: Vec<Waypoint>) -> &'a mut Request {
+
+        // Set waypoints in Request struct.
+        self.waypoints = Some(waypoints);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
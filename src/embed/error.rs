@@ -0,0 +1,98 @@
+//! Maps Embed API error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when building a Google Maps Embed API URL.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The Embed API can only avoid ferries, highways and tolls. Indoor steps
+    /// can not be avoided.
+    AvoidIndoor,
+    /// Waypoints may not be specified in Transit travel mode.
+    EitherWaypointsOrTransitMode(usize),
+    /// The Street View field of view must be between 10 and 100 degrees.
+    InvalidFieldOfView(u8),
+    /// The Street View heading must be between -180 and 360 degrees.
+    InvalidHeading(i16),
+    /// API client library attempted to parse a string that contained an invalid
+    /// map type code. See `google_maps\src\embed\map_type.rs` for more
+    /// information.
+    InvalidMapTypeCode(String),
+    /// The Street View pitch must be between -90 and 90 degrees.
+    InvalidPitch(i8),
+    /// The map zoom level must be between 0 and 21.
+    InvalidZoom(u8),
+    /// Encoded polylines can not be used as waypoints in an embedded map.
+    PolylineWaypoint(String),
+    /// The request must be validated before a query string may be built.
+    RequestNotValidated,
+    /// The Embed API supports a maximum of 20 waypoints.
+    TooManyWaypoints(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::AvoidIndoor => write!(f,
+                "Google Maps Embed API client: \
+                The Embed API can only avoid `ferries`, `highways` and `tolls`. \
+                Remove `Avoid::Indoor` from the restrictions."),
+            Error::EitherWaypointsOrTransitMode(waypoint_count) => write!(f,
+                "Google Maps Embed API client: \
+                Waypoints cannot be used with the `transit` travel mode. \
+                {waypoint_count} waypoints are set. \
+                Remove the waypoints or choose another travel mode."),
+            Error::InvalidFieldOfView(fov) => write!(f,
+                "Google Maps Embed API client: \
+                `{fov}` is not a valid field of view. \
+                The field of view must be between 10 and 100 degrees."),
+            Error::InvalidHeading(heading) => write!(f,
+                "Google Maps Embed API client: \
+                `{heading}` is not a valid heading. \
+                The heading must be between -180 and 360 degrees."),
+            Error::InvalidMapTypeCode(map_type_code) => write!(f,
+                "Google Maps Embed API client: \
+                `{map_type_code}` is not a valid map type code. \
+                Valid codes are `roadmap`, and `satellite`."),
+            Error::InvalidPitch(pitch) => write!(f,
+                "Google Maps Embed API client: \
+                `{pitch}` is not a valid pitch. \
+                The pitch must be between -90 and 90 degrees."),
+            Error::InvalidZoom(zoom) => write!(f,
+                "Google Maps Embed API client: \
+                `{zoom}` is not a valid zoom level. \
+                The zoom level must be between 0 and 21."),
+            Error::PolylineWaypoint(polyline) => write!(f,
+                "Google Maps Embed API client: \
+                The encoded polyline waypoint `{polyline}` can not be used in an embedded map. \
+                Decode it into `Waypoint::LatLng` points instead."),
+            Error::RequestNotValidated => write!(f,
+                "Google Maps Embed API client: \
+                The request must be validated before a query string may be built. \
+                Ensure the validate() method is called before build()."),
+            Error::TooManyWaypoints(waypoint_count) => write!(f,
+                "Google Maps Embed API client: \
+                The maximum allowed number of waypoints is 20 plus the origin and destination. \
+                {waypoint_count} waypoints are set. \
+                Please remove {} waypoint(s).",
+                waypoint_count - 20),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. The Embed API URL builder does not depend on
+    /// any other library for its errors.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    } // fn
+} // impl
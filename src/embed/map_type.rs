@@ -0,0 +1,130 @@
+//! Contains the `MapType` enum and its associated traits. It is used to
+//! select the type of map tiles loaded by an embedded map.
+
+use crate::embed::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Defines the [type of map
+/// tiles](https://developers.google.com/maps/documentation/embed/embedding-map#optional_parameters)
+/// to load in an embedded map.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum MapType {
+    /// The default road map view.
+    #[serde(alias = "roadmap")]
+    Roadmap,
+    /// Google Earth satellite images.
+    #[serde(alias = "satellite")]
+    Satellite,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for MapType {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match MapType::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MapType> for String {
+    /// Converts a `MapType` enum to a `String` that contains a map type code.
+    fn from(map_type: &MapType) -> String {
+        match map_type {
+            MapType::Roadmap => String::from("roadmap"),
+            MapType::Satellite => String::from("satellite"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static MAP_TYPES_BY_CODE: phf::Map<&'static str, MapType> = phf_map! {
+    "roadmap" => MapType::Roadmap,
+    "satellite" => MapType::Satellite,
+};
+
+impl std::convert::TryFrom<&str> for MapType {
+    // Error definitions are contained in the
+    // `google_maps\src\embed\error.rs` module.
+    type Error = crate::embed::error::Error;
+    /// Gets a `MapType` enum from a `String` that contains a valid map type
+    /// code.
+    fn try_from(map_type_code: &str) -> Result<Self, Self::Error> {
+        MAP_TYPES_BY_CODE
+            .get(map_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidMapTypeCode(map_type_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for MapType {
+    // Error definitions are contained in the
+    // `google_maps\src\embed\error.rs` module.
+    type Err = crate::embed::error::Error;
+    /// Gets a `MapType` enum from a `String` that contains a valid map type
+    /// code.
+    fn from_str(map_type_code: &str) -> Result<Self, Self::Err> {
+        MAP_TYPES_BY_CODE
+            .get(map_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidMapTypeCode(map_type_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for MapType {
+    /// Returns a reasonable default variant for the `MapType` enum type.
+    fn default() -> Self {
+        MapType::Roadmap
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MapType {
+    /// Formats a `MapType` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MapType::Roadmap => write!(f, "Roadmap"),
+            MapType::Satellite => write!(f, "Satellite"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_map_type_codes() {
+        assert_eq!("satellite".parse::<MapType>(), Ok(MapType::Satellite));
+        assert_eq!(serde_json::from_str::<MapType>("\"roadmap\"").unwrap(), MapType::Roadmap);
+        assert_eq!(
+            "hybrid".parse::<MapType>(),
+            Err(Error::InvalidMapTypeCode("hybrid".to_string()))
+        ); // assert_eq!
+        assert!(serde_json::from_str::<MapType>("\"hybrid\"").is_err());
+    } // fn
+
+    #[test]
+    fn map_type_round_trips_through_its_code() {
+        for map_type in [MapType::Roadmap, MapType::Satellite] {
+            assert_eq!(String::from(&map_type).parse::<MapType>(), Ok(map_type));
+        } // for
+    } // fn
+} // mod
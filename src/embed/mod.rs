@@ -0,0 +1,55 @@
+//! **Maps Embed API** - Place an interactive map, or Street View panorama on
+//! your site with a simple HTTP request.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/embed/get-started)
+//!
+//! The Maps Embed API lets you display a map or Street View panorama by
+//! setting the URL produced by this module as the `src` attribute of an
+//! `<iframe>`. Nothing is requested from Google by this client: the URL is
+//! built (and validated) locally, and the visitor's browser loads it.
+//!
+//! Maps Embed API usage is free, but the API key used must have the Maps
+//! Embed API enabled. Because the key is visible in the page source, it
+//! should be restricted to your website's domain(s).
+//!
+//! # [Modes](https://developers.google.com/maps/documentation/embed/embedding-map)
+//!
+//! * `place` ‧ Displays a map pin at a particular place or address.
+//! * `view` ‧ Returns a map with no markers or directions.
+//! * `directions` ‧ Displays the path between two or more specified points on
+//! the map, as well as the distance and travel time.
+//! * `streetview` ‧ Shows interactive panoramic views from designated
+//! locations.
+//! * `search` ‧ Shows results for a search across the visible map region.
+//!
+//! # Example
+//!
+//! ```rust
+//! let iframe_src = google_maps_client.embed_directions(
+//!     Location::Address(String::from("Oslo Norway")),
+//!     Location::Address(String::from("Telemark Norway")),
+//! )
+//! .with_waypoints(vec![Waypoint::Address(String::from("Drammen Norway"))])
+//! .with_restrictions(vec![Avoid::Tolls, Avoid::Highways])
+//! .query_url()?;
+//! ```
+
+pub mod directions;
+pub mod error;
+pub mod map_type;
+pub mod place;
+pub mod search;
+pub mod street_view;
+pub mod view;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::embed::{
+    directions::Request as EmbedDirectionsRequest,
+    error::Error as EmbedError,
+    map_type::MapType,
+    place::Request as EmbedPlaceRequest,
+    search::Request as EmbedSearchRequest,
+    street_view::Request as EmbedStreetViewRequest,
+    view::Request as EmbedViewRequest,
+}; // crate::embed
@@ -0,0 +1,43 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::request::location::Location;
+use crate::embed::place::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Embed Place URL with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `q` ‧ The place to mark on the map: a place name, address, latitude &
+    /// longitude, or place ID.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        q: Location,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            q,
+            // Optional parameters:
+            center: None,
+            zoom: None,
+            map_type: None,
+            language: None,
+            region: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+
+    } // fn
+
+} // impl
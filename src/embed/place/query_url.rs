@@ -0,0 +1,65 @@
+use crate::embed::place::{SERVICE_URL, Request};
+use crate::embed::error::Error;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `<iframe>` URL that represents the map you've built.
+    ///
+    /// ## Description:
+    ///
+    /// The returned URL may be used directly as the `src` attribute of an
+    /// `<iframe>` element. Note that the URL contains your API key, so the key
+    /// should be restricted to the Maps Embed API and to your website's
+    /// domain(s).
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.validate()?.build()?.query.as_ref().unwrap(),
+        }; // match
+        Ok(format!("{SERVICE_URL}?{query_string}"))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use crate::directions::request::location::Location;
+    use crate::embed::{error::Error, map_type::MapType};
+
+    #[test]
+    fn query_url_has_the_place_and_options() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let mut request = client.embed_place(Location::Address("Eiffel Tower, Paris".to_string()));
+        let url = request
+            .with_zoom(17)
+            .with_map_type(MapType::Satellite)
+            .query_url();
+        assert_eq!(
+            url.unwrap(),
+            "https://www.google.com/maps/embed/v1/place\
+            ?key=YOUR_GOOGLE_API_KEY_HERE&q=Eiffel%20Tower%2C%20Paris\
+            &zoom=17&maptype=satellite"
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn query_url_rejects_a_zoom_level_beyond_21() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let mut request = client.embed_place(Location::Address("Eiffel Tower, Paris".to_string()));
+        assert_eq!(request.with_zoom(22).query_url(), Err(Error::InvalidZoom(22)));
+    } // fn
+} // mod
@@ -0,0 +1,37 @@
+use crate::embed::place::Request;
+use crate::language::Language;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the language to use for UI elements and for the display of
+    /// labels on map tiles.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `language` ‧ The language the embedded map should be presented in.
+    /// This parameter is only supported for some country tiles; if the
+    /// specific language requested is not supported for the tile set, then
+    /// the default language for that tileset will be used.
+    ///
+    /// ## Example:
+    ///
+    /// * Force language to French:
+    /// ```rust
+    /// .with_language(Language::French)
+    /// ```
+
+    pub fn with_language(&'a mut self, language: Language) -> &'a mut Request {
+
+        // Set language in Request struct.
+        self.language = Some(language);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
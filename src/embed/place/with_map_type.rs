@@ -0,0 +1,33 @@
+use crate::embed::map_type::MapType;
+use crate::embed::place::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Defines the type of map tiles to load.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `map_type` ‧ Either `MapType::Roadmap` (the default) or
+    /// `MapType::Satellite`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_map_type(MapType::Satellite)
+    /// ```
+
+    pub fn with_map_type(&'a mut self, map_type: MapType) -> &'a mut Request {
+
+        // Set map type in Request struct.
+        self.map_type = Some(map_type);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
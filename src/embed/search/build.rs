@@ -0,0 +1,70 @@
+use crate::embed::error::Error;
+use crate::embed::search::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Maps Embed API Search mode based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // Ensure request has been validated before building the query string:
+        if !self.validated { return Err(Error::RequestNotValidated) }
+
+        // Builds the "required parameters" portion of the query string:
+        let mut query = format!(
+            "key={}&q={}",
+            self.client.key,
+            utf8_percent_encode(&self.q, NON_ALPHANUMERIC),
+        ); // format!
+
+        // Builds the "optional parameters" portion of the query string:
+
+        // Center key/value pair:
+        if let Some(center) = &self.center {
+            query.push_str("&center=");
+            query.push_str(&utf8_percent_encode(&String::from(center), NON_ALPHANUMERIC).to_string())
+        } // if
+
+        // Zoom key/value pair:
+        if let Some(zoom) = &self.zoom {
+            query.push_str("&zoom=");
+            query.push_str(&zoom.to_string())
+        } // if
+
+        // Map type key/value pair:
+        if let Some(map_type) = &self.map_type {
+            query.push_str("&maptype=");
+            query.push_str(&String::from(map_type))
+        } // if
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&language=");
+            query.push_str(&String::from(language))
+        } // if
+
+        // Region key/value pair:
+        if let Some(region) = &self.region {
+            query.push_str("&region=");
+            query.push_str(&String::from(region))
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
@@ -0,0 +1,83 @@
+//! The Maps Embed API **Search** mode displays results for a search across
+//! the visible map region.
+//!
+//! # [Parameters](https://developers.google.com/maps/documentation/embed/embedding-map#search_mode)
+//!
+//! * `q` (required) - Defines the search term. It can include a geographic
+//! restriction, such as _in+Seattle_ or _near+98033_.
+
+mod build;
+mod new;
+mod query_url;
+mod validate;
+mod with_center;
+mod with_language;
+mod with_map_type;
+mod with_region;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::embed::map_type::MapType;
+use crate::language::Language;
+use crate::latlng::LatLng;
+use crate::region::Region;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.google.com/maps/embed/v1/search";
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Embed Search_ URL**. The methods implemented for this struct are
+/// what's used to build your `<iframe>` URL.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// Defines the search term. It can include a geographic restriction,
+    /// such as _in+Seattle_ or _near+98033_.
+    q: String,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Defines the center of the map view. Accepts comma-separated latitude
+    /// and longitude values.
+    center: Option<LatLng>,
+
+    /// Sets the initial zoom level of the map. Accepted values range from 0
+    /// (the whole world) to 21 (individual buildings).
+    zoom: Option<u8>,
+
+    /// Defines the type of map tiles to load.
+    map_type: Option<MapType>,
+
+    /// Defines the language to use for UI elements and for the display of
+    /// labels on map tiles. By default, visitors will see a map in their own
+    /// language.
+    language: Option<Language>,
+
+    /// Defines the appropriate borders and labels to display, based on
+    /// geo-political sensitivities.
+    region: Option<Region>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be embedded in the `<iframe>` URL.
+    query: Option<String>,
+
+    /// Has the request been validated?
+    validated: bool,
+
+} // struct
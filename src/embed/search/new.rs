@@ -0,0 +1,41 @@
+use crate::client::GoogleMapsClient;
+use crate::embed::search::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Embed Search URL with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `q` ‧ The search term, for example `"record stores in Seattle"`.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        q: String,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            q,
+            // Optional parameters:
+            center: None,
+            zoom: None,
+            map_type: None,
+            language: None,
+            region: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+
+    } // fn
+
+} // impl
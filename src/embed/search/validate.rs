@@ -0,0 +1,35 @@
+use crate::embed::{error::Error, search::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that the
+    /// Maps Embed API will accept them - i.e. it will not allow a zoom level
+    /// outside of 0 to 21.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // If a zoom level has been set, ensure that it is between 0 and 21:
+        if let Some(zoom) = self.zoom {
+            if zoom > 21 {
+                return Err(Error::InvalidZoom(zoom));
+            } // if
+        } // if
+
+        // Indicate that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
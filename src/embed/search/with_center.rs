@@ -0,0 +1,32 @@
+use crate::embed::search::Request;
+use crate::latlng::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Defines the center of the map view.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `center` ‧ The latitude & longitude of the center of the map view.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_center(LatLng::try_from_dec(dec!(37.4218), dec!(-122.0840))?)
+    /// ```
+
+    pub fn with_center(&'a mut self, center: LatLng) -> &'a mut Request {
+
+        // Set center in Request struct.
+        self.center = Some(center);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
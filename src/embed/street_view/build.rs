@@ -0,0 +1,96 @@
+use crate::embed::error::Error;
+use crate::embed::street_view::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Maps Embed API Street View mode based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // Ensure request has been validated before building the query string:
+        if !self.validated { return Err(Error::RequestNotValidated) }
+
+        // Builds the "required parameters" portion of the query string. A
+        // panorama ID, if one was given, takes the place of the location:
+        let mut query = match &self.pano {
+            Some(pano) => format!(
+                "key={}&pano={}",
+                self.client.key,
+                utf8_percent_encode(pano, NON_ALPHANUMERIC),
+            ), // format!
+            None => format!(
+                "key={}&location={}",
+                self.client.key,
+                utf8_percent_encode(&String::from(&self.location), NON_ALPHANUMERIC),
+            ), // format!
+        }; // match
+
+        // Builds the "optional parameters" portion of the query string:
+
+        // Heading key/value pair:
+        if let Some(heading) = &self.heading {
+            query.push_str("&heading=");
+            query.push_str(&heading.to_string())
+        } // if
+
+        // Pitch key/value pair:
+        if let Some(pitch) = &self.pitch {
+            query.push_str("&pitch=");
+            query.push_str(&pitch.to_string())
+        } // if
+
+        // Field of view key/value pair:
+        if let Some(field_of_view) = &self.field_of_view {
+            query.push_str("&fov=");
+            query.push_str(&field_of_view.to_string())
+        } // if
+
+        // Center key/value pair:
+        if let Some(center) = &self.center {
+            query.push_str("&center=");
+            query.push_str(&utf8_percent_encode(&String::from(center), NON_ALPHANUMERIC).to_string())
+        } // if
+
+        // Zoom key/value pair:
+        if let Some(zoom) = &self.zoom {
+            query.push_str("&zoom=");
+            query.push_str(&zoom.to_string())
+        } // if
+
+        // Map type key/value pair:
+        if let Some(map_type) = &self.map_type {
+            query.push_str("&maptype=");
+            query.push_str(&String::from(map_type))
+        } // if
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&language=");
+            query.push_str(&String::from(language))
+        } // if
+
+        // Region key/value pair:
+        if let Some(region) = &self.region {
+            query.push_str("&region=");
+            query.push_str(&String::from(region))
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
@@ -0,0 +1,103 @@
+//! The Maps Embed API **Street View** mode displays interactive panoramic
+//! views from designated locations within its coverage area.
+//!
+//! # [Parameters](https://developers.google.com/maps/documentation/embed/embedding-map#streetview_mode)
+//!
+//! * `location` or `pano` (one is required) - The latitude & longitude of the
+//! panorama, or a specific panorama ID.
+
+mod build;
+mod new;
+mod query_url;
+mod validate;
+mod with_center;
+mod with_field_of_view;
+mod with_heading;
+mod with_language;
+mod with_map_type;
+mod with_pano;
+mod with_pitch;
+mod with_region;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::embed::map_type::MapType;
+use crate::language::Language;
+use crate::latlng::LatLng;
+use crate::region::Region;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.google.com/maps/embed/v1/streetview";
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Embed Street View_ URL**. The methods implemented for this struct are
+/// what's used to build your `<iframe>` URL.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The latitude & longitude of the place to display a panorama for. The
+    /// Embed API displays the closest panorama to this location.
+    location: LatLng,
+
+    // Optional parameters:
+    // --------------------
+
+    /// A specific panorama ID. If a panorama ID is given, it is used instead
+    /// of the `location`.
+    pano: Option<String>,
+
+    /// Indicates the compass heading of the camera in degrees clockwise from
+    /// North. Accepted values are from -180 to 360 degrees.
+    heading: Option<i16>,
+
+    /// Specifies the angle, up or down, of the camera. The pitch is specified
+    /// in degrees from -90 to 90.
+    pitch: Option<i8>,
+
+    /// Determines the horizontal field of view of the image. It accepts
+    /// values in degrees, with a range of 10 - 100. The default is 90.
+    field_of_view: Option<u8>,
+
+    /// Defines the center of the map view. Accepts comma-separated latitude
+    /// and longitude values.
+    center: Option<LatLng>,
+
+    /// Sets the initial zoom level of the map. Accepted values range from 0
+    /// (the whole world) to 21 (individual buildings).
+    zoom: Option<u8>,
+
+    /// Defines the type of map tiles to load.
+    map_type: Option<MapType>,
+
+    /// Defines the language to use for UI elements and for the display of
+    /// labels on map tiles. By default, visitors will see a map in their own
+    /// language.
+    language: Option<Language>,
+
+    /// Defines the appropriate borders and labels to display, based on
+    /// geo-political sensitivities.
+    region: Option<Region>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be embedded in the `<iframe>` URL.
+    query: Option<String>,
+
+    /// Has the request been validated?
+    validated: bool,
+
+} // struct
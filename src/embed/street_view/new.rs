@@ -0,0 +1,46 @@
+use crate::client::GoogleMapsClient;
+use crate::embed::street_view::Request;
+use crate::latlng::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Embed Street View URL with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The latitude & longitude of the place to display a panorama for.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        location: LatLng,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            location,
+            // Optional parameters:
+            pano: None,
+            heading: None,
+            pitch: None,
+            field_of_view: None,
+            center: None,
+            zoom: None,
+            map_type: None,
+            language: None,
+            region: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+
+    } // fn
+
+} // impl
@@ -0,0 +1,57 @@
+use crate::embed::{error::Error, street_view::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that the
+    /// Maps Embed API will accept them - i.e. it will not allow a zoom level
+    /// outside of 0 to 21, or a camera pitch outside of -90 to 90.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&'a mut self) -> Result<&'a mut Request, Error> {
+
+        // If a heading has been set, ensure that it is between -180 and 360:
+        if let Some(heading) = self.heading {
+            if !(-180..=360).contains(&heading) {
+                return Err(Error::InvalidHeading(heading));
+            } // if
+        } // if
+
+        // If a pitch has been set, ensure that it is between -90 and 90:
+        if let Some(pitch) = self.pitch {
+            if !(-90..=90).contains(&pitch) {
+                return Err(Error::InvalidPitch(pitch));
+            } // if
+        } // if
+
+        // If a field of view has been set, ensure that it is between 10 and
+        // 100:
+        if let Some(field_of_view) = self.field_of_view {
+            if !(10..=100).contains(&field_of_view) {
+                return Err(Error::InvalidFieldOfView(field_of_view));
+            } // if
+        } // if
+
+        // If a zoom level has been set, ensure that it is between 0 and 21:
+        if let Some(zoom) = self.zoom {
+            if zoom > 21 {
+                return Err(Error::InvalidZoom(zoom));
+            } // if
+        } // if
+
+        // Indicate that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
@@ -0,0 +1,31 @@
+use crate::embed::street_view::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the horizontal field of view of the image.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `field_of_view` ‧ The field of view in degrees, from 10 to 100.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_field_of_view(35)
+    /// ```
+
+    pub fn with_field_of_view(&'a mut self, field_of_view: u8) -> &'a mut Request {
+
+        // Set field of view in Request struct.
+        self.field_of_view = Some(field_of_view);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
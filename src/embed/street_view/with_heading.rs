@@ -0,0 +1,32 @@
+use crate::embed::street_view::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the compass heading of the camera.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `heading` ‧ The heading in degrees clockwise from North, from -180
+    /// to 360.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_heading(210)
+    /// ```
+
+    pub fn with_heading(&'a mut self, heading: i16) -> &'a mut Request {
+
+        // Set heading in Request struct.
+        self.heading = Some(heading);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,32 @@
+use crate::embed::street_view::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify a specific Street View panorama to display.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `pano` ‧ The panorama ID. When set, the panorama ID is sent instead
+    /// of the location.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_pano(String::from("eTnPNGoy4bxR9LpjjfFuOw"))
+    /// ```
+
+    pub fn with_pano(&'a mut self, pano: String) -> &'a mut Request {
+
+        // Set pano in Request struct.
+        self.pano = Some(pano);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,32 @@
+use crate::embed::street_view::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the angle, up or down, of the camera.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `pitch` ‧ The pitch in degrees from -90 to 90. Positive values will
+    /// angle the camera up, while negative values will angle the camera down.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_pitch(10)
+    /// ```
+
+    pub fn with_pitch(&'a mut self, pitch: i8) -> &'a mut Request {
+
+        // Set pitch in Request struct.
+        self.pitch = Some(pitch);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
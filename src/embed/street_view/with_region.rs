@@ -0,0 +1,35 @@
+use crate::embed::street_view::Request;
+use crate::region::Region;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the region, which defines the appropriate borders and labels to
+    /// display based on geo-political sensitivities.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `region` ‧ The region code, specified as a two-character ccTLD
+    /// ('top-level domain') value.
+    ///
+    /// ## Example:
+    ///
+    /// * Show borders and labels as seen from Japan:
+    /// ```rust
+    /// .with_region(Region::Japan)
+    /// ```
+
+    pub fn with_region(&'a mut self, region: Region) -> &'a mut Request {
+
+        // Set region in Request struct.
+        self.region = Some(region);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,33 @@
+use crate::embed::street_view::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the initial zoom level of the map.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `zoom` ‧ Accepted values range from 0 (the whole world) to 21
+    /// (individual buildings). The upper limit can vary depending on the map
+    /// data available at the selected location.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_zoom(15)
+    /// ```
+
+    pub fn with_zoom(&'a mut self, zoom: u8) -> &'a mut Request {
+
+        // Set zoom in Request struct.
+        self.zoom = Some(zoom);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
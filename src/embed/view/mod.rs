@@ -0,0 +1,76 @@
+//! The Maps Embed API **View** mode returns a map with no markers or
+//! directions.
+//!
+//! # [Parameters](https://developers.google.com/maps/documentation/embed/embedding-map#view_mode)
+//!
+//! * `center` (required) - Defines the center of the map view.
+
+mod build;
+mod new;
+mod query_url;
+mod validate;
+mod with_language;
+mod with_map_type;
+mod with_region;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::embed::map_type::MapType;
+use crate::language::Language;
+use crate::latlng::LatLng;
+use crate::region::Region;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.google.com/maps/embed/v1/view";
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Embed View_ URL**. The methods implemented for this struct are
+/// what's used to build your `<iframe>` URL.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// Defines the center of the map view.
+    center: LatLng,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Sets the initial zoom level of the map. Accepted values range from 0
+    /// (the whole world) to 21 (individual buildings).
+    zoom: Option<u8>,
+
+    /// Defines the type of map tiles to load.
+    map_type: Option<MapType>,
+
+    /// Defines the language to use for UI elements and for the display of
+    /// labels on map tiles. By default, visitors will see a map in their own
+    /// language.
+    language: Option<Language>,
+
+    /// Defines the appropriate borders and labels to display, based on
+    /// geo-political sensitivities.
+    region: Option<Region>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be embedded in the `<iframe>` URL.
+    query: Option<String>,
+
+    /// Has the request been validated?
+    validated: bool,
+
+} // struct
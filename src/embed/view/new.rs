@@ -0,0 +1,41 @@
+use crate::client::GoogleMapsClient;
+use crate::embed::view::Request;
+use crate::latlng::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Embed View URL with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `center` ‧ The latitude & longitude of the center of the map view.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        center: LatLng,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            center,
+            // Optional parameters:
+            zoom: None,
+            map_type: None,
+            language: None,
+            region: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+
+    } // fn
+
+} // impl
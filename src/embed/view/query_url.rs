@@ -0,0 +1,33 @@
+use crate::embed::view::{SERVICE_URL, Request};
+use crate::embed::error::Error;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `<iframe>` URL that represents the map you've built.
+    ///
+    /// ## Description:
+    ///
+    /// The returned URL may be used directly as the `src` attribute of an
+    /// `<iframe>` element. Note that the URL contains your API key, so the key
+    /// should be restricted to the Maps Embed API and to your website's
+    /// domain(s).
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.validate()?.build()?.query.as_ref().unwrap(),
+        }; // match
+        Ok(format!("{SERVICE_URL}?{query_string}"))
+    } // fn
+
+} // impl
@@ -28,6 +28,40 @@ pub enum Error {
     /// that is invalid. See `google_maps\src\latlng.rs` for more
     /// information.
     InvalidLatLongString(String),
+    /// API client library attempted to parse a degrees-minutes-seconds or
+    /// degrees-decimal-minutes string that could not be understood. See
+    /// `google_maps\src\latlng\dms.rs` for more information.
+    InvalidDmsString(String),
+    /// API client library attempted to parse a degrees-minutes-seconds string
+    /// whose hemisphere letters were missing, repeated, or contradicted a
+    /// minus sign.
+    InvalidHemisphere(String),
+    /// API client library attempted to parse a degrees-minutes-seconds string
+    /// with a minutes value that was not less than 60.
+    MinutesOutOfRange(Decimal),
+    /// API client library attempted to parse a degrees-minutes-seconds string
+    /// with a seconds value that was not less than 60.
+    SecondsOutOfRange(Decimal),
+    /// API client library attempted to parse a UTM coordinate string that
+    /// could not be understood. See `google_maps\src\latlng\utm.rs` for more
+    /// information.
+    InvalidUtmString(String),
+    /// API client library encountered a UTM zone number outside of 1 to 60.
+    InvalidUtmZone(u8),
+    /// API client library encountered a UTM latitude band letter outside of
+    /// `C` to `X`. The polar bands `A`, `B`, `Y` and `Z` use the Universal
+    /// Polar Stereographic system, which is not supported.
+    InvalidUtmBand(char),
+    /// API client library attempted to convert a latitude outside of the UTM
+    /// system's 80° S to 84° N range into UTM or MGRS.
+    UtmLatitudeOutOfRange(Decimal),
+    /// API client library attempted to parse an MGRS grid reference that could
+    /// not be understood. See `google_maps\src\latlng\mgrs.rs` for more
+    /// information.
+    InvalidMgrsString(String),
+    /// API client library attempted to format an MGRS grid reference with more
+    /// than 5 digits of precision per axis.
+    InvalidMgrsPrecision(u8),
     /// API client library attempted to parse a string that contained an invalid
     /// place type code. See `google_maps\src\place_type.rs` for more
     /// information.
@@ -86,7 +120,75 @@ impl std::fmt::Display for Error {
                 f,
                 "Google Maps Platform API client: \
                 `{value}` is an invalid `LatLng` string."
-            )
+            ),
+            Error::InvalidDmsString(value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{value}` is not a valid degrees-minutes-seconds or \
+                degrees-decimal-minutes coordinate pair.
+                Examples of valid strings are `40°26'46\"N 79°58'56\"W` and \
+                `N 40°26.767' W 79°58.933'`."
+            ),
+            Error::InvalidHemisphere(value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the hemispheres in `{value}` are invalid.
+                Each coordinate may have one hemisphere letter, `N` or `S` for \
+                latitude and `E` or `W` for longitude, which may not be combined \
+                with a minus sign."
+            ),
+            Error::MinutesOutOfRange(minutes) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{minutes}` is an invalid minutes value.
+                Minutes must be at least 0 and less than 60."
+            ),
+            Error::SecondsOutOfRange(seconds) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{seconds}` is an invalid seconds value.
+                Seconds must be at least 0 and less than 60."
+            ),
+            Error::InvalidUtmString(value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{value}` is not a valid UTM coordinate.
+                UTM coordinates must have a zone number, a latitude band letter, \
+                an easting and a northing, for example `17T 589139 4477813`."
+            ),
+            Error::InvalidUtmZone(zone) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{zone}` is an invalid UTM zone.
+                A UTM zone must be between 1 and 60."
+            ),
+            Error::InvalidUtmBand(band) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{band}` is an invalid UTM latitude band.
+                A latitude band must be a letter from `C` to `X`, excluding `I` \
+                and `O`. Polar regions are not supported."
+            ),
+            Error::UtmLatitudeOutOfRange(latitude) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{latitude}` is outside of the UTM system.
+                UTM and MGRS only cover latitudes from -80.0° to 84.0°."
+            ),
+            Error::InvalidMgrsString(value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{value}` is not a valid MGRS grid reference.
+                MGRS grid references must have a zone number, a latitude band \
+                letter, a two letter 100 km square identifier and an even number \
+                of digits, for example `18T WL 80654 06346`."
+            ),
+            Error::InvalidMgrsPrecision(precision) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{precision}` is an invalid MGRS precision.
+                MGRS grid references may have from 0 to 5 digits per axis."
+            ),
         } // match
     } // fn
 } // impl
@@ -107,6 +209,16 @@ impl std::error::Error for Error {
             Error::InvalidPlaceTypeCode(_place_type_code) => None,
             Error::InvalidRegionCode(_region_code) => None,
             Error::InvalidLatLongString(_value) => None,
+            Error::InvalidDmsString(_value) => None,
+            Error::InvalidHemisphere(_value) => None,
+            Error::MinutesOutOfRange(_minutes) => None,
+            Error::SecondsOutOfRange(_seconds) => None,
+            Error::InvalidUtmString(_value) => None,
+            Error::InvalidUtmZone(_zone) => None,
+            Error::InvalidUtmBand(_band) => None,
+            Error::UtmLatitudeOutOfRange(_latitude) => None,
+            Error::InvalidMgrsString(_value) => None,
+            Error::InvalidMgrsPrecision(_precision) => None,
         } // match
     } // fn
 } // impl
\ No newline at end of file
@@ -0,0 +1,29 @@
+//! Provides GeoJSON export for the `Geocoding` struct, for use with the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::geocoding::response::geocoding::Geocoding;
+use geojson::{Feature, Geometry};
+
+// -----------------------------------------------------------------------------
+
+impl Geocoding {
+    /// Converts this geocoding result into a GeoJSON `Point` feature at the
+    /// result's location. The feature's bounding box is the recommended
+    /// viewport for the result.
+    ///
+    /// Every field of the result other than `geometry` (for example,
+    /// `formatted_address`, `place_id` and `types`) is copied into the
+    /// feature's properties, along with the `location_type`.
+    pub fn to_geojson(&self) -> Feature {
+        let mut feature = Feature::from(Geometry::from(&self.geometry.location));
+        feature.bbox = Some(self.geometry.viewport.to_geojson_bbox());
+        if let Ok(serde_json::Value::Object(mut properties)) = serde_json::to_value(self) {
+            properties.remove("geometry");
+            feature.properties = Some(properties);
+        } // if
+        if let Some(location_type) = &self.geometry.location_type {
+            feature.set_property("location_type", String::from(location_type));
+        } // if
+        feature
+    } // fn
+} // impl
@@ -4,6 +4,8 @@
 
 pub mod address_component;
 pub mod geocoding;
+#[cfg(feature = "geojson")]
+mod geojson_conversions;
 pub mod geometry;
 pub mod plus_code;
 pub mod status;
@@ -21,4 +21,18 @@ pub struct PlusCode {
     /// A 6 character or longer local code with an explicit location (`CWC8+R9,
     /// Mountain View, CA, USA`).
     pub compound_code: Option<String>,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PlusCode {
+    /// Decodes the `global_code` into the `Bounds` of the area that the plus
+    /// code represents, without calling the Google Maps Platform.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the `global_code` is not a valid full plus code.
+    pub fn decode(&self) -> Result<crate::bounds::Bounds, crate::open_location_code::Error> {
+        crate::open_location_code::decode(&self.global_code)
+    } // fn
+} // impl
@@ -0,0 +1,74 @@
+//! GPX error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when reading a GPX file.
+
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// A track point or waypoint contained a latitude or longitude that was
+    /// out of range. The offending point's byte position in the file is
+    /// returned.
+    InvalidCoordinate(crate::error::Error, u64),
+    /// A track point or waypoint's `lat` or `lon` attribute could not be
+    /// parsed as a number. The attribute's name, value and the point's byte
+    /// position in the file are returned.
+    InvalidNumber(String, String, u64),
+    /// A track point or waypoint was missing its `lat` or `lon` attribute. The
+    /// attribute's name and the point's byte position in the file are
+    /// returned.
+    MissingAttribute(String, u64),
+    /// The dependency library `quick-xml` could not parse the file.
+    Xml(quick_xml::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidCoordinate(error, position) => write!(f,
+                "Google Maps Platform API client: \
+                The GPX point at position {position} is invalid. {error}"),
+            Error::InvalidNumber(attribute, value, position) => write!(f,
+                "Google Maps Platform API client: \
+                `{value}` in the `{attribute}` attribute of the GPX point at \
+                position {position} is not a valid number."),
+            Error::MissingAttribute(attribute, position) => write!(f,
+                "Google Maps Platform API client: \
+                The GPX point at position {position} is missing its `{attribute}` \
+                attribute."),
+            Error::Xml(error) => write!(f,
+                "Google Maps Platform API client in the quick-xml library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidCoordinate(error, _position) => Some(error),
+            Error::InvalidNumber(_attribute, _value, _position) => None,
+            Error::MissingAttribute(_attribute, _position) => None,
+            Error::Xml(error) => Some(error),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<quick_xml::Error> for Error {
+    /// This trait converts from a `quick-xml` error type into a GPX error
+    /// type.
+    fn from(error: quick_xml::Error) -> Error {
+        Error::Xml(error)
+    } // fn
+} // impl
@@ -0,0 +1,37 @@
+//! Reads and writes [GPX](https://www.topografix.com/gpx.asp) (GPS Exchange
+//! Format) files, the format used by most GPS devices including Garmin's.
+//!
+//! * Directions API routes, Roads API snapped paths, and Elevation API
+//! profiles may be exported with their `to_gpx()` methods.
+//!
+//! * GPX tracks may be read into a `Vec<LatLng>` with `track_points`, for
+//! example to feed the Roads API's _Snap To Roads_ service.
+//!
+//! * GPX waypoints may be read into a `Vec<Waypoint>` with `waypoints`, for
+//! use with the Directions API.
+//!
+//! # Example:
+//!
+//! ```rust
+//! let path = google_maps::gpx::track_points(&std::fs::read_to_string("ride.gpx")?)?;
+//! let snapped = google_maps.snap_to_roads(path).execute().await?;
+//! std::fs::write("snapped.gpx", snapped.to_gpx())?;
+//! ```
+
+pub mod error;
+mod read;
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "roads",
+))]
+mod write;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::gpx::error::Error;
+pub use crate::gpx::read::track_points;
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::gpx::read::waypoints;
@@ -0,0 +1,89 @@
+//! Reads track points and waypoints from a GPX file.
+
+use crate::gpx::error::Error;
+use crate::latlng::LatLng;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+//
+/// Reads every track point (`<trkpt>`) in a GPX file, in order. The points of
+/// every track and track segment are joined into a single path.
+///
+/// ## Errors:
+///
+/// * Returns an error if the file is not valid XML, or if a track point has a
+/// missing or invalid `lat` or `lon` attribute.
+
+pub fn track_points(gpx: &str) -> Result<Vec<LatLng>, Error> {
+    read_points(gpx, b"trkpt")
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads every waypoint (`<wpt>`) in a GPX file, in order, as Directions API
+/// waypoints.
+///
+/// ## Errors:
+///
+/// * Returns an error if the file is not valid XML, or if a waypoint has a
+/// missing or invalid `lat` or `lon` attribute.
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub fn waypoints(
+    gpx: &str
+) -> Result<Vec<crate::directions::request::waypoint::Waypoint>, Error> {
+    Ok(read_points(gpx, b"wpt")?
+        .into_iter()
+        .map(crate::directions::request::waypoint::Waypoint::LatLng)
+        .collect())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the location of every element with the given name in a GPX file.
+
+fn read_points(gpx: &str, element: &[u8]) -> Result<Vec<LatLng>, Error> {
+
+    let mut reader = Reader::from_str(gpx);
+    let mut points: Vec<LatLng> = Vec::new();
+
+    loop {
+        let position = reader.buffer_position();
+        match reader.read_event()? {
+            Event::Start(tag) | Event::Empty(tag) if tag.local_name().as_ref() == element => {
+                let lat = read_attribute(&tag, "lat", position)?;
+                let lng = read_attribute(&tag, "lon", position)?;
+                let latlng = LatLng::try_from_dec(lat, lng)
+                    .map_err(|error| Error::InvalidCoordinate(error, position))?;
+                points.push(latlng);
+            }, // Event::Start
+            Event::Eof => break,
+            _ => (),
+        } // match
+    } // loop
+
+    Ok(points)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a coordinate attribute from a GPX point's tag.
+
+fn read_attribute(tag: &BytesStart, name: &str, position: u64) -> Result<Decimal, Error> {
+
+    let attribute = tag
+        .try_get_attribute(name)
+        .map_err(quick_xml::Error::from)?
+        .ok_or_else(|| Error::MissingAttribute(name.to_string(), position))?;
+
+    let value = attribute.unescape_value()?;
+
+    Decimal::from_str(value.trim())
+        .or_else(|_| Decimal::from_scientific(value.trim()))
+        .map_err(|_| Error::InvalidNumber(name.to_string(), value.to_string(), position))
+
+} // fn
@@ -0,0 +1,132 @@
+//! Exports Directions API routes, Roads API snapped paths and Elevation API
+//! profiles as GPX 1.1 files.
+
+use crate::latlng::LatLng;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+use quick_xml::escape::escape;
+
+// -----------------------------------------------------------------------------
+
+/// The opening of every GPX file written by this module.
+const GPX_HEADER: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+    <gpx version=\"1.1\" creator=\"google_maps\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n";
+
+/// The closing of every GPX file written by this module.
+const GPX_FOOTER: &str = "</gpx>\n";
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl crate::directions::response::route::Route {
+    /// Exports this route as a GPX file.
+    ///
+    /// The file contains a route (`<rte>`) with a route point at the start of
+    /// every step, described by the step's instructions, which GPS devices
+    /// use for turn-by-turn navigation. It also contains a track (`<trk>`),
+    /// with one segment per leg, holding the route's full decoded geometry.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn to_gpx(&self) -> Result<String, crate::polyline::Error> {
+        use crate::directions::response::route_path::RoutePath;
+
+        let name = escape(&self.summary);
+        let mut gpx = String::from(GPX_HEADER);
+
+        gpx.push_str(&format!("  <rte>\n    <name>{name}</name>\n"));
+        for leg in &self.legs {
+            for (index, step) in leg.steps.iter().enumerate() {
+                gpx.push_str(&format!("    <rtept {}>\n", attributes(&step.start_location)));
+                if index == 0 {
+                    gpx.push_str(&format!("      <name>{}</name>\n", escape(&leg.start_address)));
+                } // if
+                let instructions = step.plain_instructions().to_string();
+                gpx.push_str(&format!("      <desc>{}</desc>\n", escape(&instructions)));
+                gpx.push_str("    </rtept>\n");
+            } // for
+        } // for
+        if let Some(leg) = self.legs.last() {
+            gpx.push_str(&format!(
+                "    <rtept {}>\n      <name>{}</name>\n    </rtept>\n",
+                attributes(&leg.end_location),
+                escape(&leg.end_address),
+            )); // push_str
+        } // if
+        gpx.push_str("  </rte>\n");
+
+        gpx.push_str(&format!("  <trk>\n    <name>{name}</name>\n"));
+        for leg in &self.legs {
+            let path = RoutePath::try_from_leg(leg)?;
+            gpx.push_str("    <trkseg>\n");
+            for vertex in path.vertices() {
+                gpx.push_str(&format!("      <trkpt {}/>\n", attributes(&vertex.location)));
+            } // for
+            gpx.push_str("    </trkseg>\n");
+        } // for
+        gpx.push_str("  </trk>\n");
+
+        gpx.push_str(GPX_FOOTER);
+        Ok(gpx)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "roads")]
+impl crate::roads::snap_to_roads::response::Response {
+    /// Exports the snapped points in this response as a GPX file containing a
+    /// single track (`<trk>`).
+    pub fn to_gpx(&self) -> String {
+        let points = self.snapped_points
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|snapped_point| format!("      <trkpt {}/>\n", attributes(&snapped_point.location)));
+        track(points)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "elevation")]
+impl crate::elevation::response::Response {
+    /// Exports the elevation points in this response as a GPX file containing
+    /// a single track (`<trk>`). Each track point has its elevation, in
+    /// meters, in an `<ele>` element.
+    pub fn to_gpx(&self) -> String {
+        let points = self.results
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|point| format!(
+                "      <trkpt {}>\n        <ele>{}</ele>\n      </trkpt>\n",
+                attributes(&point.location),
+                point.elevation.meters(),
+            )); // format!
+        track(points)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Wraps pre-formatted track points in a complete GPX file with a single track
+/// and track segment.
+
+#[cfg(any(feature = "roads", feature = "elevation"))]
+fn track(points: impl Iterator<Item = String>) -> String {
+    let mut gpx = String::from(GPX_HEADER);
+    gpx.push_str("  <trk>\n    <trkseg>\n");
+    points.for_each(|point| gpx.push_str(&point));
+    gpx.push_str("    </trkseg>\n  </trk>\n");
+    gpx.push_str(GPX_FOOTER);
+    gpx
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Formats the `lat` and `lon` attributes of a GPX point.
+
+fn attributes(latlng: &LatLng) -> String {
+    format!("lat=\"{}\" lon=\"{}\"", latlng.lat.normalize(), latlng.lng.normalize())
+} // fn
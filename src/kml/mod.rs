@@ -0,0 +1,22 @@
+//! Writes [KML](https://developers.google.com/kml/documentation) (Keyhole
+//! Markup Language) files, the format used by Google Earth.
+//!
+//! Directions API routes, Roads API snapped paths, and Elevation API profiles
+//! may be exported with their `to_kml()` methods.
+//!
+//! # Example:
+//!
+//! ```rust
+//! let directions = google_maps.directions(origin, destination).execute().await?;
+//! if let Some(route) = directions.routes.first() {
+//!     std::fs::write("route.kml", route.to_kml()?)?;
+//! }
+//! ```
+
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "roads",
+))]
+mod write;
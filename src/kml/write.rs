@@ -0,0 +1,128 @@
+//! Exports Directions API routes, Roads API snapped paths and Elevation API
+//! profiles as KML 2.2 documents.
+
+use crate::latlng::LatLng;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+use quick_xml::escape::escape;
+
+// -----------------------------------------------------------------------------
+
+/// The opening of every KML document written by this module.
+const KML_HEADER: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+    <kml xmlns=\"http://www.opengis.net/kml/2.2\">\n  <Document>\n";
+
+/// The closing of every KML document written by this module.
+const KML_FOOTER: &str = "  </Document>\n</kml>\n";
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl crate::directions::response::route::Route {
+    /// Exports this route as a KML document.
+    ///
+    /// The document contains a `LineString` placemark with the route's full
+    /// decoded geometry, and a folder of `Point` placemarks at the start of
+    /// every step. The step placemarks' descriptions hold Google's HTML
+    /// instructions, which Google Earth renders in its info balloons.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if a step's polyline could not be decoded.
+    pub fn to_kml(&self) -> Result<String, crate::polyline::Error> {
+        use crate::directions::response::route_path::RoutePath;
+
+        let path = RoutePath::try_from_route(self)?;
+        let coordinates = path
+            .vertices()
+            .iter()
+            .map(|vertex| coordinate(&vertex.location))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let name = escape(&self.summary);
+        let mut kml = String::from(KML_HEADER);
+        kml.push_str(&format!("    <name>{name}</name>\n"));
+        kml.push_str(&format!(
+            "    <Placemark>\n      <name>{name}</name>\n      <description>{}</description>\n      \
+            <LineString>\n        <tessellate>1</tessellate>\n        \
+            <coordinates>{coordinates}</coordinates>\n      </LineString>\n    </Placemark>\n",
+            escape(&self.copyrights),
+        )); // push_str
+
+        kml.push_str("    <Folder>\n      <name>Steps</name>\n");
+        for step in self.legs.iter().flat_map(|leg| &leg.steps) {
+            kml.push_str(&format!(
+                "      <Placemark>\n        <description>{}</description>\n        \
+                <Point>\n          <coordinates>{}</coordinates>\n        </Point>\n      </Placemark>\n",
+                escape(&step.html_instructions),
+                coordinate(&step.start_location),
+            )); // push_str
+        } // for
+        kml.push_str("    </Folder>\n");
+
+        kml.push_str(KML_FOOTER);
+        Ok(kml)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "roads")]
+impl crate::roads::snap_to_roads::response::Response {
+    /// Exports the snapped points in this response as a KML document
+    /// containing a single `LineString` placemark.
+    pub fn to_kml(&self) -> String {
+        let coordinates = self.snapped_points
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|snapped_point| coordinate(&snapped_point.location))
+            .collect::<Vec<String>>()
+            .join(" ");
+        line_string(&coordinates, "clampToGround")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "elevation")]
+impl crate::elevation::response::Response {
+    /// Exports the elevation points in this response as a KML document
+    /// containing a single `LineString` placemark. Each coordinate includes
+    /// its elevation, in meters, and is drawn at that absolute altitude.
+    pub fn to_kml(&self) -> String {
+        let coordinates = self.results
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|point| format!("{},{}", coordinate(&point.location), point.elevation.meters()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        line_string(&coordinates, "absolute")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Wraps pre-formatted coordinates in a complete KML document with a single
+/// `LineString` placemark.
+
+#[cfg(any(feature = "roads", feature = "elevation"))]
+fn line_string(coordinates: &str, altitude_mode: &str) -> String {
+    let mut kml = String::from(KML_HEADER);
+    kml.push_str(&format!(
+        "    <Placemark>\n      <LineString>\n        <altitudeMode>{altitude_mode}</altitudeMode>\n        \
+        <coordinates>{coordinates}</coordinates>\n      </LineString>\n    </Placemark>\n"
+    )); // push_str
+    kml.push_str(KML_FOOTER);
+    kml
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Formats a KML coordinate. KML coordinates are ordered longitude first, then
+/// latitude.
+
+fn coordinate(latlng: &LatLng) -> String {
+    format!("{},{}", latlng.lng.normalize(), latlng.lat.normalize())
+} // fn
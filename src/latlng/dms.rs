@@ -0,0 +1,396 @@
+//! Parses and formats `LatLng` coordinates written in sexagesimal notation:
+//! degrees-minutes-seconds (DMS) such as `40°26'46"N 79°58'56"W`, and
+//! degrees-decimal-minutes (DDM) such as `40°26.767'N 79°58.933'W`.
+//!
+//! The parser is deliberately forgiving because people type coordinates in
+//! many ways. Hemisphere letters may come before or after each coordinate,
+//! minus signs may be used instead, typographic primes (`′` and `″`) and
+//! plain quotes are both accepted, and the unit markers may be left out
+//! entirely if the coordinates are separated by a comma or hemisphere letters.
+
+use crate::error::Error;
+use crate::latlng::LatLng;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromStr;
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Parses a coordinate pair written in degrees-minutes-seconds, such as
+    /// `40°26'46"N 79°58'56"W`, or in degrees-decimal-minutes, such as
+    /// `N 40°26.767' W 079°58.933'`. Decimal degrees with hemisphere letters,
+    /// such as `40.446°N 79.982°W`, are also accepted.
+    ///
+    /// Latitude is expected first. If both coordinates have hemisphere
+    /// letters, they may be given in either order.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidDmsString` if the string does not contain
+    /// exactly two coordinates of one to three numbers each, or if any number
+    /// other than the last in a coordinate has a fractional part.
+    /// * Returns `Error::InvalidHemisphere` if a hemisphere letter is repeated,
+    /// does not match the coordinate it is attached to, or is combined with a
+    /// minus sign.
+    /// * Returns `Error::MinutesOutOfRange` or `Error::SecondsOutOfRange` if
+    /// minutes or seconds are not less than 60.
+    /// * Returns `Error::InvalidLatitude` or `Error::InvalidLongitude` if the
+    /// resulting coordinates are out of range.
+    pub fn try_from_dms(value: &str) -> Result<LatLng, Error> {
+        let mut coordinates = split_coordinates(value)?;
+
+        // Coordinates given longitude first can be identified by their
+        // hemisphere letters:
+        let (first, second) = (coordinates[0].hemisphere, coordinates[1].hemisphere);
+        if matches!(first, Some('E' | 'W')) || matches!(second, Some('N' | 'S')) {
+            coordinates.swap(0, 1);
+        } // if
+
+        let lat = coordinates[0].to_degrees(['N', 'S'], value)?;
+        let lng = coordinates[1].to_degrees(['E', 'W'], value)?;
+        LatLng::try_from_dec(lat, lng)
+    } // fn
+
+    /// Formats the coordinates as degrees, minutes and seconds with
+    /// hemisphere letters, for example `40°26'46"N 79°58'56"W`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `decimal_places` ‧ The number of decimal places to show for the
+    /// seconds. `0` is precise to about 30 meters, and `2` to about 30
+    /// centimeters.
+    pub fn to_dms_string(&self, decimal_places: u32) -> String {
+        let format = |value: Decimal, positive: char, negative: char| {
+            // Rounding the total number of seconds first means that, for
+            // example, 59.9999" is carried over into the minutes rather than
+            // being shown as 60":
+            let total = (value.abs() * dec!(3600)).round_dp(decimal_places);
+            let degrees = (total / dec!(3600)).trunc();
+            let minutes = ((total - degrees * dec!(3600)) / dec!(60)).trunc();
+            let seconds = total - degrees * dec!(3600) - minutes * dec!(60);
+            format!(
+                "{degrees}°{minutes:02}'{seconds}\"{hemisphere}",
+                seconds = pad_sexagesimal(seconds, decimal_places),
+                hemisphere = if value < Decimal::ZERO { negative } else { positive },
+            ) // format!
+        }; // format
+
+        format!("{} {}", format(self.lat, 'N', 'S'), format(self.lng, 'E', 'W'))
+    } // fn
+
+    /// Formats the coordinates as degrees and decimal minutes with hemisphere
+    /// letters, for example `40°26.767'N 79°58.933'W`. This is the format
+    /// used by most marine and aviation GPS receivers.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `decimal_places` ‧ The number of decimal places to show for the
+    /// minutes. `3` is precise to about 2 meters.
+    pub fn to_ddm_string(&self, decimal_places: u32) -> String {
+        let format = |value: Decimal, positive: char, negative: char| {
+            let total = (value.abs() * dec!(60)).round_dp(decimal_places);
+            let degrees = (total / dec!(60)).trunc();
+            let minutes = total - degrees * dec!(60);
+            format!(
+                "{degrees}°{minutes}'{hemisphere}",
+                minutes = pad_sexagesimal(minutes, decimal_places),
+                hemisphere = if value < Decimal::ZERO { negative } else { positive },
+            ) // format!
+        }; // format
+
+        format!("{} {}", format(self.lat, 'N', 'S'), format(self.lng, 'E', 'W'))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The unit that a number in a sexagesimal coordinate was marked with.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Unit {
+    Degrees,
+    Minutes,
+    Seconds,
+    Unmarked,
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// A single latitude or longitude while it is being parsed.
+
+#[derive(Clone, Debug, Default)]
+struct Coordinate {
+    /// Degrees, minutes and seconds, in that order. Missing parts are zero.
+    parts: Vec<Decimal>,
+    /// The hemisphere letter, in upper case, if one was given.
+    hemisphere: Option<char>,
+    /// Whether the coordinate was preceded by a minus sign.
+    negative: bool,
+} // struct
+
+impl Coordinate {
+    /// Returns `true` if no numbers have been read into this coordinate yet.
+    fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    } // fn
+
+    /// Adds a number to the coordinate in the position given by its unit.
+    fn push(&mut self, number: Decimal, unit: Unit, value: &str) -> Result<(), Error> {
+        let position = match unit {
+            Unit::Degrees => 0,
+            Unit::Minutes => 1,
+            Unit::Seconds => 2,
+            Unit::Unmarked => self.parts.len(),
+        }; // match
+
+        // Parts must be given in order, and each only once:
+        if position < self.parts.len() || position > 2 {
+            return Err(Error::InvalidDmsString(value.to_string()));
+        } // if
+
+        self.parts.resize(position, Decimal::ZERO);
+        self.parts.push(number);
+        Ok(())
+    } // fn
+
+    /// Returns `true` if a number with this unit can not belong to this
+    /// coordinate and must start the next one.
+    fn starts_next(&self, unit: Unit) -> bool {
+        !self.is_empty() && (unit == Unit::Degrees || (unit == Unit::Unmarked && self.parts.len() == 3))
+    } // fn
+
+    /// Converts the coordinate into signed decimal degrees.
+    fn to_degrees(&self, hemispheres: [char; 2], value: &str) -> Result<Decimal, Error> {
+        // Only the last part may have a fractional component:
+        if self.parts[..self.parts.len() - 1].iter().any(|part| !part.fract().is_zero()) {
+            return Err(Error::InvalidDmsString(value.to_string()));
+        } // if
+
+        let minutes = self.parts.get(1).copied().unwrap_or_default();
+        let seconds = self.parts.get(2).copied().unwrap_or_default();
+
+        if minutes >= dec!(60) {
+            return Err(Error::MinutesOutOfRange(minutes));
+        } // if
+
+        if seconds >= dec!(60) {
+            return Err(Error::SecondsOutOfRange(seconds));
+        } // if
+
+        let degrees = self.parts[0] + minutes / dec!(60) + seconds / dec!(3600);
+
+        let negative = match self.hemisphere {
+            None => self.negative,
+            Some(hemisphere) if hemisphere == hemispheres[0] && !self.negative => false,
+            Some(hemisphere) if hemisphere == hemispheres[1] && !self.negative => true,
+            Some(_hemisphere) => return Err(Error::InvalidHemisphere(value.to_string())),
+        }; // match
+
+        // 8 decimal places is about one millimetre, and keeps values such as
+        // 1/3 of a minute from printing dozens of digits:
+        let degrees = degrees.round_dp(8).normalize();
+        Ok(if negative { -degrees } else { degrees })
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a string into exactly two coordinates.
+
+fn split_coordinates(value: &str) -> Result<[Coordinate; 2], Error> {
+    let mut coordinates: Vec<Coordinate> = Vec::new();
+    let mut current = Coordinate::default();
+    let mut characters = value.trim().chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '0'..='9' | '.' => {
+                let mut number = String::from(character);
+                while let Some(next) = characters.next_if(|next| next.is_ascii_digit() || *next == '.') {
+                    number.push(next);
+                } // while
+                let number = Decimal::from_str(&number)
+                    .map_err(|_error| Error::InvalidDmsString(value.to_string()))?;
+
+                // Skip any spaces between the number and its unit marker:
+                while characters.next_if(|next| next.is_whitespace()).is_some() {}
+
+                let unit = if characters.next_if(|next| matches!(next, '°' | 'º' | '˚')).is_some() {
+                    Unit::Degrees
+                } else if characters.next_if(|next| matches!(next, '"' | '″' | '”')).is_some() {
+                    Unit::Seconds
+                } else if characters.next_if(|next| matches!(next, '\'' | '′' | '’')).is_some() {
+                    // Two apostrophes are often typed instead of a double
+                    // prime:
+                    if characters.next_if_eq(&'\'').is_some() { Unit::Seconds } else { Unit::Minutes }
+                } else {
+                    Unit::Unmarked
+                }; // if
+
+                if current.starts_next(unit) {
+                    coordinates.push(std::mem::take(&mut current));
+                } // if
+
+                current.push(number, unit, value)?;
+            }, // number
+
+            '-' | '−' => {
+                if !current.is_empty() {
+                    coordinates.push(std::mem::take(&mut current));
+                } // if
+                if current.negative {
+                    return Err(Error::InvalidDmsString(value.to_string()));
+                } // if
+                current.negative = true;
+            }, // minus sign
+
+            '+' => {
+                if !current.is_empty() {
+                    coordinates.push(std::mem::take(&mut current));
+                } // if
+            }, // plus sign
+
+            'N' | 'S' | 'E' | 'W' | 'n' | 's' | 'e' | 'w' => {
+                let hemisphere = character.to_ascii_uppercase();
+                if current.hemisphere.is_some() {
+                    // A letter that follows a complete coordinate with its own
+                    // letter begins the next coordinate:
+                    if current.is_empty() {
+                        return Err(Error::InvalidHemisphere(value.to_string()));
+                    } // if
+                    coordinates.push(std::mem::take(&mut current));
+                    current.hemisphere = Some(hemisphere);
+                } else {
+                    current.hemisphere = Some(hemisphere);
+                    // A letter after the numbers ends the coordinate:
+                    if !current.is_empty() {
+                        coordinates.push(std::mem::take(&mut current));
+                    } // if
+                } // if
+            }, // hemisphere
+
+            ',' | ';' | '/' => {
+                if current.is_empty() {
+                    if current.hemisphere.is_some() || current.negative {
+                        return Err(Error::InvalidDmsString(value.to_string()));
+                    } // if
+                } else {
+                    coordinates.push(std::mem::take(&mut current));
+                } // if
+            }, // separator
+
+            character if character.is_whitespace() => {},
+
+            _ => return Err(Error::InvalidDmsString(value.to_string())),
+        } // match
+    } // while
+
+    if !current.is_empty() {
+        coordinates.push(current);
+    } else if current.hemisphere.is_some() || current.negative {
+        return Err(Error::InvalidDmsString(value.to_string()));
+    } // if
+
+    <[Coordinate; 2]>::try_from(coordinates)
+        .map_err(|_coordinates| Error::InvalidDmsString(value.to_string()))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Formats minutes or seconds with a leading zero and a fixed number of
+/// decimal places, for example `05.20`.
+
+fn pad_sexagesimal(value: Decimal, decimal_places: u32) -> String {
+    let formatted = format!("{:.*}", decimal_places as usize, value);
+    if value < dec!(10) {
+        format!("0{formatted}")
+    } else {
+        formatted
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: &LatLng, expected: &LatLng, degrees: Decimal) {
+        assert!(
+            (actual.lat - expected.lat).abs() <= degrees && (actual.lng - expected.lng).abs() <= degrees,
+            "{actual:?} is not within {degrees}° of {expected:?}",
+        ); // assert!
+    } // fn
+
+    fn points() -> Vec<LatLng> {
+        [(40.446, -79.982), (-33.8568, 151.2153), (0.0, 0.0), (-89.999_99, 179.999_99), (51.4778, -0.0015)]
+            .into_iter()
+            .map(|(lat, lng)| LatLng::try_from_f64(lat, lng).unwrap())
+            .collect()
+    } // fn
+
+    #[test]
+    fn formats_dms_and_ddm() {
+        let pittsburgh = LatLng::try_from_f64(40.446, -79.982).unwrap();
+        assert_eq!(pittsburgh.to_dms_string(0), "40°26'46\"N 79°58'55\"W");
+        assert_eq!(pittsburgh.to_ddm_string(3), "40°26.760'N 79°58.920'W");
+
+        // 59.9999" rounds up into the minutes instead of showing 60":
+        let carried = LatLng::try_from_dec(dec!(10.999_999_99), dec!(0)).unwrap();
+        assert_eq!(carried.to_dms_string(0), "11°00'00\"N 0°00'00\"E");
+    } // fn
+
+    #[test]
+    fn round_trips_dms() {
+        for point in points() {
+            let parsed = LatLng::try_from_dms(&point.to_dms_string(2)).unwrap();
+            assert_near(&parsed, &point, dec!(0.000_005));
+        } // for
+    } // fn
+
+    #[test]
+    fn round_trips_ddm() {
+        for point in points() {
+            let parsed = LatLng::try_from_dms(&point.to_ddm_string(4)).unwrap();
+            assert_near(&parsed, &point, dec!(0.000_005));
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_common_spellings() {
+        let expected = LatLng::try_from_dec(dec!(40.446111), dec!(-79.982222)).unwrap();
+        for value in [
+            "40°26'46\"N 79°58'56\"W",
+            "40°26′46″N 79°58′56″W",
+            "40° 26' 46'' N, 79° 58' 56'' W",
+            "N 40°26'46\" W 79°58'56\"",
+            "79°58'56\"W 40°26'46\"N",
+            "40 26 46, -79 58 56",
+        ] {
+            assert_near(&LatLng::try_from_dms(value).unwrap(), &expected, dec!(0.000_001));
+        } // for
+
+        let ddm = LatLng::try_from_dms("N 40°26.767' W 079°58.933'").unwrap();
+        assert_near(&ddm, &expected, dec!(0.000_01));
+    } // fn
+
+    #[test]
+    fn rejects_malformed_input() {
+        let invalid = |value: &str| Error::InvalidDmsString(value.to_string());
+        assert_eq!(LatLng::try_from_dms("hello"), Err(invalid("hello")));
+        assert_eq!(LatLng::try_from_dms("40°26'46\"N"), Err(invalid("40°26'46\"N")));
+        assert_eq!(LatLng::try_from_dms("40.5°26'N 79°W"), Err(invalid("40.5°26'N 79°W")));
+        assert_eq!(LatLng::try_from_dms("40°61'N 79°W"), Err(Error::MinutesOutOfRange(dec!(61))));
+        assert_eq!(LatLng::try_from_dms("40°26'60\"N 79°W"), Err(Error::SecondsOutOfRange(dec!(60))));
+        assert_eq!(
+            LatLng::try_from_dms("40°N 79°N"),
+            Err(Error::InvalidHemisphere("40°N 79°N".to_string())),
+        ); // assert_eq!
+        assert_eq!(
+            LatLng::try_from_dms("-40°S 79°W"),
+            Err(Error::InvalidHemisphere("-40°S 79°W".to_string())),
+        ); // assert_eq!
+        assert!(matches!(LatLng::try_from_dms("95°N 10°E"), Err(Error::InvalidLatitude(..))));
+    } // fn
+} // mod
@@ -0,0 +1,341 @@
+//! Great-circle and ellipsoidal geodesy for the `LatLng` struct: distances,
+//! bearings, destinations, midpoints and interpolation.
+//!
+//! Spherical calculations use the mean radius of the Earth and are accurate to
+//! within about 0.5%. `vincenty_distance` uses the WGS-84 ellipsoid and is
+//! accurate to within a millimetre.
+
+use crate::error::Error;
+use crate::latlng::LatLng;
+use rust_decimal::{Decimal, prelude::FromPrimitive, prelude::ToPrimitive};
+
+// -----------------------------------------------------------------------------
+
+/// The mean radius of the Earth in meters, as defined by the International
+/// Union of Geodesy and Geophysics.
+pub(crate) const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// The semi-major axis (equatorial radius) of the WGS-84 ellipsoid in meters.
+pub(crate) const WGS84_A: f64 = 6_378_137.0;
+
+/// The flattening of the WGS-84 ellipsoid.
+pub(crate) const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// The number of decimal places that calculated coordinates are rounded to.
+/// 8 decimal places is about one millimetre at the equator.
+const COORDINATE_DP: u32 = 8;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the latitude in radians. `Decimal` to `f64` conversion is exact
+    /// to about 15 significant digits, which is far beyond any real-world
+    /// positioning accuracy.
+    pub(crate) fn lat_radians(&self) -> f64 {
+        self.lat.to_f64().unwrap_or_default().to_radians()
+    } // fn
+
+    /// Returns the longitude in radians.
+    pub(crate) fn lng_radians(&self) -> f64 {
+        self.lng.to_f64().unwrap_or_default().to_radians()
+    } // fn
+
+    /// Builds a `LatLng` from coordinates in radians. The latitude is clamped
+    /// to ±90° and the longitude is wrapped into the -180° to +180° range.
+    pub(crate) fn try_from_radians(lat: f64, lng: f64) -> Result<LatLng, Error> {
+        let lat = lat.to_degrees().clamp(-90.0, 90.0);
+        let lng = (lng.to_degrees() + 540.0).rem_euclid(360.0) - 180.0;
+
+        let lat: Decimal = Decimal::from_f64(lat)
+            .ok_or_else(|| Error::FloatToDecimalConversionError(lat.to_string()))?
+            .round_dp(COORDINATE_DP)
+            .normalize();
+
+        let lng: Decimal = Decimal::from_f64(lng)
+            .ok_or_else(|| Error::FloatToDecimalConversionError(lng.to_string()))?
+            .round_dp(COORDINATE_DP)
+            .normalize();
+
+        LatLng::try_from_dec(lat, lng)
+    } // fn
+
+    /// Returns the angular distance, in radians, between this point and
+    /// another point along a great circle.
+    fn angular_distance(&self, other: &LatLng) -> f64 {
+        let (phi1, phi2) = (self.lat_radians(), other.lat_radians());
+        let delta_phi = phi2 - phi1;
+        let delta_lambda = other.lng_radians() - self.lng_radians();
+        let a = (delta_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+        2.0 * a.sqrt().atan2((1.0 - a).sqrt())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the great-circle distance, in meters, between this point and
+    /// another point using the
+    /// [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula).
+    ///
+    /// This is a fast approximation that is useful for pre-filtering
+    /// candidates before making paid requests. Use `vincenty_distance` when
+    /// sub-metre accuracy is required.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let toronto = LatLng::try_from_dec(dec!(43.6532), dec!(-79.3832))?;
+    /// let montreal = LatLng::try_from_dec(dec!(45.5019), dec!(-73.5674))?;
+    /// // About 504 kilometers:
+    /// let distance = toronto.haversine_distance(&montreal);
+    /// ```
+    pub fn haversine_distance(&self, other: &LatLng) -> f64 {
+        EARTH_RADIUS_METERS * self.angular_distance(other)
+    } // fn
+
+    /// Returns the initial bearing (forward azimuth), in degrees clockwise
+    /// from true north, that would be followed from this point to reach
+    /// another point along a great circle. The result is between 0° and 360°.
+    pub fn initial_bearing(&self, other: &LatLng) -> f64 {
+        let (phi1, phi2) = (self.lat_radians(), other.lat_radians());
+        let delta_lambda = other.lng_radians() - self.lng_radians();
+        let y = delta_lambda.sin() * phi2.cos();
+        let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * delta_lambda.cos();
+        (y.atan2(x).to_degrees() + 360.0) % 360.0
+    } // fn
+
+    /// Returns the final bearing, in degrees clockwise from true north, that
+    /// a traveller would be facing on arrival at another point after following
+    /// a great circle from this point. The bearing changes along the way
+    /// unless travelling due north, south, or along the equator.
+    pub fn final_bearing(&self, other: &LatLng) -> f64 {
+        (other.initial_bearing(self) + 180.0) % 360.0
+    } // fn
+
+    /// Returns the point reached by travelling `distance` meters along a great
+    /// circle from this point, starting at `bearing` degrees clockwise from
+    /// true north.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::FloatToDecimalConversionError` if the `bearing` or
+    /// `distance` is not a finite number.
+    pub fn destination(&self, bearing: f64, distance: f64) -> Result<LatLng, Error> {
+        let delta = distance / EARTH_RADIUS_METERS;
+        let theta = bearing.to_radians();
+        let (phi1, lambda1) = (self.lat_radians(), self.lng_radians());
+        let phi2 = (phi1.sin() * delta.cos() + phi1.cos() * delta.sin() * theta.cos()).asin();
+        let lambda2 = lambda1 + (theta.sin() * delta.sin() * phi1.cos()).atan2(delta.cos() - phi1.sin() * phi2.sin());
+        LatLng::try_from_radians(phi2, lambda2)
+    } // fn
+
+    /// Returns the point half-way between this point and another point along
+    /// a great circle. This is not the same as averaging the coordinates,
+    /// which drifts from the shortest path over long distances.
+    ///
+    /// ## Errors:
+    ///
+    /// * This method can only fail if the resulting coordinate could not be
+    /// converted into a `Decimal`, which should not happen for valid points.
+    pub fn midpoint(&self, other: &LatLng) -> Result<LatLng, Error> {
+        let (phi1, lambda1) = (self.lat_radians(), self.lng_radians());
+        let phi2 = other.lat_radians();
+        let delta_lambda = other.lng_radians() - lambda1;
+        let bx = phi2.cos() * delta_lambda.cos();
+        let by = phi2.cos() * delta_lambda.sin();
+        let phi3 = (phi1.sin() + phi2.sin()).atan2(((phi1.cos() + bx).powi(2) + by.powi(2)).sqrt());
+        let lambda3 = lambda1 + by.atan2(phi1.cos() + bx);
+        LatLng::try_from_radians(phi3, lambda3)
+    } // fn
+
+    /// Returns the point at `fraction` of the way from this point to another
+    /// point along a great circle. A `fraction` of `0.0` returns this point,
+    /// and `1.0` returns the other point. Values outside of that range
+    /// extrapolate along the same great circle.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::FloatToDecimalConversionError` if `fraction` is not a
+    /// finite number.
+    pub fn interpolate(&self, other: &LatLng, fraction: f64) -> Result<LatLng, Error> {
+        let delta = self.angular_distance(other);
+
+        // Coincident points have no great circle between them:
+        if delta == 0.0 {
+            return Ok(self.clone());
+        } // if
+
+        let (phi1, lambda1) = (self.lat_radians(), self.lng_radians());
+        let (phi2, lambda2) = (other.lat_radians(), other.lng_radians());
+        let a = ((1.0 - fraction) * delta).sin() / delta.sin();
+        let b = (fraction * delta).sin() / delta.sin();
+        let x = a * phi1.cos() * lambda1.cos() + b * phi2.cos() * lambda2.cos();
+        let y = a * phi1.cos() * lambda1.sin() + b * phi2.cos() * lambda2.sin();
+        let z = a * phi1.sin() + b * phi2.sin();
+        LatLng::try_from_radians(z.atan2(x.hypot(y)), y.atan2(x))
+    } // fn
+
+    /// Returns the distance, in meters, between this point and another point
+    /// on the WGS-84 ellipsoid using [Vincenty's inverse
+    /// formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae).
+    ///
+    /// This is slower than `haversine_distance` but accurate to within a
+    /// millimetre. Returns `None` if the formula fails to converge, which only
+    /// happens for nearly antipodal points.
+    pub fn vincenty_distance(&self, other: &LatLng) -> Option<f64> {
+        let b = (1.0 - WGS84_F) * WGS84_A;
+        let l = other.lng_radians() - self.lng_radians();
+        let u1 = ((1.0 - WGS84_F) * self.lat_radians().tan()).atan();
+        let u2 = ((1.0 - WGS84_F) * other.lat_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+
+        for _iteration in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+
+            // Coincident points:
+            if sin_sigma == 0.0 {
+                return Some(0.0);
+            } // if
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+
+            // Both points are on the equator:
+            let cos_2sigmam = if cos_sq_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            }; // if
+
+            let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+            let lambda_previous = lambda;
+            lambda = l + (1.0 - c) * WGS84_F * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sigmam + c * cos_sigma * (-1.0 + 2.0 * cos_2sigmam.powi(2))));
+
+            if (lambda - lambda_previous).abs() < 1e-12 {
+                let u_sq = cos_sq_alpha * (WGS84_A.powi(2) - b.powi(2)) / b.powi(2);
+                let big_a = 1.0 + u_sq / 16384.0
+                    * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+                let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+                let delta_sigma = big_b * sin_sigma * (cos_2sigmam + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigmam.powi(2))
+                        - big_b / 6.0 * cos_2sigmam * (-3.0 + 4.0 * sin_sigma.powi(2))
+                            * (-3.0 + 4.0 * cos_2sigmam.powi(2))));
+                return Some(b * big_a * (sigma - delta_sigma));
+            } // if
+        } // for
+
+        None
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lng: f64) -> LatLng {
+        LatLng::try_from_f64(lat, lng).unwrap()
+    } // fn
+
+    /// The end points of the example in Vincenty's 1975 paper, from Flinders
+    /// Peak to Buninyong in Victoria, Australia. The distance between them on
+    /// the ellipsoid is 54,972.271 m, with a forward azimuth of 306°52'05.37"
+    /// and a reverse azimuth of 127°10'25.07".
+    fn flinders_peak_and_buninyong() -> (LatLng, LatLng) {
+        (
+            LatLng::try_from_dms("37°57'03.72030\"S 144°25'29.52440\"E").unwrap(),
+            LatLng::try_from_dms("37°39'10.15610\"S 143°55'35.38390\"E").unwrap(),
+        ) // tuple
+    } // fn
+
+    #[test]
+    fn vincenty_matches_the_reference_example() {
+        let (flinders_peak, buninyong) = flinders_peak_and_buninyong();
+        let distance = flinders_peak.vincenty_distance(&buninyong).unwrap();
+        assert!((distance - 54_972.271).abs() < 0.002, "{distance}");
+        assert_eq!(buninyong.vincenty_distance(&flinders_peak).map(f64::round), Some(54_972.0));
+        assert_eq!(buninyong.vincenty_distance(&buninyong), Some(0.0));
+    } // fn
+
+    #[test]
+    fn vincenty_gives_up_near_antipodes() {
+        assert_eq!(point(0.0, 0.0).vincenty_distance(&point(0.5, 179.7)), None);
+        assert_eq!(point(0.0, 0.0).vincenty_distance(&point(0.0, 180.0)), None);
+        assert_eq!(point(10.0, 0.0).vincenty_distance(&point(-10.0, 180.0)), None);
+        // Nearly, but not quite, antipodal points still converge:
+        assert!(point(0.0, 0.0).vincenty_distance(&point(0.5, 179.5)).is_some());
+    } // fn
+
+    #[test]
+    fn haversine_is_within_half_a_percent() {
+        let (flinders_peak, buninyong) = flinders_peak_and_buninyong();
+        let distance = flinders_peak.haversine_distance(&buninyong);
+        assert!((distance / 54_972.271 - 1.0).abs() < 0.005, "{distance}");
+
+        // One degree along the equator or a meridian:
+        let degree = EARTH_RADIUS_METERS * std::f64::consts::PI / 180.0;
+        assert!((point(0.0, 0.0).haversine_distance(&point(0.0, 1.0)) - degree).abs() < 0.001);
+        assert!((point(10.0, 20.0).haversine_distance(&point(11.0, 20.0)) - degree).abs() < 0.001);
+        // Across the antimeridian:
+        assert!((point(0.0, 179.5).haversine_distance(&point(0.0, -179.5)) - degree).abs() < 0.001);
+    } // fn
+
+    #[test]
+    fn bearings() {
+        let origin = point(0.0, 0.0);
+        assert!((origin.initial_bearing(&point(1.0, 0.0)) - 0.0).abs() < 1e-9);
+        assert!((origin.initial_bearing(&point(0.0, 1.0)) - 90.0).abs() < 1e-9);
+        assert!((origin.initial_bearing(&point(-1.0, 0.0)) - 180.0).abs() < 1e-9);
+        assert!((origin.initial_bearing(&point(0.0, -1.0)) - 270.0).abs() < 1e-9);
+
+        // The great circle bearings are close to the ellipsoidal azimuths:
+        let (flinders_peak, buninyong) = flinders_peak_and_buninyong();
+        assert!((flinders_peak.initial_bearing(&buninyong) - 306.868).abs() < 0.2);
+        assert!((flinders_peak.final_bearing(&buninyong) - 307.174).abs() < 0.2);
+        assert!((buninyong.initial_bearing(&flinders_peak) - 127.174).abs() < 0.2);
+    } // fn
+
+    #[test]
+    fn destination_and_bearing_round_trip() {
+        for origin in [point(0.0, 0.0), point(-37.95, 144.42), point(64.1, -21.9), point(-12.0, 179.9)] {
+            for bearing in [0.0, 45.0, 135.0, 200.0, 315.0] {
+                for distance in [1_000.0, 25_000.0, 1_000_000.0] {
+                    let destination = origin.destination(bearing, distance).unwrap();
+                    let travelled = origin.haversine_distance(&destination);
+                    let heading = origin.initial_bearing(&destination);
+                    assert!((travelled - distance).abs() < 0.01, "{origin} {bearing}° {distance} m: {travelled}");
+                    assert!(((heading - bearing + 540.0) % 360.0 - 180.0).abs() < 0.001, "{origin} {bearing}°: {heading}");
+                } // for
+            } // for
+        } // for
+
+        // Travelling east across the antimeridian wraps the longitude:
+        let degree = EARTH_RADIUS_METERS * std::f64::consts::PI / 180.0;
+        assert_eq!(point(0.0, 179.5).destination(90.0, degree).unwrap(), point(0.0, -179.5));
+        assert!(point(0.0, 0.0).destination(f64::NAN, 1.0).is_err());
+    } // fn
+
+    #[test]
+    fn midpoint_and_interpolate() {
+        let (start, end) = (point(0.0, 0.0), point(0.0, 10.0));
+        assert_eq!(start.midpoint(&end).unwrap(), point(0.0, 5.0));
+        assert_eq!(start.interpolate(&end, 0.5).unwrap(), point(0.0, 5.0));
+        assert_eq!(start.interpolate(&end, 0.0).unwrap(), start);
+        assert_eq!(start.interpolate(&end, 1.0).unwrap(), end);
+        assert_eq!(start.interpolate(&start, 0.5).unwrap(), start);
+
+        // The great circle between two points at the same latitude bows
+        // towards the pole:
+        let midpoint = point(50.0, -10.0).midpoint(&point(50.0, 10.0)).unwrap();
+        assert!(midpoint.lat > Decimal::from(50));
+    } // fn
+} // mod
@@ -0,0 +1,38 @@
+//! Provides some `LatLng` conversion methods for the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::latlng::LatLng;
+use geojson::{Geometry, Position, Value};
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Converts a `LatLng` struct into a GeoJSON position. Note that GeoJSON
+    /// positions are ordered longitude first, then latitude.
+    pub fn to_geojson_position(&self) -> Position {
+        vec![
+            self.lng.to_f64().unwrap_or_default(),
+            self.lat.to_f64().unwrap_or_default(),
+        ] // vec!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&LatLng> for Geometry {
+    /// Converts a `google_maps::LatLng` struct to a GeoJSON `Point` geometry.
+    fn from(latlng: &LatLng) -> Self {
+        Geometry::new(Value::Point(latlng.to_geojson_position()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a path of `LatLng` points into a GeoJSON `LineString` geometry.
+
+pub(crate) fn to_line_string(points: &[LatLng]) -> Geometry {
+    Geometry::new(Value::LineString(
+        points.iter().map(LatLng::to_geojson_position).collect()
+    )) // Geometry
+} // fn
@@ -0,0 +1,221 @@
+//! Parses and formats `LatLng` coordinates as [Military Grid Reference
+//! System](https://en.wikipedia.org/wiki/Military_Grid_Reference_System)
+//! (MGRS) grid references such as `18T WL 80654 06346`. MGRS is built on UTM,
+//! replacing the leading digits of the easting and northing with letters that
+//! identify a 100 km square. It is widely used by emergency services and
+//! search & rescue teams.
+
+use crate::error::Error;
+use crate::latlng::{
+    utm::{band_index, validate_zone, Utm, LATITUDE_BANDS},
+    LatLng,
+}; // crate::latlng
+
+// -----------------------------------------------------------------------------
+
+/// The 100 km column letters. Zones use the three sets of eight letters in
+/// turn, so that neighbouring zones do not share column letters.
+const COLUMN_LETTERS: [&str; 3] = ["ABCDEFGH", "JKLMNPQR", "STUVWXYZ"];
+
+/// The 100 km row letters. Even numbered zones start five letters later, so
+/// that neighbouring zones do not share row letters.
+const ROW_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUV";
+
+/// The northing span, in meters, after which the row letters repeat.
+const ROW_CYCLE: f64 = 2_000_000.0;
+
+/// The maximum number of digits of easting or northing in a grid reference.
+const MAX_PRECISION: u8 = 5;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Formats this point as an MGRS grid reference, for example
+    /// `18T WL 80654 06346`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `precision` ‧ The number of digits of easting and northing, from `0`
+    /// (a 100 km square) to `5` (a 1 m square). Digits are truncated rather
+    /// than rounded, as the MGRS standard requires, so the reference always
+    /// identifies the square that contains the point.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidMgrsPrecision` if `precision` is greater than
+    /// 5.
+    /// * Returns `Error::UtmLatitudeOutOfRange` if the latitude is south of
+    /// 80° S or north of 84° N.
+    pub fn to_mgrs(&self, precision: u8) -> Result<String, Error> {
+        if precision > MAX_PRECISION {
+            return Err(Error::InvalidMgrsPrecision(precision));
+        } // if
+
+        let utm = self.to_utm()?;
+        let set = usize::from((utm.zone - 1) % 3);
+        let column = (utm.easting / 100_000.0).floor() as usize;
+        let row = ((utm.northing % ROW_CYCLE) / 100_000.0).floor() as usize
+            + if utm.zone.is_multiple_of(2) { 5 } else { 0 };
+
+        let column_letter = COLUMN_LETTERS[set].chars().nth(column.saturating_sub(1)).unwrap_or('A');
+        let row_letter = ROW_LETTERS.chars().nth(row % 20).unwrap_or('A');
+
+        let digits = |meters: f64| {
+            let value = (meters % 100_000.0 / 10_f64.powi(i32::from(MAX_PRECISION - precision))).floor();
+            format!("{value:0width$}", width = usize::from(precision))
+        }; // digits
+
+        let square = format!("{zone}{band} {column_letter}{row_letter}", zone = utm.zone, band = utm.band);
+
+        Ok(if precision == 0 {
+            square
+        } else {
+            format!("{square} {} {}", digits(utm.easting), digits(utm.northing))
+        }) // Ok
+    } // fn
+
+    /// Parses an MGRS grid reference, such as `18T WL 80654 06346` or
+    /// `18TWL8065406346`, into the center of the square it identifies.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidMgrsString` if the string is not an MGRS grid
+    /// reference, or its 100 km square letters are not valid for its zone.
+    /// * Returns `Error::InvalidUtmZone` or `Error::InvalidUtmBand` if the
+    /// zone number or band letter is invalid.
+    pub fn try_from_mgrs(value: &str) -> Result<LatLng, Error> {
+        let invalid = || Error::InvalidMgrsString(value.to_string());
+        let compact: String = value
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .map(|character| character.to_ascii_uppercase())
+            .collect();
+
+        let zone_length = compact.chars().take_while(char::is_ascii_digit).count();
+        let zone: u8 = compact[..zone_length].parse().map_err(|_error| invalid())?;
+        validate_zone(zone)?;
+
+        let mut letters = compact[zone_length..].chars();
+        let band = letters.next().ok_or_else(invalid)?;
+        if !band.is_ascii_alphabetic() {
+            return Err(invalid());
+        } // if
+        let band_index = band_index(band)?;
+        let column_letter = letters.next().ok_or_else(invalid)?;
+        let row_letter = letters.next().ok_or_else(invalid)?;
+
+        let digits = letters.as_str();
+        if digits.len() % 2 == 1
+            || digits.len() > usize::from(MAX_PRECISION) * 2
+            || !digits.chars().all(|digit| digit.is_ascii_digit()) {
+            return Err(invalid());
+        } // if
+
+        // Each digit is a power of ten finer than the last. The grid reference
+        // identifies the south-west corner of a square, so the center of the
+        // square is half of its size further on:
+        let precision = digits.len() / 2;
+        let size = 10_f64.powi(5 - i32::try_from(precision).unwrap_or(5));
+        let parse = |digits: &str| -> f64 {
+            digits.parse::<f64>().unwrap_or_default() * size + size / 2.0
+        }; // parse
+        let (easting_digits, northing_digits) = digits.split_at(precision);
+
+        let set = usize::from((zone - 1) % 3);
+        let column = COLUMN_LETTERS[set].find(column_letter).ok_or_else(invalid)? + 1;
+        let mut row = ROW_LETTERS.find(row_letter).ok_or_else(invalid)?;
+        if zone.is_multiple_of(2) {
+            row = (row + 15) % 20;
+        } // if
+
+        let easting = column as f64 * 100_000.0 + parse(easting_digits);
+        let row_northing = row as f64 * 100_000.0 + parse(northing_digits);
+
+        // The row letters repeat every 2,000 km. The latitude band resolves
+        // which repetition is meant: it is the first one that is not south of
+        // the band's southern edge:
+        let band_south = -80.0 + 8.0 * band_index as f64;
+        let band_northing = LatLng::try_from_f64(band_south, crate::latlng::utm::central_meridian(zone))?
+            .to_utm()?
+            .northing;
+        let band_northing = (band_northing / 100_000.0).floor() * 100_000.0;
+        let mut northing = row_northing;
+        while northing < band_northing {
+            northing += ROW_CYCLE;
+        } // while
+
+        let band_letter = LATITUDE_BANDS.chars().nth(band_index).unwrap_or(band);
+        Utm { zone, band: band_letter, easting, northing }.to_latlng()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lng: f64) -> LatLng {
+        LatLng::try_from_f64(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn formats_each_precision() {
+        let pittsburgh = point(40.446, -79.982);
+        assert_eq!(pittsburgh.to_mgrs(5).unwrap(), "17T NE 86328 77758");
+        assert_eq!(pittsburgh.to_mgrs(2).unwrap(), "17T NE 86 77");
+        assert_eq!(pittsburgh.to_mgrs(0).unwrap(), "17T NE");
+        assert_eq!(pittsburgh.to_mgrs(6), Err(Error::InvalidMgrsPrecision(6)));
+        assert_eq!(point(-80.5, 0.0).to_mgrs(5), Err(Error::UtmLatitudeOutOfRange(rust_decimal_macros::dec!(-80.5))));
+    } // fn
+
+    #[test]
+    fn round_trips_near_band_and_zone_edges() {
+        for (lat, lng) in [
+            // Zones 1 and 60 at the antimeridian:
+            (0.0, -180.0), (0.0, 179.9999), (-45.0, -179.9999), (52.0, 179.9999),
+            // Zone boundaries elsewhere:
+            (10.0, -0.0001), (10.0, 0.0001),
+            // The first and last latitude bands, and the boundary between the
+            // L and M bands:
+            (-79.99, -70.0), (83.99, 10.0), (-8.0001, 3.0), (-7.9999, 3.0),
+            // The Norway and Svalbard exceptions:
+            (60.0, 5.0), (78.0, 20.0),
+            (40.446, -79.982), (-33.8568, 151.2153),
+        ] {
+            let reference = point(lat, lng).to_mgrs(5).unwrap();
+            let parsed = LatLng::try_from_mgrs(&reference).unwrap();
+            let (parsed_lat, parsed_lng) = (parsed.lat_radians().to_degrees(), parsed.lng_radians().to_degrees());
+            assert!((parsed_lat - lat).abs() < 0.000_1, "{reference}: {lat} became {parsed_lat}");
+            assert!((parsed_lng - lng).abs() < 0.000_1, "{reference}: {lng} became {parsed_lng}");
+
+            // The center of the square is inside the square, so it has the
+            // same grid reference:
+            assert_eq!(parsed.to_mgrs(5).unwrap(), reference);
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_compact_and_coarse_references() {
+        let spaced = LatLng::try_from_mgrs("17T NE 86328 77758").unwrap();
+        assert_eq!(LatLng::try_from_mgrs("17tne8632877758").unwrap(), spaced);
+
+        // A 100 km square is parsed into its center:
+        let square = LatLng::try_from_mgrs("17T NE").unwrap();
+        assert_eq!(square.to_mgrs(1).unwrap(), "17T NE 5 5");
+    } // fn
+
+    #[test]
+    fn rejects_malformed_input() {
+        let invalid = |value: &str| Err(Error::InvalidMgrsString(value.to_string()));
+        assert_eq!(LatLng::try_from_mgrs("17T NE 8632 777"), invalid("17T NE 8632 777"));
+        assert_eq!(LatLng::try_from_mgrs("17T NE 863281 777581"), invalid("17T NE 863281 777581"));
+        assert_eq!(LatLng::try_from_mgrs("17T N"), invalid("17T N"));
+        assert_eq!(LatLng::try_from_mgrs("T NE 1 1"), invalid("T NE 1 1"));
+        // Zone 17 uses the second set of column letters, which has no `A`:
+        assert_eq!(LatLng::try_from_mgrs("17T AE 1 1"), invalid("17T AE 1 1"));
+        assert_eq!(LatLng::try_from_mgrs("0T NE 1 1"), Err(Error::InvalidUtmZone(0)));
+        assert_eq!(LatLng::try_from_mgrs("61T NE 1 1"), Err(Error::InvalidUtmZone(61)));
+        assert_eq!(LatLng::try_from_mgrs("17A NE 1 1"), Err(Error::InvalidUtmBand('A')));
+    } // fn
+} // mod
@@ -2,8 +2,14 @@
 //! latitude & longitude coorindate system is used to specify a position or
 //! location on the Earth's surface.
 
+mod dms;
 #[cfg(feature = "geo")]
 mod geo;
+pub(crate) mod geodesy;
+#[cfg(feature = "geojson")]
+pub(crate) mod geojson_conversions;
+mod mgrs;
+pub(crate) mod utm;
 
 // -----------------------------------------------------------------------------
 
@@ -171,6 +177,54 @@ impl std::str::FromStr for LatLng {
 
 // -----------------------------------------------------------------------------
 
+impl LatLng {
+    /// Parses coordinates written in any of the formats that this library
+    /// understands, which is useful for free-form input such as an address
+    /// field:
+    ///
+    /// * Decimal degrees: `40.446, -79.982`
+    /// * Degrees-minutes-seconds: `40°26'46"N 79°58'56"W`
+    /// * Degrees-decimal-minutes: `N 40°26.767' W 79°58.933'`
+    /// * UTM: `17T 589139 4477813`
+    /// * MGRS: `17T NE 89139 77813`
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns the error from the parser for the format that the string most
+    /// resembles. Strings that resemble none of the formats return
+    /// `Error::InvalidDmsString`.
+    pub fn try_from_any_format(value: &str) -> Result<LatLng, Error> {
+        if let Ok(latlng) = LatLng::from_str(value) {
+            return Ok(latlng);
+        } // if
+
+        // MGRS grid references are a zone number followed by three letters and
+        // an even number of digits:
+        let compact: String = value.chars().filter(|character| !character.is_whitespace()).collect();
+        let zone_length = compact.chars().take_while(char::is_ascii_digit).count();
+        let mut after_zone = compact.chars().skip(zone_length);
+        if (1..=2).contains(&zone_length)
+            && after_zone.by_ref().take(3).filter(char::is_ascii_alphabetic).count() == 3
+            && after_zone.all(|character| character.is_ascii_digit()) {
+            return LatLng::try_from_mgrs(value);
+        } // if
+
+        // UTM eastings are always six digits long, which tells them apart from
+        // degrees that happen to follow a number and a hemisphere letter. Zone
+        // and band errors are only returned once the easting and northing
+        // have parsed, so `65 N 20 W` still falls through to the DMS parser:
+        match utm::Utm::from_str(value) {
+            Ok(utm) if (100_000.0..1_000_000.0).contains(&utm.easting) => return utm.to_latlng(),
+            Err(error @ (Error::InvalidUtmZone(_) | Error::InvalidUtmBand(_))) => return Err(error),
+            _ => {},
+        } // match
+
+        LatLng::try_from_dms(value)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::convert::From<&LatLng> for String {
     /// Converts a `LatLng` struct to a `String` that contains a
     /// latitude/longitude pair.
@@ -220,4 +274,57 @@ impl std::fmt::Display for LatLng {
             }, // match
         ) // write!
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(value: &str, lat: Decimal, lng: Decimal) {
+        let parsed = LatLng::try_from_any_format(value).unwrap();
+        assert!(
+            (parsed.lat - lat).abs() < dec!(0.000_1) && (parsed.lng - lng).abs() < dec!(0.000_1),
+            "{value} became {parsed:?}",
+        ); // assert!
+    } // fn
+
+    #[test]
+    fn parses_each_format() {
+        for value in [
+            "40.446, -79.982",
+            "40°26'45.6\"N 79°58'55.2\"W",
+            "N 40°26.760' W 079°58.920'",
+            "17T 586329 4477758",
+            "17T NE 86328 77758",
+            "17TNE8632877758",
+        ] {
+            assert_near(value, dec!(40.446), dec!(-79.982));
+        } // for
+    } // fn
+
+    #[test]
+    fn prefers_degrees_over_utm_lookalikes() {
+        // A number, a letter and two more numbers may be degrees with
+        // hemisphere letters rather than a UTM coordinate:
+        assert_near("65 N 20 W", dec!(65), dec!(-20));
+        assert_near("80 N 79 W", dec!(80), dec!(-79));
+        assert_near("17 N 20 E", dec!(17), dec!(20));
+    } // fn
+
+    #[test]
+    fn reports_the_closest_format_error() {
+        assert_eq!(LatLng::try_from_any_format("61T 586329 4477758"), Err(Error::InvalidUtmZone(61)));
+        assert_eq!(LatLng::try_from_any_format("17I 586329 4477758"), Err(Error::InvalidUtmBand('I')));
+        assert_eq!(
+            LatLng::try_from_any_format("17T AE 1 1"),
+            Err(Error::InvalidMgrsString("17T AE 1 1".to_string())),
+        ); // assert_eq!
+        assert_eq!(
+            LatLng::try_from_any_format("somewhere"),
+            Err(Error::InvalidDmsString("somewhere".to_string())),
+        ); // assert_eq!
+        assert_eq!(LatLng::try_from_any_format("40°61'N 79°W"), Err(Error::MinutesOutOfRange(dec!(61))));
+    } // fn
+} // mod
@@ -0,0 +1,373 @@
+//! Contains the `Utm` struct and its conversions to and from `LatLng`. The
+//! [Universal Transverse
+//! Mercator](https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system)
+//! system divides the world into 60 zones, each 6° of longitude wide, and
+//! gives positions within a zone as metres east and north of a reference
+//! point.
+//!
+//! Conversions use the WGS-84 ellipsoid and the series expansions from
+//! Snyder's _Map Projections: A Working Manual_ (USGS Professional Paper
+//! 1395), which are accurate to well under a metre within a zone.
+
+use crate::error::Error;
+use crate::latlng::{geodesy::{WGS84_A, WGS84_F}, LatLng};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, FromStr, ToPrimitive};
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+
+/// The scale factor along each zone's central meridian.
+const K0: f64 = 0.9996;
+
+/// The easting of each zone's central meridian, in meters.
+const FALSE_EASTING: f64 = 500_000.0;
+
+/// The northing added to coordinates in the southern hemisphere, in meters.
+const FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// The latitude band letters from 80° S to 84° N. Each band is 8° tall, except
+/// for `X` which is 12° tall.
+pub(crate) const LATITUDE_BANDS: &str = "CDEFGHJKLMNPQRSTUVWX";
+
+// -----------------------------------------------------------------------------
+//
+/// A position in the Universal Transverse Mercator coordinate system, such as
+/// `17T 589139 4477813`.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Utm {
+    /// The zone number, from 1 to 60.
+    pub zone: u8,
+    /// The latitude band letter, from `C` to `X`. Bands `N` and higher are in
+    /// the northern hemisphere.
+    pub band: char,
+    /// The distance east of the zone's false origin, in meters.
+    pub easting: f64,
+    /// The distance north of the equator, in meters. In the southern
+    /// hemisphere, 10,000 km is added so that northings are never negative.
+    pub northing: f64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Utm {
+    /// Returns `true` if this position is in the northern hemisphere.
+    pub fn is_northern(&self) -> bool {
+        self.band >= 'N'
+    } // fn
+
+    /// Converts this UTM position into a latitude & longitude.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidUtmZone` or `Error::InvalidUtmBand` if the
+    /// zone number or band letter is invalid.
+    /// * Returns `Error::FloatToDecimalConversionError` if the easting or
+    /// northing is not a finite number.
+    pub fn to_latlng(&self) -> Result<LatLng, Error> {
+        validate_zone(self.zone)?;
+        band_index(self.band)?;
+
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let ep2 = e2 / (1.0 - e2);
+        let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+        let x = self.easting - FALSE_EASTING;
+        let y = if self.is_northern() { self.northing } else { self.northing - FALSE_NORTHING };
+
+        // The footpoint latitude, which has the same meridian distance as
+        // the northing:
+        let mu = y / K0 / (WGS84_A * (1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0));
+        let phi1 = mu
+            + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+            + (21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+            + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+            + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+        let (sin_phi1, cos_phi1) = phi1.sin_cos();
+        let n1 = WGS84_A / (1.0 - e2 * sin_phi1.powi(2)).sqrt();
+        let t1 = phi1.tan().powi(2);
+        let c1 = ep2 * cos_phi1.powi(2);
+        let r1 = WGS84_A * (1.0 - e2) / (1.0 - e2 * sin_phi1.powi(2)).powf(1.5);
+        let d = x / (n1 * K0);
+
+        let phi = phi1 - (n1 * phi1.tan() / r1) * (d.powi(2) / 2.0
+            - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1.powi(2) - 9.0 * ep2) * d.powi(4) / 24.0
+            + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1.powi(2) - 252.0 * ep2 - 3.0 * c1.powi(2)) * d.powi(6) / 720.0);
+
+        let lambda = central_meridian(self.zone).to_radians() + (d
+            - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+            + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1.powi(2) + 8.0 * ep2 + 24.0 * t1.powi(2)) * d.powi(5) / 120.0)
+            / cos_phi1;
+
+        if !phi.is_finite() || !lambda.is_finite() {
+            return Err(Error::FloatToDecimalConversionError(format!("{}, {}", self.easting, self.northing)));
+        } // if
+
+        LatLng::try_from_radians(phi, lambda)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Converts this point into Universal Transverse Mercator coordinates. The
+    /// zone exceptions for south-western Norway and Svalbard are applied.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::UtmLatitudeOutOfRange` if the latitude is south of
+    /// 80° S or north of 84° N. Those regions use the Universal Polar
+    /// Stereographic system instead.
+    pub fn to_utm(&self) -> Result<Utm, Error> {
+        if self.lat < dec!(-80.0) || self.lat > dec!(84.0) {
+            return Err(Error::UtmLatitudeOutOfRange(self.lat));
+        } // if
+
+        let lat = self.lat.to_f64().unwrap_or_default();
+        let lng = self.lng.to_f64().unwrap_or_default();
+        let zone = utm_zone(lat, lng);
+        let band = LATITUDE_BANDS
+            .chars()
+            .nth(((lat + 80.0) / 8.0).floor().clamp(0.0, 19.0) as usize)
+            .unwrap_or('X');
+
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let ep2 = e2 / (1.0 - e2);
+        let phi = self.lat_radians();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        let n = WGS84_A / (1.0 - e2 * sin_phi.powi(2)).sqrt();
+        let t = phi.tan().powi(2);
+        let c = ep2 * cos_phi.powi(2);
+        let mut delta_lambda = self.lng_radians() - central_meridian(zone).to_radians();
+        // Zone 1 and zone 60 meet at the antimeridian:
+        if delta_lambda > std::f64::consts::PI {
+            delta_lambda -= 2.0 * std::f64::consts::PI;
+        } else if delta_lambda < -std::f64::consts::PI {
+            delta_lambda += 2.0 * std::f64::consts::PI;
+        } // if
+        let a = cos_phi * delta_lambda;
+
+        // The distance along the meridian from the equator:
+        let m = WGS84_A * (
+            (1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e2.powi(2) / 32.0 + 45.0 * e2.powi(3) / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e2.powi(2) / 256.0 + 45.0 * e2.powi(3) / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e2.powi(3) / 3072.0) * (6.0 * phi).sin()
+        ); // m
+
+        let easting = K0 * n * (a
+            + (1.0 - t + c) * a.powi(3) / 6.0
+            + (5.0 - 18.0 * t + t.powi(2) + 72.0 * c - 58.0 * ep2) * a.powi(5) / 120.0)
+            + FALSE_EASTING;
+
+        let mut northing = K0 * (m + n * phi.tan() * (a.powi(2) / 2.0
+            + (5.0 - t + 9.0 * c + 4.0 * c.powi(2)) * a.powi(4) / 24.0
+            + (61.0 - 58.0 * t + t.powi(2) + 600.0 * c - 330.0 * ep2) * a.powi(6) / 720.0));
+
+        if lat < 0.0 {
+            northing += FALSE_NORTHING;
+        } // if
+
+        Ok(Utm { zone, band, easting, northing })
+    } // fn
+
+    /// Parses a Universal Transverse Mercator coordinate such as
+    /// `17T 589139 4477813`. The zone and band may be separated by a space,
+    /// and the easting and northing may have decimal places or `mE` and `mN`
+    /// suffixes.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidUtmString` if the string is not a UTM
+    /// coordinate.
+    /// * Returns `Error::InvalidUtmZone` or `Error::InvalidUtmBand` if the
+    /// zone number or band letter is invalid.
+    pub fn try_from_utm(value: &str) -> Result<LatLng, Error> {
+        Utm::from_str(value)?.to_latlng()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Utm {
+    type Err = Error;
+    /// Parses a UTM coordinate such as `17T 589139 4477813`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidUtmString(value.to_string());
+        let compact: String = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        let zone_length = compact.chars().take_while(char::is_ascii_digit).count();
+        let zone: u8 = compact[..zone_length].parse().map_err(|_error| invalid())?;
+
+        let mut rest = compact[zone_length..].trim_start().chars();
+        let band = rest.next().ok_or_else(invalid)?.to_ascii_uppercase();
+        let numbers: Vec<&str> = rest.as_str().split_whitespace().collect();
+        let [easting, northing] = numbers.as_slice() else { return Err(invalid()) };
+        if !band.is_ascii_alphabetic() {
+            return Err(invalid());
+        } // if
+
+        let parse = |number: &str, suffix: &str| -> Result<f64, Error> {
+            let number = number
+                .strip_suffix(suffix)
+                .or_else(|| number.strip_suffix(&suffix.to_lowercase()))
+                .unwrap_or(number);
+            Decimal::from_str(number)
+                .ok()
+                .filter(|number| !number.is_sign_negative())
+                .and_then(|number| number.to_f64())
+                .ok_or_else(invalid)
+        }; // parse
+
+        let easting = parse(easting, "mE")?;
+        let northing = parse(northing, "mN")?;
+
+        // The string is shaped like a UTM coordinate, so any problems with the
+        // zone or band are reported precisely:
+        validate_zone(zone)?;
+        band_index(band)?;
+
+        Ok(Utm { zone, band, easting, northing })
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Utm {
+    /// Formats the position as `17T 589139 4477813`, rounded to the metre.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{zone}{band} {easting} {northing}",
+            zone = self.zone,
+            band = self.band,
+            easting = Decimal::from_f64(self.easting).unwrap_or_default().round(),
+            northing = Decimal::from_f64(self.northing).unwrap_or_default().round(),
+        ) // write!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the UTM zone for a latitude & longitude in degrees, including the
+/// Norway and Svalbard exceptions.
+
+pub(crate) fn utm_zone(lat: f64, lng: f64) -> u8 {
+    // Longitude 180° belongs to zone 60, not to a 61st zone:
+    let zone = (((lng + 180.0) / 6.0).floor().clamp(0.0, 59.0) as u8) + 1;
+
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lng) {
+        return 32;
+    } // if
+
+    if (72.0..=84.0).contains(&lat) && (0.0..42.0).contains(&lng) {
+        return match lng {
+            lng if lng < 9.0 => 31,
+            lng if lng < 21.0 => 33,
+            lng if lng < 33.0 => 35,
+            _ => 37,
+        }; // return
+    } // if
+
+    zone
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the longitude, in degrees, of a zone's central meridian.
+
+pub(crate) fn central_meridian(zone: u8) -> f64 {
+    f64::from(zone) * 6.0 - 183.0
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns an error if the zone number is not between 1 and 60.
+
+pub(crate) fn validate_zone(zone: u8) -> Result<(), Error> {
+    if (1..=60).contains(&zone) {
+        Ok(())
+    } else {
+        Err(Error::InvalidUtmZone(zone))
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the index of a latitude band letter, from 0 for `C` to 19 for `X`.
+
+pub(crate) fn band_index(band: char) -> Result<usize, Error> {
+    LATITUDE_BANDS
+        .find(band.to_ascii_uppercase())
+        .ok_or(Error::InvalidUtmBand(band))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lng: f64) -> LatLng {
+        LatLng::try_from_f64(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn converts_to_utm() {
+        let utm = point(40.446, -79.982).to_utm().unwrap();
+        assert_eq!(utm.to_string(), "17T 586329 4477758");
+        assert!(utm.is_northern());
+
+        let utm = point(-33.8568, 151.2153).to_utm().unwrap();
+        assert_eq!(utm.to_string(), "56H 334901 6252289");
+        assert!(!utm.is_northern());
+    } // fn
+
+    #[test]
+    fn applies_zone_exceptions() {
+        // Longitude 180° is the eastern edge of zone 60, and -180° the western
+        // edge of zone 1:
+        assert_eq!(point(0.0, 180.0).to_utm().unwrap().zone, 60);
+        assert_eq!(point(0.0, -180.0).to_utm().unwrap().zone, 1);
+        // South-western Norway and Svalbard:
+        assert_eq!(point(60.0, 5.0).to_utm().unwrap().zone, 32);
+        assert_eq!(point(78.0, 20.0).to_utm().unwrap().zone, 33);
+        assert_eq!(point(78.0, 8.0).to_utm().unwrap().zone, 31);
+    } // fn
+
+    #[test]
+    fn round_trips() {
+        for (lat, lng) in [
+            (40.446, -79.982), (-33.8568, 151.2153), (0.0, -180.0), (0.0, 179.9999),
+            (83.99, 10.0), (-79.99, -70.0), (60.0, 5.0), (78.0, 20.0), (-8.0001, 3.0),
+        ] {
+            let parsed = LatLng::try_from_utm(&point(lat, lng).to_utm().unwrap().to_string()).unwrap();
+            let (parsed_lat, parsed_lng) = (parsed.lat_radians().to_degrees(), parsed.lng_radians().to_degrees());
+            assert!((parsed_lat - lat).abs() < 0.000_1, "{lat} became {parsed_lat}");
+            // -180° and 180° are the same meridian:
+            assert!(((parsed_lng - lng + 540.0) % 360.0 - 180.0).abs() < 0.000_1, "{lng} became {parsed_lng}");
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_spellings() {
+        let expected = Utm { zone: 17, band: 'T', easting: 586_329.0, northing: 4_477_758.5 };
+        for value in ["17T 586329 4477758.5", "17 T 586329mE 4477758.5mN", "17t  586329me 4477758.5mn"] {
+            assert_eq!(Utm::from_str(value).unwrap().to_string(), expected.to_string());
+        } // for
+    } // fn
+
+    #[test]
+    fn rejects_malformed_input() {
+        let invalid = |value: &str| Err(Error::InvalidUtmString(value.to_string()));
+        assert_eq!(LatLng::try_from_utm("17T 586329"), invalid("17T 586329"));
+        assert_eq!(LatLng::try_from_utm("T 586329 4477758"), invalid("T 586329 4477758"));
+        assert_eq!(LatLng::try_from_utm("17T -5 4477758"), invalid("17T -5 4477758"));
+        assert_eq!(LatLng::try_from_utm("65 N 20 W"), invalid("65 N 20 W"));
+        assert_eq!(LatLng::try_from_utm("61T 586329 4477758"), Err(Error::InvalidUtmZone(61)));
+        assert_eq!(LatLng::try_from_utm("17I 586329 4477758"), Err(Error::InvalidUtmBand('I')));
+        assert_eq!(point(84.5, 0.0).to_utm(), Err(Error::UtmLatitudeOutOfRange(dec!(84.5))));
+    } // fn
+} // mod
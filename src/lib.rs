@@ -25,7 +25,7 @@
 //!
 //! * In your project's `Cargo.toml` file, under the `[dependencies]` section:
 //!
-//!     * Add `google_maps = "3.1"`. Check
+//!     * Add `google_maps = "4.0"`. Check
 //!         [crates.io](https://crates.io/crates/google_maps) for the latest
 //!         version number.
 //!
@@ -38,6 +38,17 @@
 //!
 //! # What's new?
 //!
+//! * 4.0.0: 2026-10-19: ⚠ **Breaking change**: `DirectionsDistance.value` is
+//! now a `Distance` instead of a `u32` number of meters, and the elevation
+//! `Point.elevation` and `Point.resolution` fields are now a `Distance` instead
+//! of an `f64` number of meters. Call `.meters()` for the old values, for
+//! example `leg.distance.value.meters()`. The JSON format is unchanged, since a
+//! `Distance` is serialized as a number of meters.
+//!
+//! * 4.0.0: 2026-10-19: Add the `Distance` type, which converts between metric
+//! and imperial units and formats distances for display. The optional `uom`
+//! feature converts distances to and from `uom` lengths.
+//!
 //! * 3.1.1: 2023-01-29: Update to Chinese languages. Thanks
 //! [rick68](https://github.com/rick68)!
 //!
@@ -167,7 +178,7 @@
 //!
 //! if let Some(results) = &elevation.results {
 //!     for result in results {
-//!         println!("Elevation: {} meters", result.elevation)
+//!         println!("Elevation: {} meters", result.elevation.meters())
 //!     }
 //! }
 //! ```
@@ -315,13 +326,23 @@
 //! * directions
 //! * distance_matrix
 //! * elevation
+//! * embed (opt-in, not enabled by default)
 //! * geocoding
+//! * maps_urls (opt-in, not enabled by default)
 //! * places
 //! * roads
+//! * solar (opt-in, not enabled by default)
 //! * time_zone
+//! * weather (opt-in, not enabled by default)
 //! * enable-reqwest (uses [reqwest](https://crates.io/crates/reqwest) for
 //! querying Google Maps API).
 //! * geo (support for [geo](https://crates.io/crates/geo-types) crate types)
+//! * geojson (export responses as [GeoJSON](https://crates.io/crates/geojson)
+//! features)
+//! * gpx (import and export [GPX](https://www.topografix.com/gpx.asp) files)
+//! * kml (export [KML](https://developers.google.com/kml/documentation) files)
+//! * uom (convert distances to and from [uom](https://crates.io/crates/uom)
+//! lengths)
 //!
 //! Note: The Places autocomplete APIs have been put in the `autocomplete`
 //! feature flag. The rest of the Places APIs will be put under the `places`
@@ -401,6 +422,11 @@
 
 mod bounds;
 mod client;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+mod csv;
+mod distance;
+#[cfg(feature = "chrono")]
+mod duration;
 mod language;
 mod latlng;
 mod place_type;
@@ -408,7 +434,10 @@ mod region;
 mod serde;
 pub mod country;
 pub mod error;
+pub mod open_location_code;
+pub mod polyline;
 pub mod prelude;
+pub mod web_mercator;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
 // feature flags:
@@ -419,13 +448,29 @@ pub mod directions;
 pub mod distance_matrix;
 #[cfg(feature = "elevation")]
 pub mod elevation;
+#[cfg(feature = "embed")]
+pub mod embed;
 #[cfg(feature = "geocoding")]
 pub mod geocoding;
+#[cfg(feature = "gpx")]
+pub mod gpx;
+#[cfg(feature = "kml")]
+pub mod kml;
+#[cfg(feature = "maps_urls")]
+pub mod maps_urls;
 #[cfg(feature = "time_zone")]
 pub mod time_zone;
 pub mod places;
 #[cfg(feature = "roads")]
 pub mod roads;
+#[cfg(feature = "distance_matrix")]
+pub mod route_optimization;
+#[cfg(feature = "solar")]
+pub mod solar;
+#[cfg(all(any(feature = "directions", feature = "distance_matrix"), feature = "enable-reqwest"))]
+pub mod traffic_profile;
+#[cfg(feature = "weather")]
+pub mod weather;
 
 // Re-exports:
 
@@ -434,12 +479,19 @@ pub use crate::{
     client::GoogleMapsClient,
     client::GoogleMapsClient as ClientSettings,
     country::Country,
+    distance::Distance,
+    distance::display::DisplayDistance,
+    distance::unit::DistanceUnit,
     language::Language,
     latlng::LatLng,
+    latlng::utm::Utm,
     place_type::PlaceType,
     region::Region,
 }; // crate
 
+#[cfg(feature = "chrono")]
+pub use crate::duration::DisplayDuration;
+
 // Optional dependencies:
 
 #[cfg(feature = "enable-reqwest")]
@@ -0,0 +1,110 @@
+//! Contains the `BaseMap` enum and its associated traits. It is used to
+//! select the type of map displayed by a Maps URL.
+
+use crate::maps_urls::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Defines the [type of map](https://developers.google.com/maps/documentation/urls/get-started#map-action)
+/// to display.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum BaseMap {
+    /// The default road map view.
+    #[serde(alias = "roadmap")]
+    Roadmap,
+    /// Google Earth satellite images.
+    #[serde(alias = "satellite")]
+    Satellite,
+    /// Physical relief map images.
+    #[serde(alias = "terrain")]
+    Terrain,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for BaseMap {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match BaseMap::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&BaseMap> for String {
+    /// Converts a `BaseMap` enum to a `String` that contains a base map code.
+    fn from(base_map: &BaseMap) -> String {
+        match base_map {
+            BaseMap::Roadmap => String::from("roadmap"),
+            BaseMap::Satellite => String::from("satellite"),
+            BaseMap::Terrain => String::from("terrain"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static BASE_MAPS_BY_CODE: phf::Map<&'static str, BaseMap> = phf_map! {
+    "roadmap" => BaseMap::Roadmap,
+    "satellite" => BaseMap::Satellite,
+    "terrain" => BaseMap::Terrain,
+};
+
+impl std::convert::TryFrom<&str> for BaseMap {
+    // Error definitions are contained in the
+    // `google_maps\src\maps_urls\error.rs` module.
+    type Error = crate::maps_urls::error::Error;
+    /// Gets a `BaseMap` enum from a `String` that contains a valid base map
+    /// code.
+    fn try_from(base_map_code: &str) -> Result<Self, Self::Error> {
+        BASE_MAPS_BY_CODE
+            .get(base_map_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidBaseMapCode(base_map_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for BaseMap {
+    // Error definitions are contained in the
+    // `google_maps\src\maps_urls\error.rs` module.
+    type Err = crate::maps_urls::error::Error;
+    /// Gets a `BaseMap` enum from a `String` that contains a valid base map
+    /// code.
+    fn from_str(base_map_code: &str) -> Result<Self, Self::Err> {
+        BASE_MAPS_BY_CODE
+            .get(base_map_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidBaseMapCode(base_map_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for BaseMap {
+    /// Returns a reasonable default variant for the `BaseMap` enum type.
+    fn default() -> Self {
+        BaseMap::Roadmap
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for BaseMap {
+    /// Formats a `BaseMap` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BaseMap::Roadmap => write!(f, "Roadmap"),
+            BaseMap::Satellite => write!(f, "Satellite"),
+            BaseMap::Terrain => write!(f, "Terrain"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,373 @@
+//! Contains the `Directions` struct. It is used to build a Maps URL that
+//! displays the path between two or more points on a map, as well as the
+//! distance and travel time.
+
+use crate::directions::request::{
+    avoid::Avoid,
+    location::Location,
+    waypoint::Waypoint,
+}; // crate::directions::request
+use crate::directions::travel_mode::TravelMode;
+use crate::maps_urls::{SERVICE_URL, encode, error::Error, location_parts, waypoint_parts};
+
+// -----------------------------------------------------------------------------
+//
+/// The [directions action](https://developers.google.com/maps/documentation/urls/get-started#directions-action)
+/// displays the path between two or more specified points on the map, as well
+/// as the distance and travel time.
+///
+/// ## Example:
+///
+/// ```rust
+/// let url = MapsUrlsDirections::new(Location::Address("Cleveland, OH".to_string()))
+///     .with_origin(Location::Address("Paris, France".to_string()))
+///     .with_waypoints(vec![Waypoint::Address("Berlin, Germany".to_string())])
+///     .with_travel_mode(TravelMode::Driving)
+///     .with_restrictions(vec![Avoid::Tolls, Avoid::Ferries])
+///     .url()?;
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Directions {
+
+    /// Defines the endpoint of the directions.
+    destination: Location,
+
+    /// Defines the starting point from which to display directions. If none
+    /// is specified, Google Maps uses the user's current location, if
+    /// available.
+    origin: Option<Location>,
+
+    /// Specifies one or more intermediary places to route directions through
+    /// between the origin and destination.
+    waypoints: Vec<Waypoint>,
+
+    /// Defines the method of travel. If no travel mode is specified, the
+    /// Google Map shows one or more of the most relevant modes for the
+    /// specified route and/or user preferences.
+    travel_mode: Option<TravelMode>,
+
+    /// Sets features the route should try to avoid.
+    restrictions: Vec<Avoid>,
+
+    /// Launches either turn-by-turn navigation or route preview to the
+    /// specified destination, based on whether the origin is available.
+    navigate: bool,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Directions {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps URLs directions link.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `destination` ‧ The endpoint of the directions.
+
+    pub fn new(destination: Location) -> Directions {
+        Directions {
+            destination,
+            origin: None,
+            waypoints: Vec::new(),
+            travel_mode: None,
+            restrictions: Vec::new(),
+            navigate: false,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the starting point of the directions. If no origin is set,
+    /// Google Maps uses the user's current location, if available.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_origin(Location::Address("Paris, France".to_string()))
+    /// ```
+
+    pub fn with_origin(&mut self, origin: Location) -> &mut Directions {
+        self.origin = Some(origin);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies intermediary places to route directions through. Maps URLs
+    /// support up to 9 waypoints, which must be either all place IDs or none.
+    /// Encoded polyline waypoints are not supported.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_waypoints(vec![Waypoint::Address("Berlin, Germany".to_string())])
+    /// ```
+
+    pub fn with_waypoints(&mut self, waypoints: Vec<Waypoint>) -> &mut Directions {
+        self.waypoints = waypoints;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the method of travel.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_travel_mode(TravelMode::Bicycling)
+    /// ```
+
+    pub fn with_travel_mode(&mut self, travel_mode: TravelMode) -> &mut Directions {
+        self.travel_mode = Some(travel_mode);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies features the route should try to avoid. Only
+    /// `Avoid::Ferries`, `Avoid::Highways` and `Avoid::Tolls` are supported
+    /// by Maps URLs.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_restrictions(vec![Avoid::Highways, Avoid::Tolls])
+    /// ```
+
+    pub fn with_restrictions(&mut self, restrictions: Vec<Avoid>) -> &mut Directions {
+        self.restrictions = restrictions;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Launches turn-by-turn navigation (or a route preview, if the origin is
+    /// not available) instead of just showing the route.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_navigation(true)
+    /// ```
+
+    pub fn with_navigation(&mut self, navigate: bool) -> &mut Directions {
+        self.navigate = navigate;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the Maps URL for the directions you've built.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn url(&self) -> Result<String, Error> {
+
+        // Validate the parameters before building the URL:
+
+        if self.waypoints.len() > 9 {
+            return Err(Error::TooManyWaypoints(self.waypoints.len()))
+        } // if
+
+        if self.travel_mode == Some(TravelMode::Transit) && !self.waypoints.is_empty() {
+            return Err(Error::EitherWaypointsOrTransitMode(self.waypoints.len()))
+        } // if
+
+        if self.restrictions.contains(&Avoid::Indoor) {
+            return Err(Error::AvoidIndoor)
+        } // if
+
+        let mut url = format!("{SERVICE_URL}/dir/?api=1");
+
+        // Origin key/value pairs:
+        if let Some(origin) = &self.origin {
+            let (text, place_id) = location_parts(origin);
+            url.push_str("&origin=");
+            url.push_str(&encode(&text));
+            if let Some(place_id) = place_id {
+                url.push_str("&origin_place_id=");
+                url.push_str(&encode(&place_id));
+            } // if
+        } // if
+
+        // Destination key/value pairs:
+        let (text, place_id) = location_parts(&self.destination);
+        url.push_str("&destination=");
+        url.push_str(&encode(&text));
+        if let Some(place_id) = place_id {
+            url.push_str("&destination_place_id=");
+            url.push_str(&encode(&place_id));
+        } // if
+
+        // Travel mode key/value pair:
+        if let Some(travel_mode) = &self.travel_mode {
+            url.push_str("&travelmode=");
+            url.push_str(&String::from(travel_mode).to_lowercase());
+        } // if
+
+        // Navigation key/value pair:
+        if self.navigate {
+            url.push_str("&dir_action=navigate");
+        } // if
+
+        // Waypoints key/value pairs. Maps URLs expect a pipe-separated list of
+        // waypoint texts, and a parallel list of place IDs:
+        if !self.waypoints.is_empty() {
+            let parts = self.waypoints
+                .iter()
+                .map(waypoint_parts)
+                .collect::<Result<Vec<(String, Option<String>)>, Error>>()?;
+            let place_id_count = parts
+                .iter()
+                .filter(|(_text, place_id)| place_id.is_some())
+                .count();
+            if place_id_count != 0 && place_id_count != parts.len() {
+                return Err(Error::MixedWaypointPlaceIds)
+            } // if
+            let texts = parts
+                .iter()
+                .map(|(text, _place_id)| text.as_str())
+                .collect::<Vec<&str>>()
+                .join("|");
+            url.push_str("&waypoints=");
+            url.push_str(&encode(&texts));
+            if place_id_count != 0 {
+                let place_ids = parts
+                    .iter()
+                    .filter_map(|(_text, place_id)| place_id.as_deref())
+                    .collect::<Vec<&str>>()
+                    .join("|");
+                url.push_str("&waypoint_place_ids=");
+                url.push_str(&encode(&place_ids));
+            } // if
+        } // if
+
+        // Avoid key/value pair:
+        if !self.restrictions.is_empty() {
+            let restrictions = self.restrictions
+                .iter()
+                .map(String::from)
+                .collect::<Vec<String>>()
+                .join(",");
+            url.push_str("&avoid=");
+            url.push_str(&encode(&restrictions));
+        } // if
+
+        Ok(url)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&crate::directions::request::Request<'_>> for Directions {
+    /// Builds a Maps URLs directions link that describes the same trip as a
+    /// Directions API request: the same origin, destination, waypoints,
+    /// travel mode and restrictions. Note that Maps URLs can not optimize
+    /// waypoints; if waypoint optimization was requested, reorder the
+    /// waypoints using the response's `waypoint_order` first.
+    fn from(request: &crate::directions::request::Request<'_>) -> Directions {
+        Directions {
+            destination: request.destination.clone(),
+            origin: Some(request.origin.clone()),
+            waypoints: request.waypoints.clone().unwrap_or_default(),
+            travel_mode: request.travel_mode.clone(),
+            restrictions: request.restrictions.clone().unwrap_or_default(),
+            navigate: false,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GoogleMapsClient;
+
+    #[test]
+    fn url_encodes_the_trip() {
+        let url = Directions::new(Location::Address("Cleveland, OH".to_string()))
+            .with_origin(Location::Address("Paris, France".to_string()))
+            .with_waypoints(vec![
+                Waypoint::Address("Berlin, Germany".to_string()),
+                Waypoint::Address("Reykjavik".to_string()),
+            ]) // vec!
+            .with_travel_mode(TravelMode::Driving)
+            .with_restrictions(vec![Avoid::Tolls, Avoid::Ferries])
+            .url();
+        assert_eq!(
+            url.unwrap(),
+            "https://www.google.com/maps/dir/?api=1\
+            &origin=Paris%2C%20France&destination=Cleveland%2C%20OH\
+            &travelmode=driving\
+            &waypoints=Berlin%2C%20Germany%7CReykjavik\
+            &avoid=tolls%2Cferries"
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn url_sends_place_ids_next_to_their_text() {
+        let url = Directions::new(Location::PlaceId("ChIJKxDbe_lYwokRVf__s8CPn-o".to_string()))
+            .with_navigation(true)
+            .url();
+        assert_eq!(
+            url.unwrap(),
+            "https://www.google.com/maps/dir/?api=1\
+            &destination=ChIJKxDbe%5FlYwokRVf%5F%5Fs8CPn%2Do\
+            &destination_place_id=ChIJKxDbe%5FlYwokRVf%5F%5Fs8CPn%2Do\
+            &dir_action=navigate"
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn url_rejects_what_maps_urls_can_not_express() {
+        let destination = Location::Address("Cleveland, OH".to_string());
+
+        let url = Directions::new(destination.clone())
+            .with_waypoints(vec![Waypoint::Address("Berlin".to_string())])
+            .with_travel_mode(TravelMode::Transit)
+            .url();
+        assert_eq!(url, Err(Error::EitherWaypointsOrTransitMode(1)));
+
+        let url = Directions::new(destination.clone())
+            .with_waypoints(vec![
+                Waypoint::Address("Berlin".to_string()),
+                Waypoint::PlaceId("ChIJAVkDPzdOqEcRcDteW0YgIQQ".to_string()),
+            ]) // vec!
+            .url();
+        assert_eq!(url, Err(Error::MixedWaypointPlaceIds));
+
+        let url = Directions::new(destination)
+            .with_restrictions(vec![Avoid::Indoor])
+            .url();
+        assert_eq!(url, Err(Error::AvoidIndoor));
+    } // fn
+
+    #[test]
+    fn converts_a_directions_request() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let mut request = client.directions(
+            Location::Address("Chicago, IL".to_string()),
+            Location::Address("Indianapolis, IN".to_string()),
+        ); // directions
+        let request = request
+            .with_waypoints(&[Waypoint::Address("Gary, IN".to_string())])
+            .with_travel_mode(TravelMode::Driving);
+
+        let directions = Directions::from(&*request);
+        assert_eq!(directions.origin, Some(Location::Address("Chicago, IL".to_string())));
+        assert_eq!(directions.destination, Location::Address("Indianapolis, IN".to_string()));
+        assert_eq!(directions.waypoints, vec![Waypoint::Address("Gary, IN".to_string())]);
+        assert_eq!(directions.travel_mode, Some(TravelMode::Driving));
+        assert!(!directions.navigate);
+    } // fn
+} // mod
@@ -0,0 +1,148 @@
+//! Contains the `DisplayMap` struct. It is used to build a Maps URL that
+//! launches Google Maps with no markers or directions.
+
+use crate::latlng::LatLng;
+use crate::maps_urls::{SERVICE_URL, base_map::BaseMap, encode, error::Error, layer::Layer};
+
+// -----------------------------------------------------------------------------
+//
+/// The [map action](https://developers.google.com/maps/documentation/urls/get-started#map-action)
+/// returns a map with no markers or directions.
+///
+/// ## Example:
+///
+/// ```rust
+/// let url = DisplayMap::new(LatLng::try_from_dec(dec!(-33.8569), dec!(151.2152))?)
+///     .with_zoom(12)
+///     .with_base_map(BaseMap::Satellite)
+///     .url()?;
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DisplayMap {
+
+    /// Defines the center of the map window.
+    center: LatLng,
+
+    /// Sets the initial zoom level of the map. Accepted values are whole
+    /// integers ranging from 0 (the whole world) to 21 (individual
+    /// buildings).
+    zoom: Option<u8>,
+
+    /// Defines the type of map to display.
+    base_map: Option<BaseMap>,
+
+    /// Defines an extra layer to display on the map, if any.
+    layer: Option<Layer>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl DisplayMap {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps URLs map display.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `center` ‧ The center of the map window.
+
+    pub fn new(center: LatLng) -> DisplayMap {
+        DisplayMap {
+            center,
+            zoom: None,
+            base_map: None,
+            layer: None,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the initial zoom level of the map, from 0 (the whole world) to 21
+    /// (individual buildings). The upper zoom limit can vary depending on the
+    /// map data available at the selected location.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_zoom(15)
+    /// ```
+
+    pub fn with_zoom(&mut self, zoom: u8) -> &mut DisplayMap {
+        self.zoom = Some(zoom);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Defines the type of map to display.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_base_map(BaseMap::Terrain)
+    /// ```
+
+    pub fn with_base_map(&mut self, base_map: BaseMap) -> &mut DisplayMap {
+        self.base_map = Some(base_map);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Defines an extra layer to display on the map.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_layer(Layer::Traffic)
+    /// ```
+
+    pub fn with_layer(&mut self, layer: Layer) -> &mut DisplayMap {
+        self.layer = Some(layer);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the Maps URL for the map you've built.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn url(&self) -> Result<String, Error> {
+
+        let mut url = format!(
+            "{SERVICE_URL}/@?api=1&map_action=map&center={}",
+            encode(&String::from(&self.center)),
+        );
+
+        // Zoom key/value pair:
+        if let Some(zoom) = self.zoom {
+            if zoom > 21 {
+                return Err(Error::InvalidZoom(zoom))
+            } // if
+            url.push_str("&zoom=");
+            url.push_str(&zoom.to_string());
+        } // if
+
+        // Base map key/value pair:
+        if let Some(base_map) = &self.base_map {
+            url.push_str("&basemap=");
+            url.push_str(&String::from(base_map));
+        } // if
+
+        // Layer key/value pair:
+        if let Some(layer) = &self.layer {
+            url.push_str("&layer=");
+            url.push_str(&String::from(layer));
+        } // if
+
+        Ok(url)
+
+    } // fn
+
+} // impl
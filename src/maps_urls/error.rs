@@ -0,0 +1,107 @@
+//! Maps URLs error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when building a Google Maps URL.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// Maps URLs can only avoid ferries, highways and tolls. Indoor steps can
+    /// not be avoided.
+    AvoidIndoor,
+    /// Waypoints may not be specified in Transit travel mode.
+    EitherWaypointsOrTransitMode(usize),
+    /// The Street View field of view must be between 10 and 100 degrees.
+    InvalidFieldOfView(u8),
+    /// The Street View heading must be between -180 and 360 degrees.
+    InvalidHeading(i16),
+    /// API client library attempted to parse a string that contained an invalid
+    /// base map code. See `google_maps\src\maps_urls\base_map.rs` for more
+    /// information.
+    InvalidBaseMapCode(String),
+    /// API client library attempted to parse a string that contained an invalid
+    /// layer code. See `google_maps\src\maps_urls\layer.rs` for more
+    /// information.
+    InvalidLayerCode(String),
+    /// The Street View pitch must be between -90 and 90 degrees.
+    InvalidPitch(i8),
+    /// The map zoom level must be between 0 and 21.
+    InvalidZoom(u8),
+    /// Maps URLs must either give every waypoint as a place ID, or none of
+    /// them.
+    MixedWaypointPlaceIds,
+    /// Encoded polylines can not be used as waypoints in a Maps URL.
+    PolylineWaypoint(String),
+    /// Maps URLs support a maximum of 9 waypoints.
+    TooManyWaypoints(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::AvoidIndoor => write!(f,
+                "Google Maps URLs builder: \
+                Maps URLs can only avoid `ferries`, `highways` and `tolls`. \
+                Remove `Avoid::Indoor` from the restrictions."),
+            Error::EitherWaypointsOrTransitMode(waypoint_count) => write!(f,
+                "Google Maps URLs builder: \
+                Waypoints cannot be used with the `transit` travel mode. \
+                {waypoint_count} waypoints are set. \
+                Remove the waypoints or choose another travel mode."),
+            Error::InvalidFieldOfView(fov) => write!(f,
+                "Google Maps URLs builder: \
+                `{fov}` is not a valid field of view. \
+                The field of view must be between 10 and 100 degrees."),
+            Error::InvalidHeading(heading) => write!(f,
+                "Google Maps URLs builder: \
+                `{heading}` is not a valid heading. \
+                The heading must be between -180 and 360 degrees."),
+            Error::InvalidBaseMapCode(base_map_code) => write!(f,
+                "Google Maps URLs builder: \
+                `{base_map_code}` is not a valid base map code. \
+                Valid codes are `roadmap`, `satellite`, and `terrain`."),
+            Error::InvalidLayerCode(layer_code) => write!(f,
+                "Google Maps URLs builder: \
+                `{layer_code}` is not a valid layer code. \
+                Valid codes are `none`, `transit`, `traffic`, and `bicycling`."),
+            Error::InvalidPitch(pitch) => write!(f,
+                "Google Maps URLs builder: \
+                `{pitch}` is not a valid pitch. \
+                The pitch must be between -90 and 90 degrees."),
+            Error::InvalidZoom(zoom) => write!(f,
+                "Google Maps URLs builder: \
+                `{zoom}` is not a valid zoom level. \
+                The zoom level must be between 0 and 21."),
+            Error::MixedWaypointPlaceIds => write!(f,
+                "Google Maps URLs builder: \
+                Maps URLs require either all waypoints to be place IDs, or none of them. \
+                Use `Waypoint::PlaceId` for every waypoint, or for none."),
+            Error::PolylineWaypoint(polyline) => write!(f,
+                "Google Maps URLs builder: \
+                The encoded polyline waypoint `{polyline}` can not be used in a Maps URL. \
+                Decode it into `Waypoint::LatLng` points instead."),
+            Error::TooManyWaypoints(waypoint_count) => write!(f,
+                "Google Maps URLs builder: \
+                The maximum allowed number of waypoints is 9 plus the origin and destination. \
+                {waypoint_count} waypoints are set. \
+                Please remove {} waypoint(s).",
+                waypoint_count - 9),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. The Maps URLs builder does not depend on any
+    /// other library for its errors.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    } // fn
+} // impl
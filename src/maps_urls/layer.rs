@@ -0,0 +1,116 @@
+//! Contains the `Layer` enum and its associated traits. It is used to select
+//! an extra layer to display on the map opened by a Maps URL.
+
+use crate::maps_urls::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Defines an extra [layer](https://developers.google.com/maps/documentation/urls/get-started#map-action)
+/// to display on the map, if any.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Layer {
+    /// No extra layer.
+    #[serde(alias = "none")]
+    None,
+    /// Public transit lines.
+    #[serde(alias = "transit")]
+    Transit,
+    /// Current traffic conditions.
+    #[serde(alias = "traffic")]
+    Traffic,
+    /// Bicycle routes and lanes.
+    #[serde(alias = "bicycling")]
+    Bicycling,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Layer {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Layer::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Layer> for String {
+    /// Converts a `Layer` enum to a `String` that contains a layer code.
+    fn from(layer: &Layer) -> String {
+        match layer {
+            Layer::None => String::from("none"),
+            Layer::Transit => String::from("transit"),
+            Layer::Traffic => String::from("traffic"),
+            Layer::Bicycling => String::from("bicycling"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static LAYERS_BY_CODE: phf::Map<&'static str, Layer> = phf_map! {
+    "none" => Layer::None,
+    "transit" => Layer::Transit,
+    "traffic" => Layer::Traffic,
+    "bicycling" => Layer::Bicycling,
+};
+
+impl std::convert::TryFrom<&str> for Layer {
+    // Error definitions are contained in the
+    // `google_maps\src\maps_urls\error.rs` module.
+    type Error = crate::maps_urls::error::Error;
+    /// Gets a `Layer` enum from a `String` that contains a valid layer
+    /// code.
+    fn try_from(layer_code: &str) -> Result<Self, Self::Error> {
+        LAYERS_BY_CODE
+            .get(layer_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidLayerCode(layer_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Layer {
+    // Error definitions are contained in the
+    // `google_maps\src\maps_urls\error.rs` module.
+    type Err = crate::maps_urls::error::Error;
+    /// Gets a `Layer` enum from a `String` that contains a valid layer
+    /// code.
+    fn from_str(layer_code: &str) -> Result<Self, Self::Err> {
+        LAYERS_BY_CODE
+            .get(layer_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidLayerCode(layer_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Layer {
+    /// Returns a reasonable default variant for the `Layer` enum type.
+    fn default() -> Self {
+        Layer::None
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Layer {
+    /// Formats a `Layer` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Layer::None => write!(f, "None"),
+            Layer::Transit => write!(f, "Transit"),
+            Layer::Traffic => write!(f, "Traffic"),
+            Layer::Bicycling => write!(f, "Bicycling"),
+        } // match
+    } // fn
+} // impl
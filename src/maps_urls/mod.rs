@@ -0,0 +1,120 @@
+//! **Maps URLs** - Build universal, cross-platform URLs that launch Google
+//! Maps and perform searches, get directions, display maps, and show Street
+//! View panoramas.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/urls/get-started)
+//!
+//! Maps URLs provides a universal, cross-platform URL scheme that you can use
+//! to launch Google Maps. If the Google Maps app is installed on the user's
+//! device, the URL opens the app; otherwise the URL opens Google Maps in a
+//! browser. **Maps URLs do not require a Google API key**, so the builders in
+//! this module do not need a `GoogleMapsClient`.
+//!
+//! The builders accept the same typed inputs as the Directions API client
+//! (`Location`, `Waypoint`, `TravelMode`, `Avoid` and `LatLng`), so a link
+//! generated here will describe the same trip as a `directions()` request
+//! built from the same values. A `maps_urls::Directions` may also be built
+//! directly from a `DirectionsRequest`.
+//!
+//! # [Actions](https://developers.google.com/maps/documentation/urls/get-started#forming-the-url)
+//!
+//! * `Search` ‧ Launch a Google Map that displays a pin for a specific place,
+//! or perform a general search and launch a map to display the results.
+//!
+//! * `Directions` ‧ Request directions and launch Google Maps with the results.
+//!
+//! * `DisplayMap` ‧ Launch Google Maps with no markers or directions.
+//!
+//! * `Pano` ‧ Launch an interactive Street View panorama.
+//!
+//! # Example
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let url = MapsUrlsDirections::new(Location::Address("Space Needle, Seattle WA".to_string()))
+//!     .with_origin(Location::Address("Pike Place Market, Seattle WA".to_string()))
+//!     .with_travel_mode(TravelMode::Walking)
+//!     .url()?;
+//! ```
+
+pub mod base_map;
+pub mod directions;
+pub mod display_map;
+pub mod error;
+pub mod layer;
+pub mod pano;
+pub mod search;
+
+// -----------------------------------------------------------------------------
+
+use crate::directions::request::{location::Location, waypoint::Waypoint};
+use crate::maps_urls::error::Error;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.google.com/maps";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::maps_urls::{
+    base_map::BaseMap,
+    directions::Directions as MapsUrlsDirections,
+    display_map::DisplayMap,
+    error::Error as MapsUrlsError,
+    layer::Layer,
+    pano::Pano,
+    search::Search as MapsUrlsSearch,
+}; // crate::maps_urls
+
+// -----------------------------------------------------------------------------
+//
+/// Percent-encodes a value for use in a Maps URL query string.
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a `Location` into the (unencoded) text value and the optional place
+/// ID that Maps URLs expect in separate parameters.
+///
+/// Maps URLs require the text parameter even when a place ID is supplied, but
+/// the place ID takes precedence. When only a place ID is known, the place ID
+/// itself is used as the text value.
+
+fn location_parts(location: &Location) -> (String, Option<String>) {
+    match location {
+        Location::Address(address) => (address.clone(), None),
+        Location::LatLng(latlng) => (String::from(latlng), None),
+        Location::PlaceId(place_id) => (place_id.clone(), Some(place_id.clone())),
+        #[cfg(feature = "geo")]
+        Location::Coordinate(coordinate) =>
+            (format!("{},{}", coordinate.y, coordinate.x), None),
+        #[cfg(feature = "geo")]
+        Location::Point(point) =>
+            (format!("{},{}", point.y(), point.x()), None),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a `Waypoint` into its text value and optional place ID. Encoded
+/// polylines can not be expressed in a Maps URL.
+
+fn waypoint_parts(waypoint: &Waypoint) -> Result<(String, Option<String>), Error> {
+    match waypoint {
+        Waypoint::Address(address) => Ok((address.clone(), None)),
+        Waypoint::LatLng(latlng) => Ok((String::from(latlng), None)),
+        Waypoint::PlaceId(place_id) => Ok((place_id.clone(), Some(place_id.clone()))),
+        Waypoint::Polyline(polyline) => Err(Error::PolylineWaypoint(polyline.clone())),
+        #[cfg(feature = "geo")]
+        Waypoint::Coordinate(coordinate) =>
+            Ok((format!("{},{}", coordinate.y, coordinate.x), None)),
+        #[cfg(feature = "geo")]
+        Waypoint::Point(point) =>
+            Ok((format!("{},{}", point.y(), point.x()), None)),
+    } // match
+} // fn
@@ -0,0 +1,232 @@
+//! Contains the `Pano` struct. It is used to build a Maps URL that launches
+//! an interactive Street View panorama.
+
+use crate::latlng::LatLng;
+use crate::maps_urls::{SERVICE_URL, encode, error::Error};
+
+// -----------------------------------------------------------------------------
+//
+/// The [Street View action](https://developers.google.com/maps/documentation/urls/get-started#street-view-action)
+/// launches a viewer for interactive Street View panoramas. The panorama is
+/// chosen either by a location (`viewpoint`) or by a specific panorama ID.
+///
+/// ## Example:
+///
+/// ```rust
+/// let url = Pano::from_viewpoint(LatLng::try_from_dec(dec!(48.857832), dec!(2.295226))?)
+///     .with_heading(-45)
+///     .with_pitch(38)
+///     .with_field_of_view(80)
+///     .url()?;
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Pano {
+
+    /// The map action displays a Street View panorama of the closest image to
+    /// this location.
+    viewpoint: Option<LatLng>,
+
+    /// A specific panorama ID of the image to display. If a `pano` is
+    /// specified, a `viewpoint` may also be specified; the `viewpoint` will
+    /// only be used if Google Maps cannot find the panorama ID.
+    pano: Option<String>,
+
+    /// Indicates the compass heading of the camera in degrees clockwise from
+    /// North. Accepted values are from -180 to 360 degrees.
+    heading: Option<i16>,
+
+    /// Specifies the angle, up or down, of the camera. The pitch is specified
+    /// in degrees from -90 to 90.
+    pitch: Option<i8>,
+
+    /// Determines the horizontal field of view of the image. The field of
+    /// view is expressed in degrees, with a range of 10 - 100.
+    field_of_view: Option<u8>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Pano {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Street View panorama of the
+    /// closest image to a location.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `viewpoint` ‧ The location to display a panorama for.
+
+    pub fn from_viewpoint(viewpoint: LatLng) -> Pano {
+        Pano {
+            viewpoint: Some(viewpoint),
+            pano: None,
+            heading: None,
+            pitch: None,
+            field_of_view: None,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a specific Street View panorama.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `pano` ‧ The panorama ID of the image to display.
+
+    pub fn from_pano_id(pano: String) -> Pano {
+        Pano {
+            viewpoint: None,
+            pano: Some(pano),
+            heading: None,
+            pitch: None,
+            field_of_view: None,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a fallback location, used if Google Maps cannot find the panorama
+    /// ID.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_viewpoint(LatLng::try_from_dec(dec!(48.857832), dec!(2.295226))?)
+    /// ```
+
+    pub fn with_viewpoint(&mut self, viewpoint: LatLng) -> &mut Pano {
+        self.viewpoint = Some(viewpoint);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the compass heading of the camera in degrees clockwise from North,
+    /// from -180 to 360.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_heading(90)
+    /// ```
+
+    pub fn with_heading(&mut self, heading: i16) -> &mut Pano {
+        self.heading = Some(heading);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the angle, up or down, of the camera in degrees from -90 to 90.
+    /// Positive values will angle the camera up, while negative values will
+    /// angle the camera down.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_pitch(10)
+    /// ```
+
+    pub fn with_pitch(&mut self, pitch: i8) -> &mut Pano {
+        self.pitch = Some(pitch);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the horizontal field of view of the image in degrees, from 10 to
+    /// 100. The default is 90.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_field_of_view(60)
+    /// ```
+
+    pub fn with_field_of_view(&mut self, field_of_view: u8) -> &mut Pano {
+        self.field_of_view = Some(field_of_view);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the Maps URL for the panorama you've built.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn url(&self) -> Result<String, Error> {
+
+        let mut url = format!("{SERVICE_URL}/@?api=1&map_action=pano");
+
+        // Viewpoint key/value pair:
+        if let Some(viewpoint) = &self.viewpoint {
+            url.push_str("&viewpoint=");
+            url.push_str(&encode(&String::from(viewpoint)));
+        } // if
+
+        // Panorama ID key/value pair:
+        if let Some(pano) = &self.pano {
+            url.push_str("&pano=");
+            url.push_str(&encode(pano));
+        } // if
+
+        // Heading key/value pair:
+        if let Some(heading) = self.heading {
+            if !(-180..=360).contains(&heading) {
+                return Err(Error::InvalidHeading(heading))
+            } // if
+            url.push_str("&heading=");
+            url.push_str(&heading.to_string());
+        } // if
+
+        // Pitch key/value pair:
+        if let Some(pitch) = self.pitch {
+            if !(-90..=90).contains(&pitch) {
+                return Err(Error::InvalidPitch(pitch))
+            } // if
+            url.push_str("&pitch=");
+            url.push_str(&pitch.to_string());
+        } // if
+
+        // Field of view key/value pair:
+        if let Some(field_of_view) = self.field_of_view {
+            if !(10..=100).contains(&field_of_view) {
+                return Err(Error::InvalidFieldOfView(field_of_view))
+            } // if
+            url.push_str("&fov=");
+            url.push_str(&field_of_view.to_string());
+        } // if
+
+        Ok(url)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_checks_the_camera_angles() {
+        let viewpoint = LatLng::try_from_f64(48.857832, 2.295226).unwrap();
+
+        let url = Pano::from_viewpoint(viewpoint.clone()).with_heading(-45).with_pitch(38).url();
+        assert_eq!(
+            url.unwrap(),
+            "https://www.google.com/maps/@?api=1&map_action=pano\
+            &viewpoint=48%2E857832%2C2%2E295226&heading=-45&pitch=38"
+        ); // assert_eq!
+
+        let url = Pano::from_viewpoint(viewpoint).with_field_of_view(120).url();
+        assert_eq!(url, Err(Error::InvalidFieldOfView(120)));
+    } // fn
+} // mod
@@ -0,0 +1,132 @@
+//! Contains the `Search` struct. It is used to build a Maps URL that displays
+//! a pin for a specific place, or the results of a general search.
+
+use crate::directions::request::location::Location;
+use crate::maps_urls::{SERVICE_URL, encode, location_parts};
+
+// -----------------------------------------------------------------------------
+//
+/// The [search action](https://developers.google.com/maps/documentation/urls/get-started#search-action)
+/// displays results for a search across the visible map region. When searching
+/// for a specific place, the resulting map puts a pin in the specified location
+/// and displays available place details.
+///
+/// ## Example:
+///
+/// ```rust
+/// let url = MapsUrlsSearch::new(Location::Address("pizza seattle wa".to_string()))
+///     .url();
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Search {
+
+    /// Defines the place(s) to highlight on the map. A place name, address,
+    /// or comma-separated latitude/longitude coordinates may be used. A
+    /// `Location::PlaceId` is sent as a `query_place_id`.
+    query: Location,
+
+    /// Text to send as the `query` parameter when the `query` location is a
+    /// place ID. Google uses it as a fallback if the place ID can not be
+    /// resolved.
+    query_text: Option<String>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Search {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps URLs search.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `query` ‧ The place to search for. A `Location::Address` performs a
+    /// text search (for example, `"pizza seattle wa"`), `Location::LatLng`
+    /// drops a pin at the coordinates, and `Location::PlaceId` opens that
+    /// specific place.
+
+    pub fn new(query: Location) -> Search {
+        Search {
+            query,
+            query_text: None,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the text sent alongside a place ID. Maps URLs require a
+    /// `query` value even when a place ID is given. If this is not set, the
+    /// place ID itself is sent as the text.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `query_text` ‧ A place name or address describing the place ID.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_query_text("Google Sydney".to_string())
+    /// ```
+
+    pub fn with_query_text(&mut self, query_text: String) -> &mut Search {
+        self.query_text = Some(query_text);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the Maps URL for the search you've built.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn url(&self) -> String {
+
+        let (text, place_id) = location_parts(&self.query);
+
+        let text = match (&place_id, &self.query_text) {
+            (Some(_place_id), Some(query_text)) => query_text.clone(),
+            _ => text,
+        }; // match
+
+        let mut url = format!("{SERVICE_URL}/search/?api=1&query={}", encode(&text));
+
+        if let Some(place_id) = place_id {
+            url.push_str("&query_place_id=");
+            url.push_str(&encode(&place_id));
+        } // if
+
+        url
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_encodes_the_query() {
+        let url = Search::new(Location::Address("pizza seattle wa".to_string())).url();
+        assert_eq!(url, "https://www.google.com/maps/search/?api=1&query=pizza%20seattle%20wa");
+    } // fn
+
+    #[test]
+    fn url_uses_the_query_text_for_a_place_id() {
+        let url = Search::new(Location::PlaceId("ChIJ3S-JXmauEmsRUcIaWtf4MzE".to_string()))
+            .with_query_text("Sydney Opera House".to_string())
+            .url();
+        assert_eq!(
+            url,
+            "https://www.google.com/maps/search/?api=1&query=Sydney%20Opera%20House\
+            &query_place_id=ChIJ3S%2DJXmauEmsRUcIaWtf4MzE"
+        ); // assert_eq!
+    } // fn
+} // mod
@@ -0,0 +1,150 @@
+//! Decodes a plus code into the area it represents.
+
+use crate::bounds::Bounds;
+use crate::latlng::LatLng;
+use crate::open_location_code::{
+    digit_value, error::Error, validate::is_full, ENCODING_BASE, FINAL_LAT_PRECISION,
+    FINAL_LNG_PRECISION, GRID_COLUMNS, GRID_LAT_FIRST_PLACE_VALUE, GRID_LNG_FIRST_PLACE_VALUE,
+    GRID_ROWS, MAX_DIGIT_COUNT, PAIR_CODE_LENGTH, PAIR_FIRST_PLACE_VALUE, PAIR_PRECISION,
+}; // crate::open_location_code
+use rust_decimal::Decimal;
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a full plus code into the `Bounds` of the area it represents. Use
+/// `Bounds::center` to get a single location for the code.
+///
+/// ## Errors:
+///
+/// * Returns `Error::NotFullCode` if the code is a valid short code. Short
+/// codes must be recovered with `recover_nearest` first.
+/// * Returns `Error::InvalidCode` if the string is not a plus code.
+
+pub fn decode(code: &str) -> Result<Bounds, Error> {
+    decode_area(code).map(|(bounds, _code_length)| bounds)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a full plus code into the `Bounds` of the area it represents and
+/// the number of digits in the code.
+
+pub(super) fn decode_area(code: &str) -> Result<(Bounds, usize), Error> {
+
+    if !is_full(code) {
+        return Err(if super::is_short(code) {
+            Error::NotFullCode(code.to_string())
+        } else {
+            Error::InvalidCode(code.to_string())
+        }); // return
+    } // if
+
+    let digits: Vec<i64> = code
+        .chars()
+        .filter_map(digit_value)
+        .take(MAX_DIGIT_COUNT)
+        .collect();
+
+    // Decode the latitude & longitude pairs:
+    let pair_digits = digits.len().min(PAIR_CODE_LENGTH);
+    let mut lat_value = -90 * PAIR_PRECISION;
+    let mut lng_value = -180 * PAIR_PRECISION;
+    let mut place_value = PAIR_FIRST_PLACE_VALUE;
+
+    for (index, pair) in digits[..pair_digits].chunks(2).enumerate() {
+        lat_value += pair[0] * place_value;
+        lng_value += pair.get(1).copied().unwrap_or_default() * place_value;
+        if (index + 1) * 2 < pair_digits {
+            place_value /= ENCODING_BASE;
+        } // if
+    } // for
+
+    let mut lat_precision = Decimal::new(place_value, 0) / Decimal::from(PAIR_PRECISION);
+    let mut lng_precision = lat_precision;
+
+    // Decode the grid digits, if any:
+    let mut grid_lat_value: i64 = 0;
+    let mut grid_lng_value: i64 = 0;
+
+    if digits.len() > PAIR_CODE_LENGTH {
+        let mut row_value = GRID_LAT_FIRST_PLACE_VALUE;
+        let mut column_value = GRID_LNG_FIRST_PLACE_VALUE;
+        for (index, digit) in digits.iter().enumerate().skip(PAIR_CODE_LENGTH) {
+            grid_lat_value += digit / GRID_COLUMNS * row_value;
+            grid_lng_value += digit % GRID_COLUMNS * column_value;
+            if index < digits.len() - 1 {
+                row_value /= GRID_ROWS;
+                column_value /= GRID_COLUMNS;
+            } // if
+        } // for
+        lat_precision = Decimal::from(row_value) / Decimal::from(FINAL_LAT_PRECISION);
+        lng_precision = Decimal::from(column_value) / Decimal::from(FINAL_LNG_PRECISION);
+    } // if
+
+    let lat = Decimal::from(lat_value) / Decimal::from(PAIR_PRECISION)
+        + Decimal::from(grid_lat_value) / Decimal::from(FINAL_LAT_PRECISION);
+    let lng = Decimal::from(lng_value) / Decimal::from(PAIR_PRECISION)
+        + Decimal::from(grid_lng_value) / Decimal::from(FINAL_LNG_PRECISION);
+
+    let bounds = Bounds {
+        southwest: LatLng { lat: lat.normalize(), lng: lng.normalize() },
+        northeast: LatLng {
+            lat: (lat + lat_precision).normalize(),
+            lng: (lng + lng_precision).normalize(),
+        }, // LatLng
+    }; // Bounds
+
+    Ok((bounds, digits.len()))
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn bounds(south: Decimal, west: Decimal, north: Decimal, east: Decimal) -> Bounds {
+        Bounds {
+            southwest: LatLng { lat: south, lng: west },
+            northeast: LatLng { lat: north, lng: east },
+        } // Bounds
+    } // fn
+
+    #[test]
+    fn decodes_specification_examples() {
+        assert_eq!(
+            decode("7FG49QCJ+2V").unwrap(),
+            bounds(dec!(20.37), dec!(2.782125), dec!(20.370125), dec!(2.78225)),
+        ); // assert_eq!
+        assert_eq!(
+            decode("7fg49qcj+2v").unwrap(),
+            decode("7FG49QCJ+2V").unwrap(),
+        ); // assert_eq!
+        assert_eq!(decode("7FG40000+").unwrap(), bounds(dec!(20), dec!(2), dec!(21), dec!(3)));
+
+        let zurich = decode("8FVC9G8F+6X").unwrap();
+        let center = zurich.center();
+        assert!((center.lat - dec!(47.365590)).abs() < dec!(0.0001));
+        assert!((center.lng - dec!(8.524997)).abs() < dec!(0.0001));
+    } // fn
+
+    #[test]
+    fn decodes_grid_digits() {
+        let (area, code_length) = decode_area("9C3W9QCJ+2VX").unwrap();
+        assert_eq!(code_length, 11);
+        assert_eq!(area.southwest, LatLng { lat: dec!(51.3701), lng: dec!(-1.21778125) });
+        assert!(area.contains(&LatLng { lat: dec!(51.3701125), lng: dec!(-1.217765625) }));
+        let (height, width) = area.span();
+        assert_eq!((height, width), (dec!(0.000025), dec!(0.00003125)));
+    } // fn
+
+    #[test]
+    fn rejects_short_and_invalid_codes() {
+        assert_eq!(decode("9G8F+6X"), Err(Error::NotFullCode("9G8F+6X".to_string())));
+        assert_eq!(decode("8FVC9G8F6X"), Err(Error::InvalidCode("8FVC9G8F6X".to_string())));
+        assert_eq!(decode("WFVC9G8F+6X"), Err(Error::InvalidCode("WFVC9G8F+6X".to_string())));
+        assert_eq!(decode(""), Err(Error::InvalidCode(String::new())));
+    } // fn
+} // mod
@@ -0,0 +1,149 @@
+//! Encodes a `LatLng` into a plus code.
+
+use crate::latlng::LatLng;
+use crate::open_location_code::{
+    error::Error, ALPHABET, ENCODING_BASE, FINAL_LAT_PRECISION, FINAL_LNG_PRECISION,
+    GRID_CODE_LENGTH, GRID_COLUMNS, GRID_ROWS, MAX_DIGIT_COUNT, MIN_DIGIT_COUNT, PADDING,
+    PAIR_CODE_LENGTH, SEPARATOR, SEPARATOR_POSITION,
+}; // crate::open_location_code
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a location into a plus code of the given length.
+///
+/// ## Arguments:
+///
+/// * `latlng` ‧ The location to encode.
+///
+/// * `code_length` ‧ The number of digits in the code. A 10 digit code
+/// represents an area of about 14 × 14 meters, and each additional digit
+/// divides that area by 20. Codes longer than 15 digits are truncated to 15.
+///
+/// ## Errors:
+///
+/// * Returns `Error::InvalidCodeLength` if `code_length` is less than 2, or is
+/// an odd number less than 10.
+
+pub fn encode(latlng: &LatLng, code_length: usize) -> Result<String, Error> {
+
+    if code_length < MIN_DIGIT_COUNT
+        || (code_length < PAIR_CODE_LENGTH && code_length % 2 == 1) {
+        return Err(Error::InvalidCodeLength(code_length));
+    } // if
+
+    let code_length = code_length.min(MAX_DIGIT_COUNT);
+
+    // Working in integers avoids the floating-point rounding errors that
+    // would otherwise put some locations in the wrong cell. `Decimal`
+    // multiplication is exact:
+    let lat_max = 180 * FINAL_LAT_PRECISION;
+    let mut lat_value = (latlng.lat * Decimal::from(FINAL_LAT_PRECISION))
+        .floor()
+        .to_i64()
+        .unwrap_or_default()
+        + 90 * FINAL_LAT_PRECISION;
+    lat_value = lat_value.clamp(0, lat_max - 1);
+
+    let mut lng_value = ((latlng.lng * Decimal::from(FINAL_LNG_PRECISION))
+        .floor()
+        .to_i64()
+        .unwrap_or_default()
+        + 180 * FINAL_LNG_PRECISION)
+        .rem_euclid(360 * FINAL_LNG_PRECISION);
+
+    // Digits are produced from least to most significant:
+    let mut reversed: Vec<u8> = Vec::with_capacity(MAX_DIGIT_COUNT + 1);
+
+    if code_length > PAIR_CODE_LENGTH {
+        for _digit in 0..GRID_CODE_LENGTH {
+            let row = lat_value % GRID_ROWS;
+            let column = lng_value % GRID_COLUMNS;
+            reversed.push(alphabet(row * GRID_COLUMNS + column));
+            lat_value /= GRID_ROWS;
+            lng_value /= GRID_COLUMNS;
+        } // for
+    } else {
+        lat_value /= GRID_ROWS.pow(5);
+        lng_value /= GRID_COLUMNS.pow(5);
+    } // if
+
+    for pair in 0..PAIR_CODE_LENGTH / 2 {
+        reversed.push(alphabet(lng_value % ENCODING_BASE));
+        reversed.push(alphabet(lat_value % ENCODING_BASE));
+        lat_value /= ENCODING_BASE;
+        lng_value /= ENCODING_BASE;
+        if pair == 0 {
+            reversed.push(SEPARATOR as u8);
+        } // if
+    } // for
+
+    let code: String = reversed.iter().rev().map(|digit| char::from(*digit)).collect();
+
+    if code_length < SEPARATOR_POSITION {
+        let padding = PADDING.to_string().repeat(SEPARATOR_POSITION - code_length);
+        Ok(format!("{}{padding}{SEPARATOR}", &code[..code_length]))
+    } else {
+        Ok(code[..=code_length].to_string())
+    } // if
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the code character for a digit value from 0 to 19.
+
+fn alphabet(value: i64) -> u8 {
+    usize::try_from(value)
+        .ok()
+        .and_then(|index| ALPHABET.get(index))
+        .copied()
+        .unwrap_or(ALPHABET[0])
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn point(lat: Decimal, lng: Decimal) -> LatLng {
+        LatLng::try_from_dec(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn encodes_specification_examples() {
+        assert_eq!(encode(&point(dec!(47.365590), dec!(8.524997)), 10).unwrap(), "8FVC9G8F+6X");
+        assert_eq!(encode(&point(dec!(20.3700625), dec!(2.7821875)), 10).unwrap(), "7FG49QCJ+2V");
+        assert_eq!(encode(&point(dec!(51.3701125), dec!(-1.217765625)), 11).unwrap(), "9C3W9QCJ+2VX");
+    } // fn
+
+    #[test]
+    fn pads_short_codes() {
+        let location = point(dec!(20.375), dec!(2.775));
+        assert_eq!(encode(&location, 4).unwrap(), "7FG40000+");
+        assert_eq!(encode(&location, 6).unwrap(), "7FG49Q00+");
+        assert_eq!(encode(&location, 2).unwrap(), "7F000000+");
+    } // fn
+
+    #[test]
+    fn clips_and_wraps_coordinates() {
+        // The north pole is encoded as the top row of cells, and 180° is the
+        // same as -180°:
+        assert_eq!(encode(&point(dec!(90), dec!(1)), 4).unwrap(), "CFX30000+");
+        assert_eq!(encode(&point(dec!(0), dec!(180)), 4).unwrap(), encode(&point(dec!(0), dec!(-180)), 4).unwrap());
+    } // fn
+
+    #[test]
+    fn rejects_invalid_lengths() {
+        let location = point(dec!(47.365590), dec!(8.524997));
+        assert_eq!(encode(&location, 0), Err(Error::InvalidCodeLength(0)));
+        assert_eq!(encode(&location, 1), Err(Error::InvalidCodeLength(1)));
+        assert_eq!(encode(&location, 7), Err(Error::InvalidCodeLength(7)));
+        // Odd lengths are only allowed in the grid, and long codes are
+        // truncated to 15 digits:
+        assert_eq!(encode(&location, 11).unwrap().len(), 12);
+        assert_eq!(encode(&location, 20).unwrap(), encode(&location, 15).unwrap());
+    } // fn
+} // mod
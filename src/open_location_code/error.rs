@@ -0,0 +1,64 @@
+//! Open Location Code error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when encoding, decoding, shortening or
+/// recovering a plus code.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// A code can not be shortened if it is padded with zeros.
+    CannotShortenPaddedCode(String),
+    /// A code must be at least 6 digits long to be shortened.
+    CodeTooShortToShorten(String),
+    /// The string is not a valid plus code.
+    InvalidCode(String),
+    /// A code may only be between 2 and 15 digits long, and codes shorter than
+    /// 10 digits must have an even number of digits.
+    InvalidCodeLength(usize),
+    /// The operation requires a full code but was given a short code, such as
+    /// `9G8F+6X` instead of `8FVC9G8F+6X`.
+    NotFullCode(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::CannotShortenPaddedCode(code) => write!(f,
+                "Google Maps Platform API client: \
+                `{code}` is padded with zeros and can not be shortened."),
+            Error::CodeTooShortToShorten(code) => write!(f,
+                "Google Maps Platform API client: \
+                `{code}` is too short to be shortened. \
+                Only codes with at least 6 digits can be shortened."),
+            Error::InvalidCode(code) => write!(f,
+                "Google Maps Platform API client: \
+                `{code}` is not a valid plus code."),
+            Error::InvalidCodeLength(length) => write!(f,
+                "Google Maps Platform API client: \
+                `{length}` is not a valid plus code length. \
+                Codes must be between 2 and 15 digits long, and codes shorter \
+                than 10 digits must have an even number of digits."),
+            Error::NotFullCode(code) => write!(f,
+                "Google Maps Platform API client: \
+                `{code}` is not a full plus code. \
+                Use `recover_nearest` to recover the full code from a nearby location."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    } // fn
+} // impl
@@ -0,0 +1,118 @@
+//! An offline implementation of Google's [Open Location
+//! Code](https://github.com/google/open-location-code/blob/main/Documentation/Specification/specification.md)
+//! specification, better known as [plus codes](https://plus.codes/).
+//!
+//! Plus codes are short codes that can be used in place of a street address
+//! where buildings are not numbered or streets are not named. This module can
+//! encode a `LatLng` into a plus code, decode a plus code into the `Bounds` of
+//! the area it represents, and shorten or recover codes relative to a nearby
+//! reference location, without calling any Google Maps Platform API.
+//!
+//! # Example:
+//!
+//! ```rust
+//! use google_maps::open_location_code;
+//!
+//! let code = open_location_code::encode(&LatLng::try_from_dec(dec!(47.365590), dec!(8.524997))?, 10)?;
+//! assert_eq!(code, "8FVC9G8F+6X");
+//!
+//! // Someone near Zürich could type the short form of that code:
+//! let reference = LatLng::try_from_dec(dec!(47.37), dec!(8.54))?;
+//! let full = open_location_code::recover_nearest("9G8F+6X", &reference)?;
+//! assert_eq!(full, "8FVC9G8F+6X");
+//!
+//! let area = open_location_code::decode(&full)?;
+//! ```
+
+mod decode;
+mod encode;
+pub mod error;
+mod recover_nearest;
+mod shorten;
+mod validate;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::open_location_code::{
+    decode::decode,
+    encode::encode,
+    error::Error,
+    recover_nearest::recover_nearest,
+    shorten::shorten,
+    validate::{is_full, is_short, is_valid},
+}; // crate
+
+// -----------------------------------------------------------------------------
+
+/// The character that separates the first eight digits of a code from the
+/// rest.
+const SEPARATOR: char = '+';
+
+/// The number of digits before the separator in a full code.
+const SEPARATOR_POSITION: usize = 8;
+
+/// The character used to pad codes that are shorter than eight digits.
+const PADDING: char = '0';
+
+/// The 20 characters used to encode digits. Vowels and easily confused
+/// characters are excluded so that codes do not spell words.
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+
+/// The number base of each pair of digits.
+const ENCODING_BASE: i64 = 20;
+
+/// The minimum number of digits in a code.
+const MIN_DIGIT_COUNT: usize = 2;
+
+/// The maximum number of digits in a code. Longer codes are truncated.
+const MAX_DIGIT_COUNT: usize = 15;
+
+/// The number of digits encoded as latitude & longitude pairs. Digits beyond
+/// this are encoded using a 4 × 5 grid.
+const PAIR_CODE_LENGTH: usize = 10;
+
+/// The place value, in units of `PAIR_PRECISION`, of the first pair of
+/// digits.
+const PAIR_FIRST_PLACE_VALUE: i64 = 160_000;
+
+/// The number of `PAIR_PRECISION` units in a degree.
+const PAIR_PRECISION: i64 = 8_000;
+
+/// The number of digits encoded using the grid.
+const GRID_CODE_LENGTH: usize = 5;
+
+/// The number of columns (longitude divisions) in each grid step.
+const GRID_COLUMNS: i64 = 4;
+
+/// The number of rows (latitude divisions) in each grid step.
+const GRID_ROWS: i64 = 5;
+
+/// The place value, in units of `FINAL_LAT_PRECISION`, of the first grid
+/// row.
+const GRID_LAT_FIRST_PLACE_VALUE: i64 = 625;
+
+/// The place value, in units of `FINAL_LNG_PRECISION`, of the first grid
+/// column.
+const GRID_LNG_FIRST_PLACE_VALUE: i64 = 256;
+
+/// The number of units in a degree of latitude at the maximum code length.
+const FINAL_LAT_PRECISION: i64 = 25_000_000;
+
+/// The number of units in a degree of longitude at the maximum code length.
+const FINAL_LNG_PRECISION: i64 = 8_192_000;
+
+/// The minimum length of a code that may be shortened.
+const MIN_TRIMMABLE_CODE_LEN: usize = 6;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the position of a character in the code alphabet, or `None` if it
+/// is not a code digit. Lower case characters are accepted.
+
+fn digit_value(character: char) -> Option<i64> {
+    let upper = character.to_ascii_uppercase();
+    ALPHABET
+        .iter()
+        .position(|digit| char::from(*digit) == upper)
+        .and_then(|position| i64::try_from(position).ok())
+} // fn
@@ -0,0 +1,130 @@
+//! Recovers a full plus code from a short code and a nearby reference
+//! location.
+
+use crate::latlng::LatLng;
+use crate::open_location_code::{
+    decode::decode_area, encode::encode, error::Error, validate::{is_full, is_short},
+    PAIR_CODE_LENGTH, SEPARATOR, SEPARATOR_POSITION,
+}; // crate::open_location_code
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+//
+/// Recovers the full plus code nearest to the reference location from a short
+/// code. For example, `9G8F+6X` near Zürich becomes `8FVC9G8F+6X`.
+///
+/// The short code's missing leading digits are taken from the reference
+/// location. If that puts the code's area more than half a cell away from the
+/// reference, the neighbouring cell is used instead. Full codes are returned
+/// unchanged, in upper case.
+///
+/// ## Errors:
+///
+/// * Returns `Error::InvalidCode` if the string is not a plus code.
+
+pub fn recover_nearest(short_code: &str, reference: &LatLng) -> Result<String, Error> {
+
+    if !is_short(short_code) {
+        return if is_full(short_code) {
+            Ok(short_code.to_uppercase())
+        } else {
+            Err(Error::InvalidCode(short_code.to_string()))
+        }; // return
+    } // if
+
+    let short_code = short_code.to_uppercase();
+    let padding_length = SEPARATOR_POSITION - short_code.find(SEPARATOR).unwrap_or_default();
+
+    // The size, in degrees, of the area that the missing digits represent:
+    let resolution = match padding_length {
+        2 => dec!(20),
+        4 => dec!(1),
+        6 => dec!(0.05),
+        _ => dec!(0.0025),
+    }; // match
+    let half_resolution = resolution / dec!(2);
+
+    let prefix = encode(reference, PAIR_CODE_LENGTH)?;
+    let (area, code_length) = decode_area(&format!("{}{short_code}", &prefix[..padding_length]))?;
+
+    let mut center_lat = ((area.southwest.lat + area.northeast.lat) / dec!(2)).min(dec!(90));
+    let mut center_lng = ((area.southwest.lng + area.northeast.lng) / dec!(2)).min(dec!(180));
+
+    // Move to the neighbouring cell if it is closer to the reference, without
+    // going past the poles:
+    if reference.lat + half_resolution < center_lat && center_lat - resolution >= dec!(-90) {
+        center_lat -= resolution;
+    } else if reference.lat - half_resolution > center_lat && center_lat + resolution <= dec!(90) {
+        center_lat += resolution;
+    } // if
+
+    if reference.lng + half_resolution < center_lng {
+        center_lng -= resolution;
+    } else if reference.lng - half_resolution > center_lng {
+        center_lng += resolution;
+    } // if
+
+    encode(&LatLng { lat: center_lat, lng: wrap_lng(center_lng) }, code_length)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Wraps a longitude into the -180° to +180° range.
+
+fn wrap_lng(lng: Decimal) -> Decimal {
+    if lng > dec!(180) {
+        lng - dec!(360)
+    } else if lng < dec!(-180) {
+        lng + dec!(360)
+    } else {
+        lng
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open_location_code::shorten;
+
+    fn point(lat: Decimal, lng: Decimal) -> LatLng {
+        LatLng::try_from_dec(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn recovers_specification_examples() {
+        assert_eq!(recover_nearest("9G8F+6X", &point(dec!(47.4), dec!(8.6))).unwrap(), "8FVC9G8F+6X");
+        assert_eq!(recover_nearest("+2VX", &point(dec!(51.3701125), dec!(-1.217765625))).unwrap(), "9C3W9QCJ+2VX");
+        assert_eq!(recover_nearest("22+", &point(dec!(42.899), dec!(9.012))).unwrap(), "8FJFW222+");
+        // Full codes are returned unchanged:
+        assert_eq!(recover_nearest("8fvc9g8f+6x", &point(dec!(0), dec!(0))).unwrap(), "8FVC9G8F+6X");
+    } // fn
+
+    #[test]
+    fn recovers_the_nearest_cell_across_boundaries() {
+        // Just across the antimeridian from the code's cell:
+        let code = encode(&point(dec!(10), dec!(179.99)), 10).unwrap();
+        let short = &code[4..];
+        assert_eq!(recover_nearest(short, &point(dec!(10), dec!(-179.99))).unwrap(), code);
+
+        // Shortened codes are always recovered from the same reference:
+        for (lat, lng) in [(dec!(51.5), dec!(-0.12)), (dec!(-33.86), dec!(151.21)), (dec!(89.9), dec!(10))] {
+            let reference = point(lat, lng);
+            let code = encode(&point(lat + dec!(0.003), lng - dec!(0.002)), 10).unwrap();
+            let short = shorten(&code, &reference).unwrap();
+            assert!(short.len() < code.len(), "{code} was not shortened");
+            assert_eq!(recover_nearest(&short, &reference).unwrap(), code);
+        } // for
+    } // fn
+
+    #[test]
+    fn rejects_invalid_codes() {
+        let reference = point(dec!(47.4), dec!(8.6));
+        assert_eq!(recover_nearest("9G8F6X", &reference), Err(Error::InvalidCode("9G8F6X".to_string())));
+        assert_eq!(recover_nearest("9G8F+6", &reference), Err(Error::InvalidCode("9G8F+6".to_string())));
+        assert_eq!(recover_nearest("9G8F+6XA", &reference), Err(Error::InvalidCode("9G8F+6XA".to_string())));
+    } // fn
+} // mod
@@ -0,0 +1,92 @@
+//! Shortens a full plus code relative to a nearby reference location.
+
+use crate::latlng::LatLng;
+use crate::open_location_code::{
+    decode::decode_area, error::Error, MIN_TRIMMABLE_CODE_LEN, PADDING,
+}; // crate::open_location_code
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+//
+/// Removes as many leading digits from a full plus code as possible, so that
+/// it can still be recovered with `recover_nearest` using the reference
+/// location. For example, `8FVC9G8F+6X` near Zürich becomes `9G8F+6X`.
+///
+/// The reference location should be close to the code's area, such as the
+/// center of the city or town that the code will be displayed with. The code
+/// is returned unchanged if the reference is too far away to shorten it.
+///
+/// ## Errors:
+///
+/// * Returns `Error::NotFullCode` or `Error::InvalidCode` if the code is not a
+/// full plus code.
+/// * Returns `Error::CannotShortenPaddedCode` if the code is padded with zeros.
+/// * Returns `Error::CodeTooShortToShorten` if the code has fewer than 6
+/// digits.
+
+pub fn shorten(code: &str, reference: &LatLng) -> Result<String, Error> {
+
+    let (area, code_length) = decode_area(code)?;
+
+    if code.contains(PADDING) {
+        return Err(Error::CannotShortenPaddedCode(code.to_string()));
+    } // if
+
+    let code = code.to_uppercase();
+
+    if code_length < MIN_TRIMMABLE_CODE_LEN {
+        return Err(Error::CodeTooShortToShorten(code));
+    } // if
+
+    let center_lat = ((area.southwest.lat + area.northeast.lat) / dec!(2)).min(dec!(90));
+    let center_lng = ((area.southwest.lng + area.northeast.lng) / dec!(2)).min(dec!(180));
+    let range = (center_lat - reference.lat).abs().max((center_lng - reference.lng).abs());
+
+    // The more digits are removed, the closer the reference must be to
+    // recover them. A safety factor of 0.3 keeps the reference well within
+    // the area that the remaining digits cover:
+    let resolutions: [(Decimal, usize); 3] = [
+        (dec!(0.0025), 8),
+        (dec!(0.05), 6),
+        (dec!(1), 4),
+    ]; // resolutions
+
+    Ok(resolutions
+        .iter()
+        .find(|(resolution, _removed)| range < resolution * dec!(0.3))
+        .map_or_else(|| code.clone(), |(_resolution, removed)| code[*removed..].to_string()))
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: Decimal, lng: Decimal) -> LatLng {
+        LatLng::try_from_dec(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn shortens_specification_examples() {
+        assert_eq!(shorten("9C3W9QCJ+2VX", &point(dec!(51.3701125), dec!(-1.217765625))).unwrap(), "+2VX");
+        assert_eq!(shorten("9C3W9QCJ+2VX", &point(dec!(51.3708675), dec!(-1.217765625))).unwrap(), "CJ+2VX");
+        assert_eq!(shorten("8FJFW222+", &point(dec!(42.899), dec!(9.012))).unwrap(), "22+");
+        assert_eq!(shorten("8fvc9g8f+6x", &point(dec!(47.37), dec!(8.54))).unwrap(), "9G8F+6X");
+    } // fn
+
+    #[test]
+    fn keeps_codes_far_from_the_reference() {
+        assert_eq!(shorten("8FVC9G8F+6X", &point(dec!(-33.86), dec!(151.21))).unwrap(), "8FVC9G8F+6X");
+    } // fn
+
+    #[test]
+    fn rejects_codes_that_can_not_be_shortened() {
+        let reference = point(dec!(20.375), dec!(2.775));
+        assert_eq!(shorten("7FG40000+", &reference), Err(Error::CannotShortenPaddedCode("7FG40000+".to_string())));
+        assert_eq!(shorten("9QCJ+2V", &reference), Err(Error::NotFullCode("9QCJ+2V".to_string())));
+        assert_eq!(shorten("7FG4++", &reference), Err(Error::InvalidCode("7FG4++".to_string())));
+    } // fn
+} // mod
@@ -0,0 +1,135 @@
+//! Checks whether strings are valid, full or short plus codes.
+
+use crate::open_location_code::{
+    digit_value, ENCODING_BASE, MIN_DIGIT_COUNT, PADDING, SEPARATOR, SEPARATOR_POSITION,
+}; // crate::open_location_code
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the string is a valid full or short plus code. Lower case
+/// characters are accepted.
+
+pub fn is_valid(code: &str) -> bool {
+
+    if code.len() < MIN_DIGIT_COUNT {
+        return false;
+    } // if
+
+    // A code must contain exactly one separator, at an even position no
+    // further than the 8th digit:
+    let Some(separator) = code.find(SEPARATOR) else { return false };
+    if code.rfind(SEPARATOR) != Some(separator)
+        || separator > SEPARATOR_POSITION
+        || separator % 2 == 1 {
+        return false;
+    } // if
+
+    // Padding may only appear in full codes, in a single run with an even
+    // length that is not at the start, and must be followed only by the
+    // separator:
+    if let Some(padding_start) = code.find(PADDING) {
+        let padding_length = code[padding_start..]
+            .chars()
+            .take_while(|character| *character == PADDING)
+            .count();
+        if separator < SEPARATOR_POSITION
+            || padding_start == 0
+            || padding_length % 2 == 1
+            || padding_start + padding_length != separator
+            || !code.ends_with(SEPARATOR) {
+            return false;
+        } // if
+    } // if
+
+    // A single digit after the separator is not allowed:
+    if code.len() - separator - 1 == 1 {
+        return false;
+    } // if
+
+    code.chars()
+        .filter(|character| *character != SEPARATOR && *character != PADDING)
+        .all(|character| digit_value(character).is_some())
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the string is a valid short plus code, such as
+/// `9G8F+6X`. A short code has had some of its leading digits removed and
+/// must be recovered relative to a nearby location before it can be decoded.
+
+pub fn is_short(code: &str) -> bool {
+    is_valid(code) && code.find(SEPARATOR).is_some_and(|separator| separator < SEPARATOR_POSITION)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the string is a valid full plus code, such as
+/// `8FVC9G8F+6X`, which may be decoded without a reference location.
+
+pub fn is_full(code: &str) -> bool {
+
+    if !is_valid(code) || is_short(code) {
+        return false;
+    } // if
+
+    // The first latitude digit can not be beyond 90° and the first longitude
+    // digit can not be beyond 180°:
+    let mut digits = code.chars().filter_map(digit_value);
+    let first_lat = digits.next().unwrap_or_default() * ENCODING_BASE;
+    let first_lng = digits.next().unwrap_or_default() * ENCODING_BASE;
+    first_lat < 180 && first_lng < 360
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_full_and_short_codes() {
+        for code in ["8FVC9G8F+6X", "8fvc9g8f+6x", "9C3W9QCJ+2VX", "7FG40000+", "7FG49Q00+", "8FVC9G8F+"] {
+            assert!(is_valid(code) && is_full(code) && !is_short(code), "{code}");
+        } // for
+        for code in ["9G8F+6X", "+2VX", "CJ+2VX", "22+"] {
+            assert!(is_valid(code) && is_short(code) && !is_full(code), "{code}");
+        } // for
+    } // fn
+
+    #[test]
+    fn rejects_invalid_codes() {
+        for code in [
+            "",
+            "8",
+            // No separator, several separators, or one in the wrong place:
+            "8FVC9G8F6X",
+            "8FVC9G8F++6X",
+            "8FVC9G8+F6X",
+            "8FVC9G8F6X+",
+            // A single digit after the separator:
+            "8FVC9G8F+6",
+            // Characters that are not in the alphabet:
+            "8FVC9G8F+6A",
+            "8FVC 9G8F+6X",
+            // Padding that is odd, at the start, or followed by digits:
+            "7FG4000+",
+            "00000000+",
+            "7FG40000+6X",
+            "7F00+",
+        ] {
+            assert!(!is_valid(code) && !is_full(code) && !is_short(code), "{code}");
+        } // for
+    } // fn
+
+    #[test]
+    fn full_codes_must_be_on_the_globe() {
+        // `W` and `X` are beyond 90° of latitude in the first position, and
+        // beyond 180° of longitude in the second:
+        assert!(is_valid("WFVC9G8F+6X"));
+        assert!(!is_full("WFVC9G8F+6X"));
+        assert!(!is_full("8XVC9G8F+6X"));
+        assert!(is_full("CVX3X2X2+X2"));
+    } // fn
+} // mod
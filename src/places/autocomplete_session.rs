@@ -0,0 +1,174 @@
+//! Contains the `AutocompleteSession` struct. It manages the session token
+//! shared by a user's _Place Autocomplete_ requests and the _Place Details_
+//! request that concludes them.
+
+use crate::client::GoogleMapsClient;
+use crate::places::place_autocomplete::request::Request as PlaceAutocompleteRequest;
+use crate::places::place_details::request::Request as PlaceDetailsRequest;
+use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+use uuid::Uuid;
+
+// -----------------------------------------------------------------------------
+//
+/// An [autocomplete
+/// session](https://developers.google.com/maps/documentation/places/web-service/session-tokens)
+/// groups the query and selection phases of a user's autocomplete search into
+/// a discrete session for billing purposes.
+///
+/// The session begins when the user starts typing a query, and concludes when
+/// they select a place and a call to _Place Details_ is made. Each session can
+/// have multiple _Place Autocomplete_ requests, followed by one place
+/// selection. Once a session has concluded, the token is no longer valid.
+///
+/// `AutocompleteSession` mints a fresh version 4 UUID as its session token and
+/// applies it to every _Place Autocomplete_ request it creates. Calling
+/// `place_details` consumes the session, so the token can not be reused by
+/// mistake. A new session must be started for the next search.
+///
+/// ## Example:
+///
+/// ```rust
+/// let session = google_maps_client.autocomplete_session();
+///
+/// // As the user types:
+/// let predictions = session.place_autocomplete("Edmon".to_string())
+///     .execute()
+///     .await?;
+///
+/// // For billing reconciliation:
+/// println!("{} requests with token {}", session.autocomplete_requests(), session.sessiontoken());
+///
+/// // Once the user selects a prediction:
+/// if let Some(place_id) = predictions.predictions[0].place_id.clone() {
+///     let details = session.place_details(place_id).execute().await?;
+/// }
+/// ```
+
+#[derive(Debug)]
+pub struct AutocompleteSession<'a> {
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The version 4 UUID that identifies this session to Google.
+    sessiontoken: String,
+
+    /// The number of _Place Autocomplete_ requests executed with this
+    /// session's token. It is shared with the requests, which count
+    /// themselves when they are executed.
+    autocomplete_requests: Arc<AtomicUsize>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> AutocompleteSession<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Starts a new autocomplete session with a freshly generated session
+    /// token.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    pub fn new(client: &'a GoogleMapsClient) -> AutocompleteSession<'a> {
+        AutocompleteSession {
+            client,
+            sessiontoken: Uuid::new_v4().to_string(),
+            autocomplete_requests: Arc::new(AtomicUsize::new(0)),
+        } // AutocompleteSession
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the session token that is sent with each of this session's
+    /// requests.
+
+    pub fn sessiontoken(&self) -> &str {
+        &self.sessiontoken
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of _Place Autocomplete_ requests that have been
+    /// executed in this session. This may be compared against Google's billing
+    /// reports: a session is billed once, no matter how many autocomplete
+    /// requests it contains, as long as it is concluded with `place_details`.
+    ///
+    /// A request is counted each time its `execute` method is called, whether
+    /// or not it succeeds. Requests that are built but never executed are not
+    /// counted. Retries made by the client within a single `execute` are not
+    /// counted separately.
+
+    pub fn autocomplete_requests(&self) -> usize {
+        self.autocomplete_requests.load(Ordering::Relaxed)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a _Place Autocomplete_ request that carries this session's
+    /// token. This is typically called on every keystroke as the user types.
+    /// The request is counted by `autocomplete_requests` once it is executed.
+    /// The returned request may be further configured with the builder
+    /// pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `input` ‧ The text string on which to search.
+
+    pub fn place_autocomplete(&self, input: String) -> PlaceAutocompleteRequest<'a> {
+        let mut request = PlaceAutocompleteRequest::new(self.client, input);
+        request.sessiontoken = Some(self.sessiontoken.clone());
+        request.session_requests = Some(Arc::clone(&self.autocomplete_requests));
+        request
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Concludes the session with a _Place Details_ request for the place the
+    /// user selected, carrying this session's token. The session is consumed,
+    /// so its token can not be used again.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `place_id` ‧ The place ID of the prediction that the user selected.
+
+    pub fn place_details(self, place_id: String) -> PlaceDetailsRequest<'a> {
+        tracing::debug!(
+            "Concluding autocomplete session `{}` after {} Place Autocomplete request(s)",
+            self.sessiontoken,
+            self.autocomplete_requests(),
+        );
+        let mut request = PlaceDetailsRequest::new(self.client, place_id);
+        request.sessiontoken = Some(self.sessiontoken);
+        request
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_not_counted_when_built() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let session = AutocompleteSession::new(&client);
+
+        let first = session.place_autocomplete("Edmon".to_string());
+        let second = session.place_autocomplete("Edmont".to_string());
+        assert_eq!(session.autocomplete_requests(), 0);
+
+        // Both requests carry the session's token and share its counter:
+        for request in [&first, &second] {
+            assert_eq!(request.sessiontoken.as_deref(), Some(session.sessiontoken()));
+            let session_requests = request.session_requests.as_ref().unwrap();
+            assert!(Arc::ptr_eq(session_requests, &session.autocomplete_requests));
+        } // for
+    } // fn
+} // mod
@@ -0,0 +1,29 @@
+//! Provides GeoJSON export for the `Place` struct, for use with the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::places::place::Place;
+use geojson::{Feature, Geometry};
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Converts this place into a GeoJSON `Point` feature at the place's
+    /// location. The feature's bounding box is the place's recommended
+    /// viewport. If Google did not return the place's `geometry`, the
+    /// feature's geometry is `null`.
+    ///
+    /// Every field of the place other than `geometry` (for example, `name`,
+    /// `place_id` and `rating`) is copied into the feature's properties.
+    pub fn to_geojson(&self) -> Feature {
+        let mut feature = Feature::default();
+        if let Some(geometry) = &self.geometry {
+            feature.geometry = Some(Geometry::from(&geometry.location));
+            feature.bbox = Some(geometry.viewport.to_geojson_bbox());
+        } // if
+        if let Ok(serde_json::Value::Object(mut properties)) = serde_json::to_value(self) {
+            properties.remove("geometry");
+            feature.properties = Some(properties);
+        } // if
+        feature
+    } // fn
+} // impl
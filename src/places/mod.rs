@@ -101,10 +101,20 @@ pub use crate::places::place_autocomplete::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(all(feature = "autocomplete", feature = "places"))]
+pub mod autocomplete_session;
+
+#[cfg(all(feature = "autocomplete", feature = "places"))]
+pub use crate::places::autocomplete_session::AutocompleteSession;
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub mod business_status;
 #[cfg(feature = "places")]
 pub mod error;
+#[cfg(all(feature = "geojson", feature = "places"))]
+mod geojson_conversions;
 #[cfg(feature = "places")]
 pub mod place;
 #[cfg(feature = "places")]
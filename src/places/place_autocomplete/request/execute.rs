@@ -21,6 +21,11 @@ impl<'a> Request<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(&'a mut self) -> Result<Response, Error> {
+        // Let the request's autocomplete session know that it was sent:
+        #[cfg(all(feature = "autocomplete", feature = "places"))]
+        if let Some(session_requests) = &self.session_requests {
+            session_requests.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        } // if
         self.build().get().await
     } // fn
 
@@ -166,7 +166,7 @@ pub struct Request<'a> {
     /// * Be sure to pass a unique session token for each new session. Using the
     /// same token for more than one session will result in each request being
     /// billed individually.
-    sessiontoken: Option<String>,
+    pub(crate) sessiontoken: Option<String>,
 
     /// Returns only those places that are strictly within the region defined by
     /// `location` and `radius`. This is a restriction, rather than a bias,
@@ -189,4 +189,9 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 
+    /// Counts the executions of this request for the `AutocompleteSession`
+    /// that created it, if any.
+    #[cfg(all(feature = "autocomplete", feature = "places"))]
+    pub(crate) session_requests: Option<std::sync::Arc<std::sync::atomic::AtomicUsize>>,
+
 } // struct
\ No newline at end of file
@@ -38,6 +38,8 @@ impl<'a> Request<'a> {
             types: vec![],
             // Internal use only:
             query: None,
+            #[cfg(all(feature = "autocomplete", feature = "places"))]
+            session_requests: None,
         } // struct
 
     } // fn
@@ -157,7 +157,7 @@ pub struct Request<'a> {
     /// * Be sure to pass a unique session token for each new session. Using the
     /// same token for more than one session will result in each request being
     /// billed individually.
-    sessiontoken: Option<String>,
+    pub(crate) sessiontoken: Option<String>,
 
     // Internal use only:
     // ------------------
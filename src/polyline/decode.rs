@@ -0,0 +1,163 @@
+//! Decodes a Google encoded polyline string into a series of `LatLng`
+//! coordinates.
+
+use crate::latlng::LatLng;
+use crate::polyline::{error::Error, precision::Precision};
+use rust_decimal::Decimal;
+
+// -----------------------------------------------------------------------------
+
+/// The largest number of 5-bit chunks a single value may span. Seven chunks
+/// (35 bits) comfortably hold any zig-zag encoded coordinate delta at 6
+/// decimal places of precision.
+const MAX_CHUNKS: u32 = 7;
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a Google [encoded
+/// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+/// string into a `Vec` of `LatLng` coordinates.
+///
+/// ## Arguments:
+///
+/// * `encoded` ‧ The encoded polyline string. For example, the `points` field
+/// of a Directions response's `Polyline`.
+///
+/// * `precision` ‧ The number of decimal places that the coordinates were
+/// encoded with. Google Maps Platform services use `Precision::Five`.
+///
+/// ## Errors:
+///
+/// * `Error::InvalidCharacter` if the string contains a character outside of
+/// the `?` to `~` range.
+/// * `Error::UnterminatedValue` if the string is truncated.
+/// * `Error::ValueOverflow` if a value is too large to be a coordinate.
+/// * `Error::InvalidCoordinate` if a decoded coordinate is out of range.
+
+pub fn decode(encoded: &str, precision: Precision) -> Result<Vec<LatLng>, Error> {
+
+    let mut chars = encoded.char_indices().peekable();
+    let mut points: Vec<LatLng> = Vec::with_capacity(encoded.len() / 4);
+    let mut lat: i64 = 0;
+    let mut lng: i64 = 0;
+
+    while let Some(&(lat_position, _)) = chars.peek() {
+
+        let lat_delta = decode_value(&mut chars, lat_position)?;
+
+        // A latitude must always be followed by a longitude:
+        let lng_position = chars
+            .peek()
+            .map(|(position, _)| *position)
+            .ok_or(Error::UnterminatedValue(encoded.len()))?;
+
+        let lng_delta = decode_value(&mut chars, lng_position)?;
+
+        lat = lat.checked_add(lat_delta).ok_or(Error::ValueOverflow(lat_position))?;
+        lng = lng.checked_add(lng_delta).ok_or(Error::ValueOverflow(lng_position))?;
+
+        let latlng = LatLng::try_from_dec(
+            Decimal::new(lat, precision.scale()),
+            Decimal::new(lng, precision.scale()),
+        ).map_err(|error| Error::InvalidCoordinate(error, lat_position))?;
+
+        points.push(latlng);
+
+    } // while
+
+    Ok(points)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a single zig-zag encoded, variable-length value from the character
+/// stream. `start` is the byte position of the value's first character and is
+/// used for error reporting.
+
+fn decode_value(
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    start: usize,
+) -> Result<i64, Error> {
+
+    let mut result: u64 = 0;
+    let mut chunks: u32 = 0;
+
+    loop {
+
+        let (position, character) = chars.next().ok_or(Error::UnterminatedValue(start))?;
+
+        if !('?'..='~').contains(&character) {
+            return Err(Error::InvalidCharacter(character, position));
+        } // if
+
+        if chunks == MAX_CHUNKS {
+            return Err(Error::ValueOverflow(start));
+        } // if
+
+        // Each character carries 5 bits of the value, offset by 63 to keep the
+        // string printable. The sixth bit flags that another chunk follows:
+        let chunk = u64::from(character) - 63;
+        result |= (chunk & 0x1f) << (chunks * 5);
+        chunks += 1;
+
+        if chunk < 0x20 { break }
+
+    } // loop
+
+    // Undo the zig-zag encoding, which stores the sign in the lowest bit. The
+    // value is at most 35 bits long so the conversion can not fail:
+    let magnitude = i64::try_from(result >> 1).map_err(|_| Error::ValueOverflow(start))?;
+
+    if result & 1 == 1 {
+        Ok(!magnitude)
+    } else {
+        Ok(magnitude)
+    } // if
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_truncated_chunk() {
+        // The reference string without its final character, which leaves the
+        // last longitude with a continuation bit and nothing after it:
+        assert_eq!(
+            decode("_p~iF~ps|U_ulLnnqC_mqNvxq`", Precision::Five),
+            Err(Error::UnterminatedValue(22)),
+        );
+    } // fn
+
+    #[test]
+    fn rejects_latitude_without_longitude() {
+        assert_eq!(decode("_p~iF", Precision::Five), Err(Error::UnterminatedValue(5)));
+    } // fn
+
+    #[test]
+    fn rejects_out_of_range_characters() {
+        assert_eq!(decode("_p~iF ps|U", Precision::Five), Err(Error::InvalidCharacter(' ', 5)));
+        assert_eq!(decode("_p~iF~ps|U>", Precision::Five), Err(Error::InvalidCharacter('>', 10)));
+        assert_eq!(decode("é", Precision::Five), Err(Error::InvalidCharacter('é', 0)));
+    } // fn
+
+    #[test]
+    fn rejects_varint_overflow() {
+        // Eight chunks is more than any coordinate needs:
+        assert_eq!(decode("~~~~~~~~?", Precision::Five), Err(Error::ValueOverflow(0)));
+        assert_eq!(decode("??~~~~~~~~?", Precision::Five), Err(Error::ValueOverflow(2)));
+    } // fn
+
+    #[test]
+    fn rejects_out_of_range_coordinates() {
+        // Seven chunks fit, but the value is far beyond 90°:
+        assert!(matches!(
+            decode("~~~~~~???", Precision::Five),
+            Err(Error::InvalidCoordinate(_, 0)),
+        ));
+    } // fn
+} // mod
@@ -0,0 +1,181 @@
+//! Encodes a series of `LatLng` coordinates into a Google encoded polyline
+//! string.
+
+use crate::latlng::LatLng;
+use crate::polyline::precision::Precision;
+use rust_decimal::{prelude::ToPrimitive, RoundingStrategy};
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a slice of `LatLng` coordinates into a Google [encoded
+/// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+/// string.
+///
+/// Coordinates are rounded to the requested number of decimal places, so
+/// `decode(&encode(points, precision), precision)` returns the original points
+/// rounded to that precision.
+///
+/// ## Arguments:
+///
+/// * `points` ‧ The coordinates to encode, in order.
+///
+/// * `precision` ‧ The number of decimal places to preserve. Google Maps
+/// Platform services expect `Precision::Five`.
+
+pub fn encode(points: &[LatLng], precision: Precision) -> String {
+
+    let mut encoded = String::with_capacity(points.len() * 8);
+    let mut previous_lat: i64 = 0;
+    let mut previous_lng: i64 = 0;
+
+    for point in points {
+        let lat = scale(point.lat, precision);
+        let lng = scale(point.lng, precision);
+        encode_value(lat - previous_lat, &mut encoded);
+        encode_value(lng - previous_lng, &mut encoded);
+        previous_lat = lat;
+        previous_lng = lng;
+    } // for
+
+    encoded
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Multiplies a coordinate by the precision's factor and rounds it to the
+/// nearest integer, rounding half away from zero as Google's reference
+/// implementation does.
+
+fn scale(coordinate: rust_decimal::Decimal, precision: Precision) -> i64 {
+    // `LatLng` coordinates are bounded to ±180° so the scaled value always
+    // fits into an `i64`:
+    (coordinate * precision.factor())
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+        .unwrap_or_default()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends a single value to the encoded string using zig-zag encoding and
+/// 5-bit variable-length chunks.
+
+fn encode_value(value: i64, encoded: &mut String) {
+
+    // Move the sign into the lowest bit so that small negative numbers also
+    // produce short strings:
+    let mut value: u64 = if value < 0 {
+        (value.unsigned_abs() << 1) - 1
+    } else {
+        value.unsigned_abs() << 1
+    }; // if
+
+    while value >= 0x20 {
+        encoded.push(chunk_to_char((value & 0x1f) | 0x20));
+        value >>= 5;
+    } // while
+
+    encoded.push(chunk_to_char(value));
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Offsets a 6-bit chunk by 63 so that it becomes a printable ASCII character.
+
+fn chunk_to_char(chunk: u64) -> char {
+    // Chunks are at most 6 bits long so the result is always in the `?` to `~`
+    // range:
+    char::from(u8::try_from(chunk + 63).unwrap_or(b'?'))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polyline::decode::decode;
+    use rust_decimal::Decimal;
+
+    /// The example from Google's polyline algorithm documentation.
+    const REFERENCE: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+    fn reference_points() -> Vec<LatLng> {
+        [("38.5", "-120.2"), ("40.7", "-120.95"), ("43.252", "-126.453")]
+            .into_iter()
+            .map(|(lat, lng)| LatLng::try_from_dec(lat.parse().unwrap(), lng.parse().unwrap()).unwrap())
+            .collect()
+    } // fn
+
+    /// A small linear congruential generator, so that the round trips are
+    /// random-looking but the same on every run.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            self.0 >> 11
+        } // fn
+
+        /// Returns a coordinate between `-limit` and `limit`, with eight
+        /// decimal places.
+        fn coordinate(&mut self, limit: i64) -> Decimal {
+            let range = u64::try_from(limit * 2 * 100_000_000).unwrap() + 1;
+            let value = i64::try_from(self.next() % range).unwrap() - limit * 100_000_000;
+            Decimal::new(value, 8)
+        } // fn
+    } // impl
+
+    fn round_trip(precision: Precision) {
+        let mut lcg = Lcg(u64::from(precision.scale()));
+        for length in 0..200 {
+            let points: Vec<LatLng> = (0..length % 25)
+                .map(|_| LatLng::try_from_dec(lcg.coordinate(90), lcg.coordinate(180)).unwrap())
+                .collect();
+
+            let decoded = decode(&encode(&points, precision), precision).unwrap();
+            assert_eq!(decoded.len(), points.len());
+
+            for (point, decoded) in points.iter().zip(&decoded) {
+                let round = |coordinate: Decimal| coordinate
+                    .round_dp_with_strategy(precision.scale(), RoundingStrategy::MidpointAwayFromZero);
+                assert_eq!(decoded.lat, round(point.lat), "latitude of {point}");
+                assert_eq!(decoded.lng, round(point.lng), "longitude of {point}");
+            } // for
+        } // for
+    } // fn
+
+    #[test]
+    fn encodes_reference_string() {
+        assert_eq!(encode(&reference_points(), Precision::Five), REFERENCE);
+    } // fn
+
+    #[test]
+    fn decodes_reference_string() {
+        assert_eq!(decode(REFERENCE, Precision::Five).unwrap(), reference_points());
+    } // fn
+
+    #[test]
+    fn reference_round_trips_at_precision_six() {
+        let encoded = encode(&reference_points(), Precision::Six);
+        assert_ne!(encoded, REFERENCE);
+        assert_eq!(decode(&encoded, Precision::Six).unwrap(), reference_points());
+    } // fn
+
+    #[test]
+    fn round_trips_at_precision_five() {
+        round_trip(Precision::Five);
+    } // fn
+
+    #[test]
+    fn round_trips_at_precision_six() {
+        round_trip(Precision::Six);
+    } // fn
+
+    #[test]
+    fn encodes_nothing_as_empty_string() {
+        assert_eq!(encode(&[], Precision::Five), "");
+        assert_eq!(decode("", Precision::Five).unwrap(), Vec::new());
+    } // fn
+} // mod
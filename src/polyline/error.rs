@@ -0,0 +1,75 @@
+//! Encoded polyline error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when decoding or encoding a polyline.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// A path could not be simplified enough to fit within the requested
+    /// number of bytes. The requested budget and the length of the shortest
+    /// possible encoding (its first and last points) are returned.
+    ByteBudgetTooSmall(usize, usize),
+    /// The encoded polyline contained a character that is outside of the
+    /// `?` to `~` range used by the algorithm. The character and its byte
+    /// position in the string are returned.
+    InvalidCharacter(char, usize),
+    /// A decoded coordinate was not a valid latitude or longitude. The
+    /// offending pair and the byte position of its latitude are returned.
+    InvalidCoordinate(crate::error::Error, usize),
+    /// The encoded polyline ended in the middle of a value, or contained a
+    /// latitude without a matching longitude. The byte position where the
+    /// incomplete value starts is returned.
+    UnterminatedValue(usize),
+    /// A value in the encoded polyline was too large to be represented. The
+    /// byte position where the value starts is returned.
+    ValueOverflow(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::ByteBudgetTooSmall(budget, minimum) => write!(f,
+                "Google Maps Platform API client: \
+                The path could not be simplified to fit within {budget} bytes. \
+                Its first and last points alone encode to {minimum} bytes."),
+            Error::InvalidCharacter(character, position) => write!(f,
+                "Google Maps Platform API client: \
+                `{character}` at position {position} is not a valid encoded polyline character. \
+                Encoded polylines may only contain the characters `?` through `~`."),
+            Error::InvalidCoordinate(error, position) => write!(f,
+                "Google Maps Platform API client: \
+                The coordinate decoded at position {position} is invalid. {error}"),
+            Error::UnterminatedValue(position) => write!(f,
+                "Google Maps Platform API client: \
+                The encoded polyline ends in the middle of the value that starts \
+                at position {position}. The polyline may have been truncated."),
+            Error::ValueOverflow(position) => write!(f,
+                "Google Maps Platform API client: \
+                The value that starts at position {position} of the encoded \
+                polyline is too large to be a coordinate."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ByteBudgetTooSmall(_budget, _minimum) => None,
+            Error::InvalidCharacter(_character, _position) => None,
+            Error::InvalidCoordinate(error, _position) => Some(error),
+            Error::UnterminatedValue(_position) => None,
+            Error::ValueOverflow(_position) => None,
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,37 @@
+//! Google's [Encoded Polyline
+//! Algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+//!
+//! Polyline encoding is a lossy compression algorithm that allows you to store
+//! a series of coordinates as a single string. The Google Maps Platform uses
+//! it to describe route geometry in Directions responses, and accepts it for
+//! waypoints and elevation locations.
+//!
+//! Most Google services encode coordinates with a precision of 5 decimal
+//! places. Some other services (such as OSRM and Valhalla) use a precision of
+//! 6 decimal places. Both are supported through the `Precision` enum.
+//!
+//! # Example:
+//!
+//! ```rust
+//! use google_maps::polyline::{decode, encode, Precision};
+//!
+//! let points = decode("_p~iF~ps|U_ulLnnqC_mqNvxq`@", Precision::Five)?;
+//! assert_eq!(points.len(), 3);
+//! assert_eq!(encode(&points, Precision::Five), "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//! ```
+
+mod decode;
+mod encode;
+pub mod error;
+pub mod precision;
+mod simplify;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::polyline::{
+    decode::decode,
+    encode::encode,
+    error::Error,
+    precision::Precision,
+    simplify::{douglas_peucker, simplify_to_fit, visvalingam_whyatt},
+}; // crate
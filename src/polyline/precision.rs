@@ -0,0 +1,65 @@
+//! Contains the `Precision` enum and its associated traits. It is used to
+//! specify the number of decimal places preserved by an encoded polyline.
+
+use rust_decimal::Decimal;
+
+// -----------------------------------------------------------------------------
+//
+/// The number of decimal places that coordinates are rounded to before they
+/// are encoded into a polyline.
+///
+/// Google Maps Platform services always use a precision of 5 decimal places,
+/// or about 1 metre. A precision of 6 decimal places is used by some routing
+/// engines outside of Google such as OSRM and Valhalla.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+pub enum Precision {
+    /// Coordinates are rounded to 5 decimal places. This is the precision
+    /// used by the Google Maps Platform.
+    Five,
+    /// Coordinates are rounded to 6 decimal places.
+    Six,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Precision {
+    /// Returns the number of decimal places preserved by this precision.
+    pub const fn scale(&self) -> u32 {
+        match self {
+            Precision::Five => 5,
+            Precision::Six => 6,
+        } // match
+    } // fn
+
+    /// Returns the factor that coordinates are multiplied by before they are
+    /// rounded to integers. For example, `100000` for `Precision::Five`.
+    pub fn factor(&self) -> Decimal {
+        match self {
+            Precision::Five => Decimal::from(100_000),
+            Precision::Six => Decimal::from(1_000_000),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Precision {
+    /// Returns a reasonable default variant for the `Precision` enum type.
+    fn default() -> Self {
+        Precision::Five
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Precision {
+    /// Formats a `Precision` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Precision::Five => write!(f, "5 decimal places"),
+            Precision::Six => write!(f, "6 decimal places"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,406 @@
+//! Simplifies a path by removing points that contribute little to its shape.
+//! This is useful for shrinking long GPS tracks so that they fit within the
+//! Google Maps Platform's 8192 character URL limit.
+//!
+//! Both algorithms always keep the first and last points. Distances and areas
+//! are measured on a flat projection centered on the path, which is accurate
+//! for paths up to a few hundred kilometers long.
+
+use crate::latlng::{geodesy::EARTH_RADIUS_METERS, LatLng};
+use crate::polyline::{encode::encode, error::Error, precision::Precision};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::f64::consts::{PI, TAU};
+
+// -----------------------------------------------------------------------------
+//
+/// Simplifies a path using the
+/// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm)
+/// algorithm. Every removed point is within `tolerance` meters of the
+/// simplified path.
+///
+/// Douglas-Peucker is a good choice when the simplified path must stay close
+/// to the original, for example when requesting elevations along a track.
+///
+/// ## Arguments:
+///
+/// * `points` ‧ The path to simplify.
+///
+/// * `tolerance` ‧ The maximum distance, in meters, that any removed point
+/// may be from the simplified path.
+
+pub fn douglas_peucker(points: &[LatLng], tolerance: f64) -> Vec<LatLng> {
+
+    if points.len() <= 2 {
+        return points.to_vec();
+    } // if
+
+    let projected = project(points);
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Ranges are processed with an explicit stack rather than recursion so
+    // that very long tracks can not overflow the call stack:
+    let mut ranges = vec![(0, points.len() - 1)];
+
+    while let Some((first, last)) = ranges.pop() {
+        let farthest = (first + 1..last)
+            .map(|index| (index, segment_distance(projected[index], projected[first], projected[last])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((index, distance)) = farthest {
+            if distance > tolerance {
+                keep[index] = true;
+                ranges.push((first, index));
+                ranges.push((index, last));
+            } // if
+        } // if
+    } // while
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_point, keep)| *keep)
+        .map(|(point, _keep)| point.clone())
+        .collect()
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Simplifies a path using the
+/// [Visvalingam–Whyatt](https://en.wikipedia.org/wiki/Visvalingam%E2%80%93Whyatt_algorithm)
+/// algorithm. Points are removed, smallest first, while the triangle each
+/// forms with its neighbours covers less than `minimum_area` square meters.
+///
+/// Visvalingam-Whyatt tends to keep the overall character of a path better
+/// than Douglas-Peucker at high levels of simplification.
+///
+/// ## Arguments:
+///
+/// * `points` ‧ The path to simplify.
+///
+/// * `minimum_area` ‧ Points whose effective area is smaller than this, in
+/// square meters, are removed.
+
+pub fn visvalingam_whyatt(points: &[LatLng], minimum_area: f64) -> Vec<LatLng> {
+    points
+        .iter()
+        .zip(effective_areas(points))
+        .filter(|(_point, area)| *area >= minimum_area)
+        .map(|(point, _area)| point.clone())
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Simplifies a path until its encoded polyline is no longer than `max_bytes`
+/// bytes. Points are removed in Visvalingam-Whyatt order, so the points that
+/// contribute least to the shape of the path are removed first. The path is
+/// returned unchanged if it already fits.
+///
+/// Remember that the `enc:` prefix and any other request parameters also
+/// count towards the URL limit.
+///
+/// ## Arguments:
+///
+/// * `points` ‧ The path to simplify.
+///
+/// * `max_bytes` ‧ The maximum length of the encoded polyline.
+///
+/// * `precision` ‧ The precision that the path will be encoded with.
+///
+/// ## Errors:
+///
+/// * Returns `Error::ByteBudgetTooSmall` if even the first and last points
+/// alone do not fit within `max_bytes`.
+
+pub fn simplify_to_fit(
+    points: &[LatLng],
+    max_bytes: usize,
+    precision: Precision,
+) -> Result<Vec<LatLng>, Error> {
+
+    if encode(points, precision).len() <= max_bytes {
+        return Ok(points.to_vec());
+    } // if
+
+    // Rank every point by its effective area, most significant first. The
+    // first and last points have an infinite area and are always kept:
+    let areas = effective_areas(points);
+    let mut ranking: Vec<usize> = (0..points.len()).collect();
+    ranking.sort_by(|a, b| areas[*b].total_cmp(&areas[*a]));
+
+    let keep_most_significant = |count: usize| -> Vec<LatLng> {
+        let mut indices = ranking[..count].to_vec();
+        indices.sort_unstable();
+        indices.into_iter().map(|index| points[index].clone()).collect()
+    }; // keep_most_significant
+
+    // Binary search for the largest number of points that fits:
+    let minimum = points.len().min(2);
+    let (mut low, mut high) = (minimum, points.len());
+
+    let minimum_bytes = encode(&keep_most_significant(minimum), precision).len();
+    if minimum_bytes > max_bytes {
+        return Err(Error::ByteBudgetTooSmall(max_bytes, minimum_bytes));
+    } // if
+
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+        if encode(&keep_most_significant(middle), precision).len() <= max_bytes {
+            low = middle;
+        } else {
+            high = middle - 1;
+        } // if
+    } // while
+
+    Ok(keep_most_significant(low))
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the Visvalingam-Whyatt effective area, in square meters, of every
+/// point in the path. The first and last points have an infinite area.
+
+fn effective_areas(points: &[LatLng]) -> Vec<f64> {
+
+    let mut areas = vec![f64::INFINITY; points.len()];
+
+    if points.len() <= 2 {
+        return areas;
+    } // if
+
+    let projected = project(points);
+    let mut previous: Vec<usize> = (0..points.len()).map(|index| index.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..points.len()).map(|index| index + 1).collect();
+    let mut heap: BinaryHeap<Reverse<Area>> = BinaryHeap::new();
+
+    for index in 1..points.len() - 1 {
+        let area = triangle_area(projected[index - 1], projected[index], projected[index + 1]);
+        areas[index] = area;
+        heap.push(Reverse(Area(area, index)));
+    } // for
+
+    // Repeatedly remove the point with the smallest triangle. A point's
+    // effective area is never less than that of a point removed before it:
+    let mut largest_removed = 0.0_f64;
+
+    while let Some(Reverse(Area(area, index))) = heap.pop() {
+
+        // Skip entries that were superseded when a neighbour was removed:
+        if area.total_cmp(&areas[index]) != Ordering::Equal || next[index] == 0 {
+            continue;
+        } // if
+
+        largest_removed = largest_removed.max(area);
+        areas[index] = largest_removed;
+        let (before, after) = (previous[index], next[index]);
+        next[before] = after;
+        previous[after] = before;
+        next[index] = 0;
+
+        for neighbour in [before, after] {
+            if neighbour != 0 && neighbour != points.len() - 1 {
+                let area = triangle_area(
+                    projected[previous[neighbour]],
+                    projected[neighbour],
+                    projected[next[neighbour]],
+                ); // triangle_area
+                areas[neighbour] = area;
+                heap.push(Reverse(Area(area, neighbour)));
+            } // if
+        } // for
+
+    } // while
+
+    areas
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// A triangle area and the index of the point at its middle vertex, ordered by
+/// area so that it may be stored in a `BinaryHeap`.
+
+#[derive(Clone, Copy, Debug)]
+struct Area(f64, usize);
+
+impl PartialEq for Area {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    } // fn
+} // impl
+
+impl Eq for Area {}
+
+impl PartialOrd for Area {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    } // fn
+} // impl
+
+impl Ord for Area {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Projects the path onto a flat plane, in meters, centered on its average
+/// latitude. Longitudes are unwrapped so that paths crossing the antimeridian
+/// stay continuous.
+
+fn project(points: &[LatLng]) -> Vec<(f64, f64)> {
+
+    let mean_lat = points.iter().map(LatLng::lat_radians).sum::<f64>() / points.len() as f64;
+    let scale = mean_lat.cos() * EARTH_RADIUS_METERS;
+
+    let mut previous = points.first().map_or(0.0, LatLng::lng_radians);
+    let mut unwrapped = previous;
+
+    points
+        .iter()
+        .map(|point| {
+            let delta = point.lng_radians() - previous;
+            unwrapped += (delta + PI).rem_euclid(TAU) - PI;
+            previous = point.lng_radians();
+            (unwrapped * scale, point.lat_radians() * EARTH_RADIUS_METERS)
+        }) // map
+        .collect()
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the distance from point `p` to the line segment from `a` to `b`.
+
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }; // if
+    (p.0 - a.0 - t * dx).hypot(p.1 - a.1 - t * dy)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the area of the triangle formed by three points.
+
+fn triangle_area(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One thousandth of a degree is about 111 meters.
+    fn point(lat: f64, lng: f64) -> LatLng {
+        LatLng::try_from_f64(lat, lng).unwrap()
+    } // fn
+
+    /// An L-shaped path: east along the equator for about 555 meters, then
+    /// north for another 555 meters. Every other point wiggles about 11
+    /// meters off the line.
+    fn l_shape() -> Vec<LatLng> {
+        (0..=10)
+            .map(|index: u32| {
+                let wiggle = if index % 2 == 1 { 0.0001 } else { 0.0 };
+                let step = f64::from(index) * 0.001;
+                if index <= 5 {
+                    point(wiggle, step)
+                } else {
+                    point(step - 0.005, 0.005 + wiggle)
+                } // if
+            }) // map
+            .collect()
+    } // fn
+
+    /// Points on a straight line along the equator.
+    fn collinear() -> Vec<LatLng> {
+        (0..=10).map(|index| point(0.0, f64::from(index) * 0.001)).collect()
+    } // fn
+
+    #[test]
+    fn douglas_peucker_keeps_endpoints() {
+        let points = l_shape();
+        let simplified = douglas_peucker(&points, 50.0);
+        assert_eq!(simplified.first(), points.first());
+        assert_eq!(simplified.last(), points.last());
+        // Only the corner is far enough from the line to be kept:
+        assert_eq!(simplified, vec![points[0].clone(), points[5].clone(), points[10].clone()]);
+    } // fn
+
+    #[test]
+    fn douglas_peucker_with_no_tolerance_keeps_every_corner() {
+        let points = l_shape();
+        assert_eq!(douglas_peucker(&points, 0.0), points);
+    } // fn
+
+    #[test]
+    fn douglas_peucker_collapses_straight_lines() {
+        let points = collinear();
+        assert_eq!(douglas_peucker(&points, 1.0), vec![points[0].clone(), points[10].clone()]);
+    } // fn
+
+    #[test]
+    fn douglas_peucker_returns_short_paths_unchanged() {
+        let points = l_shape();
+        assert_eq!(douglas_peucker(&[], 10.0), Vec::<LatLng>::new());
+        assert_eq!(douglas_peucker(&points[..1], 10.0), points[..1]);
+        assert_eq!(douglas_peucker(&points[..2], 10.0), points[..2]);
+    } // fn
+
+    #[test]
+    fn visvalingam_whyatt_keeps_endpoints() {
+        let points = l_shape();
+        let simplified = visvalingam_whyatt(&points, 10_000.0);
+        assert_eq!(simplified, vec![points[0].clone(), points[5].clone(), points[10].clone()]);
+        assert_eq!(visvalingam_whyatt(&points, f64::MAX), vec![points[0].clone(), points[10].clone()]);
+    } // fn
+
+    #[test]
+    fn visvalingam_whyatt_with_no_minimum_area_keeps_every_point() {
+        assert_eq!(visvalingam_whyatt(&l_shape(), 0.0), l_shape());
+        assert_eq!(visvalingam_whyatt(&collinear(), 0.0), collinear());
+    } // fn
+
+    #[test]
+    fn visvalingam_whyatt_collapses_straight_lines() {
+        let points = collinear();
+        assert_eq!(visvalingam_whyatt(&points, 1.0), vec![points[0].clone(), points[10].clone()]);
+    } // fn
+
+    #[test]
+    fn visvalingam_whyatt_returns_short_paths_unchanged() {
+        let points = l_shape();
+        assert_eq!(visvalingam_whyatt(&[], 10.0), Vec::<LatLng>::new());
+        assert_eq!(visvalingam_whyatt(&points[..1], 10.0), points[..1]);
+        assert_eq!(visvalingam_whyatt(&points[..2], 10.0), points[..2]);
+    } // fn
+
+    #[test]
+    fn simplify_to_fit_keeps_the_most_significant_points() {
+        let points = l_shape();
+        let full = encode(&points, Precision::Five).len();
+        assert_eq!(simplify_to_fit(&points, full, Precision::Five).unwrap(), points);
+
+        let three = encode(&[points[0].clone(), points[5].clone(), points[10].clone()], Precision::Five).len();
+        let simplified = simplify_to_fit(&points, three, Precision::Five).unwrap();
+        assert_eq!(simplified, vec![points[0].clone(), points[5].clone(), points[10].clone()]);
+
+        let two = encode(&[points[0].clone(), points[10].clone()], Precision::Five).len();
+        assert_eq!(
+            simplify_to_fit(&points, two - 1, Precision::Five),
+            Err(Error::ByteBudgetTooSmall(two - 1, two)),
+        ); // assert_eq!
+    } // fn
+} // mod
@@ -11,6 +11,8 @@ pub use rust_decimal_macros::dec;
 pub use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, offset::TimeZone, Utc};
 #[cfg(any(feature = "directions", feature = "time_zone"))]
 pub use chrono_tz::Tz;
+#[cfg(feature = "chrono")]
+pub use crate::duration::DisplayDuration;
 
 // =============================================================================
 //
@@ -21,10 +23,19 @@ pub use crate::{
     client::GoogleMapsClient,
     client::GoogleMapsClient as ClientSettings,
     country::Country,
+    distance::Distance,
+    distance::display::DisplayDistance,
+    distance::unit::DistanceUnit,
     language::Language,
     latlng::LatLng,
+    latlng::utm::Utm,
+    open_location_code::Error as OpenLocationCodeError,
     place_type::PlaceType,
+    polyline::Error as PolylineError,
+    polyline::Precision as PolylinePrecision,
     region::Region,
+    web_mercator::Error as WebMercatorError,
+    web_mercator::{PixelCoordinate, Tile, TileRange, WorldCoordinate},
 }; // crate
 
 // =============================================================================
@@ -52,11 +63,19 @@ pub use crate::directions::{
     }, // request
     response::{
         driving_maneuver::DrivingManeuver,
+        instruction::Instruction,
+        itinerary::{
+            Itinerary,
+            segment::{Ride, Segment as ItinerarySegment, Transfer, Wait, Walk},
+        }, // itinerary
         leg::Leg,
         overview_polyline::OverviewPolyline,
+        plain_instructions::PlainInstructions,
         polyline::Polyline,
         Response as DirectionsResponse,
         route::Route,
+        route_path::{PathVertex, RoutePath, RoutePosition, RouteRemaining},
+        route_totals::{ModeTotals, RouteCriterion, RouteTotals},
         status::Status as DirectionsStatus,
         step::Step,
         transit_agency::TransitAgency,
@@ -88,12 +107,45 @@ pub use crate::directions::{
 #[cfg(feature = "distance_matrix")]
 pub use crate::distance_matrix::{
     request::Request as DistanceMatrixRequest,
+    response::element_status::ElementStatus,
+    response::matrix::{Cell as MatrixCell, Matrix, Metric as MatrixMetric},
     response::Response as DistanceMatrixResponse,
     response::status::Status as DistanceMatrixStatus,
 }; // use crate::distance_matrix
 
 // -----------------------------------------------------------------------------
 
+#[cfg(all(feature = "distance_matrix", feature = "enable-reqwest"))]
+pub use crate::distance_matrix::large_distance_matrix::{
+    LargeDistanceMatrix,
+    LargeDistanceMatrixResponse,
+    MatrixTile,
+    TileFailure,
+}; // use crate::distance_matrix::large_distance_matrix
+
+#[cfg(all(feature = "distance_matrix", feature = "enable-reqwest"))]
+pub use crate::distance_matrix::isochrone::{
+    error::Error as IsochroneError,
+    Isochrone,
+    IsochroneRay,
+    IsochroneResponse,
+}; // use crate::distance_matrix::isochrone
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(any(feature = "directions", feature = "distance_matrix"), feature = "enable-reqwest"))]
+pub use crate::traffic_profile::{
+    DepartureSweep,
+    error::Error as DepartureSweepError,
+    SweepFailure,
+    SweepRequest,
+    TrafficProfile,
+    TrafficProfileResponse,
+    TrafficSample,
+}; // use crate::traffic_profile
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "elevation")]
 pub use crate::elevation::{
     error::Error as ElevationError,
@@ -110,6 +162,19 @@ pub use crate::elevation::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "embed")]
+pub use crate::embed::{
+    directions::Request as EmbedDirectionsRequest,
+    error::Error as EmbedError,
+    map_type::MapType,
+    place::Request as EmbedPlaceRequest,
+    search::Request as EmbedSearchRequest,
+    street_view::Request as EmbedStreetViewRequest,
+    view::Request as EmbedViewRequest,
+}; // crate::embed
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "geocoding")]
 pub use crate::geocoding::{
     error::Error as GeocodingError,
@@ -131,6 +196,19 @@ pub use crate::geocoding::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "maps_urls")]
+pub use crate::maps_urls::{
+    base_map::BaseMap,
+    directions::Directions as MapsUrlsDirections,
+    display_map::DisplayMap,
+    error::Error as MapsUrlsError,
+    layer::Layer,
+    pano::Pano,
+    search::Search as MapsUrlsSearch,
+}; // crate::maps_urls
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
@@ -141,6 +219,12 @@ pub use crate::time_zone::{
     }, // reponse
 }; // crate::time_zone
 
+#[cfg(all(feature = "time_zone", feature = "enable-reqwest"))]
+pub use crate::time_zone::cache::TimeZoneCache;
+
+#[cfg(all(feature = "time_zone", feature = "directions", feature = "enable-reqwest"))]
+pub use crate::time_zone::route_schedule::{LegSchedule, Schedule, StepSchedule};
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "autocomplete")]
@@ -171,6 +255,11 @@ pub use crate::places::place_autocomplete::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(all(feature = "autocomplete", feature = "places"))]
+pub use crate::places::autocomplete_session::AutocompleteSession;
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub use crate::places::{
     business_status::BusinessStatus,
@@ -207,6 +296,20 @@ pub use crate::places::place_details::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "distance_matrix")]
+pub use crate::route_optimization::{
+    error::Error as RouteOptimizationError,
+    RouteEnd,
+    Solution as RouteSolution,
+    TimeWindow,
+    Tsp,
+    Vehicle,
+    VehicleRoute,
+    Vrp,
+}; // crate::route_optimization
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "roads")]
 pub use crate::roads::{
     error::Error as RoadsError,
@@ -229,4 +332,122 @@ pub use crate::roads::snap_to_roads::{
 pub use crate::roads::nearest_roads::{
     response::Response as NearestRoadsResponse,
     request::Request as NearestRoadsRequest,
-}; // crate::roads::nearest_roads
\ No newline at end of file
+}; // crate::roads::nearest_roads
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "solar")]
+pub use crate::solar::{
+    date::Date as SolarDate,
+    error::Error as SolarError,
+    error_response::ErrorResponse as SolarErrorResponse,
+    imagery_quality::ImageryQuality,
+    status::Status as SolarStatus,
+}; // crate::solar
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "solar")]
+pub use crate::solar::building_insights::{
+    request::Request as BuildingInsightsRequest,
+    response::{
+        financial_analysis::FinancialAnalysis,
+        money::Money,
+        Response as BuildingInsightsResponse,
+        roof_segment_size_and_sunshine_stats::RoofSegmentSizeAndSunshineStats,
+        size_and_sunshine_stats::SizeAndSunshineStats,
+        solar_panel::SolarPanel,
+        solar_panel_config::SolarPanelConfig,
+        solar_panel_orientation::SolarPanelOrientation,
+        solar_potential::SolarPotential,
+    }, // response
+}; // crate::solar::building_insights
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "solar")]
+pub use crate::solar::data_layers::{
+    request::{
+        data_layer_view::DataLayerView,
+        Request as DataLayersRequest,
+    }, // request
+    response::Response as DataLayersResponse,
+}; // crate::solar::data_layers
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "weather")]
+pub use crate::weather::{
+    air_pressure::AirPressure,
+    cardinal_direction::CardinalDirection,
+    date::Date as WeatherDate,
+    display_date_time::DisplayDateTime,
+    error::Error as WeatherError,
+    error_response::ErrorResponse as WeatherErrorResponse,
+    ice_thickness::IceThickness,
+    interval::Interval,
+    localized_text::LocalizedText,
+    precipitation::Precipitation,
+    precipitation_probability::PrecipitationProbability,
+    precipitation_type::PrecipitationType,
+    quantitative_precipitation_forecast::QuantitativePrecipitationForecast,
+    speed_unit::SpeedUnit,
+    status::Status as WeatherStatus,
+    temperature::Temperature,
+    temperature_unit::TemperatureUnit,
+    time_zone::TimeZone as WeatherTimeZone,
+    unit::Unit as WeatherUnit,
+    visibility::Visibility,
+    weather_condition::WeatherCondition,
+    weather_condition_type::WeatherConditionType,
+    wind::Wind,
+    wind_direction::WindDirection,
+    wind_speed::WindSpeed,
+}; // crate::weather
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "weather")]
+pub use crate::weather::current_conditions::{
+    request::Request as CurrentConditionsRequest,
+    response::{
+        current_conditions_history::CurrentConditionsHistory,
+        Response as CurrentConditionsResponse,
+    }, // response
+}; // crate::weather::current_conditions
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "weather")]
+pub use crate::weather::forecast_days::{
+    request::Request as ForecastDaysRequest,
+    response::{
+        forecast_day::ForecastDay,
+        forecast_day_part::ForecastDayPart,
+        moon_events::MoonEvents,
+        moon_phase::MoonPhase,
+        Response as ForecastDaysResponse,
+        sun_events::SunEvents,
+    }, // response
+}; // crate::weather::forecast_days
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "weather")]
+pub use crate::weather::forecast_hours::{
+    request::Request as ForecastHoursRequest,
+    response::{
+        forecast_hour::ForecastHour,
+        Response as ForecastHoursResponse,
+    }, // response
+}; // crate::weather::forecast_hours
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "weather")]
+pub use crate::weather::history_hours::{
+    request::Request as HistoryHoursRequest,
+    response::{
+        history_hour::HistoryHour,
+        Response as HistoryHoursResponse,
+    }, // response
+}; // crate::weather::history_hours
@@ -21,6 +21,8 @@ pub enum Api {
     TimeZone,
     Places,
     Roads,
+    Solar,
+    Weather,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -37,6 +39,8 @@ impl std::convert::From<&Api> for String {
             Api::TimeZone => String::from("Time Zone"),
             Api::Places => String::from("Places"),
             Api::Roads => String::from("Roads"),
+            Api::Solar => String::from("Solar"),
+            Api::Weather => String::from("Weather"),
         } // match
     } // fn
 } // impl
@@ -20,6 +20,16 @@ use crate::request_rate::api_rate::ApiRate;
 use crate::request_rate::api::Api;
 use std::collections::HashMap;
 
+// -----------------------------------------------------------------------------
+//
+/// The number of requests that `LargeDistanceMatrix`, `Isochrone` and
+/// `DepartureSweep` may have in flight at once, unless changed with their
+/// `with_max_concurrent_requests` methods. The client's `RequestRate` still
+/// applies to every request.
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(crate) const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+
 // -----------------------------------------------------------------------------
 //
 /// Contains the request rates for the Google Maps Platform and the individual
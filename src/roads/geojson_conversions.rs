@@ -0,0 +1,57 @@
+//! Provides GeoJSON export for the Roads API's `SnappedPoint` struct and
+//! responses, for use with the [geojson](https://crates.io/crates/geojson)
+//! crate.
+
+use crate::roads::snapped_point::SnappedPoint;
+use crate::roads::{nearest_roads, snap_to_roads};
+use geojson::{Feature, FeatureCollection, Geometry};
+
+// -----------------------------------------------------------------------------
+
+impl SnappedPoint {
+    /// Converts this snapped point into a GeoJSON `Point` feature. The
+    /// feature has `place_id` and `original_index` properties when Google
+    /// provides them.
+    pub fn to_geojson(&self) -> Feature {
+        let mut feature = Feature::from(Geometry::from(&self.location));
+        if let Some(place_id) = &self.place_id {
+            feature.set_property("place_id", place_id.clone());
+        } // if
+        if let Some(original_index) = self.origin_index {
+            feature.set_property("original_index", original_index);
+        } // if
+        feature
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl snap_to_roads::response::Response {
+    /// Converts the snapped points in this response into a GeoJSON feature
+    /// collection of `Point` features, in order along the road.
+    pub fn to_geojson(&self) -> FeatureCollection {
+        to_feature_collection(self.snapped_points.as_deref())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl nearest_roads::response::Response {
+    /// Converts the snapped points in this response into a GeoJSON feature
+    /// collection of `Point` features.
+    pub fn to_geojson(&self) -> FeatureCollection {
+        to_feature_collection(self.snapped_points.as_deref())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a list of snapped points into a GeoJSON feature collection.
+
+fn to_feature_collection(snapped_points: Option<&[SnappedPoint]>) -> FeatureCollection {
+    snapped_points
+        .unwrap_or_default()
+        .iter()
+        .map(SnappedPoint::to_geojson)
+        .collect()
+} // fn
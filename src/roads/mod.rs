@@ -56,6 +56,8 @@
 
 pub mod error;
 pub mod error_response;
+#[cfg(feature = "geojson")]
+mod geojson_conversions;
 pub mod nearest_roads;
 pub mod snap_to_roads;
 pub mod snapped_point;
@@ -4,6 +4,7 @@
 
 // -----------------------------------------------------------------------------
 
+use crate::distance::Distance;
 use crate::roads::error_response::ErrorResponse;
 use crate::roads::snapped_point::SnappedPoint;
 use serde::{Deserialize, Serialize};
@@ -46,4 +47,20 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the length of the snapped path: the sum of the great-circle
+    /// distances between consecutive snapped points. Request with
+    /// `with_interpolation(true)` for a length that follows the road's curves.
+    pub fn distance(&self) -> Distance {
+        self.snapped_points
+            .as_deref()
+            .unwrap_or_default()
+            .windows(2)
+            .map(|pair| Distance::from_meters(pair[0].location.haversine_distance(&pair[1].location)))
+            .sum()
+    } // fn
+} // impl
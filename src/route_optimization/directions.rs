@@ -0,0 +1,211 @@
+//! Turns optimized routes back into Directions API requests, so that each
+//! vehicle can be given turn-by-turn directions.
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::{location::Location, waypoint::Waypoint, Request};
+use crate::route_optimization::{error::Error, VehicleRoute};
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of intermediate waypoints in a Directions API request.
+const MAX_WAYPOINTS: usize = 25;
+
+// -----------------------------------------------------------------------------
+
+impl VehicleRoute {
+    /// Returns the Directions API requests that drive this route, with the
+    /// stops in their optimized order.
+    ///
+    /// A Directions API request may only have 25 waypoints between its origin
+    /// and destination, so longer routes are split into several requests. Each
+    /// request starts where the previous one finished. A route without any
+    /// legs returns no requests.
+    ///
+    /// The requests are returned with their default settings. Set the travel
+    /// mode, departure time and other options on each request before
+    /// executing it. Waypoint optimization should stay off, since it would
+    /// undo the order found here.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `waypoints` ‧ The locations, in the same order as the origins and
+    /// destinations of the distance matrix the route was solved from.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::LocationOutOfRange` if the route visits a location
+    /// that is not in `waypoints`.
+    ///
+    /// * Returns `Error::UnsupportedWaypoint` if a request would start or end
+    /// at a `Waypoint::Polyline`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// for route in &solution.routes {
+    ///     for mut request in route.directions(&google_maps_client, &locations)? {
+    ///         let directions = request
+    ///             .with_travel_mode(TravelMode::Driving)
+    ///             .execute()
+    ///             .await?;
+    ///     }
+    /// }
+    /// ```
+    pub fn directions<'a>(
+        &self,
+        client: &'a GoogleMapsClient,
+        waypoints: &[Waypoint],
+    ) -> Result<Vec<Request<'a>>, Error> {
+
+        let locations = self.locations();
+
+        if let Some(location) = locations.iter().find(|location| **location >= waypoints.len()) {
+            return Err(Error::LocationOutOfRange(*location, waypoints.len()));
+        } // if
+
+        // Consecutive requests share their finishing and starting location, so
+        // each one covers `MAX_WAYPOINTS + 1` new legs:
+        (0..locations.len().saturating_sub(1))
+            .step_by(MAX_WAYPOINTS + 1)
+            .map(|first| {
+                let last = (first + MAX_WAYPOINTS + 1).min(locations.len() - 1);
+                let mut request = Request::new(
+                    client,
+                    location(locations[first], waypoints)?,
+                    location(locations[last], waypoints)?,
+                ); // Request
+                if last - first > 1 {
+                    request.waypoints = Some(
+                        locations[first + 1..last]
+                            .iter()
+                            .map(|location| waypoints[*location].clone())
+                            .collect()
+                    ); // Some
+                } // if
+                Ok(request)
+            }) // map
+            .collect()
+
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a waypoint into a location that can be used as the origin or
+/// destination of a Directions API request.
+
+fn location(index: usize, waypoints: &[Waypoint]) -> Result<Location, Error> {
+    match &waypoints[index] {
+        Waypoint::Address(address) => Ok(Location::Address(address.clone())),
+        Waypoint::LatLng(latlng) => Ok(Location::LatLng(latlng.clone())),
+        Waypoint::PlaceId(place_id) => Ok(Location::PlaceId(place_id.clone())),
+        Waypoint::Polyline(_polyline) => Err(Error::UnsupportedWaypoint(index)),
+        #[cfg(feature = "geo")]
+        Waypoint::Coordinate(coordinate) => Ok(Location::Coordinate(*coordinate)),
+        #[cfg(feature = "geo")]
+        Waypoint::Point(point) => Ok(Location::Point(*point)),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(stops: usize) -> VehicleRoute {
+        VehicleRoute {
+            vehicle: 0,
+            start: 0,
+            stops: (1..=stops).collect(),
+            end: Some(0),
+            cost: 0,
+            load: 0,
+            service_starts: Vec::new(),
+        } // VehicleRoute
+    } // fn
+
+    fn waypoints(count: usize) -> Vec<Waypoint> {
+        (0..count).map(|index| Waypoint::Address(index.to_string())).collect()
+    } // fn
+
+    fn address(index: usize) -> Location {
+        Location::Address(index.to_string())
+    } // fn
+
+    #[test]
+    fn fits_short_routes_in_one_request() {
+        let client = GoogleMapsClient::new("x");
+        let requests = route(25).directions(&client, &waypoints(26)).unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].origin, address(0));
+        assert_eq!(requests[0].destination, address(0));
+        assert_eq!(requests[0].waypoints, Some(waypoints(26)[1..].to_vec()));
+    } // fn
+
+    #[test]
+    fn splits_long_routes_into_groups_of_25_waypoints() {
+        let client = GoogleMapsClient::new("x");
+        // 60 stops plus the return to the start make 61 legs, which is two
+        // requests of 26 legs and one of 9:
+        let requests = route(60).directions(&client, &waypoints(61)).unwrap();
+
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests.iter().map(|request| request.waypoints.as_ref().map_or(0, Vec::len)).collect::<Vec<_>>(),
+            vec![25, 25, 8],
+        );
+
+        // Each request starts where the previous one finished:
+        assert_eq!(requests[0].origin, address(0));
+        assert_eq!(requests[0].destination, address(26));
+        assert_eq!(requests[1].origin, address(26));
+        assert_eq!(requests[1].destination, address(52));
+        assert_eq!(requests[2].origin, address(52));
+        assert_eq!(requests[2].destination, address(0));
+        assert_eq!(requests[1].waypoints.as_ref().unwrap()[0], Waypoint::Address("27".to_string()));
+    } // fn
+
+    #[test]
+    fn sends_single_legs_without_waypoints() {
+        let client = GoogleMapsClient::new("x");
+        let mut single_leg = route(1);
+        single_leg.end = None;
+        let requests = single_leg.directions(&client, &waypoints(2)).unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].destination, address(1));
+        assert_eq!(requests[0].waypoints, None);
+    } // fn
+
+    #[test]
+    fn returns_no_requests_for_routes_without_legs() {
+        let client = GoogleMapsClient::new("x");
+        let mut empty = route(0);
+        empty.end = None;
+        assert!(empty.directions(&client, &waypoints(1)).unwrap().is_empty());
+    } // fn
+
+    #[test]
+    fn rejects_missing_and_unsupported_waypoints() {
+        let client = GoogleMapsClient::new("x");
+        assert_eq!(
+            route(3).directions(&client, &waypoints(3)).err(),
+            Some(Error::LocationOutOfRange(3, 3)),
+        );
+
+        let mut polyline = waypoints(4);
+        polyline[3] = Waypoint::Polyline("??".to_string());
+        // A polyline in the middle of a request is allowed, but not at its end:
+        assert!(route(3).directions(&client, &polyline).is_ok());
+        let mut ends_at_polyline = route(3);
+        ends_at_polyline.end = None;
+        assert_eq!(
+            ends_at_polyline.directions(&client, &polyline).err(),
+            Some(Error::UnsupportedWaypoint(3)),
+        );
+    } // fn
+} // mod
@@ -0,0 +1,78 @@
+//! Route optimization error types and error messages.
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when setting up or solving a route optimization
+/// problem.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// A location's time window closes before it opens.
+    InvalidTimeWindow(usize),
+    /// A list of per-location values, such as demands or time windows, does
+    /// not have one value for each location in the matrix.
+    LengthMismatch(String, usize, usize),
+    /// A location index is not in the matrix.
+    LocationOutOfRange(usize, usize),
+    /// The distance matrix does not have the same locations as its origins and
+    /// destinations.
+    MatrixNotSquare(usize, usize),
+    /// The problem has no vehicles to assign stops to.
+    NoVehicles,
+    /// A location can not be used as the origin or destination of a
+    /// directions request. This happens with `Waypoint::Polyline`.
+    UnsupportedWaypoint(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidTimeWindow(location) => write!(f,
+                "Google Maps Platform API client: \
+                the time window for location {location} closes before it opens."),
+            Error::LengthMismatch(name, length, locations) => write!(f,
+                "Google Maps Platform API client: \
+                {length} {name} were provided for {locations} locations. \
+                Provide one value for each location in the distance matrix."),
+            Error::LocationOutOfRange(location, locations) => write!(f,
+                "Google Maps Platform API client: \
+                location {location} is out of range. \
+                The distance matrix has {locations} locations."),
+            Error::MatrixNotSquare(origins, destinations) => write!(f,
+                "Google Maps Platform API client: \
+                the distance matrix has {origins} origins and {destinations} destinations. \
+                Route optimization needs a matrix with the same list of locations \
+                as both its origins and its destinations."),
+            Error::NoVehicles => write!(f,
+                "Google Maps Platform API client: \
+                at least one vehicle is required to optimize routes."),
+            Error::UnsupportedWaypoint(location) => write!(f,
+                "Google Maps Platform API client: \
+                location {location} is an encoded polyline. \
+                Polylines can not be used as the start or end of a directions request."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidTimeWindow(_location) => None,
+            Error::LengthMismatch(_name, _length, _locations) => None,
+            Error::LocationOutOfRange(_location, _locations) => None,
+            Error::MatrixNotSquare(_origins, _destinations) => None,
+            Error::NoVehicles => None,
+            Error::UnsupportedWaypoint(_location) => None,
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,106 @@
+//! Route optimization over Distance Matrix API results. This solves travelling
+//! salesman problems (`Tsp`) and capacitated vehicle routing problems with
+//! time windows (`Vrp`) locally, without further API calls.
+//!
+//! The Directions API can reorder up to 25 waypoints with
+//! `with_waypoint_optimization`, but only for a single vehicle. This module
+//! has no limit on the number of stops, supports several vehicles, and can
+//! turn each vehicle's route back into Directions API requests.
+//!
+//! The solver builds a starting solution with the nearest-neighbour heuristic
+//! and improves it with 2-opt and Or-opt local search. It is fast and finds
+//! good solutions for problems with up to a few hundred stops, but it does not
+//! guarantee the best possible solution.
+//!
+//! ## Example:
+//!
+//! ```rust
+//! // Location 0 is the depot. The rest are deliveries:
+//! let request = google_maps_client.distance_matrix(locations.clone(), locations.clone());
+//! let matrix = LargeDistanceMatrix::new(request).execute().await?.matrix();
+//!
+//! let vans = vec![
+//!     Vehicle { capacity: Some(40), ..Vehicle::new(0) },
+//!     Vehicle { capacity: Some(40), ..Vehicle::new(0) },
+//! ];
+//!
+//! let solution = Vrp::new(&matrix, vans)
+//!     .with_demands(parcels_per_location)
+//!     .solve()?;
+//!
+//! for route in &solution.routes {
+//!     let requests = route.directions(&google_maps_client, &locations)?;
+//!     // ...
+//! }
+//! ```
+
+#[cfg(feature = "directions")]
+mod directions;
+mod solution;
+mod solver;
+mod time_window;
+mod tsp;
+mod vehicle;
+mod vrp;
+pub mod error;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::route_optimization::{
+    solution::{Solution, VehicleRoute},
+    time_window::TimeWindow,
+    tsp::Tsp,
+    vehicle::{RouteEnd, Vehicle},
+    vrp::Vrp,
+}; // crate::route_optimization
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::distance_matrix::response::{matrix::{Cell, Matrix}, Response};
+
+    /// Builds a distance matrix from a table of travel times in seconds. Each
+    /// route's distance in meters is the same as its travel time, and `None`
+    /// marks a pair of locations with no route.
+    pub(crate) fn matrix(seconds: &[&[Option<i64>]]) -> Matrix<Option<Cell>> {
+        let rows: Vec<String> = seconds
+            .iter()
+            .map(|row| {
+                let elements: Vec<String> = row
+                    .iter()
+                    .map(|value| match value {
+                        Some(value) => format!(
+                            r#"{{"distance":{{"text":"","value":{value}}},"duration":{{"text":"","value":{value}}},"status":"OK"}}"#
+                        ), // format!
+                        None => r#"{"status":"ZERO_RESULTS"}"#.to_string(),
+                    }) // map
+                    .collect();
+                format!(r#"{{"elements":[{}]}}"#, elements.join(","))
+            }) // map
+            .collect();
+
+        let addresses = vec![r#""""#; seconds.len()].join(",");
+        let json = format!(
+            r#"{{"destination_addresses":[{addresses}],"origin_addresses":[{addresses}],"rows":[{}],"status":"OK"}}"#,
+            rows.join(","),
+        ); // format!
+
+        json.parse::<Response>().unwrap().matrix()
+    } // fn
+
+    /// Builds a distance matrix from the Manhattan distances between points on
+    /// a grid.
+    pub(crate) fn grid(points: &[(i64, i64)]) -> Matrix<Option<Cell>> {
+        let rows: Vec<Vec<Option<i64>>> = points
+            .iter()
+            .map(|(x1, y1)| points
+                .iter()
+                .map(|(x2, y2)| Some((x1 - x2).abs() + (y1 - y2).abs()))
+                .collect()
+            ) // map
+            .collect();
+        let rows: Vec<&[Option<i64>]> = rows.iter().map(Vec::as_slice).collect();
+        matrix(&rows)
+    } // fn
+} // mod
@@ -0,0 +1,86 @@
+//! Contains the `Solution` and `VehicleRoute` structs, which hold the routes
+//! found by the solver.
+
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The route that a single vehicle should drive. All locations are indices
+/// into the distance matrix that the problem was built from.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VehicleRoute {
+    /// The index of the vehicle, in the order the vehicles were provided.
+    pub vehicle: usize,
+
+    /// The location the vehicle starts from.
+    pub start: usize,
+
+    /// The stops to visit, in order. This does not include the start and end.
+    pub stops: Vec<usize>,
+
+    /// The location the vehicle finishes at, or `None` if it finishes at its
+    /// last stop.
+    pub end: Option<usize>,
+
+    /// The total cost of the route, in the units of the metric that was
+    /// optimized: meters or seconds.
+    pub cost: i64,
+
+    /// The total demand of the route's stops.
+    pub load: u32,
+
+    /// The time at which the vehicle begins serving each stop, measured from
+    /// its departure. This includes any time spent waiting for a stop's time
+    /// window to open.
+    pub service_starts: Vec<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl VehicleRoute {
+    /// Returns every location the vehicle visits, in order, starting with its
+    /// start location and finishing with its end location if it has one.
+    pub fn locations(&self) -> Vec<usize> {
+        std::iter::once(self.start)
+            .chain(self.stops.iter().copied())
+            .chain(self.end)
+            .collect()
+    } // fn
+
+    /// Returns whether the vehicle has any stops to visit.
+    pub fn is_empty(&self) -> bool {
+        self.stops.is_empty()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The routes found for a routing problem.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Solution {
+    /// One route per vehicle, in the order the vehicles were provided.
+    /// Vehicles that have no route from their start to their end are left out.
+    pub routes: Vec<VehicleRoute>,
+
+    /// The stops that could not be assigned to any vehicle without exceeding
+    /// a capacity, missing a time window, or using a pair of locations that
+    /// has no route.
+    pub unassigned: Vec<usize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Solution {
+    /// Returns the total cost of all routes, in the units of the metric that
+    /// was optimized: meters or seconds.
+    pub fn total_cost(&self) -> i64 {
+        self.routes.iter().map(|route| route.cost).sum()
+    } // fn
+
+    /// Returns whether every stop was assigned to a vehicle.
+    pub fn is_complete(&self) -> bool {
+        self.unassigned.is_empty()
+    } // fn
+} // impl
@@ -0,0 +1,75 @@
+//! Builds a starting solution with the nearest-neighbour heuristic.
+
+use crate::route_optimization::solver::problem::Problem;
+
+// -----------------------------------------------------------------------------
+//
+/// Fills each vehicle in turn by repeatedly driving to the cheapest unvisited
+/// stop that keeps the route feasible. Returns the routes, one per vehicle,
+/// and the stops that no vehicle could take.
+
+pub(crate) fn nearest_neighbour(problem: &Problem) -> (Vec<Vec<usize>>, Vec<usize>) {
+
+    let mut unassigned = problem.stops.clone();
+    let mut routes = vec![Vec::new(); problem.vehicles.len()];
+
+    for (vehicle, route) in routes.iter_mut().enumerate() {
+        loop {
+            let location = route.last().copied().unwrap_or(problem.vehicles[vehicle].start);
+
+            let nearest = unassigned
+                .iter()
+                .enumerate()
+                .filter_map(|(index, stop)| {
+                    let (cost, _travel_time) = problem.leg(location, *stop)?;
+                    let mut candidate = route.clone();
+                    candidate.push(*stop);
+                    problem.evaluate(vehicle, &candidate).map(|_evaluation| (index, cost))
+                }) // filter_map
+                .min_by_key(|(_index, cost)| *cost);
+
+            match nearest {
+                Some((index, _cost)) => route.push(unassigned.remove(index)),
+                None => break,
+            } // match
+        } // loop
+    } // for
+
+    (routes, unassigned)
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distance_matrix::response::matrix::Metric;
+    use crate::route_optimization::{tests::grid, Vehicle};
+
+    #[test]
+    fn visits_the_nearest_stop_next() {
+        let matrix = grid(&[(0, 0), (1, 0), (5, 0), (2, 0)]);
+        let problem = Problem::new(&matrix, Metric::Duration, &[Vehicle::new(0)], None, None, None).unwrap();
+        assert_eq!(nearest_neighbour(&problem), (vec![vec![1, 3, 2]], vec![]));
+    } // fn
+
+    #[test]
+    fn leaves_stops_that_exceed_the_capacity() {
+        let matrix = grid(&[(0, 0), (1, 0), (5, 0), (2, 0)]);
+        let vehicle = Vehicle { capacity: Some(2), ..Vehicle::new(0) };
+        let problem = Problem::new(&matrix, Metric::Duration, &[vehicle], Some(&[0, 1, 1, 1]), None, None).unwrap();
+        assert_eq!(nearest_neighbour(&problem), (vec![vec![1, 3]], vec![2]));
+    } // fn
+
+    #[test]
+    fn fills_each_vehicle_in_turn() {
+        let matrix = grid(&[(0, 0), (1, 0), (5, 0), (2, 0)]);
+        let vehicles = [
+            Vehicle { capacity: Some(1), ..Vehicle::new(0) },
+            Vehicle { capacity: Some(2), ..Vehicle::new(0) },
+        ];
+        let problem = Problem::new(&matrix, Metric::Duration, &vehicles, Some(&[0, 1, 1, 1]), None, None).unwrap();
+        assert_eq!(nearest_neighbour(&problem), (vec![vec![1], vec![3, 2]], vec![]));
+    } // fn
+} // mod
@@ -0,0 +1,271 @@
+//! Improves a solution with local search: inserting unassigned stops, 2-opt
+//! and Or-opt. Every move keeps the routes feasible and lowers the total cost,
+//! so the search always finishes.
+
+use crate::route_optimization::solver::problem::Problem;
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of times the set of moves is repeated. Each round
+/// usually finds fewer improvements than the last, and most solutions settle
+/// within a handful of rounds.
+const MAX_ROUNDS: usize = 100;
+
+/// The longest run of consecutive stops that Or-opt moves at once.
+const MAX_SEGMENT_LENGTH: usize = 3;
+
+// -----------------------------------------------------------------------------
+//
+/// Applies improving moves to the routes until none are left or `MAX_ROUNDS`
+/// is reached.
+
+pub(crate) fn improve(problem: &Problem, routes: &mut [Vec<usize>], unassigned: &mut Vec<usize>) {
+    for _round in 0..MAX_ROUNDS {
+        let mut improved = insert_unassigned(problem, routes, unassigned);
+
+        for (vehicle, route) in routes.iter_mut().enumerate() {
+            improved |= two_opt(problem, vehicle, route);
+        } // for
+
+        improved |= or_opt(problem, routes);
+
+        if !improved {
+            break;
+        } // if
+    } // for
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the cost of a route, or `None` if it is infeasible.
+
+fn cost(problem: &Problem, vehicle: usize, route: &[usize]) -> Option<i64> {
+    problem.evaluate(vehicle, route).map(|evaluation| evaluation.cost)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Inserts each unassigned stop wherever it adds the least cost, if it fits in
+/// any route. Serving a stop is worth more than any cost, so these moves are
+/// made even though they make the solution more expensive.
+
+fn insert_unassigned(problem: &Problem, routes: &mut [Vec<usize>], unassigned: &mut Vec<usize>) -> bool {
+    let mut inserted = false;
+    let mut index = 0;
+
+    while index < unassigned.len() {
+        let stop = unassigned[index];
+
+        let cheapest = routes
+            .iter()
+            .enumerate()
+            .flat_map(|(vehicle, route)| (0..=route.len()).map(move |position| (vehicle, route, position)))
+            .filter_map(|(vehicle, route, position)| {
+                let mut candidate = route.clone();
+                candidate.insert(position, stop);
+                let added_cost = cost(problem, vehicle, &candidate)? - cost(problem, vehicle, route)?;
+                Some((added_cost, vehicle, position))
+            }) // filter_map
+            .min_by_key(|(added_cost, _vehicle, _position)| *added_cost);
+
+        match cheapest {
+            Some((_added_cost, vehicle, position)) => {
+                routes[vehicle].insert(position, unassigned.remove(index));
+                inserted = true;
+            }, // Some
+            None => index += 1,
+        } // match
+    } // while
+
+    inserted
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reverses sections of a route wherever doing so lowers its cost. On a map
+/// this untangles routes that cross over themselves.
+
+fn two_opt(problem: &Problem, vehicle: usize, route: &mut [usize]) -> bool {
+    let Some(mut current_cost) = cost(problem, vehicle, route) else {
+        return false;
+    }; // let
+
+    let mut improved = false;
+
+    for first in 0..route.len() {
+        for last in first + 1..route.len() {
+            route[first..=last].reverse();
+            match cost(problem, vehicle, route) {
+                Some(new_cost) if new_cost < current_cost => {
+                    current_cost = new_cost;
+                    improved = true;
+                }, // Some
+                // Not an improvement, so undo the reversal:
+                _ => route[first..=last].reverse(),
+            } // match
+        } // for
+    } // for
+
+    improved
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Moves runs of up to `MAX_SEGMENT_LENGTH` consecutive stops to wherever they
+/// are cheapest, either elsewhere in the same route or into another vehicle's
+/// route.
+
+fn or_opt(problem: &Problem, routes: &mut [Vec<usize>]) -> bool {
+    let mut improved = false;
+
+    for from in 0..routes.len() {
+        for length in 1..=MAX_SEGMENT_LENGTH {
+            let mut start = 0;
+            while start + length <= routes[from].len() {
+                // After a successful move, different stops occupy `start`, so
+                // they are tried before moving on:
+                if move_segment(problem, routes, from, start, length) {
+                    improved = true;
+                } else {
+                    start += 1;
+                } // if
+            } // while
+        } // for
+    } // for
+
+    improved
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Moves the `length` stops at `start` in route `from` to the position that
+/// lowers the total cost the most. Returns `false`, without changing anything,
+/// if no position is an improvement.
+
+fn move_segment(
+    problem: &Problem,
+    routes: &mut [Vec<usize>],
+    from: usize,
+    start: usize,
+    length: usize,
+) -> bool {
+
+    let Some(old_from_cost) = cost(problem, from, &routes[from]) else {
+        return false;
+    }; // let
+
+    let segment = &routes[from][start..start + length];
+    let mut remaining = routes[from].clone();
+    remaining.drain(start..start + length);
+    let remaining_cost = cost(problem, from, &remaining);
+
+    // The best move found so far, as its change in cost, the route the
+    // segment moves to, and the new version of that route:
+    let mut best: Option<(i64, usize, Vec<usize>)> = None;
+
+    for (to, route) in routes.iter().enumerate() {
+        // Within the same route, the segment is reinserted into what is left
+        // of the route. Otherwise, it is inserted into the other route and the
+        // route it came from shrinks:
+        let (target, old_cost, base_cost) = if to == from {
+            (&remaining, old_from_cost, 0)
+        } else {
+            let (Some(old_to_cost), Some(remaining_cost)) = (cost(problem, to, route), remaining_cost) else {
+                continue;
+            }; // let
+            (route, old_from_cost + old_to_cost, remaining_cost)
+        }; // if
+
+        for position in 0..=target.len() {
+            if to == from && position == start {
+                continue;
+            } // if
+
+            let mut candidate = target.clone();
+            candidate.splice(position..position, segment.iter().copied());
+
+            if let Some(new_cost) = cost(problem, to, &candidate) {
+                let change = base_cost + new_cost - old_cost;
+                if change < 0 && best.as_ref().is_none_or(|(best_change, _to, _route)| change < *best_change) {
+                    best = Some((change, to, candidate));
+                } // if
+            } // if
+        } // for
+    } // for
+
+    match best {
+        Some((_change, to, route)) => {
+            if to != from {
+                routes[from] = remaining;
+            } // if
+            routes[to] = route;
+            true
+        }, // Some
+        None => false,
+    } // match
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distance_matrix::response::matrix::Metric;
+    use crate::route_optimization::{solver::construct, tests::grid, RouteEnd, TimeWindow, Vehicle};
+    use chrono::Duration;
+
+    #[test]
+    fn improves_on_the_nearest_neighbour_route() {
+        // Nearest neighbour goes to 1 first, then has to double back past the
+        // start to reach 2 before driving out to 3:
+        let matrix = grid(&[(0, 0), (1, 0), (-2, 0), (5, 0)]);
+        let vehicle = Vehicle { end: RouteEnd::Anywhere, ..Vehicle::new(0) };
+        let problem = Problem::new(&matrix, Metric::Duration, &[vehicle], None, None, None).unwrap();
+
+        let (mut routes, mut unassigned) = construct::nearest_neighbour(&problem);
+        assert_eq!(routes, vec![vec![1, 2, 3]]);
+        assert_eq!(cost(&problem, 0, &routes[0]), Some(11));
+
+        improve(&problem, &mut routes, &mut unassigned);
+        assert_eq!(routes, vec![vec![2, 1, 3]]);
+        assert_eq!(cost(&problem, 0, &routes[0]), Some(9));
+    } // fn
+
+    #[test]
+    fn inserts_unassigned_stops_that_fit() {
+        let matrix = grid(&[(0, 0), (1, 0), (2, 0), (3, 0)]);
+        let vehicles = [
+            Vehicle { capacity: Some(1), ..Vehicle::new(0) },
+            Vehicle { capacity: Some(1), ..Vehicle::new(0) },
+        ];
+        let problem = Problem::new(&matrix, Metric::Duration, &vehicles, Some(&[0, 1, 1, 1]), None, None).unwrap();
+
+        let mut routes = vec![vec![], vec![]];
+        let mut unassigned = vec![1, 2, 3];
+        improve(&problem, &mut routes, &mut unassigned);
+
+        assert_eq!(routes.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 1]);
+        assert_eq!(unassigned.len(), 1);
+    } // fn
+
+    #[test]
+    fn keeps_routes_feasible() {
+        // Visiting 1 first would be cheaper, but then 2 is reached after its
+        // time window closes:
+        let matrix = grid(&[(0, 0), (1, 0), (-2, 0)]);
+        let problem = Problem::new(
+            &matrix,
+            Metric::Duration,
+            &[Vehicle { end: RouteEnd::Anywhere, ..Vehicle::new(0) }],
+            None,
+            Some(&[None, None, Some(TimeWindow::until(Duration::seconds(2)))]),
+            None,
+        ).unwrap();
+
+        let mut routes = vec![vec![2, 1]];
+        assert_eq!(cost(&problem, 0, &[1, 2]), None);
+        improve(&problem, &mut routes, &mut vec![]);
+        assert_eq!(routes, vec![vec![2, 1]]);
+    } // fn
+} // mod
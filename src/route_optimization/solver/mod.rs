@@ -0,0 +1,49 @@
+//! The heuristic solver shared by `Tsp` and `Vrp`. A starting solution is built
+//! with the nearest-neighbour heuristic and then improved with 2-opt and
+//! Or-opt local search.
+
+mod construct;
+mod improve;
+pub(crate) mod problem;
+
+// -----------------------------------------------------------------------------
+
+use crate::route_optimization::{Solution, VehicleRoute};
+use crate::route_optimization::solver::problem::Problem;
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Solves a routing problem and packages the routes into a `Solution`.
+
+pub(crate) fn solve(problem: &Problem) -> Solution {
+
+    let (mut routes, mut unassigned) = construct::nearest_neighbour(problem);
+    improve::improve(problem, &mut routes, &mut unassigned);
+    unassigned.sort_unstable();
+
+    let routes = routes
+        .into_iter()
+        .enumerate()
+        .filter_map(|(vehicle, stops)| {
+            // Every route is kept feasible by the solver, so this only skips
+            // vehicles that can't even drive from their start to their end:
+            let evaluation = problem.evaluate(vehicle, &stops)?;
+            Some(VehicleRoute {
+                vehicle,
+                start: problem.vehicles[vehicle].start,
+                end: problem.vehicles[vehicle].end_location(),
+                stops,
+                cost: evaluation.cost,
+                load: evaluation.load,
+                service_starts: evaluation.service_starts
+                    .into_iter()
+                    .map(Duration::seconds)
+                    .collect(),
+            }) // VehicleRoute
+        }) // filter_map
+        .collect();
+
+    Solution { routes, unassigned }
+
+} // fn
@@ -0,0 +1,218 @@
+//! Contains the `Problem` struct, which is the solver's flattened copy of a
+//! routing problem, and the function that checks and prices a route.
+
+use crate::distance_matrix::response::matrix::{Cell, Matrix, Metric};
+use crate::route_optimization::{error::Error, TimeWindow, Vehicle};
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// A routing problem in the form the solver works with. Costs and travel
+/// times are stored in row-major order, with `None` for pairs that have no
+/// route. All times are in seconds.
+
+#[derive(Clone, Debug)]
+pub(crate) struct Problem {
+    /// The number of locations.
+    size: usize,
+    /// The cost of travelling between each pair of locations.
+    costs: Vec<Option<i64>>,
+    /// The time it takes to travel between each pair of locations.
+    travel_times: Vec<Option<i64>>,
+    /// The vehicles that stops may be assigned to.
+    pub(crate) vehicles: Vec<Vehicle>,
+    /// How much of a vehicle's capacity each location uses.
+    demands: Vec<u32>,
+    /// When each location may be visited, as `(earliest, latest)`.
+    time_windows: Vec<Option<(i64, i64)>>,
+    /// How long a vehicle spends at each location.
+    service_times: Vec<i64>,
+    /// The locations that must be visited. This is every location that is not
+    /// the start or end of a vehicle.
+    pub(crate) stops: Vec<usize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The outcome of driving a feasible route.
+
+#[derive(Clone, Debug)]
+pub(crate) struct Evaluation {
+    /// The total cost of the route, including the legs to and from the
+    /// vehicle's start and end.
+    pub(crate) cost: i64,
+    /// The total demand of the route's stops.
+    pub(crate) load: u32,
+    /// The time at which the vehicle begins serving each stop.
+    pub(crate) service_starts: Vec<i64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Problem {
+    /// Copies a distance matrix and the problem's settings into a `Problem`,
+    /// checking that they are consistent with each other.
+    pub(crate) fn new(
+        matrix: &Matrix<Option<Cell>>,
+        metric: Metric,
+        vehicles: &[Vehicle],
+        demands: Option<&[u32]>,
+        time_windows: Option<&[Option<TimeWindow>]>,
+        service_times: Option<&[Duration]>,
+    ) -> Result<Problem, Error> {
+
+        let size = matrix.origin_count();
+        if size != matrix.destination_count() {
+            return Err(Error::MatrixNotSquare(size, matrix.destination_count()));
+        } // if
+
+        if vehicles.is_empty() {
+            return Err(Error::NoVehicles);
+        } // if
+
+        for vehicle in vehicles {
+            for location in std::iter::once(vehicle.start).chain(vehicle.end_location()) {
+                if location >= size {
+                    return Err(Error::LocationOutOfRange(location, size));
+                } // if
+            } // for
+        } // for
+
+        let demands = per_location("demands", demands, size, |demand| *demand)?;
+
+        let time_windows = per_location("time windows", time_windows, size, |time_window| {
+            time_window.map(|time_window| (
+                time_window.earliest.num_seconds(),
+                time_window.latest.num_seconds(),
+            )) // map
+        })?; // per_location
+
+        if let Some(location) = time_windows
+            .iter()
+            .position(|time_window| matches!(time_window, Some((earliest, latest)) if earliest > latest)) {
+            return Err(Error::InvalidTimeWindow(location));
+        } // if
+
+        let service_times = per_location("service times", service_times, size, Duration::num_seconds)?;
+
+        // Durations in traffic are only used when they are what's being
+        // optimized. Otherwise, the regular duration is the travel time:
+        let travel_metric = match metric {
+            Metric::DurationInTraffic => Metric::DurationInTraffic,
+            Metric::Distance | Metric::Duration => Metric::Duration,
+        }; // match
+
+        let costs = matrix
+            .iter()
+            .map(|(_origin, _destination, cell)| cell.as_ref().map(|cell| cell.value(metric)))
+            .collect();
+
+        let travel_times = matrix
+            .iter()
+            .map(|(_origin, _destination, cell)| cell.as_ref().map(|cell| cell.value(travel_metric)))
+            .collect();
+
+        let stops = (0..size)
+            .filter(|location| vehicles.iter().all(|vehicle|
+                vehicle.start != *location && vehicle.end_location() != Some(*location)
+            )) // filter
+            .collect();
+
+        Ok(Problem {
+            size,
+            costs,
+            travel_times,
+            vehicles: vehicles.to_vec(),
+            demands,
+            time_windows,
+            service_times,
+            stops,
+        }) // Problem
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+
+    /// Returns the cost and travel time from one location to another, or
+    /// `None` if there is no route between them.
+    pub(crate) fn leg(&self, from: usize, to: usize) -> Option<(i64, i64)> {
+        if from == to {
+            return Some((0, 0));
+        } // if
+
+        let index = from * self.size + to;
+        Some((self.costs[index]?, self.travel_times[index]?))
+    } // fn
+
+    // -------------------------------------------------------------------------
+
+    /// Returns the time at which service may begin at a location for a vehicle
+    /// that arrives at `time`, waiting for the location's time window to open
+    /// if needed. Returns `None` if the time window has already closed.
+    fn begin_service(&self, location: usize, time: i64) -> Option<i64> {
+        match self.time_windows[location] {
+            Some((_earliest, latest)) if time > latest => None,
+            Some((earliest, _latest)) => Some(time.max(earliest)),
+            None => Some(time),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+
+    /// Drives a vehicle through a sequence of stops. Returns `None` if the
+    /// route is infeasible: if it exceeds the vehicle's capacity, misses a
+    /// time window, or uses a pair of locations that has no route.
+    pub(crate) fn evaluate(&self, vehicle: usize, stops: &[usize]) -> Option<Evaluation> {
+
+        let vehicle = &self.vehicles[vehicle];
+
+        let load = stops
+            .iter()
+            .try_fold(0_u32, |load, stop| load.checked_add(self.demands[*stop]))?;
+        if vehicle.capacity.is_some_and(|capacity| load > capacity) {
+            return None;
+        } // if
+
+        let mut cost = 0;
+        let mut time = self.begin_service(vehicle.start, 0)?;
+        let mut location = vehicle.start;
+        let mut service_starts = Vec::with_capacity(stops.len());
+
+        for stop in stops {
+            let (leg_cost, travel_time) = self.leg(location, *stop)?;
+            cost += leg_cost;
+            time = self.begin_service(*stop, time + travel_time)?;
+            service_starts.push(time);
+            time += self.service_times[*stop];
+            location = *stop;
+        } // for
+
+        if let Some(end) = vehicle.end_location() {
+            let (leg_cost, travel_time) = self.leg(location, end)?;
+            cost += leg_cost;
+            self.begin_service(end, time + travel_time)?;
+        } // if
+
+        Some(Evaluation { cost, load, service_starts })
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Converts an optional list of per-location values, checking that it has one
+/// value for each location. A missing list becomes a list of default values.
+
+fn per_location<T, U: Default + Clone>(
+    name: &str,
+    values: Option<&[T]>,
+    size: usize,
+    convert: impl Fn(&T) -> U,
+) -> Result<Vec<U>, Error> {
+    match values {
+        None => Ok(vec![U::default(); size]),
+        Some(values) if values.len() == size => Ok(values.iter().map(convert).collect()),
+        Some(values) => Err(Error::LengthMismatch(name.to_string(), values.len(), size)),
+    } // match
+} // fn
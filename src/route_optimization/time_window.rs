@@ -0,0 +1,36 @@
+//! Contains the `TimeWindow` struct, which limits when a location may be
+//! visited.
+
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The period during which a location may be visited. Times are measured from
+/// the moment the vehicles depart.
+///
+/// A vehicle that arrives before the window opens waits until it does. A
+/// vehicle may not arrive after the window closes.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TimeWindow {
+    /// The earliest time at which the visit may begin.
+    pub earliest: Duration,
+
+    /// The latest time at which the visit may begin.
+    pub latest: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TimeWindow {
+    /// Returns a time window that opens at `earliest` and closes at `latest`.
+    pub fn new(earliest: Duration, latest: Duration) -> TimeWindow {
+        TimeWindow { earliest, latest }
+    } // fn
+
+    /// Returns a time window that opens when the vehicles depart and closes at
+    /// `latest`.
+    pub fn until(latest: Duration) -> TimeWindow {
+        TimeWindow { earliest: Duration::zero(), latest }
+    } // fn
+} // impl
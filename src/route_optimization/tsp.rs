@@ -0,0 +1,244 @@
+//! Contains the `Tsp` builder, which solves travelling salesman problems, with
+//! or without time windows.
+
+use crate::distance_matrix::response::matrix::{Cell, Matrix, Metric};
+use crate::route_optimization::{
+    error::Error,
+    RouteEnd,
+    Solution,
+    TimeWindow,
+    Vehicle,
+    Vrp,
+}; // use crate::route_optimization
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// A travelling salesman problem: find the cheapest order in which a single
+/// vehicle can visit every location in a distance matrix.
+///
+/// By default the tour starts at location 0 and returns there (a closed
+/// tour). Use `with_end` for an open tour or one that finishes elsewhere.
+///
+/// ## Example:
+///
+/// ```rust
+/// let matrix = response.matrix();
+///
+/// let solution = Tsp::new(&matrix)
+///     .with_end(RouteEnd::Anywhere)
+///     .solve()?;
+///
+/// println!("Visit the stops in this order: {:?}", solution.routes[0].stops);
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct Tsp<'m> {
+    /// The travel costs between locations.
+    matrix: &'m Matrix<Option<Cell>>,
+    /// Where the tour starts and finishes.
+    vehicle: Vehicle,
+    /// What to minimize.
+    metric: Metric,
+    /// When each location may be visited.
+    time_windows: Option<Vec<Option<TimeWindow>>>,
+    /// How long is spent at each location.
+    service_times: Option<Vec<Duration>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'m> Tsp<'m> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Starts a travelling salesman problem with a closed tour from location
+    /// 0.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `matrix` ‧ A distance matrix that has the same list of locations as
+    /// both its origins and its destinations.
+
+    pub fn new(matrix: &'m Matrix<Option<Cell>>) -> Tsp<'m> {
+        Tsp {
+            matrix,
+            vehicle: Vehicle::new(0),
+            metric: Metric::default(),
+            time_windows: None,
+            service_times: None,
+        } // Tsp
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the index of the location that the tour starts from.
+
+    pub fn with_start(&mut self, start: usize) -> &mut Tsp<'m> {
+        self.vehicle.start = start;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets where the tour finishes: back at the start (the default), at the
+    /// last stop, or at a specific location.
+
+    pub fn with_end(&mut self, end: RouteEnd) -> &mut Tsp<'m> {
+        self.vehicle.end = end;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets what the solver minimizes. The default is `Metric::Duration`.
+
+    pub fn with_metric(&mut self, metric: Metric) -> &mut Tsp<'m> {
+        self.metric = metric;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets when each location may be visited. See `Vrp::with_time_windows`
+    /// for details.
+
+    pub fn with_time_windows(&mut self, time_windows: Vec<Option<TimeWindow>>) -> &mut Tsp<'m> {
+        self.time_windows = Some(time_windows);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how long is spent at each location. See `Vrp::with_service_times`
+    /// for details.
+
+    pub fn with_service_times(&mut self, service_times: Vec<Duration>) -> &mut Tsp<'m> {
+        self.service_times = Some(service_times);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Solves the problem. The solution has a single route. Locations that
+    /// can't be reached within their time windows are listed in its
+    /// `unassigned` field.
+    ///
+    /// ## Errors:
+    ///
+    /// * See `Vrp::solve`.
+
+    pub fn solve(&self) -> Result<Solution, Error> {
+        let mut vrp = Vrp::new(self.matrix, vec![self.vehicle.clone()]);
+        vrp.with_metric(self.metric);
+
+        if let Some(time_windows) = &self.time_windows {
+            vrp.with_time_windows(time_windows.clone());
+        } // if
+
+        if let Some(service_times) = &self.service_times {
+            vrp.with_service_times(service_times.clone());
+        } // if
+
+        vrp.solve()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route_optimization::tests::{grid, matrix};
+
+    #[test]
+    fn finds_the_optimal_tour() {
+        // The corners of a square, numbered so that visiting them in index
+        // order zig-zags across it:
+        let matrix = grid(&[(0, 0), (4, 4), (0, 4), (4, 0)]);
+        let solution = Tsp::new(&matrix).solve().unwrap();
+
+        assert!(solution.is_complete());
+        assert_eq!(solution.total_cost(), 16);
+        let stops = &solution.routes[0].stops;
+        assert!(*stops == [2, 1, 3] || *stops == [3, 1, 2], "{stops:?}");
+    } // fn
+
+    #[test]
+    fn finds_the_optimal_tour_in_an_asymmetric_matrix() {
+        // Travelling "clockwise" costs 1 and every other leg costs 10:
+        let matrix = matrix(&[
+            &[Some(0), Some(1), Some(10), Some(10)],
+            &[Some(10), Some(0), Some(1), Some(10)],
+            &[Some(10), Some(10), Some(0), Some(1)],
+            &[Some(1), Some(10), Some(10), Some(0)],
+        ]);
+        let solution = Tsp::new(&matrix).solve().unwrap();
+
+        assert_eq!(solution.routes[0].stops, vec![1, 2, 3]);
+        assert_eq!(solution.total_cost(), 4);
+    } // fn
+
+    #[test]
+    fn avoids_missing_routes() {
+        // The cheapest tour would drive from 1 to 2, but there is no route
+        // between them:
+        let matrix = matrix(&[
+            &[Some(0), Some(1), Some(5), Some(9)],
+            &[Some(1), Some(0), None, Some(8)],
+            &[Some(5), Some(1), Some(0), Some(4)],
+            &[Some(9), Some(8), Some(4), Some(0)],
+        ]);
+        let solution = Tsp::new(&matrix).solve().unwrap();
+
+        assert!(solution.is_complete());
+        assert_eq!(solution.routes[0].stops, vec![3, 2, 1]);
+        assert_eq!(solution.total_cost(), 15);
+    } // fn
+
+    #[test]
+    fn finishes_at_the_requested_end() {
+        let matrix = grid(&[(0, 0), (3, 0), (1, 0), (2, 0)]);
+        let solution = Tsp::new(&matrix).with_end(RouteEnd::At(1)).solve().unwrap();
+
+        let route = &solution.routes[0];
+        assert_eq!(route.stops, vec![2, 3]);
+        assert_eq!(route.end, Some(1));
+        assert_eq!(route.locations(), vec![0, 2, 3, 1]);
+        assert_eq!(route.cost, 3);
+    } // fn
+
+    #[test]
+    fn follows_time_windows() {
+        // Without time windows the route would go around the square in either
+        // direction. The window on 3 means it has to be visited first:
+        let matrix = grid(&[(0, 0), (4, 4), (0, 4), (4, 0)]);
+        let solution = Tsp::new(&matrix)
+            .with_time_windows(vec![None, None, None, Some(TimeWindow::until(Duration::seconds(4)))])
+            .with_service_times(vec![Duration::zero(), Duration::seconds(1), Duration::seconds(1), Duration::seconds(1)])
+            .solve()
+            .unwrap();
+
+        let route = &solution.routes[0];
+        assert_eq!(route.stops, vec![3, 1, 2]);
+        assert_eq!(
+            route.service_starts,
+            vec![Duration::seconds(4), Duration::seconds(9), Duration::seconds(14)],
+        );
+    } // fn
+
+    #[test]
+    fn leaves_out_stops_whose_time_window_can_not_be_met() {
+        // Location 2 is 4 seconds away but must be visited within 3:
+        let matrix = grid(&[(0, 0), (1, 0), (0, 4)]);
+        let solution = Tsp::new(&matrix)
+            .with_time_windows(vec![None, None, Some(TimeWindow::until(Duration::seconds(3)))])
+            .solve()
+            .unwrap();
+
+        assert!(!solution.is_complete());
+        assert_eq!(solution.unassigned, vec![2]);
+        assert_eq!(solution.routes[0].stops, vec![1]);
+    } // fn
+} // mod
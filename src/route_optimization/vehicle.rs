@@ -0,0 +1,81 @@
+//! Contains the `Vehicle` struct and the `RouteEnd` enum, which describe where
+//! a vehicle starts and finishes and how much it can carry.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// Where a vehicle's route finishes.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum RouteEnd {
+    /// The vehicle returns to its start location. This is a closed tour.
+    ReturnToStart,
+    /// The vehicle finishes at its last stop. This is an open tour.
+    Anywhere,
+    /// The vehicle finishes at the location with this index, for example a
+    /// second depot.
+    At(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for RouteEnd {
+    /// Returns a reasonable default variant for the `RouteEnd` enum type.
+    fn default() -> Self {
+        RouteEnd::ReturnToStart
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// A vehicle that stops can be assigned to.
+///
+/// ## Example:
+///
+/// ```rust
+/// // A van that leaves the depot at location 0, carries up to 12 parcels, and
+/// // finishes the day at its driver's home at location 1:
+/// let van = Vehicle {
+///     capacity: Some(12),
+///     end: RouteEnd::At(1),
+///     ..Vehicle::new(0)
+/// };
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Vehicle {
+    /// The index of the location the vehicle starts from.
+    pub start: usize,
+
+    /// Where the vehicle's route finishes.
+    pub end: RouteEnd,
+
+    /// The total demand that the vehicle can serve, in the same units as the
+    /// problem's demands. `None` means the vehicle has no capacity limit.
+    pub capacity: Option<u32>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Vehicle {
+    /// Returns a vehicle without a capacity limit that starts and finishes at
+    /// the location with the given index.
+    pub fn new(start: usize) -> Vehicle {
+        Vehicle {
+            start,
+            end: RouteEnd::ReturnToStart,
+            capacity: None,
+        } // Vehicle
+    } // fn
+
+    /// Returns the index of the location where the vehicle finishes, or `None`
+    /// if the vehicle finishes at its last stop.
+    pub fn end_location(&self) -> Option<usize> {
+        match self.end {
+            RouteEnd::ReturnToStart => Some(self.start),
+            RouteEnd::Anywhere => None,
+            RouteEnd::At(end) => Some(end),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,273 @@
+//! Contains the `Vrp` builder, which solves capacitated vehicle routing
+//! problems with time windows.
+
+use crate::distance_matrix::response::matrix::{Cell, Matrix, Metric};
+use crate::route_optimization::{
+    error::Error,
+    solver::{self, problem::Problem},
+    Solution,
+    TimeWindow,
+    Vehicle,
+}; // use crate::route_optimization
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// A vehicle routing problem: share the stops in a distance matrix between
+/// several vehicles so that the total cost is as low as possible.
+///
+/// Every location that is not the start or end of a vehicle is a stop that
+/// should be visited once. The per-location settings (demands, time windows
+/// and service times) are indexed the same way as the matrix.
+
+#[derive(Clone, Debug)]
+pub struct Vrp<'m> {
+    /// The travel costs between locations.
+    matrix: &'m Matrix<Option<Cell>>,
+    /// The vehicles that stops may be assigned to.
+    vehicles: Vec<Vehicle>,
+    /// What to minimize.
+    metric: Metric,
+    /// How much of a vehicle's capacity each location uses.
+    demands: Option<Vec<u32>>,
+    /// When each location may be visited.
+    time_windows: Option<Vec<Option<TimeWindow>>>,
+    /// How long a vehicle spends at each location.
+    service_times: Option<Vec<Duration>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'m> Vrp<'m> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Starts a vehicle routing problem.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `matrix` ‧ A distance matrix that has the same list of locations as
+    /// both its origins and its destinations. Get this from
+    /// `Response::matrix()`, using a `LargeDistanceMatrix` for more than 10
+    /// locations.
+    ///
+    /// * `vehicles` ‧ The vehicles that stops may be assigned to.
+
+    pub fn new(matrix: &'m Matrix<Option<Cell>>, vehicles: Vec<Vehicle>) -> Vrp<'m> {
+        Vrp {
+            matrix,
+            vehicles,
+            metric: Metric::default(),
+            demands: None,
+            time_windows: None,
+            service_times: None,
+        } // Vrp
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets what the solver minimizes. The default is `Metric::Duration`.
+    /// Time windows are always checked against travel times, even when the
+    /// distance is being minimized.
+
+    pub fn with_metric(&mut self, metric: Metric) -> &mut Vrp<'m> {
+        self.metric = metric;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how much of a vehicle's capacity each location uses, for example
+    /// the number of parcels to deliver there. Locations have no demand by
+    /// default.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `demands` ‧ One demand per location in the matrix. The demands of
+    /// start and end locations are ignored.
+
+    pub fn with_demands(&mut self, demands: Vec<u32>) -> &mut Vrp<'m> {
+        self.demands = Some(demands);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets when each location may be visited. Locations may be visited at
+    /// any time by default.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `time_windows` ‧ One optional time window per location in the matrix.
+    /// A time window on a vehicle's start location delays its departure until
+    /// the window opens.
+
+    pub fn with_time_windows(&mut self, time_windows: Vec<Option<TimeWindow>>) -> &mut Vrp<'m> {
+        self.time_windows = Some(time_windows);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how long a vehicle spends at each location, for example to unload.
+    /// Vehicles don't stop at locations by default.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `service_times` ‧ One service time per location in the matrix.
+
+    pub fn with_service_times(&mut self, service_times: Vec<Duration>) -> &mut Vrp<'m> {
+        self.service_times = Some(service_times);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Solves the problem. The solver is a heuristic: the solution is usually
+    /// good, but it is not guaranteed to be the best possible one.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the matrix is not square, if there are no
+    /// vehicles, if a vehicle's start or end is not in the matrix, if a
+    /// per-location setting doesn't have one value per location, or if a time
+    /// window closes before it opens.
+    ///
+    /// * Stops that can't be served are not errors. They are listed in the
+    /// solution's `unassigned` field.
+
+    pub fn solve(&self) -> Result<Solution, Error> {
+        let problem = Problem::new(
+            self.matrix,
+            self.metric,
+            &self.vehicles,
+            self.demands.as_deref(),
+            self.time_windows.as_deref(),
+            self.service_times.as_deref(),
+        )?; // Problem
+        Ok(solver::solve(&problem))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route_optimization::tests::grid;
+
+    /// A depot with two stops to its west and two to its east.
+    fn depot_and_four_stops() -> Matrix<Option<Cell>> {
+        grid(&[(0, 0), (-1, 0), (-2, 0), (1, 0), (2, 0)])
+    } // fn
+
+    fn vans(capacity: u32) -> Vec<Vehicle> {
+        vec![
+            Vehicle { capacity: Some(capacity), ..Vehicle::new(0) },
+            Vehicle { capacity: Some(capacity), ..Vehicle::new(0) },
+        ]
+    } // fn
+
+    #[test]
+    fn splits_stops_between_vehicles_by_capacity() {
+        let matrix = depot_and_four_stops();
+        let solution = Vrp::new(&matrix, vans(2))
+            .with_demands(vec![0, 1, 1, 1, 1])
+            .solve()
+            .unwrap();
+
+        assert!(solution.is_complete());
+        assert_eq!(solution.total_cost(), 8);
+        let mut stops: Vec<Vec<usize>> = solution.routes
+            .iter()
+            .map(|route| {
+                assert_eq!(route.load, 2);
+                let mut stops = route.stops.clone();
+                stops.sort_unstable();
+                stops
+            }) // map
+            .collect();
+        stops.sort();
+        assert_eq!(stops, vec![vec![1, 2], vec![3, 4]]);
+    } // fn
+
+    #[test]
+    fn leaves_out_stops_that_exceed_every_capacity() {
+        let matrix = depot_and_four_stops();
+        let solution = Vrp::new(&matrix, vans(2))
+            .with_demands(vec![0, 1, 3, 1, 1])
+            .solve()
+            .unwrap();
+
+        assert_eq!(solution.unassigned, vec![2]);
+        assert!(solution.routes.iter().all(|route| route.load <= 2));
+        assert_eq!(solution.routes.iter().map(|route| route.stops.len()).sum::<usize>(), 3);
+    } // fn
+
+    #[test]
+    fn leaves_out_stops_when_the_fleet_is_full() {
+        let matrix = depot_and_four_stops();
+        let solution = Vrp::new(&matrix, vans(1))
+            .with_demands(vec![0, 1, 1, 1, 1])
+            .solve()
+            .unwrap();
+
+        // The two nearest stops are served, one by each van:
+        assert_eq!(solution.unassigned, vec![2, 4]);
+    } // fn
+
+    #[test]
+    fn leaves_out_stops_whose_time_window_can_not_be_met() {
+        let matrix = depot_and_four_stops();
+        let solution = Vrp::new(&matrix, vans(4))
+            .with_time_windows(vec![None, None, Some(TimeWindow::until(Duration::seconds(1))), None, None])
+            .solve()
+            .unwrap();
+
+        assert_eq!(solution.unassigned, vec![2]);
+    } // fn
+
+    #[test]
+    fn waits_for_time_windows_to_open() {
+        let matrix = depot_and_four_stops();
+        let solution = Vrp::new(&matrix, vec![Vehicle::new(0)])
+            .with_time_windows(vec![
+                None,
+                Some(TimeWindow::new(Duration::seconds(10), Duration::seconds(20))),
+                None,
+                None,
+                None,
+            ]) // with_time_windows
+            .with_service_times(vec![Duration::seconds(5); 5])
+            .solve()
+            .unwrap();
+
+        let route = &solution.routes[0];
+        let position = route.stops.iter().position(|stop| *stop == 1).unwrap();
+        assert!(route.service_starts[position] >= Duration::seconds(10));
+        assert!(route.service_starts[position] <= Duration::seconds(20));
+        assert!(solution.is_complete());
+    } // fn
+
+    #[test]
+    fn rejects_inconsistent_problems() {
+        let matrix = depot_and_four_stops();
+
+        assert_eq!(Vrp::new(&matrix, vec![]).solve(), Err(Error::NoVehicles));
+        assert_eq!(
+            Vrp::new(&matrix, vec![Vehicle::new(5)]).solve(),
+            Err(Error::LocationOutOfRange(5, 5)),
+        );
+        assert_eq!(
+            Vrp::new(&matrix, vans(1)).with_demands(vec![1, 1]).solve(),
+            Err(Error::LengthMismatch("demands".to_string(), 2, 5)),
+        );
+        assert_eq!(
+            Vrp::new(&matrix, vans(1))
+                .with_time_windows(vec![None, Some(TimeWindow::new(Duration::seconds(2), Duration::seconds(1))), None, None, None])
+                .solve(),
+            Err(Error::InvalidTimeWindow(1)),
+        );
+    } // fn
+} // mod
@@ -0,0 +1,32 @@
+//! The Solar API **Building Insights** service locates the building closest
+//! to a query point, and returns information about its location, dimensions
+//! and solar potential.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#query-parameters)
+//!
+//! * `location` - The longitude and latitude from which the API looks for the
+//! nearest known building.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#query-parameters)
+//!
+//! * `requiredQuality` - The minimum quality level allowed in the results. No
+//! result with lower quality than this will be returned. Not specifying this
+//! is equivalent to restricting to `HIGH` quality only.
+//!
+//! * `exactQualityRequired` - Whether to require exact quality of the imagery.
+//! If set to `false`, the `requiredQuality` field is interpreted as the
+//! minimum required quality, such that `HIGH` quality imagery may be returned
+//! when `requiredQuality` is set to `MEDIUM`. If set to `true`,
+//! `requiredQuality` is interpreted as the exact required quality.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://solar.googleapis.com/v1/buildingInsights:findClosest";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::solar::building_insights::request::Request as BuildingInsightsRequest;
+pub use crate::solar::building_insights::response::Response as BuildingInsightsResponse;
@@ -0,0 +1,46 @@
+use crate::solar::building_insights::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Building Insights request
+    /// based on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &'a mut Request {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&location.latitude={latitude}&location.longitude={longitude}&requiredQuality={quality}",
+            key=self.client.key,
+            latitude=self.location.lat.normalize(),
+            longitude=self.location.lng.normalize(),
+            quality=String::from(&self.required_quality),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Exact quality required key/value pair:
+        if let Some(exact_quality_required) = &self.exact_quality_required {
+            query.push_str("&exactQualityRequired=");
+            query.push_str(&exact_quality_required.to_string())
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,65 @@
+//! **Look in this module for documentation on building your _Building
+//! Insights_ query**. In particular, look at the _Request_ struct for examples
+//! of the builder pattern. This module contains the tools (enums, structs,
+//! methods) for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod with_exact_quality_required;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::solar::imagery_quality::ImageryQuality;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Building Insights_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The longitude and latitude from which the API looks for the nearest
+    /// known building.
+    location: LatLng,
+
+    /// The minimum quality level allowed in the results. No result with lower
+    /// quality than this will be returned.
+    required_quality: ImageryQuality,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Whether to require exact quality of the imagery. If set to `false`,
+    /// the `required_quality` field is interpreted as the minimum required
+    /// quality, such that `High` quality imagery may be returned when
+    /// `required_quality` is set to `Medium`. If set to `true`,
+    /// `required_quality` is interpreted as the exact required quality and
+    /// only `Medium` quality imagery is returned if `required_quality` is set
+    /// to `Medium`.
+    exact_quality_required: Option<bool>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
@@ -0,0 +1,43 @@
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::solar::building_insights::request::Request;
+use crate::solar::imagery_quality::ImageryQuality;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Building Insights query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The longitude and latitude from which the API looks for
+    /// the nearest known building.
+    /// * `required_quality` ‧ The minimum quality level allowed in the
+    /// results. No result with lower quality than this will be returned.
+
+    pub fn new(
+        client: &GoogleMapsClient,
+        location: LatLng,
+        required_quality: ImageryQuality,
+    ) -> Request {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            location,
+            required_quality,
+            // Optional parameters:
+            exact_quality_required: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
@@ -0,0 +1,58 @@
+use crate::solar::building_insights::{SERVICE_URL, request::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// Returns the query string that will be sent to the Google Maps API. It
+    /// is the result of the builder pattern. This method could be useful for
+    /// records or logging. It could also be used for passing to your HTTP
+    /// client of choice and executing the HTTP GET request yourself.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> String {
+
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.build().query.as_ref().unwrap(),
+        }; // match
+
+        format!("{SERVICE_URL}?{query_string}")
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use crate::latlng::LatLng;
+    use crate::solar::imagery_quality::ImageryQuality;
+
+    #[test]
+    fn query_url_has_the_location_and_quality() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let location = LatLng::try_from_f64(37.4450, -122.1390).unwrap();
+        let mut request = client.building_insights(location, ImageryQuality::Medium);
+        assert_eq!(
+            request.with_exact_quality_required(true).query_url(),
+            "https://solar.googleapis.com/v1/buildingInsights:findClosest\
+            ?key=YOUR_GOOGLE_API_KEY_HERE\
+            &location.latitude=37.445&location.longitude=-122.139\
+            &requiredQuality=MEDIUM&exactQualityRequired=true"
+        ); // assert_eq!
+    } // fn
+} // mod
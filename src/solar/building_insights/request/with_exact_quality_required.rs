@@ -0,0 +1,39 @@
+use crate::solar::building_insights::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Whether to require the exact quality of the imagery.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `exact_quality_required` ‧ When `false` (the default), the
+    /// `required_quality` is interpreted as the minimum required quality, such
+    /// that `High` quality imagery may be returned when `required_quality` is
+    /// set to `Medium`. When `true`, only imagery of exactly the
+    /// `required_quality` will be returned.
+    ///
+    /// ## Example:
+    ///
+    /// * Only accept imagery of exactly the requested quality:
+    /// ```rust
+    /// .with_exact_quality_required(true)
+    /// ```
+
+    pub fn with_exact_quality_required(
+        &'a mut self,
+        exact_quality_required: bool
+    ) -> &'a mut Request {
+
+        // Set exact quality required in Request struct.
+        self.exact_quality_required = Some(exact_quality_required);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,41 @@
+//! Contains the `CashPurchaseSavings` struct. It describes the cost and
+//! benefit of an outright purchase of the solar panels.
+
+use crate::solar::building_insights::response::{money::Money, savings_over_time::SavingsOverTime};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Cost and benefit of an outright purchase of a particular configuration of
+/// solar panels with a particular electricity usage. See
+/// [CashPurchaseSavings](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#CashPurchaseSavings)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CashPurchaseSavings {
+
+    /// Initial cost before tax incentives: the amount that must be paid
+    /// out-of-pocket. Contrast with `upfront_cost`, which is after tax
+    /// incentives.
+    #[serde(alias = "outOfPocketCost")]
+    pub out_of_pocket_cost: Option<Money>,
+
+    /// Initial cost after tax incentives: it's the amount that must be paid
+    /// during first year. Contrast with `out_of_pocket_cost`, which is before
+    /// tax incentives.
+    #[serde(alias = "upfrontCost")]
+    pub upfront_cost: Option<Money>,
+
+    /// The value of all tax rebates.
+    #[serde(alias = "rebateValue")]
+    pub rebate_value: Option<Money>,
+
+    /// Number of years until payback occurs. A negative value means payback
+    /// never occurs within the lifetime period.
+    #[serde(alias = "paybackYears")]
+    pub payback_years: Option<f64>,
+
+    /// How much is saved (or not) over the lifetime period.
+    pub savings: Option<SavingsOverTime>,
+
+} // struct
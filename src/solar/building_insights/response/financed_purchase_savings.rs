@@ -0,0 +1,33 @@
+//! Contains the `FinancedPurchaseSavings` struct. It describes the cost and
+//! benefit of using a loan to buy the solar panels.
+
+use crate::solar::building_insights::response::{money::Money, savings_over_time::SavingsOverTime};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Cost and benefit of using a loan to buy a particular configuration of solar
+/// panels with a particular electricity usage. See
+/// [FinancedPurchaseSavings](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#FinancedPurchaseSavings)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FinancedPurchaseSavings {
+
+    /// Annual loan payments.
+    #[serde(alias = "annualLoanPayment")]
+    pub annual_loan_payment: Option<Money>,
+
+    /// The value of all tax rebates (including Federal Investment Tax Credit
+    /// (ITC)).
+    #[serde(alias = "rebateValue")]
+    pub rebate_value: Option<Money>,
+
+    /// The interest rate on loans assumed in this set of calculations.
+    #[serde(alias = "loanInterestRate")]
+    pub loan_interest_rate: Option<f64>,
+
+    /// How much is saved (or not) over the lifetime period.
+    pub savings: Option<SavingsOverTime>,
+
+} // struct
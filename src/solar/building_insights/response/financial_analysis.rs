@@ -0,0 +1,61 @@
+//! Contains the `FinancialAnalysis` struct. It describes the savings of the
+//! optimal panel configuration for one hypothetical monthly electricity bill.
+
+use crate::solar::building_insights::response::{
+    cash_purchase_savings::CashPurchaseSavings,
+    financed_purchase_savings::FinancedPurchaseSavings,
+    financial_details::FinancialDetails,
+    leasing_savings::LeasingSavings,
+    money::Money,
+}; // crate::solar::building_insights::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Analysis of the cost and benefits of the optimum solar layout for a
+/// particular electric bill size. See
+/// [FinancialAnalysis](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#FinancialAnalysis)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FinancialAnalysis {
+
+    /// The monthly electric bill this analysis assumes.
+    #[serde(alias = "monthlyBill")]
+    pub monthly_bill: Option<Money>,
+
+    /// Whether this is the bill size selected to be the default bill for the
+    /// area this building is in. Exactly one `FinancialAnalysis` in
+    /// `SolarPotential` should have `default_bill` set.
+    #[serde(alias = "defaultBill")]
+    pub default_bill: Option<bool>,
+
+    /// How much electricity the house uses in an average month, based on the
+    /// bill size and the local electricity rates.
+    #[serde(alias = "averageKwhPerMonth")]
+    pub average_kwh_per_month: Option<f64>,
+
+    /// Index in `solar_panel_configs` of the optimum solar layout for this
+    /// bill size. This can be `-1` indicating that there is no layout. In this
+    /// case, the remaining submessages will be omitted.
+    #[serde(alias = "panelConfigIndex")]
+    pub panel_config_index: Option<i32>,
+
+    /// Financial information that applies regardless of the financing method
+    /// used.
+    #[serde(alias = "financialDetails")]
+    pub financial_details: Option<FinancialDetails>,
+
+    /// Cost and benefit of leasing the solar panels.
+    #[serde(alias = "leasingSavings")]
+    pub leasing_savings: Option<LeasingSavings>,
+
+    /// Cost and benefit of buying the solar panels with cash.
+    #[serde(alias = "cashPurchaseSavings")]
+    pub cash_purchase_savings: Option<CashPurchaseSavings>,
+
+    /// Cost and benefit of buying the solar panels by financing the purchase.
+    #[serde(alias = "financedPurchaseSavings")]
+    pub financed_purchase_savings: Option<FinancedPurchaseSavings>,
+
+} // struct
@@ -0,0 +1,70 @@
+//! Contains the `FinancialDetails` struct. It describes the financial
+//! quantities that don't depend on the method of financing.
+
+use crate::solar::building_insights::response::money::Money;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Details of a financial analysis. Some of these details are already stored
+/// at higher levels (e.g., out of pocket cost). Total money amounts are over a
+/// lifetime period defined by the `panel_lifetime_years` field in
+/// `SolarPotential`. See
+/// [FinancialDetails](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#FinancialDetails)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FinancialDetails {
+
+    /// How many AC kWh we think the solar panels will generate in their first
+    /// year.
+    #[serde(alias = "initialAcKwhPerYear")]
+    pub initial_ac_kwh_per_year: Option<f64>,
+
+    /// Utility bill for electricity not produced by solar, for the lifetime of
+    /// the panels.
+    #[serde(alias = "remainingLifetimeUtilityBill")]
+    pub remaining_lifetime_utility_bill: Option<Money>,
+
+    /// Amount of money available from federal incentives; this applies if the
+    /// user buys (with or without a loan) the panels.
+    #[serde(alias = "federalIncentive")]
+    pub federal_incentive: Option<Money>,
+
+    /// Amount of money available from state incentives; this applies if the
+    /// user buys (with or without a loan) the panels.
+    #[serde(alias = "stateIncentive")]
+    pub state_incentive: Option<Money>,
+
+    /// Amount of money available from utility incentives; this applies if the
+    /// user buys (with or without a loan) the panels.
+    #[serde(alias = "utilityIncentive")]
+    pub utility_incentive: Option<Money>,
+
+    /// Amount of money the user will receive from Solar Renewable Energy
+    /// Credits over the panel lifetime; this applies if the user buys (with or
+    /// without a loan) the panels.
+    #[serde(alias = "lifetimeSrecTotal")]
+    pub lifetime_srec_total: Option<Money>,
+
+    /// The total cost of electricity the user would have paid over the
+    /// lifetime period if they didn't install solar.
+    #[serde(alias = "costOfElectricityWithoutSolar")]
+    pub cost_of_electricity_without_solar: Option<Money>,
+
+    /// Whether net metering is allowed.
+    #[serde(alias = "netMeteringAllowed")]
+    pub net_metering_allowed: Option<bool>,
+
+    /// Percentage (0-100) of the user's power supplied by solar. Valid for the
+    /// first year but approximately correct for future years.
+    #[serde(alias = "solarPercentage")]
+    pub solar_percentage: Option<f64>,
+
+    /// The percentage (0-100) of solar electricity production we assumed was
+    /// exported to the grid, based on the first quarter of production. This
+    /// affects the calculations if net metering is not allowed.
+    #[serde(alias = "percentageExportedToGrid")]
+    pub percentage_exported_to_grid: Option<f64>,
+
+} // struct
@@ -0,0 +1,37 @@
+//! Contains the `LeasingSavings` struct. It describes the cost and benefit of
+//! leasing the solar panels.
+
+use crate::solar::building_insights::response::{money::Money, savings_over_time::SavingsOverTime};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Cost and benefit of leasing the solar panels. See
+/// [LeasingSavings](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#LeasingSavings)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct LeasingSavings {
+
+    /// Whether leases are allowed in this jurisdiction (leases are not allowed
+    /// in some states). If this field is `false`, then the values in this
+    /// message should probably be ignored.
+    #[serde(alias = "leasesAllowed")]
+    pub leases_allowed: Option<bool>,
+
+    /// Whether leases are supported in this jurisdiction by the financial
+    /// calculation engine. If this field is `false`, then the values in this
+    /// message should probably be ignored. This is independent of
+    /// `leases_allowed`: in some areas leases are allowed, but under
+    /// conditions that aren't handled by the financial models.
+    #[serde(alias = "leasesSupported")]
+    pub leases_supported: Option<bool>,
+
+    /// Estimated annual leasing cost.
+    #[serde(alias = "annualLeasingCost")]
+    pub annual_leasing_cost: Option<Money>,
+
+    /// How much is saved (or not) over the lifetime period.
+    pub savings: Option<SavingsOverTime>,
+
+} // struct
@@ -0,0 +1,168 @@
+//! Resources (enums, structs) for processing the _Building Insights_ response
+//! from the Google Maps Platform. Look in here for more information about the
+//! data returned from Google's server and how to parse it with your program.
+
+pub mod cash_purchase_savings;
+pub mod financed_purchase_savings;
+pub mod financial_analysis;
+pub mod financial_details;
+pub mod leasing_savings;
+pub mod money;
+pub mod roof_segment_size_and_sunshine_stats;
+pub mod roof_segment_summary;
+pub mod savings_over_time;
+pub mod size_and_sunshine_stats;
+pub mod solar_panel;
+pub mod solar_panel_config;
+pub mod solar_panel_orientation;
+pub mod solar_potential;
+
+// -----------------------------------------------------------------------------
+
+use crate::bounds::Bounds;
+use crate::latlng::LatLng;
+use crate::solar::{
+    date::Date,
+    error_response::ErrorResponse,
+    imagery_quality::ImageryQuality,
+}; // crate::solar
+use crate::solar::building_insights::response::solar_potential::SolarPotential;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Building Insights_ request will be
+/// stored in this structure.
+///
+/// [BuildingInsights](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#response-body)
+/// ---------------------------------------------------------------------------------------------------------------------------------
+/// Response message for `Solar.FindClosestBuildingInsights`. Information
+/// about the location, dimensions, and solar potential of a building.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// The resource name for the building, of the format
+    /// `buildings/{place_id}`.
+    pub name: Option<String>,
+
+    /// A point near the center of the building.
+    pub center: Option<LatLng>,
+
+    /// The bounding box of the building.
+    #[serde(alias = "boundingBox")]
+    pub bounding_box: Option<Bounds>,
+
+    /// Date that the underlying imagery was acquired. This is approximate.
+    #[serde(alias = "imageryDate")]
+    pub imagery_date: Option<Date>,
+
+    /// When processing was completed on this imagery.
+    #[serde(alias = "imageryProcessedDate")]
+    pub imagery_processed_date: Option<Date>,
+
+    /// Postal code (e.g., US zip code) this building is contained by.
+    #[serde(alias = "postalCode")]
+    pub postal_code: Option<String>,
+
+    /// Administrative area 1 (e.g., in the US, the state) that contains this
+    /// building. For example, in the US, the abbreviation might be "MA" or
+    /// "CA."
+    #[serde(alias = "administrativeArea")]
+    pub administrative_area: Option<String>,
+
+    /// Statistical area (e.g., US census tract) this building is in.
+    #[serde(alias = "statisticalArea")]
+    pub statistical_area: Option<String>,
+
+    /// Region code for the country (or region) this building is in.
+    #[serde(alias = "regionCode")]
+    pub region_code: Option<String>,
+
+    /// Solar potential of the building.
+    #[serde(alias = "solarPotential")]
+    pub solar_potential: Option<SolarPotential>,
+
+    /// The quality of the imagery used to compute the data for this building.
+    #[serde(alias = "imageryQuality")]
+    pub imagery_quality: Option<ImageryQuality>,
+
+    /// In the case of an error, a standard format error response body will be
+    /// returned and the HTTP status code will be set to an error status.
+    pub error: Option<ErrorResponse>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps _Building Insights_ JSON `String` response into a
+    /// usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solar::building_insights::response::solar_panel_orientation::SolarPanelOrientation;
+    use rust_decimal::Decimal;
+
+    /// A trimmed _Building Insights_ response for a building in Palo Alto,
+    /// based on the example in Google's documentation.
+    fn response() -> Response {
+        include_str!("fixtures/building_insights.json").parse().unwrap()
+    } // fn
+
+    #[test]
+    fn parses_the_building() {
+        let response = response();
+
+        assert_eq!(response.name.as_deref(), Some("buildings/ChIJh0CMPQW7j4ARLrRiVvmg6Vs"));
+        assert_eq!(response.center, Some(LatLng::try_from_f64(37.4449439, -122.1391466).unwrap()));
+        assert_eq!(response.imagery_date, Some(Date { year: 2022, month: 8, day: 14 }));
+        assert_eq!(response.imagery_quality, Some(ImageryQuality::High));
+        assert_eq!(response.postal_code.as_deref(), Some("94303"));
+        assert_eq!(response.region_code.as_deref(), Some("US"));
+        assert!(response.bounding_box.is_some());
+        assert_eq!(response.error, None);
+    } // fn
+
+    #[test]
+    fn parses_the_solar_potential() {
+        let potential = response().solar_potential.unwrap();
+
+        assert_eq!(potential.max_array_panels_count, Some(1163));
+        assert_eq!(potential.panel_lifetime_years, Some(20));
+        assert_eq!(potential.whole_roof_stats.unwrap().sunshine_quantiles.unwrap().len(), 11);
+
+        let segments = potential.roof_segment_stats.as_ref().unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].plane_height_at_center_meters, Some(10.7823));
+
+        let panels = potential.solar_panels.as_ref().unwrap();
+        assert_eq!(panels[0].orientation, Some(SolarPanelOrientation::Landscape));
+        assert_eq!(panels[0].segment_index, Some(0));
+    } // fn
+
+    #[test]
+    fn finds_the_default_analysis_and_its_panel_config() {
+        let potential = response().solar_potential.unwrap();
+
+        let analysis = potential.default_financial_analysis().unwrap();
+        let bill = analysis.monthly_bill.as_ref().unwrap();
+        assert_eq!(bill.amount(), Decimal::new(455, 1));
+        assert_eq!(bill.to_string(), "45.5 USD");
+
+        let config = potential.panel_config_for(analysis).unwrap();
+        assert_eq!(config.panels_count, Some(5));
+
+        // The other analysis recommends no panels:
+        let first = &potential.financial_analyses.as_ref().unwrap()[0];
+        assert_eq!(potential.panel_config_for(first), None);
+    } // fn
+} // mod
@@ -0,0 +1,61 @@
+//! Contains the `Money` struct. The Solar API expresses all financial amounts
+//! using this type.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents an amount of money with its currency type. See
+/// [Money](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#Money)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+
+    /// The three-letter currency code defined in ISO 4217.
+    #[serde(alias = "currencyCode")]
+    pub currency_code: Option<String>,
+
+    /// The whole units of the amount. For example if `currency_code` is
+    /// `"USD"`, then 1 unit is one US dollar. Google transmits this 64-bit
+    /// integer as a string.
+    pub units: Option<String>,
+
+    /// Number of nano (10^-9) units of the amount. The value must be between
+    /// -999,999,999 and +999,999,999 inclusive. If `units` is positive,
+    /// `nanos` must be positive or zero.
+    pub nanos: Option<i32>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Money {
+
+    /// Returns the amount as a single `Decimal` value, combining the whole
+    /// `units` with the fractional `nanos`. Missing or unparseable components
+    /// are treated as zero.
+    pub fn amount(&self) -> Decimal {
+        let units = self.units
+            .as_deref()
+            .and_then(|units| units.parse::<i64>().ok())
+            .unwrap_or_default();
+        let nanos = self.nanos.unwrap_or_default();
+        Decimal::from(units) + Decimal::new(nanos.into(), 9)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Money {
+    /// Formats a `Money` struct into a string that is presentable to the end
+    /// user, for example `1234.50 USD`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.currency_code {
+            Some(currency_code) => write!(f, "{} {currency_code}", self.amount().normalize()),
+            None => write!(f, "{}", self.amount().normalize()),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,47 @@
+//! Contains the `RoofSegmentSizeAndSunshineStats` struct. It describes the
+//! geometry and sunlight of a single, planar roof segment.
+
+use crate::bounds::Bounds;
+use crate::latlng::LatLng;
+use crate::solar::building_insights::response::size_and_sunshine_stats::SizeAndSunshineStats;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about a roof segment on the building, with some number of
+/// panels placed on it. See
+/// [RoofSegmentSizeAndSunshineStats](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#RoofSegmentSizeAndSunshineStats)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoofSegmentSizeAndSunshineStats {
+
+    /// Angle of the roof segment relative to the theoretical ground plane. 0 =
+    /// parallel to the ground, 90 = perpendicular to the ground.
+    #[serde(alias = "pitchDegrees")]
+    pub pitch_degrees: Option<f64>,
+
+    /// Compass direction the roof segment is pointing in. 0 = North, 90 =
+    /// East, 180 = South. For a "flat" roof segment (`pitch_degrees` very
+    /// near 0), azimuth is not well defined, so for consistency, we define it
+    /// arbitrarily to be 0 (North).
+    #[serde(alias = "azimuthDegrees")]
+    pub azimuth_degrees: Option<f64>,
+
+    /// Total size and sunlight quantiles for the roof segment.
+    pub stats: Option<SizeAndSunshineStats>,
+
+    /// A point near the center of the roof segment.
+    pub center: Option<LatLng>,
+
+    /// The bounding box of the roof segment.
+    #[serde(alias = "boundingBox")]
+    pub bounding_box: Option<Bounds>,
+
+    /// The height of the roof segment plane, in meters above sea level, at the
+    /// point designated by `center`. Together with the pitch, azimuth, and
+    /// center location, this fully defines the roof segment plane.
+    #[serde(alias = "planeHeightAtCenterMeters")]
+    pub plane_height_at_center_meters: Option<f64>,
+
+} // struct
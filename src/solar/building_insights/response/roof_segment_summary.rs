@@ -0,0 +1,39 @@
+//! Contains the `RoofSegmentSummary` struct. It describes the panels placed
+//! on one roof segment for a given panel configuration.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about a roof segment on the building, with some number of
+/// panels placed on it. See
+/// [RoofSegmentSummary](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#RoofSegmentSummary)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoofSegmentSummary {
+
+    /// Angle of the roof segment relative to the theoretical ground plane. 0 =
+    /// parallel to the ground, 90 = perpendicular to the ground.
+    #[serde(alias = "pitchDegrees")]
+    pub pitch_degrees: Option<f64>,
+
+    /// Compass direction the roof segment is pointing in. 0 = North, 90 =
+    /// East, 180 = South.
+    #[serde(alias = "azimuthDegrees")]
+    pub azimuth_degrees: Option<f64>,
+
+    /// The total number of panels on this segment.
+    #[serde(alias = "panelsCount")]
+    pub panels_count: Option<u32>,
+
+    /// How much sunlight energy this part of the layout captures over the
+    /// course of a year, in DC kWh.
+    #[serde(alias = "yearlyEnergyDcKwh")]
+    pub yearly_energy_dc_kwh: Option<f64>,
+
+    /// Index into `roof_segment_stats` of the corresponding roof segment.
+    #[serde(alias = "segmentIndex")]
+    pub segment_index: Option<usize>,
+
+} // struct
@@ -0,0 +1,44 @@
+//! Contains the `SavingsOverTime` struct. It summarizes the savings of a
+//! solar installation over its lifetime.
+
+use crate::solar::building_insights::response::money::Money;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Financial information that's shared between different financing methods.
+/// See
+/// [SavingsOverTime](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SavingsOverTime)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SavingsOverTime {
+
+    /// Savings in the first year after panel installation.
+    #[serde(alias = "savingsYear1")]
+    pub savings_year1: Option<Money>,
+
+    /// Savings in the first twenty years after panel installation.
+    #[serde(alias = "savingsYear20")]
+    pub savings_year20: Option<Money>,
+
+    /// Using the assumed discount rate, what is the present value of the
+    /// cumulative 20-year savings?
+    #[serde(alias = "presentValueOfSavingsYear20")]
+    pub present_value_of_savings_year20: Option<Money>,
+
+    /// Savings in the entire panel lifetime.
+    #[serde(alias = "savingsLifetime")]
+    pub savings_lifetime: Option<Money>,
+
+    /// Using the assumed discount rate, what is the present value of the
+    /// cumulative lifetime savings?
+    #[serde(alias = "presentValueOfSavingsLifetime")]
+    pub present_value_of_savings_lifetime: Option<Money>,
+
+    /// Indicates whether this scenario is financially viable. Will be `false`
+    /// for scenarios with poor financial viability (e.g. money-losing).
+    #[serde(alias = "financiallyViable")]
+    pub financially_viable: Option<bool>,
+
+} // struct
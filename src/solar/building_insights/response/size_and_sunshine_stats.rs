@@ -0,0 +1,31 @@
+//! Contains the `SizeAndSunshineStats` struct. It describes the size and
+//! sunlight quantiles of a roof, a roof segment, or a whole building.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Size and sunniness quantiles of a roof, or part of a roof. See
+/// [SizeAndSunshineStats](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SizeAndSunshineStats)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SizeAndSunshineStats {
+
+    /// The area of the roof or roof segment, in m². This is the roof area
+    /// (accounting for tilt), not the ground footprint area.
+    #[serde(alias = "areaMeters2")]
+    pub area_meters2: Option<f64>,
+
+    /// Quantiles of the pointwise sunniness across the area. If there are N
+    /// values here, this represents the (N-1)-iles. For example, if there are
+    /// 5 values, then they would be the 0%, 25%, 50%, 75%, 100% quantiles.
+    /// Values are annual kWh/kW like `max_sunshine_hours_per_year`.
+    #[serde(alias = "sunshineQuantiles")]
+    pub sunshine_quantiles: Option<Vec<f64>>,
+
+    /// The ground footprint area covered by the roof or roof segment, in m².
+    #[serde(alias = "groundAreaMeters2")]
+    pub ground_area_meters2: Option<f64>,
+
+} // struct
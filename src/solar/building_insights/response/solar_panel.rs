@@ -0,0 +1,33 @@
+//! Contains the `SolarPanel` struct. It describes a single panel placed by
+//! the Solar API's layout algorithm.
+
+use crate::latlng::LatLng;
+use crate::solar::building_insights::response::solar_panel_orientation::SolarPanelOrientation;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A single solar panel. See
+/// [SolarPanel](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SolarPanel)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolarPanel {
+
+    /// The centre of the panel.
+    pub center: Option<LatLng>,
+
+    /// The orientation of the panel.
+    pub orientation: Option<SolarPanelOrientation>,
+
+    /// How much sunlight energy this layout captures over the course of a
+    /// year, in DC kWh.
+    #[serde(alias = "yearlyEnergyDcKwh")]
+    pub yearly_energy_dc_kwh: Option<f64>,
+
+    /// Index into `roof_segment_stats` of the roof segment that this panel is
+    /// placed on.
+    #[serde(alias = "segmentIndex")]
+    pub segment_index: Option<usize>,
+
+} // struct
@@ -0,0 +1,31 @@
+//! Contains the `SolarPanelConfig` struct. It describes one possible layout of
+//! panels on the roof.
+
+use crate::solar::building_insights::response::roof_segment_summary::RoofSegmentSummary;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Describes a particular placement of solar panels on the roof. See
+/// [SolarPanelConfig](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SolarPanelConfig)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolarPanelConfig {
+
+    /// Total number of panels. Note that this is redundant to (the sum of) the
+    /// corresponding fields in `roof_segment_summaries`.
+    #[serde(alias = "panelsCount")]
+    pub panels_count: Option<u32>,
+
+    /// How much sunlight energy this layout captures over the course of a
+    /// year, in DC kWh, assuming the panels described above.
+    #[serde(alias = "yearlyEnergyDcKwh")]
+    pub yearly_energy_dc_kwh: Option<f64>,
+
+    /// Information about the production of each roof segment that is carrying
+    /// at least one panel in this layout.
+    #[serde(alias = "roofSegmentSummaries")]
+    pub roof_segment_summaries: Option<Vec<RoofSegmentSummary>>,
+
+} // struct
@@ -0,0 +1,109 @@
+//! Contains the `SolarPanelOrientation` enum and its associated traits. It
+//! describes how a solar panel is laid out relative to its roof segment.
+
+use crate::solar::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// The [orientation of a solar
+/// panel](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SolarPanelOrientation),
+/// relative to the azimuth of the roof segment it is placed on.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum SolarPanelOrientation {
+    /// A `Landscape` panel has its long edge perpendicular to the azimuth
+    /// direction of the roof segment that it is placed on.
+    #[serde(alias = "LANDSCAPE")]
+    Landscape,
+    /// A `Portrait` panel has its long edge parallel to the azimuth direction
+    /// of the roof segment that it is placed on.
+    #[serde(alias = "PORTRAIT")]
+    Portrait,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for SolarPanelOrientation {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match SolarPanelOrientation::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SolarPanelOrientation> for String {
+    /// Converts a `SolarPanelOrientation` enum to a `String` that contains a
+    /// solar panel orientation code.
+    fn from(orientation: &SolarPanelOrientation) -> String {
+        match orientation {
+            SolarPanelOrientation::Landscape => String::from("LANDSCAPE"),
+            SolarPanelOrientation::Portrait => String::from("PORTRAIT"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static SOLAR_PANEL_ORIENTATIONS_BY_CODE: phf::Map<&'static str, SolarPanelOrientation> = phf_map! {
+    "LANDSCAPE" => SolarPanelOrientation::Landscape,
+    "PORTRAIT" => SolarPanelOrientation::Portrait,
+};
+
+impl std::convert::TryFrom<&str> for SolarPanelOrientation {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Error = crate::solar::error::Error;
+    /// Gets a `SolarPanelOrientation` enum from a `String` that contains a
+    /// valid solar panel orientation code.
+    fn try_from(orientation_code: &str) -> Result<Self, Self::Error> {
+        SOLAR_PANEL_ORIENTATIONS_BY_CODE
+            .get(orientation_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidSolarPanelOrientation(orientation_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for SolarPanelOrientation {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Err = crate::solar::error::Error;
+    /// Gets a `SolarPanelOrientation` enum from a `String` that contains a
+    /// valid solar panel orientation code.
+    fn from_str(orientation_code: &str) -> Result<Self, Self::Err> {
+        SOLAR_PANEL_ORIENTATIONS_BY_CODE
+            .get(orientation_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidSolarPanelOrientation(orientation_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for SolarPanelOrientation {
+    /// Returns a reasonable default variant for the `SolarPanelOrientation`
+    /// enum type.
+    fn default() -> Self {
+        SolarPanelOrientation::Landscape
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SolarPanelOrientation {
+    /// Formats a `SolarPanelOrientation` enum into a string that is
+    /// presentable to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SolarPanelOrientation::Landscape => write!(f, "Landscape"),
+            SolarPanelOrientation::Portrait => write!(f, "Portrait"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,127 @@
+//! Contains the `SolarPotential` struct. It holds the solar panel layouts,
+//! roof statistics and financial analyses computed for a building.
+
+use crate::solar::building_insights::response::{
+    financial_analysis::FinancialAnalysis,
+    roof_segment_size_and_sunshine_stats::RoofSegmentSizeAndSunshineStats,
+    size_and_sunshine_stats::SizeAndSunshineStats,
+    solar_panel::SolarPanel,
+    solar_panel_config::SolarPanelConfig,
+}; // crate::solar::building_insights::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the solar potential of a building. A number of fields in
+/// this are defined in terms of "panels". The fields `panel_capacity_watts`,
+/// `panel_height_meters`, and `panel_width_meters` describe the parameters of
+/// the model of panel used in these calculations. See
+/// [SolarPotential](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#SolarPotential)
+/// for more information.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolarPotential {
+
+    /// Size of the maximum array - that is, the maximum number of panels that
+    /// can fit on the roof.
+    #[serde(alias = "maxArrayPanelsCount")]
+    pub max_array_panels_count: Option<u32>,
+
+    /// Capacity, in watts, of the panel used in the calculations.
+    #[serde(alias = "panelCapacityWatts")]
+    pub panel_capacity_watts: Option<f64>,
+
+    /// Height, in meters in portrait orientation, of the panel used in the
+    /// calculations.
+    #[serde(alias = "panelHeightMeters")]
+    pub panel_height_meters: Option<f64>,
+
+    /// Width, in meters in portrait orientation, of the panel used in the
+    /// calculations.
+    #[serde(alias = "panelWidthMeters")]
+    pub panel_width_meters: Option<f64>,
+
+    /// The expected lifetime, in years, of the solar panels. This is used in
+    /// the financial calculations.
+    #[serde(alias = "panelLifetimeYears")]
+    pub panel_lifetime_years: Option<u32>,
+
+    /// Size, in square meters, of the maximum array.
+    #[serde(alias = "maxArrayAreaMeters2")]
+    pub max_array_area_meters2: Option<f64>,
+
+    /// Maximum number of sunshine hours received per year, by any point on the
+    /// roof. Sunshine hours are a measure of the total amount of insolation
+    /// (energy) received per year. 1 sunshine hour = 1 kWh per kW (where kW
+    /// refers to kW of capacity under Standard Testing Conditions).
+    #[serde(alias = "maxSunshineHoursPerYear")]
+    pub max_sunshine_hours_per_year: Option<f64>,
+
+    /// Equivalent amount of CO2 produced per MWh of grid electricity. This is
+    /// a measure of the carbon intensity of grid electricity displaced by
+    /// solar electricity.
+    #[serde(alias = "carbonOffsetFactorKgPerMwh")]
+    pub carbon_offset_factor_kg_per_mwh: Option<f64>,
+
+    /// Total size and sunlight quantiles for the part of the roof that was
+    /// assigned to some roof segment. Despite the name, this may not include
+    /// the entire building.
+    #[serde(alias = "wholeRoofStats")]
+    pub whole_roof_stats: Option<SizeAndSunshineStats>,
+
+    /// Size and sunlight quantiles for the entire building, including parts of
+    /// the roof that were not assigned to some roof segment.
+    #[serde(alias = "buildingStats")]
+    pub building_stats: Option<SizeAndSunshineStats>,
+
+    /// Size and sunlight quantiles for each roof segment.
+    #[serde(alias = "roofSegmentStats")]
+    pub roof_segment_stats: Option<Vec<RoofSegmentSizeAndSunshineStats>>,
+
+    /// Each `SolarPanel` describes a single solar panel. They are listed in the
+    /// order that the panel layout algorithm placed this. This is usually,
+    /// though not always, in decreasing order of annual energy production.
+    #[serde(alias = "solarPanels")]
+    pub solar_panels: Option<Vec<SolarPanel>>,
+
+    /// Each `SolarPanelConfig` describes a different arrangement of solar
+    /// panels on the roof. They are in order of increasing number of panels.
+    /// The `SolarPanelConfig` with `panels_count = N` is based on the first
+    /// `N` panels in the `solar_panels` list.
+    #[serde(alias = "solarPanelConfigs")]
+    pub solar_panel_configs: Option<Vec<SolarPanelConfig>>,
+
+    /// A `FinancialAnalysis` gives the savings from going solar assuming a
+    /// given monthly bill and a given electricity provider. They are in order
+    /// of increasing order of monthly bill amount. This field will be empty for
+    /// buildings in areas for which the Solar API does not have enough
+    /// information to perform financial computations.
+    #[serde(alias = "financialAnalyses")]
+    pub financial_analyses: Option<Vec<FinancialAnalysis>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl SolarPotential {
+
+    /// Returns the financial analysis that Google marked as the default bill
+    /// for the building's area, if any.
+    pub fn default_financial_analysis(&self) -> Option<&FinancialAnalysis> {
+        self.financial_analyses
+            .as_ref()?
+            .iter()
+            .find(|analysis| analysis.default_bill == Some(true))
+    } // fn
+
+    /// Returns the solar panel configuration that a financial analysis
+    /// recommends, if the analysis points to a valid layout.
+    pub fn panel_config_for(
+        &self,
+        analysis: &FinancialAnalysis
+    ) -> Option<&SolarPanelConfig> {
+        let index = usize::try_from(analysis.panel_config_index?).ok()?;
+        self.solar_panel_configs.as_ref()?.get(index)
+    } // fn
+
+} // impl
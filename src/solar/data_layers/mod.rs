@@ -0,0 +1,34 @@
+//! The Solar API **Data Layers** service gets solar information for a region
+//! surrounding a location. It returns URLs to a number of GeoTIFF files that
+//! may be downloaded using the Solar API **GeoTIFF** service.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/solar/reference/rest/v1/dataLayers/get#query-parameters)
+//!
+//! * `location` - The longitude and latitude for the center of the region to
+//! get data for.
+//!
+//! * `radiusMeters` - The radius, in meters, defining the region surrounding
+//! that centre point for which data should be returned.
+//!
+//! * `view` - The desired subset of the data to return.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/solar/reference/rest/v1/dataLayers/get#query-parameters)
+//!
+//! * `requiredQuality` - The minimum quality level allowed in the results.
+//!
+//! * `pixelSizeMeters` - The minimum scale, in meters per pixel, of the data
+//! to return.
+//!
+//! * `exactQualityRequired` - Whether to require exact quality of the imagery.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://solar.googleapis.com/v1/dataLayers:get";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::solar::data_layers::request::Request as DataLayersRequest;
+pub use crate::solar::data_layers::response::Response as DataLayersResponse;
@@ -0,0 +1,59 @@
+use crate::solar::data_layers::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Data Layers request
+    /// based on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &'a mut Request {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&location.latitude={latitude}&location.longitude={longitude}&radiusMeters={radius}&view={view}",
+            key=self.client.key,
+            latitude=self.location.lat.normalize(),
+            longitude=self.location.lng.normalize(),
+            radius=self.radius_meters,
+            view=String::from(&self.view),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Required quality key/value pair:
+        if let Some(required_quality) = &self.required_quality {
+            query.push_str("&requiredQuality=");
+            query.push_str(&String::from(required_quality))
+        }
+
+        // Pixel size key/value pair:
+        if let Some(pixel_size_meters) = &self.pixel_size_meters {
+            query.push_str("&pixelSizeMeters=");
+            query.push_str(&pixel_size_meters.to_string())
+        }
+
+        // Exact quality required key/value pair:
+        if let Some(exact_quality_required) = &self.exact_quality_required {
+            query.push_str("&exactQualityRequired=");
+            query.push_str(&exact_quality_required.to_string())
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,126 @@
+//! Contains the `DataLayerView` enum and its associated traits. It is used to
+//! specify which subset of the solar information the _Data Layers_ service
+//! should return.
+
+use crate::solar::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// What [subset of the solar
+/// information](https://developers.google.com/maps/documentation/solar/reference/rest/v1/dataLayers/get#DataLayerView)
+/// to return. Larger views take longer to compute and produce more (and
+/// larger) GeoTIFF files.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum DataLayerView {
+    /// Get the DSM only.
+    #[serde(alias = "DSM_LAYER")]
+    DsmLayer,
+    /// Get the DSM, RGB, and mask.
+    #[serde(alias = "IMAGERY_LAYERS")]
+    ImageryLayers,
+    /// Get the DSM, RGB, mask, and annual flux.
+    #[serde(alias = "IMAGERY_AND_ANNUAL_FLUX_LAYERS")]
+    ImageryAndAnnualFluxLayers,
+    /// Get the DSM, RGB, mask, annual flux, and monthly flux.
+    #[serde(alias = "IMAGERY_AND_ALL_FLUX_LAYERS")]
+    ImageryAndAllFluxLayers,
+    /// Get all data, including hourly shade.
+    #[serde(alias = "FULL_LAYERS")]
+    FullLayers,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for DataLayerView {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match DataLayerView::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&DataLayerView> for String {
+    /// Converts a `DataLayerView` enum to a `String` that contains a data
+    /// layer view code.
+    fn from(view: &DataLayerView) -> String {
+        match view {
+            DataLayerView::DsmLayer => String::from("DSM_LAYER"),
+            DataLayerView::ImageryLayers => String::from("IMAGERY_LAYERS"),
+            DataLayerView::ImageryAndAnnualFluxLayers => String::from("IMAGERY_AND_ANNUAL_FLUX_LAYERS"),
+            DataLayerView::ImageryAndAllFluxLayers => String::from("IMAGERY_AND_ALL_FLUX_LAYERS"),
+            DataLayerView::FullLayers => String::from("FULL_LAYERS"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static DATA_LAYER_VIEWS_BY_CODE: phf::Map<&'static str, DataLayerView> = phf_map! {
+    "DSM_LAYER" => DataLayerView::DsmLayer,
+    "IMAGERY_LAYERS" => DataLayerView::ImageryLayers,
+    "IMAGERY_AND_ANNUAL_FLUX_LAYERS" => DataLayerView::ImageryAndAnnualFluxLayers,
+    "IMAGERY_AND_ALL_FLUX_LAYERS" => DataLayerView::ImageryAndAllFluxLayers,
+    "FULL_LAYERS" => DataLayerView::FullLayers,
+};
+
+impl std::convert::TryFrom<&str> for DataLayerView {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Error = crate::solar::error::Error;
+    /// Gets a `DataLayerView` enum from a `String` that contains a valid data
+    /// layer view code.
+    fn try_from(view_code: &str) -> Result<Self, Self::Error> {
+        DATA_LAYER_VIEWS_BY_CODE
+            .get(view_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidDataLayerView(view_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for DataLayerView {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Err = crate::solar::error::Error;
+    /// Gets a `DataLayerView` enum from a `String` that contains a valid data
+    /// layer view code.
+    fn from_str(view_code: &str) -> Result<Self, Self::Err> {
+        DATA_LAYER_VIEWS_BY_CODE
+            .get(view_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidDataLayerView(view_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for DataLayerView {
+    /// Returns a reasonable default variant for the `DataLayerView` enum type.
+    fn default() -> Self {
+        DataLayerView::FullLayers
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for DataLayerView {
+    /// Formats a `DataLayerView` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DataLayerView::DsmLayer => write!(f, "DSM Layer"),
+            DataLayerView::ImageryLayers => write!(f, "Imagery Layers"),
+            DataLayerView::ImageryAndAnnualFluxLayers => write!(f, "Imagery and Annual Flux Layers"),
+            DataLayerView::ImageryAndAllFluxLayers => write!(f, "Imagery and All Flux Layers"),
+            DataLayerView::FullLayers => write!(f, "Full Layers"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::solar::data_layers::request::Request;
+use crate::solar::data_layers::response::Response;
+use crate::solar::error::Error;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build().get()` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<Response, Error> {
+        self.build().get().await
+    } // fn
+
+} // impl
@@ -0,0 +1,86 @@
+//! **Look in this module for documentation on building your _Data Layers_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+pub mod data_layer_view;
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod with_exact_quality_required;
+mod with_pixel_size_meters;
+mod with_required_quality;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::solar::data_layers::request::data_layer_view::DataLayerView;
+use crate::solar::imagery_quality::ImageryQuality;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Data Layers_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The longitude and latitude for the center of the region to get data
+    /// for.
+    location: LatLng,
+
+    /// The radius, in meters, defining the region surrounding that centre
+    /// point for which data should be returned. The limitations on this value
+    /// are: any value up to 100m can always be specified; values over 100m
+    /// can be specified, as long as `radius_meters` <= `pixel_size_meters` *
+    /// 1000; however, for values over 175m, the `DataLayerView` in the request
+    /// must not include monthly flux or hourly shade.
+    radius_meters: f64,
+
+    /// The desired subset of the data to return.
+    view: DataLayerView,
+
+    // Optional parameters:
+    // --------------------
+
+    /// The minimum quality level allowed in the results. No result with lower
+    /// quality than this will be returned. Not specifying this is equivalent
+    /// to restricting to `High` quality only.
+    required_quality: Option<ImageryQuality>,
+
+    /// The minimum scale, in meters per pixel, of the data to return. Values
+    /// of 0.1 (the default, if this field is not set explicitly), 0.25, 0.5,
+    /// and 1.0 are supported. Imagery components whose normal resolution is
+    /// less than `pixel_size_meters` will be returned at the resolution
+    /// specified by `pixel_size_meters`.
+    pixel_size_meters: Option<f64>,
+
+    /// Whether to require exact quality of the imagery. If set to `false`,
+    /// the `required_quality` field is interpreted as the minimum required
+    /// quality. If set to `true`, `required_quality` is interpreted as the
+    /// exact required quality.
+    exact_quality_required: Option<bool>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
@@ -0,0 +1,47 @@
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::solar::data_layers::request::{Request, data_layer_view::DataLayerView};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Data Layers query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The longitude and latitude for the center of the region
+    /// to get data for.
+    /// * `radius_meters` ‧ The radius, in meters, defining the region
+    /// surrounding that centre point for which data should be returned.
+    /// * `view` ‧ The desired subset of the data to return.
+
+    pub fn new(
+        client: &GoogleMapsClient,
+        location: LatLng,
+        radius_meters: f64,
+        view: DataLayerView,
+    ) -> Request {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            location,
+            radius_meters,
+            view,
+            // Optional parameters:
+            required_quality: None,
+            pixel_size_meters: None,
+            exact_quality_required: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
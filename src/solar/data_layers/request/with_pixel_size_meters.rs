@@ -0,0 +1,41 @@
+use crate::solar::data_layers::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the minimum scale of the data to return.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `pixel_size_meters` ‧ The minimum scale, in meters per pixel, of the
+    /// data to return. Values of 0.1 (the default), 0.25, 0.5, and 1.0 are
+    /// supported. Imagery components whose normal resolution is less than
+    /// `pixel_size_meters` will be returned at the resolution specified by
+    /// `pixel_size_meters`; imagery components whose normal resolution is
+    /// equal to or greater than `pixel_size_meters` will be returned at that
+    /// normal resolution.
+    ///
+    /// ## Example:
+    ///
+    /// * Request data at 0.5 meters per pixel:
+    /// ```rust
+    /// .with_pixel_size_meters(0.5)
+    /// ```
+
+    pub fn with_pixel_size_meters(
+        &'a mut self,
+        pixel_size_meters: f64
+    ) -> &'a mut Request {
+
+        // Set pixel size in Request struct.
+        self.pixel_size_meters = Some(pixel_size_meters);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
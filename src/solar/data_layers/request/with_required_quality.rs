@@ -0,0 +1,38 @@
+use crate::solar::data_layers::request::Request;
+use crate::solar::imagery_quality::ImageryQuality;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the minimum quality level allowed in the results.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `required_quality` ‧ No result with lower quality than this will be
+    /// returned. Not specifying this is equivalent to restricting to `High`
+    /// quality only.
+    ///
+    /// ## Example:
+    ///
+    /// * Accept medium or high quality imagery:
+    /// ```rust
+    /// .with_required_quality(ImageryQuality::Medium)
+    /// ```
+
+    pub fn with_required_quality(
+        &'a mut self,
+        required_quality: ImageryQuality
+    ) -> &'a mut Request {
+
+        // Set required quality in Request struct.
+        self.required_quality = Some(required_quality);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,95 @@
+//! Resources (enums, structs) for processing the _Data Layers_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+// -----------------------------------------------------------------------------
+
+use crate::solar::{
+    date::Date,
+    error_response::ErrorResponse,
+    imagery_quality::ImageryQuality,
+}; // crate::solar
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Data Layers_ request will be stored in
+/// this structure.
+///
+/// [DataLayers](https://developers.google.com/maps/documentation/solar/reference/rest/v1/dataLayers#DataLayers)
+/// ------------------------------------------------------------------------------------------------------------
+/// Information about the solar potential of a region surrounding a location.
+/// The actual data are contained in a number of GeoTIFF files covering the
+/// requested region, for which this message contains URLs: Each string in the
+/// `DataLayers` message contains a URL from which the corresponding GeoTIFF can
+/// be fetched. These URLs are valid for a few hours after they've been
+/// generated. Use the `geo_tiff` method of the client to download them.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// When the source imagery (from which all the other data are derived) in
+    /// this region was taken. It is necessarily somewhat approximate, as the
+    /// images may have been taken over more than one day.
+    #[serde(alias = "imageryDate")]
+    pub imagery_date: Option<Date>,
+
+    /// When processing was completed on this imagery.
+    #[serde(alias = "imageryProcessedDate")]
+    pub imagery_processed_date: Option<Date>,
+
+    /// The URL for an image of the DSM (Digital Surface Model) of the region.
+    /// Values are in meters above EGM96 geoid (i.e., sea level). Invalid
+    /// locations (where we don't have data) are stored as -9999.
+    #[serde(alias = "dsmUrl")]
+    pub dsm_url: Option<String>,
+
+    /// The URL for an image of RGB data (aerial photo) of the region.
+    #[serde(alias = "rgbUrl")]
+    pub rgb_url: Option<String>,
+
+    /// The URL for the building mask image: one bit per pixel saying whether
+    /// that pixel is considered to be part of a rooftop or not.
+    #[serde(alias = "maskUrl")]
+    pub mask_url: Option<String>,
+
+    /// The URL for the annual flux map (annual sunlight on roofs) of the
+    /// region. Values are kWh/kW/year. This is unmasked flux: flux is computed
+    /// for every location, not just building rooftops.
+    #[serde(alias = "annualFluxUrl")]
+    pub annual_flux_url: Option<String>,
+
+    /// The URL for the monthly flux map (sunlight on roofs, broken down by
+    /// month) of the region. Values are kWh/kW/year. The GeoTIFF pointed to by
+    /// this URL will contain twelve bands, corresponding to January...December,
+    /// in order.
+    #[serde(alias = "monthlyFluxUrl")]
+    pub monthly_flux_url: Option<String>,
+
+    /// Twelve URLs for hourly shade, corresponding to January...December, in
+    /// order. Each GeoTIFF will contain 24 bands, corresponding to the 24 hours
+    /// of the day. Each pixel is a 32 bit integer, corresponding to the (up to)
+    /// 31 days of that month.
+    #[serde(alias = "hourlyShadeUrls")]
+    pub hourly_shade_urls: Option<Vec<String>>,
+
+    /// The quality of the result's imagery.
+    #[serde(alias = "imageryQuality")]
+    pub imagery_quality: Option<ImageryQuality>,
+
+    /// In the case of an error, a standard format error response body will be
+    /// returned and the HTTP status code will be set to an error status.
+    pub error: Option<ErrorResponse>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps _Data Layers_ JSON `String` response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
@@ -0,0 +1,56 @@
+//! Contains the `Date` struct. The Solar API reports the capture and
+//! processing dates of its imagery as whole calendar dates.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a whole [calendar
+/// date](https://developers.google.com/maps/documentation/solar/reference/rest/v1/buildingInsights/findClosest#Date),
+/// such as the date the imagery was captured. The time of day and time zone
+/// are either specified elsewhere or are insignificant.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Date {
+
+    /// Year of the date. Must be from 1 to 9999, or 0 to specify a date
+    /// without a year.
+    pub year: u16,
+
+    /// Month of a year. Must be from 1 to 12, or 0 to specify a year without
+    /// a month and day.
+    pub month: u8,
+
+    /// Day of a month. Must be from 1 to 31 and valid for the year and month,
+    /// or 0 to specify a year by itself or a year and month where the day
+    /// isn't significant.
+    pub day: u8,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Date {
+
+    /// Converts the Solar API `Date` into a `chrono::NaiveDate`. Returns
+    /// `None` if the date is partial (i.e. the month or day is `0`) or is
+    /// otherwise not a valid calendar date.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(
+            self.year.into(),
+            self.month.into(),
+            self.day.into(),
+        ) // NaiveDate
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Date {
+    /// Formats a `Date` struct into an ISO 8601 `YYYY-MM-DD` string.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    } // fn
+} // impl
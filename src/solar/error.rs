@@ -0,0 +1,179 @@
+//! Solar API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::solar::status::Status;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Solar API client.
+
+#[derive(Debug)]
+pub enum Error {
+
+    /// Google Maps Solar API server generated an error. See the `Status`
+    /// enum for more information.
+    GoogleMapsService(Status, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// data layer view code. See
+    /// `google_maps\src\solar\data_layers\request\data_layer_view.rs` for more
+    /// information.
+    InvalidDataLayerView(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// imagery quality code. See `google_maps\src\solar\imagery_quality.rs`
+    /// for more information.
+    InvalidImageryQuality(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// solar panel orientation code. See
+    /// `google_maps\src\solar\building_insights\response\solar_panel_orientation.rs`
+    /// for more information.
+    InvalidSolarPanelOrientation(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// status code. See `google_maps\src\solar\status.rs` for more
+    /// information.
+    InvalidStatusCode(String),
+
+    /// The query string must be built before the request may be sent to the
+    /// Google Maps Solar API server.
+    QueryNotBuilt,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(reqwest::Error),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Solar API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Solar API service: {error_message}"),
+                // If the Google Maps Solar API server did not generate an
+                // error message, return a generic message derived from the
+                // response status:
+                None => match status {
+                    Status::InvalidArgument => write!(f, "Google Maps Solar API service: \
+                        Invalid argument. \
+                        1. Your API key is not valid or was not included in the request. \
+                        or, 2. Your request contained invalid arguments."),
+                    Status::PermissionDenied => write!(f, "Google Maps Solar API service: \
+                        Permission Denied. \
+                        1. API key missing or invalid. \
+                        2. Billing not enabled. \
+                        3. Solar API not enabled for this project. \
+                        or, 4. Self-imposed usage cap exceeded."),
+                    Status::NotFound => write!(f, "Google Maps Solar API service: \
+                        Not found. \
+                        No building or imagery could be found at the requested \
+                        location with the requested quality."),
+                    Status::ResourceExhausted => write!(f, "Google Maps Solar API service: \
+                        Resource exhausted. \
+                        You have exceeded the request limit that you configured \
+                        in the Google Cloud Platform Console."),
+                    _ => write!(f, "Google Maps Solar API service: {status}."),
+                } // match
+            }, // match
+            Error::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Solar API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Error::InvalidDataLayerView(view_code) => write!(f, "Google Maps Solar API client: \
+                `{view_code}` is not a valid data layer view code. \
+                Valid codes are `DSM_LAYER`, `IMAGERY_LAYERS`, \
+                `IMAGERY_AND_ANNUAL_FLUX_LAYERS`, `IMAGERY_AND_ALL_FLUX_LAYERS`, \
+                and `FULL_LAYERS`."),
+            Error::InvalidImageryQuality(quality_code) => write!(f, "Google Maps Solar API client: \
+                `{quality_code}` is not a valid imagery quality code. \
+                Valid codes are `HIGH`, `MEDIUM`, and `LOW`."),
+            Error::InvalidSolarPanelOrientation(orientation_code) => write!(f, "Google Maps Solar API client: \
+                `{orientation_code}` is not a valid solar panel orientation code. \
+                Valid codes are `LANDSCAPE`, and `PORTRAIT`."),
+            Error::InvalidStatusCode(status_code) => write!(f, "Google Maps Solar API client: \
+                `{status_code}` is not a valid status code. \
+                Valid codes are `INVALID_ARGUMENT`, `FAILED_PRECONDITION`, \
+                `OUT_OF_RANGE`, `UNAUTHENTICATED`, `PERMISSION_DENIED`, \
+                `NOT_FOUND`, `ABORTED`, `ALREADY_EXISTS`, `RESOURCE_EXHAUSTED`, \
+                `CANCELLED`, `DATA_LOSS`, `UNKNOWN`, `INTERNAL`, \
+                `UNIMPLEMENTED`, `UNAVAILABLE`, and `DEADLINE_EXCEEDED`."),
+            Error::QueryNotBuilt => write!(f, "Google Maps Solar API client library: \
+                The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => write!(f, "Google Maps Solar API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::ReqwestMessage(error) => write!(f, "Google Maps Solar API client in the Reqwest library: {error}"),
+            Error::SerdeJson(error) => write!(f, "Google Maps Solar API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Solar API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::GoogleMapsService(_error, _message) => None,
+            Error::HttpUnsuccessful(_status) => None,
+            Error::InvalidDataLayerView(_view_code) => None,
+            Error::InvalidImageryQuality(_quality_code) => None,
+            Error::InvalidSolarPanelOrientation(_orientation_code) => None,
+            Error::InvalidStatusCode(_status_code) => None,
+            Error::QueryNotBuilt => None,
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => Some(error),
+            #[cfg(feature = "enable-reqwest")]
+            Error::ReqwestMessage(_error) => None,
+            Error::SerdeJson(error) => Some(error),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Solar API error type (`google_maps::solar::error::Error`)
+    /// by wrapping it inside. This function is required to use the `?`
+    /// operator.
+    fn from(error: reqwest::Error) -> Error {
+        Error::Reqwest(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Solar API error type
+    /// (`google_maps::solar::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Error {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
@@ -0,0 +1,42 @@
+//! In the case of an error, a standard format error response body will be
+//! returned and the HTTP status code will be set to an error status.
+
+// -----------------------------------------------------------------------------
+
+use crate::solar::status::Status;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// In the case of an error, a standard format error response body will be
+/// returned and the HTTP status code will be set to an error status.
+///
+/// The response contains an object with a single error object with the
+/// following keys:
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+
+    /// This is the same as the
+    /// [HTTP status](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response.
+    pub code: u16,
+
+    /// A short description of the error.
+    pub message: String,
+
+    /// A status code indicating the nature of the error.
+    pub status: Status,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The error object is wrapped in a top-level `"error"` key. This envelope is
+/// used to recover the error from the body of an unsuccessful HTTP response.
+
+#[cfg(feature = "enable-reqwest")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ErrorEnvelope {
+    pub error: ErrorResponse,
+} // struct
@@ -0,0 +1,19 @@
+//! The Solar API **GeoTIFF** service downloads one of the GeoTIFF files that
+//! are referenced by a _Data Layers_ response. The GeoTIFF is returned as raw
+//! bytes, ready to be written to disk or handed to a GeoTIFF decoder.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/solar/reference/rest/v1/geoTiff/get#query-parameters)
+//!
+//! * `id` - The ID of the asset being requested. The _Data Layers_ service
+//! returns complete URLs that already contain this ID; either the URL or the
+//! bare ID may be passed to this client.
+
+pub mod request;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://solar.googleapis.com/v1/geoTiff:get";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::solar::geo_tiff::request::Request as GeoTiffRequest;
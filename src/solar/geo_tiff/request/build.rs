@@ -0,0 +1,46 @@
+use crate::solar::geo_tiff::request::Request;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps GeoTIFF request based on
+    /// the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &'a mut Request {
+
+        // The Data Layers service returns complete URLs such as
+        // `https://solar.googleapis.com/v1/geoTiff:get?id=...`. If we were
+        // given one of those, pull the asset ID out of its query string.
+        // Otherwise, assume we were given the bare asset ID:
+        let id: String = match self.id.split_once('?') {
+            Some((_url, query)) => query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("id="))
+                .unwrap_or_default()
+                .to_string(),
+            None => utf8_percent_encode(&self.id, NON_ALPHANUMERIC).to_string(),
+        }; // match
+
+        // Build "required parameters" portion of the query string:
+        let query = format!(
+            "id={id}&key={key}",
+            key=self.client.key,
+        );
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,26 @@
+use crate::solar::error::Error;
+use crate::solar::geo_tiff::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build().get()` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<Vec<u8>, Error> {
+        self.build().get().await
+    } // fn
+
+} // impl
@@ -0,0 +1,46 @@
+//! **Look in this module for documentation on building your _GeoTIFF_
+//! query**. This module contains the tools (enums, structs, methods) for
+//! building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _GeoTIFF_ query**. The methods implemented for this struct are what's used
+/// to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The ID of the asset being requested. This may be a complete URL, as
+    /// returned in a _Data Layers_ response, or just the value of its `id`
+    /// parameter.
+    id: String,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
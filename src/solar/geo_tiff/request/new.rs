@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+use crate::solar::geo_tiff::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a GeoTIFF query with the required,
+    /// non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `id` ‧ The GeoTIFF URL returned in a _Data Layers_ response (for
+    /// example, `dsm_url`) or the bare asset ID.
+
+    pub fn new(
+        client: &GoogleMapsClient,
+        id: String,
+    ) -> Request {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            id,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
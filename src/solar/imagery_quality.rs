@@ -0,0 +1,121 @@
+//! Contains the `ImageryQuality` enum and its associated traits. It is used to
+//! specify the minimum quality level of the imagery used to compute Solar API
+//! results, and to report the quality of the imagery that was actually used.
+
+use crate::solar::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// The [quality of the
+/// imagery](https://developers.google.com/maps/documentation/solar/reference/rest/v1/ImageryQuality)
+/// used to compute the data.
+///
+/// When used in a request as the `requiredQuality`, results will be restricted
+/// to imagery of at least this quality. For example, `Medium` will return
+/// either `High` or `Medium` quality results.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ImageryQuality {
+    /// Solar data is derived from aerial imagery captured at low-altitude and
+    /// processed at 0.1 m/pixel.
+    #[serde(alias = "HIGH")]
+    High,
+    /// Solar data is derived from enhanced aerial imagery captured at
+    /// high-altitude and processed at 0.25 m/pixel.
+    #[serde(alias = "MEDIUM")]
+    Medium,
+    /// Solar data is derived from enhanced satellite imagery processed at
+    /// 0.25 m/pixel.
+    #[serde(alias = "LOW")]
+    Low,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for ImageryQuality {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match ImageryQuality::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ImageryQuality> for String {
+    /// Converts an `ImageryQuality` enum to a `String` that contains an
+    /// imagery quality code.
+    fn from(imagery_quality: &ImageryQuality) -> String {
+        match imagery_quality {
+            ImageryQuality::High => String::from("HIGH"),
+            ImageryQuality::Medium => String::from("MEDIUM"),
+            ImageryQuality::Low => String::from("LOW"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static IMAGERY_QUALITIES_BY_CODE: phf::Map<&'static str, ImageryQuality> = phf_map! {
+    "HIGH" => ImageryQuality::High,
+    "MEDIUM" => ImageryQuality::Medium,
+    "LOW" => ImageryQuality::Low,
+};
+
+impl std::convert::TryFrom<&str> for ImageryQuality {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Error = crate::solar::error::Error;
+    /// Gets an `ImageryQuality` enum from a `String` that contains a valid
+    /// imagery quality code.
+    fn try_from(quality_code: &str) -> Result<Self, Self::Error> {
+        IMAGERY_QUALITIES_BY_CODE
+            .get(quality_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidImageryQuality(quality_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for ImageryQuality {
+    // Error definitions are contained in the
+    // `google_maps\src\solar\error.rs` module.
+    type Err = crate::solar::error::Error;
+    /// Gets an `ImageryQuality` enum from a `String` that contains a valid
+    /// imagery quality code.
+    fn from_str(quality_code: &str) -> Result<Self, Self::Err> {
+        IMAGERY_QUALITIES_BY_CODE
+            .get(quality_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidImageryQuality(quality_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for ImageryQuality {
+    /// Returns a reasonable default variant for the `ImageryQuality` enum
+    /// type. `High` matches the Solar API's own default for `requiredQuality`.
+    fn default() -> Self {
+        ImageryQuality::High
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ImageryQuality {
+    /// Formats an `ImageryQuality` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ImageryQuality::High => write!(f, "High"),
+            ImageryQuality::Medium => write!(f, "Medium"),
+            ImageryQuality::Low => write!(f, "Low"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,70 @@
+//! **Solar API** - Get rooftop solar potential, imagery and financial
+//! estimates for buildings.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/solar/overview)
+//!
+//! * **Before you begin**: Before you start using the Solar API, you need a
+//! project with a billing account and the Solar API enabled. We recommend
+//! creating multiple Project Owners and Billing Administrators, so that you'll
+//! always have someone with these roles available to your team. To learn more,
+//! see [Set up in Cloud Console](https://developers.google.com/maps/documentation/solar/cloud-setup).
+//!
+//! The Solar API offers many benefits to solar marketplace websites, solar
+//! installers, and solar SaaS designers. The API uses aerial imagery and
+//! digital surface models to estimate how much sunlight a roof receives, how
+//! many solar panels fit on it, and what installing them could save.
+//!
+//! # [Introduction](https://developers.google.com/maps/documentation/solar/overview#introduction)
+//!
+//! The Solar API is available via a simple HTTPS interface, and exposes the
+//! following services:
+//!
+//! * [Building insights](https://developers.google.com/maps/documentation/solar/building-insights)
+//! This service returns the location, dimensions, and solar potential of the
+//! building closest to a given location. It includes per-roof-segment
+//! statistics, a recommended set of panel configurations, and (in supported
+//! regions) financial analyses for each configuration.
+//!
+//! * [Data layers](https://developers.google.com/maps/documentation/solar/data-layers)
+//! This service returns URLs for raw solar information datasets for an area
+//! surrounding a location: a digital surface model, an RGB composite, a
+//! building mask, and annual, monthly & hourly solar flux layers.
+//!
+//! * [GeoTIFF](https://developers.google.com/maps/documentation/solar/reference/rest/v1/geoTiff/get)
+//! This service downloads one of the GeoTIFF files referenced by a _data
+//! layers_ response. The URLs returned by _data layers_ expire about an hour
+//! after they are generated.
+
+pub mod building_insights;
+pub mod data_layers;
+pub mod date;
+pub mod error;
+pub mod error_response;
+pub mod geo_tiff;
+pub mod imagery_quality;
+pub mod status;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::solar::{
+    date::Date as SolarDate,
+    error::Error as SolarError,
+    error_response::ErrorResponse as SolarErrorResponse,
+    imagery_quality::ImageryQuality,
+    status::Status as SolarStatus,
+}; // crate::solar
+
+pub use crate::solar::building_insights::{
+    request::Request as BuildingInsightsRequest,
+    response::Response as BuildingInsightsResponse,
+}; // crate::solar::building_insights
+
+pub use crate::solar::data_layers::{
+    request::{
+        data_layer_view::DataLayerView,
+        Request as DataLayersRequest,
+    }, // request
+    response::Response as DataLayersResponse,
+}; // crate::solar::data_layers
+
+pub use crate::solar::geo_tiff::request::Request as GeoTiffRequest;
@@ -0,0 +1,164 @@
+//! Contains the `TimeZoneCache` struct, which remembers the time zones of
+//! nearby locations so that the Time Zone API is queried as little as
+//! possible.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::time_zone::error::Error;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+// -----------------------------------------------------------------------------
+
+/// The default number of decimal places that coordinates are rounded to. Two
+/// decimal places is roughly 1 km at the equator.
+const DEFAULT_PRECISION: u32 = 2;
+
+// -----------------------------------------------------------------------------
+
+/// Looks up the time zones of locations with the Time Zone API, and remembers
+/// them by location.
+///
+/// Locations are rounded before they are looked up, so that places within
+/// about a kilometer of each other share a single query. Rounding to fewer
+/// decimal places saves queries, but may give the wrong time zone for places
+/// close to a time zone boundary.
+///
+/// ## Example:
+///
+/// ```rust
+/// let mut time_zones = TimeZoneCache::new(&google_maps_client).with_precision(1);
+/// let tz = time_zones.resolve(&LatLng::try_from_dec(dec!(50.09), dec!(14.40))?, Utc::now()).await?;
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct TimeZoneCache<'a> {
+    /// The client used to query the Time Zone API.
+    client: &'a GoogleMapsClient,
+
+    /// The number of decimal places that coordinates are rounded to.
+    precision: u32,
+
+    /// The time zones found so far, by rounded latitude and longitude.
+    time_zones: HashMap<(Decimal, Decimal), Tz>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> TimeZoneCache<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates an empty cache that queries the Time Zone API using the given
+    /// client.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    pub fn new(client: &'a GoogleMapsClient) -> Self {
+        TimeZoneCache {
+            client,
+            precision: DEFAULT_PRECISION,
+            time_zones: HashMap::new(),
+        } // TimeZoneCache
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the number of decimal places that coordinates are rounded to
+    /// before they are looked up. The default is 2, or roughly 1 km.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `precision` ‧ The number of decimal places. Each place fewer makes
+    /// the cached area roughly ten times wider.
+
+    pub fn with_precision(mut self, precision: u32) -> Self {
+        self.precision = precision;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a known time zone to the cache, for example one reported by a
+    /// transit step, so that it doesn't need to be queried.
+
+    pub fn insert(&mut self, location: &LatLng, time_zone: Tz) {
+        self.time_zones.insert(self.key(location), time_zone);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the cached time zone for a location, without querying the Time
+    /// Zone API.
+
+    pub fn get(&self, location: &LatLng) -> Option<Tz> {
+        self.time_zones.get(&self.key(location)).copied()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the time zone for a location, querying the Time Zone API if it
+    /// isn't already cached.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `location` ‧ The location to find the time zone of.
+    /// * `timestamp` ‧ The time to query the Time Zone API for. The time zone
+    /// itself doesn't depend on the time, but Google requires one.
+    ///
+    /// ## Errors:
+    ///
+    /// * Any error returned by the Time Zone API, such as
+    /// `Status::ZeroResults` for locations at sea.
+    /// * `Error::MissingTimeZoneId` if Google's response has no time zone.
+
+    pub async fn resolve(
+        &mut self,
+        location: &LatLng,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Tz, Error> {
+        if let Some(time_zone) = self.get(location) {
+            return Ok(time_zone);
+        } // if
+
+        let response = self.client
+            .time_zone(location.clone(), timestamp)
+            .execute()
+            .await?;
+
+        let time_zone = response.time_zone_id
+            .ok_or_else(|| Error::MissingTimeZoneId(location.clone()))?;
+
+        self.insert(location, time_zone);
+        Ok(time_zone)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of locations in the cache.
+
+    pub fn len(&self) -> usize {
+        self.time_zones.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the cache is empty.
+
+    pub fn is_empty(&self) -> bool {
+        self.time_zones.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Rounds a location to the cache's precision.
+
+    fn key(&self, location: &LatLng) -> (Decimal, Decimal) {
+        (location.lat.round_dp(self.precision), location.lng.round_dp(self.precision))
+    } // fn
+
+} // impl
@@ -1,5 +1,6 @@
 //! Time Zone API error types and error messages.
 
+use crate::latlng::LatLng;
 use crate::time_zone::response::status::Status;
 
 /// Errors that may be produced by the Google Maps Time Zone API client.
@@ -14,6 +15,9 @@ pub enum Error {
     /// status code. See `google_maps\src\time_zone\response\status.rs` for more
     /// information.
     InvalidStatusCode(String),
+    /// Google's response for the location did not include a time zone
+    /// identifier, so no `Tz` could be determined.
+    MissingTimeZoneId(LatLng),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Time Zone API server.
     QueryNotBuilt,
@@ -72,6 +76,8 @@ impl std::fmt::Display for Error {
                 Valid codes are `INVALID_REQUEST`, `OK`, `OVER_DAILY_LIMIT`, \
                 `OVER_QUERY_LIMIT`, `REQUEST_DENIED`, `UNKNOWN_ERROR`, and \
                 `ZERO_RESULTS`."),
+            Error::MissingTimeZoneId(location) => write!(f, "Google Maps Time Zone API client: \
+                The response for `{location}` did not include a time zone identifier."),
             Error::QueryNotBuilt => write!(f, "Google Maps Time Zone API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
@@ -94,6 +100,7 @@ impl std::error::Error for Error {
             Error::GoogleMapsService(_error, _message) => None,
             Error::HttpUnsuccessful(_status) => None,
             Error::InvalidStatusCode(_status_code) => None,
+            Error::MissingTimeZoneId(_location) => None,
             Error::QueryNotBuilt => None,
             #[cfg(feature = "enable-reqwest")]
             Error::Reqwest(error) => Some(error),
@@ -64,9 +64,13 @@
 //! on maps provided by one of the Google Maps Platform APIs. It provides an
 //! introduction to using the API and reference material on the available parameters.
 
+#[cfg(feature = "enable-reqwest")]
+pub mod cache;
 pub mod error;
 pub mod request;
 pub mod response;
+#[cfg(all(feature = "directions", feature = "enable-reqwest"))]
+pub mod route_schedule;
 
 // -----------------------------------------------------------------------------
 
@@ -0,0 +1,343 @@
+//! Contains the `Schedule` struct, which gives the expected time at every
+//! step of a Directions API route in the local time of each place.
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use crate::directions::response::{leg::Leg, route::Route};
+use crate::latlng::LatLng;
+use crate::time_zone::{cache::TimeZoneCache, error::Error, response::status::Status};
+
+// -----------------------------------------------------------------------------
+
+/// When the traveller is expected to start and finish one step of a route.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StepSchedule {
+    /// When the step starts, in the local time of its `start_location`. For
+    /// transit steps this is when the vehicle departs.
+    pub departure: DateTime<Tz>,
+
+    /// When the step ends, in the local time of its `end_location`.
+    pub arrival: DateTime<Tz>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// When the traveller is expected to start and finish one leg of a route, and
+/// each of its steps.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LegSchedule {
+    /// When the leg starts, in the local time of its `start_location`.
+    pub departure: DateTime<Tz>,
+
+    /// When the leg ends, in the local time of its `end_location`.
+    pub arrival: DateTime<Tz>,
+
+    /// The schedule for each of the leg's steps, in order.
+    pub steps: Vec<StepSchedule>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The expected times along a route. See `Route::schedule`.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Schedule {
+    /// The schedule for each of the route's legs, in order.
+    pub legs: Vec<LegSchedule>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Schedule {
+    /// Returns when the route starts, or `None` if it has no legs.
+    pub fn departure(&self) -> Option<DateTime<Tz>> {
+        self.legs.first().map(|leg| leg.departure)
+    } // fn
+
+    /// Returns when the route ends, or `None` if it has no legs. This is the
+    /// "arrive at" time, in the destination's local time.
+    pub fn arrival(&self) -> Option<DateTime<Tz>> {
+        self.legs.last().map(|leg| leg.arrival)
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl Route {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Works out when the traveller is expected at the end of each step and
+    /// leg of the route, in the local time zone of each place. This gives
+    /// correct "arrive at" times for routes that cross time zones.
+    ///
+    /// Each leg is assumed to start as soon as the previous one ends. Transit
+    /// steps use the departure and arrival times that Google reported. Other
+    /// steps add their duration to the previous step's arrival. When a leg
+    /// has a duration in traffic, its steps are stretched or shrunk to match
+    /// it.
+    ///
+    /// Time zones are looked up through the cache, which queries the Time
+    /// Zone API for locations it hasn't seen yet. Locations with no time zone,
+    /// such as those at sea on a ferry, keep the time zone of the place
+    /// before them.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `departure` ‧ When the traveller leaves the route's origin. The time
+    /// zone doesn't matter, since the times are converted to the local time
+    /// zone of the origin.
+    /// * `time_zones` ‧ The cache to look up time zones with. Reuse one cache
+    /// across routes in the same area to save Time Zone API queries.
+    ///
+    /// ## Errors:
+    ///
+    /// * Any error returned by the Time Zone API, apart from
+    /// `Status::ZeroResults`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let mut time_zones = TimeZoneCache::new(&google_maps_client);
+    /// let schedule = directions.routes[0].schedule(Utc::now().with_timezone(&Tz::UTC), &mut time_zones).await?;
+    ///
+    /// if let Some(arrival) = schedule.arrival() {
+    ///     println!("Arrive at {}", arrival.format("%H:%M %Z"));
+    /// }
+    /// ```
+
+    pub async fn schedule(
+        &self,
+        departure: DateTime<Tz>,
+        time_zones: &mut TimeZoneCache<'_>,
+    ) -> Result<Schedule, Error> {
+
+        let mut time = departure.with_timezone(&Utc);
+        let mut legs = Vec::with_capacity(self.legs.len());
+
+        // The time zone of the most recent place, for places that have none:
+        let mut time_zone = departure.timezone();
+        if let Some(first_leg) = self.legs.first() {
+            time_zone = local_time_zone(time_zones, &first_leg.start_location, time, time_zone).await?;
+        } // if
+
+        for leg in &self.legs {
+            let leg_departure = time.with_timezone(&time_zone);
+            let mut steps = Vec::with_capacity(leg.steps.len());
+            let mut elapsed = Duration::zero();
+
+            for step in &leg.steps {
+                let step_departure = time.with_timezone(&time_zone);
+
+                let (step_departure, arrival) = match &step.transit_details {
+                    Some(details) => {
+                        time_zones.insert(&step.start_location, details.departure_time.time_zone);
+                        time_zones.insert(&step.end_location, details.arrival_time.time_zone);
+                        (details.departure_time.local_time(), details.arrival_time.local_time())
+                    }, // Some
+                    None => {
+                        elapsed += step.duration.value;
+                        let arrival = match in_traffic(leg, elapsed) {
+                            Some(elapsed_in_traffic) => leg_departure.with_timezone(&Utc) + elapsed_in_traffic,
+                            None => time + step.duration.value,
+                        }; // match
+                        time_zone = local_time_zone(time_zones, &step.end_location, arrival, time_zone).await?;
+                        (step_departure, arrival.with_timezone(&time_zone))
+                    }, // None
+                }; // match
+
+                time = arrival.with_timezone(&Utc);
+                time_zone = arrival.timezone();
+                steps.push(StepSchedule { departure: step_departure, arrival });
+            } // for
+
+            // Legs from Google always have steps, but just in case:
+            if leg.steps.is_empty() {
+                time += leg.duration_in_traffic.as_ref().unwrap_or(&leg.duration).value;
+                time_zone = local_time_zone(time_zones, &leg.end_location, time, time_zone).await?;
+            } // if
+
+            legs.push(LegSchedule {
+                departure: leg_departure,
+                arrival: time.with_timezone(&time_zone),
+                steps,
+            }); // push
+        } // for
+
+        Ok(Schedule { legs })
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the time zone of a location from the cache, or the given fallback
+/// time zone if the Time Zone API has none for the location.
+
+async fn local_time_zone(
+    time_zones: &mut TimeZoneCache<'_>,
+    location: &LatLng,
+    time: DateTime<Utc>,
+    fallback: Tz,
+) -> Result<Tz, Error> {
+    match time_zones.resolve(location, time).await {
+        Ok(time_zone) => Ok(time_zone),
+        Err(Error::GoogleMapsService(Status::ZeroResults, _)) => Ok(fallback),
+        Err(error) => Err(error),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Scales the time elapsed since the start of a leg, going by its steps'
+/// durations, to the leg's duration in traffic. Returns `None` if the leg has
+/// no duration in traffic.
+
+fn in_traffic(leg: &Leg, elapsed: Duration) -> Option<Duration> {
+    let duration_in_traffic = leg.duration_in_traffic.as_ref()?.value.num_seconds();
+    let duration = leg.duration.value.num_seconds();
+    (duration > 0).then(|| Duration::seconds(elapsed.num_seconds() * duration_in_traffic / duration))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GoogleMapsClient;
+    use crate::directions::response::{directions_duration::DirectionsDuration, Response};
+    use chrono::TimeZone;
+    use chrono_tz::America::{Chicago, Indiana::Indianapolis, New_York};
+    use futures::executor::block_on;
+
+    /// A three hour drive from Chicago to Indianapolis, which crosses from
+    /// Central into Eastern time after Gary. The leg takes 3 hours 36 minutes
+    /// in traffic.
+    fn drive() -> Route {
+        include_str!("../directions/response/fixtures/driving.json")
+            .parse::<Response>()
+            .unwrap()
+            .routes
+            .remove(0)
+    } // fn
+
+    /// A cache that already knows the time zone of every place on the drive,
+    /// so that no Time Zone API queries are made.
+    fn time_zones<'a>(client: &'a GoogleMapsClient, route: &Route) -> TimeZoneCache<'a> {
+        let mut time_zones = TimeZoneCache::new(client);
+        let leg = &route.legs[0];
+        time_zones.insert(&leg.start_location, Chicago);
+        time_zones.insert(&leg.steps[0].end_location, Chicago);
+        time_zones.insert(&leg.end_location, Indianapolis);
+        time_zones
+    } // fn
+
+    fn times(schedule: &Schedule) -> Vec<(String, String)> {
+        schedule.legs[0].steps
+            .iter()
+            .map(|step| (step.departure.to_rfc3339(), step.arrival.to_rfc3339()))
+            .collect()
+    } // fn
+
+    #[test]
+    fn gives_local_times_across_time_zones() {
+        let client = GoogleMapsClient::new("x");
+        let mut route = drive();
+        route.legs[0].duration_in_traffic = None;
+        let mut time_zones = time_zones(&client, &route);
+
+        let departure = Chicago.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let schedule = block_on(route.schedule(departure, &mut time_zones)).unwrap();
+
+        assert_eq!(times(&schedule), [
+            (String::from("2026-03-20T09:00:00-05:00"), String::from("2026-03-20T09:30:00-05:00")),
+            (String::from("2026-03-20T09:30:00-05:00"), String::from("2026-03-20T13:00:00-04:00")),
+        ]); // assert_eq!
+        assert_eq!(schedule.departure(), Some(departure));
+        let arrival = schedule.arrival().unwrap();
+        assert_eq!(arrival.timezone(), Indianapolis);
+        assert_eq!(arrival, Indianapolis.with_ymd_and_hms(2026, 3, 20, 13, 0, 0).unwrap());
+        assert_eq!(time_zones.len(), 3);
+    } // fn
+
+    #[test]
+    fn converts_the_departure_to_the_origin_time_zone() {
+        let client = GoogleMapsClient::new("x");
+        let mut route = drive();
+        route.legs[0].duration_in_traffic = None;
+        let mut time_zones = time_zones(&client, &route);
+
+        let departure = Tz::UTC.with_ymd_and_hms(2026, 3, 20, 14, 0, 0).unwrap();
+        let schedule = block_on(route.schedule(departure, &mut time_zones)).unwrap();
+
+        assert_eq!(schedule.departure().unwrap().to_rfc3339(), "2026-03-20T09:00:00-05:00");
+    } // fn
+
+    #[test]
+    fn stretches_steps_to_the_duration_in_traffic() {
+        let client = GoogleMapsClient::new("x");
+        let route = drive();
+        let mut time_zones = time_zones(&client, &route);
+
+        let departure = Chicago.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let schedule = block_on(route.schedule(departure, &mut time_zones)).unwrap();
+
+        // Each step takes 20% longer than its duration:
+        assert_eq!(times(&schedule), [
+            (String::from("2026-03-20T09:00:00-05:00"), String::from("2026-03-20T09:36:00-05:00")),
+            (String::from("2026-03-20T09:36:00-05:00"), String::from("2026-03-20T13:36:00-04:00")),
+        ]); // assert_eq!
+    } // fn
+
+    #[test]
+    fn shrinks_steps_to_a_shorter_duration_in_traffic() {
+        let client = GoogleMapsClient::new("x");
+        let mut route = drive();
+        route.legs[0].duration_in_traffic = Some(DirectionsDuration {
+            text: String::from("2 hours 30 mins"),
+            value: Duration::minutes(150),
+        }); // Some
+        let mut time_zones = time_zones(&client, &route);
+
+        let departure = Chicago.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let schedule = block_on(route.schedule(departure, &mut time_zones)).unwrap();
+
+        assert_eq!(times(&schedule)[0].1, "2026-03-20T09:25:00-05:00");
+        assert_eq!(schedule.arrival().unwrap().to_rfc3339(), "2026-03-20T12:30:00-04:00");
+    } // fn
+
+    #[test]
+    fn uses_google_times_for_transit_steps() {
+        let client = GoogleMapsClient::new("x");
+        let route = include_str!("../directions/response/fixtures/transit.json")
+            .parse::<Response>()
+            .unwrap()
+            .routes
+            .remove(0);
+
+        // The transit steps add their own stops to the cache, so only the
+        // origin and the end of the first walk need to be known:
+        let mut time_zones = TimeZoneCache::new(&client);
+        time_zones.insert(&route.legs[0].start_location, New_York);
+        time_zones.insert(&route.legs[0].steps[0].end_location, New_York);
+
+        let departure = New_York.with_ymd_and_hms(2026, 3, 20, 8, 0, 0).unwrap();
+        let schedule = block_on(route.schedule(departure, &mut time_zones)).unwrap();
+
+        let at = |hour, minute| New_York.with_ymd_and_hms(2026, 3, 20, hour, minute, 0).unwrap();
+        let steps: Vec<(DateTime<Tz>, DateTime<Tz>)> = schedule.legs[0].steps
+            .iter()
+            .map(|step| (step.departure, step.arrival))
+            .collect();
+        assert_eq!(steps, [
+            (at(8, 0), at(8, 5)),
+            (at(8, 7), at(8, 22)),
+            (at(8, 22), at(8, 35)),
+            (at(8, 35), at(8, 40)),
+        ]); // assert_eq!
+        assert_eq!(schedule.arrival(), Some(at(8, 40)));
+    } // fn
+} // mod
@@ -0,0 +1,86 @@
+//! Departure sweep error types and error messages.
+
+use chrono::{DateTime, Utc};
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when sweeping a request across departure
+/// times.
+
+#[derive(Debug)]
+pub enum Error {
+    /// A departure time is in the past. Google only predicts traffic for the
+    /// present and the future.
+    DepartureInPast(DateTime<Utc>),
+    /// An error returned by the Directions API client.
+    Directions(crate::directions::error::Error),
+    /// An error returned by the Distance Matrix API client.
+    #[cfg(feature = "distance_matrix")]
+    DistanceMatrix(crate::distance_matrix::error::Error),
+    /// The sweep has no departure times.
+    NoDepartures,
+    /// The sweep has no traffic models.
+    NoTrafficModels,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::DepartureInPast(departure) => write!(f,
+                "Google Maps Platform API client: \
+                the departure time {departure} is in the past. \
+                Google only predicts traffic for departures from now on."),
+            Error::Directions(error) => write!(f, "{error}"),
+            #[cfg(feature = "distance_matrix")]
+            Error::DistanceMatrix(error) => write!(f, "{error}"),
+            Error::NoDepartures => write!(f,
+                "Google Maps Platform API client: \
+                at least one departure time is required to sweep a request. \
+                Add departures with with_departures() or with_schedule(), \
+                and make sure that the schedule's interval is positive."),
+            Error::NoTrafficModels => write!(f,
+                "Google Maps Platform API client: \
+                at least one traffic model is required to sweep a request."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DepartureInPast(_departure) => None,
+            Error::Directions(error) => error.source(),
+            #[cfg(feature = "distance_matrix")]
+            Error::DistanceMatrix(error) => error.source(),
+            Error::NoDepartures => None,
+            Error::NoTrafficModels => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<crate::directions::error::Error> for Error {
+    /// Wraps a Directions API client error.
+    fn from(error: crate::directions::error::Error) -> Error {
+        Error::Directions(error)
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl From<crate::distance_matrix::error::Error> for Error {
+    /// Wraps a Distance Matrix API client error.
+    fn from(error: crate::distance_matrix::error::Error) -> Error {
+        Error::DistanceMatrix(error)
+    } // fn
+} // impl
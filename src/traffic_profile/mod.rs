@@ -0,0 +1,413 @@
+//! Contains the `DepartureSweep` executor. It sends a Directions API or
+//! Distance Matrix API query once for every departure time in a schedule and
+//! every traffic model. It then gathers the predicted durations in traffic
+//! into a time series for each origin and destination.
+//!
+//! Such traffic profiles show how travel times change over the day and the
+//! week. This is useful for setting delivery windows, or for picking the best
+//! time to leave.
+//!
+//! Each departure time and traffic model is one request to Google. Every
+//! request goes through the client's `RequestRate`, and only a few are in
+//! flight at once. A week of departures every 15 minutes with three traffic
+//! models is 2,016 requests, so check your quota before sweeping.
+//!
+//! ## Example:
+//!
+//! ```rust
+//! let start = Utc::now() + Duration::hours(1);
+//!
+//! let profiles = DepartureSweep::new(
+//!         google_maps_client
+//!             .distance_matrix(depots, customers)
+//!             .with_travel_mode(TravelMode::Driving)
+//!             .clone()
+//!     )
+//!     .with_schedule(start, start + Duration::weeks(1), Duration::minutes(15))
+//!     .with_traffic_models([
+//!         TrafficModel::Optimistic,
+//!         TrafficModel::BestGuess,
+//!         TrafficModel::Pessimistic,
+//!     ])
+//!     .execute()
+//!     .await?;
+//!
+//! for profile in &profiles.profiles {
+//!     if let Some(slowest) = profile.slowest() {
+//!         println!(
+//!             "{} to {} ({}): up to {} when leaving at {}",
+//!             profile.origin_address,
+//!             profile.destination_address,
+//!             profile.traffic_model,
+//!             slowest.duration_in_traffic.unwrap_or_default(),
+//!             slowest.departure,
+//!         );
+//!     }
+//! }
+//! ```
+
+pub mod error;
+mod profile;
+mod response;
+mod sweep_failure;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::traffic_profile::{
+    profile::{TrafficProfile, TrafficSample},
+    response::TrafficProfileResponse,
+    sweep_failure::SweepFailure,
+}; // crate::traffic_profile
+
+use crate::directions::request::traffic_model::TrafficModel;
+use crate::request_rate::DEFAULT_MAX_CONCURRENT_REQUESTS;
+use crate::traffic_profile::{error::Error, response::Observation};
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+
+// -----------------------------------------------------------------------------
+//
+/// A query that a `DepartureSweep` may send. Both request types convert into
+/// this enum, so either may be passed to `DepartureSweep::new`.
+
+#[derive(Clone, Debug)]
+pub enum SweepRequest<'a> {
+    /// A Directions API query. Its profile has one origin and destination.
+    Directions(crate::directions::request::Request<'a>),
+    /// A Distance Matrix API query. It has one profile for each origin and
+    /// destination.
+    #[cfg(feature = "distance_matrix")]
+    DistanceMatrix(crate::distance_matrix::request::Request<'a>),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'a> From<crate::directions::request::Request<'a>> for SweepRequest<'a> {
+    /// Wraps a Directions API query.
+    fn from(request: crate::directions::request::Request<'a>) -> SweepRequest<'a> {
+        SweepRequest::Directions(request)
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl<'a> From<crate::distance_matrix::request::Request<'a>> for SweepRequest<'a> {
+    /// Wraps a Distance Matrix API query.
+    fn from(request: crate::distance_matrix::request::Request<'a>) -> SweepRequest<'a> {
+        SweepRequest::DistanceMatrix(request)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> SweepRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Checks the query's options for one departure time and traffic model,
+    /// without sending it.
+
+    fn validate(&self, departure: DateTime<Utc>, traffic_model: TrafficModel) -> Result<(), Error> {
+        match self {
+            SweepRequest::Directions(request) => {
+                request.at_departure(departure, traffic_model).validate()?;
+            }, // Directions
+            #[cfg(feature = "distance_matrix")]
+            SweepRequest::DistanceMatrix(request) => {
+                request.at_departure(departure, traffic_model).validate()?;
+            }, // DistanceMatrix
+        } // match
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends the query for one departure time and traffic model. Returns
+    /// `Error::DepartureInPast`, without sending anything, if the departure
+    /// time has passed.
+
+    async fn execute(
+        &self,
+        departure: DateTime<Utc>,
+        traffic_model: TrafficModel,
+    ) -> Result<Observation, Error> {
+        // Long sweeps may still be running when their earliest departures
+        // pass, and Google rejects departure times in the past:
+        if departure < Utc::now() {
+            return Err(Error::DepartureInPast(departure));
+        } // if
+
+        match self {
+            SweepRequest::Directions(request) => {
+                let mut request = request.at_departure(departure, traffic_model);
+                Ok(Observation::from(request.execute().await?))
+            }, // Directions
+            #[cfg(feature = "distance_matrix")]
+            SweepRequest::DistanceMatrix(request) => {
+                let mut request = request.at_departure(departure, traffic_model);
+                Ok(Observation::from(request.execute().await?))
+            }, // DistanceMatrix
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Sends a query once for every departure time and traffic model, and gathers
+/// the predicted travel times into a profile for each origin and destination.
+
+#[derive(Clone, Debug)]
+pub struct DepartureSweep<'a> {
+    /// The query. Its options, apart from the departure time and traffic
+    /// model, are applied to every request.
+    request: SweepRequest<'a>,
+
+    /// The departure times to send the query for.
+    departures: Vec<DateTime<Utc>>,
+
+    /// The traffic models to send the query with.
+    traffic_models: Vec<TrafficModel>,
+
+    /// The maximum number of requests in flight at once.
+    max_concurrent_requests: usize,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> DepartureSweep<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a Directions API or Distance Matrix API query so that it may be
+    /// sent for many departure times. The sweep has no departure times and
+    /// uses the `TrafficModel::BestGuess` traffic model until they are
+    /// changed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The query, built as usual with the
+    /// `GoogleMapsClient.directions()` or `GoogleMapsClient.distance_matrix()`
+    /// builder. Google only predicts traffic for driving, so the travel mode
+    /// should be `TravelMode::Driving`. Any departure time or traffic model
+    /// set on the query is replaced.
+
+    pub fn new(request: impl Into<SweepRequest<'a>>) -> DepartureSweep<'a> {
+        DepartureSweep {
+            request: request.into(),
+            departures: Vec::new(),
+            traffic_models: vec![TrafficModel::BestGuess],
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        } // DepartureSweep
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds departure times to the sweep.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `departures` ‧ The departure times. They must not be in the past.
+
+    pub fn with_departures(
+        &mut self,
+        departures: impl IntoIterator<Item = DateTime<Utc>>,
+    ) -> &mut DepartureSweep<'a> {
+        self.departures.extend(departures);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds evenly spaced departure times to the sweep, for example every 15
+    /// minutes for a week.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `start` ‧ The first departure time. It must not be in the past.
+    ///
+    /// * `end` ‧ The end of the schedule. Departures are added up to, but not
+    /// including, this time.
+    ///
+    /// * `interval` ‧ The time between departures. No departures are added if
+    /// it isn't positive.
+
+    pub fn with_schedule(
+        &mut self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interval: Duration,
+    ) -> &mut DepartureSweep<'a> {
+        if interval > Duration::zero() {
+            let mut departure = start;
+            while departure < end {
+                self.departures.push(departure);
+                departure += interval;
+            } // while
+        } // if
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the traffic models to sweep with. Each traffic model gets its own
+    /// profile, and its own request for every departure time. The default is
+    /// `TrafficModel::BestGuess` alone.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `traffic_models` ‧ The traffic models, for example
+    /// `[TrafficModel::Optimistic, TrafficModel::Pessimistic]` for the range
+    /// of likely travel times.
+
+    pub fn with_traffic_models(
+        &mut self,
+        traffic_models: impl IntoIterator<Item = TrafficModel>,
+    ) -> &mut DepartureSweep<'a> {
+        self.traffic_models = traffic_models.into_iter().collect();
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of departure times and traffic models that
+    /// are requested at once, 4 by default. At least one request is always
+    /// allowed, and the client's `RequestRate` still applies to every request.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `max_concurrent_requests` ‧ The number of concurrent requests.
+
+    pub fn with_max_concurrent_requests(
+        &mut self,
+        max_concurrent_requests: usize,
+    ) -> &mut DepartureSweep<'a> {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of requests that the sweep will send: one for every
+    /// departure time and traffic model.
+
+    pub fn request_count(&self) -> usize {
+        self.departures.len() * self.traffic_models.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the sweep, one request per departure time and traffic model,
+    /// and gathers the results into profiles.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns an error if the sweep has no departure times or traffic
+    /// models, if the earliest departure time is in the past, or if the
+    /// query's options are invalid, for example an arrival time. No requests
+    /// are sent in these cases.
+    ///
+    /// * Errors from individual requests are not returned here. They are
+    /// listed in the response's `failures` field, and the successful requests
+    /// are still returned.
+    ///
+    /// * Each departure time is checked again just before its request is
+    /// sent. A departure that has passed by then, which can happen when a
+    /// sweep runs for a long time or is throttled by the client's
+    /// `RequestRate`, is not sent. It is listed in `failures` with
+    /// `Error::DepartureInPast`.
+
+    pub async fn execute(&self) -> Result<TrafficProfileResponse, Error> {
+
+        let first_departure = self.departures.iter().min().ok_or(Error::NoDepartures)?;
+        let first_traffic_model = self.traffic_models.first().ok_or(Error::NoTrafficModels)?;
+
+        if *first_departure < Utc::now() {
+            return Err(Error::DepartureInPast(*first_departure));
+        } // if
+
+        // Every request shares the same options, so validating the first one
+        // reports option errors once instead of once per request:
+        self.request.validate(*first_departure, first_traffic_model.clone())?;
+
+        let requests = self.departures.iter().flat_map(|departure| {
+            self.traffic_models.iter().map(move |traffic_model| (*departure, traffic_model.clone()))
+        }); // flat_map
+
+        let results: Vec<(DateTime<Utc>, TrafficModel, Result<Observation, Error>)> =
+            stream::iter(requests)
+                .map(|(departure, traffic_model)| async move {
+                    let result = self.request.execute(departure, traffic_model.clone()).await;
+                    (departure, traffic_model, result)
+                }) // map
+                .buffer_unordered(self.max_concurrent_requests)
+                .collect()
+                .await;
+
+        Ok(response::assemble(results))
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GoogleMapsClient;
+    use crate::directions::request::location::Location;
+    use futures::executor::block_on;
+
+    fn request(client: &GoogleMapsClient) -> SweepRequest<'_> {
+        client
+            .directions(
+                Location::Address(String::from("Chicago, IL")),
+                Location::Address(String::from("Indianapolis, IN")),
+            ) // directions
+            .into()
+    } // fn
+
+    #[test]
+    fn does_not_send_departures_that_have_passed() {
+        let client = GoogleMapsClient::new("x");
+        let departure = Utc::now() - Duration::minutes(1);
+        let result = block_on(request(&client).execute(departure, TrafficModel::BestGuess));
+        assert!(matches!(result, Err(Error::DepartureInPast(past)) if past == departure));
+    } // fn
+
+    #[test]
+    fn rejects_sweeps_that_start_in_the_past() {
+        let client = GoogleMapsClient::new("x");
+        let start = Utc::now() - Duration::hours(1);
+        let result = block_on(
+            DepartureSweep::new(request(&client))
+                .with_schedule(start, start + Duration::hours(2), Duration::minutes(30))
+                .execute()
+        ); // block_on
+        assert!(matches!(result, Err(Error::DepartureInPast(past)) if past == start));
+    } // fn
+
+    #[test]
+    fn rejects_sweeps_without_departures_or_traffic_models() {
+        let client = GoogleMapsClient::new("x");
+        let start = Utc::now() + Duration::hours(1);
+
+        let mut sweep = DepartureSweep::new(request(&client));
+        sweep.with_schedule(start, start + Duration::hours(1), Duration::zero());
+        assert!(matches!(block_on(sweep.execute()), Err(Error::NoDepartures)));
+
+        sweep.with_departures([start]).with_traffic_models([]);
+        assert!(matches!(block_on(sweep.execute()), Err(Error::NoTrafficModels)));
+    } // fn
+
+    #[test]
+    fn counts_one_request_per_departure_and_traffic_model() {
+        let client = GoogleMapsClient::new("x");
+        let start = Utc::now() + Duration::hours(1);
+        let count = DepartureSweep::new(request(&client))
+            .with_schedule(start, start + Duration::hours(2), Duration::minutes(15))
+            .with_traffic_models([TrafficModel::Optimistic, TrafficModel::Pessimistic])
+            .request_count();
+        assert_eq!(count, 16);
+    } // fn
+} // mod
@@ -0,0 +1,137 @@
+//! Contains the `TrafficSample` and `TrafficProfile` structs, which hold the
+//! travel times predicted for one origin and destination across a sweep of
+//! departure times.
+
+use crate::directions::request::traffic_model::TrafficModel;
+use chrono::{DateTime, Duration, Utc};
+
+// -----------------------------------------------------------------------------
+
+/// The travel time predicted for one departure time.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TrafficSample {
+    /// When the traveller departs.
+    pub departure: DateTime<Utc>,
+
+    /// The travel time without traffic. This is `None` if Google found no
+    /// route for this departure.
+    pub duration: Option<Duration>,
+
+    /// The travel time that the traffic model predicts for this departure.
+    /// This is `None` if Google found no route, or didn't predict traffic,
+    /// for example because the travel mode isn't driving.
+    pub duration_in_traffic: Option<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TrafficSample {
+    /// Returns the predicted travel time: the duration in traffic if there is
+    /// one, otherwise the duration without traffic.
+    pub fn travel_time(&self) -> Option<Duration> {
+        self.duration_in_traffic.or(self.duration)
+    } // fn
+
+    /// Returns when the traveller is predicted to arrive.
+    pub fn arrival(&self) -> Option<DateTime<Utc>> {
+        self.travel_time().map(|travel_time| self.departure + travel_time)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The travel times predicted by one traffic model for one origin and
+/// destination, in order of departure.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TrafficProfile {
+    /// The index of the origin in the request. This is always 0 for
+    /// Directions API requests.
+    pub origin: usize,
+
+    /// The index of the destination in the request. This is always 0 for
+    /// Directions API requests.
+    pub destination: usize,
+
+    /// The origin's address as Google geocoded it. This is empty if no
+    /// request for it succeeded.
+    pub origin_address: String,
+
+    /// The destination's address as Google geocoded it. This is empty if no
+    /// request for it succeeded.
+    pub destination_address: String,
+
+    /// The traffic model that predicted the travel times.
+    pub traffic_model: TrafficModel,
+
+    /// The predicted travel time for each departure time whose request
+    /// succeeded, in order of departure.
+    pub samples: Vec<TrafficSample>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TrafficProfile {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the sample with the shortest travel time, or `None` if no
+    /// sample has a travel time. Ties go to the earliest departure.
+
+    pub fn fastest(&self) -> Option<&TrafficSample> {
+        self.samples
+            .iter()
+            .filter_map(|sample| sample.travel_time().map(|travel_time| (travel_time, sample)))
+            .min_by_key(|(travel_time, _sample)| *travel_time)
+            .map(|(_travel_time, sample)| sample)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the sample with the longest travel time, or `None` if no sample
+    /// has a travel time. Ties go to the earliest departure.
+
+    pub fn slowest(&self) -> Option<&TrafficSample> {
+        self.samples
+            .iter()
+            .filter_map(|sample| sample.travel_time().map(|travel_time| (travel_time, sample)))
+            .rev()
+            .max_by_key(|(travel_time, _sample)| *travel_time)
+            .map(|(_travel_time, sample)| sample)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the mean travel time across the samples that have one, or
+    /// `None` if none do.
+
+    pub fn mean_travel_time(&self) -> Option<Duration> {
+        let travel_times: Vec<Duration> = self.samples
+            .iter()
+            .filter_map(TrafficSample::travel_time)
+            .collect();
+
+        let count = i64::try_from(travel_times.len()).ok().filter(|count| *count > 0)?;
+        let total: i64 = travel_times.iter().map(Duration::num_seconds).sum();
+        Some(Duration::seconds(total / count))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the sample for the latest departure that arrives no later than
+    /// the given deadline, or `None` if no departure does. This is the latest
+    /// time the traveller can leave and still be on time.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `deadline` ‧ When the traveller must arrive by.
+
+    pub fn latest_departure_for(&self, deadline: DateTime<Utc>) -> Option<&TrafficSample> {
+        self.samples
+            .iter()
+            .filter(|sample| sample.arrival().is_some_and(|arrival| arrival <= deadline))
+            .max_by_key(|sample| sample.departure)
+    } // fn
+
+} // impl
@@ -0,0 +1,185 @@
+//! Contains the `TrafficProfileResponse` struct, and the function that
+//! gathers the responses for the individual departures into profiles.
+
+use crate::directions::request::traffic_model::TrafficModel;
+use crate::traffic_profile::{
+    error::Error,
+    profile::{TrafficProfile, TrafficSample},
+    sweep_failure::SweepFailure,
+}; // crate::traffic_profile
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+
+/// The result of a `DepartureSweep`.
+
+#[derive(Debug)]
+pub struct TrafficProfileResponse {
+    /// One profile for each origin, destination and traffic model, ordered by
+    /// origin, then destination, then traffic model.
+    pub profiles: Vec<TrafficProfile>,
+
+    /// The departures whose requests failed, and why, in order of departure.
+    /// This is empty if every request succeeded.
+    pub failures: Vec<SweepFailure>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TrafficProfileResponse {
+    /// Returns whether every request of the sweep succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    } // fn
+
+    /// Returns the profile for an origin, destination and traffic model, if
+    /// the sweep has one.
+    pub fn profile(
+        &self,
+        origin: usize,
+        destination: usize,
+        traffic_model: &TrafficModel,
+    ) -> Option<&TrafficProfile> {
+        self.profiles.iter().find(|profile| {
+            profile.origin == origin
+                && profile.destination == destination
+                && &profile.traffic_model == traffic_model
+        }) // find
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The travel times from one response, for every origin and destination of
+/// the request.
+
+pub(crate) struct Observation {
+    /// The origins' addresses, in request order.
+    pub(crate) origin_addresses: Vec<String>,
+
+    /// The destinations' addresses, in request order.
+    pub(crate) destination_addresses: Vec<String>,
+
+    /// The duration and duration in traffic, by origin and then destination.
+    pub(crate) rows: Vec<Vec<(Option<Duration>, Option<Duration>)>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl From<crate::directions::response::Response> for Observation {
+    /// Takes the travel times of the first route. Its duration in traffic is
+    /// only known when every leg has one.
+    fn from(response: crate::directions::response::Response) -> Observation {
+        let legs = response.routes.first().map(|route| route.legs.as_slice()).unwrap_or_default();
+
+        let duration = (!legs.is_empty())
+            .then(|| legs.iter().map(|leg| leg.duration.value).sum());
+
+        let duration_in_traffic = legs
+            .iter()
+            .map(|leg| leg.duration_in_traffic.as_ref().map(|duration| duration.value))
+            .sum::<Option<Duration>>()
+            .filter(|_duration| duration.is_some());
+
+        Observation {
+            origin_addresses: vec![legs.first().map(|leg| leg.start_address.clone()).unwrap_or_default()],
+            destination_addresses: vec![legs.last().map(|leg| leg.end_address.clone()).unwrap_or_default()],
+            rows: vec![vec![(duration, duration_in_traffic)]],
+        } // Observation
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl From<crate::distance_matrix::response::Response> for Observation {
+    /// Takes the travel times of every element. Elements that Google found no
+    /// route for have neither.
+    fn from(response: crate::distance_matrix::response::Response) -> Observation {
+        Observation {
+            origin_addresses: response.origin_addresses,
+            destination_addresses: response.destination_addresses,
+            rows: response.rows
+                .into_iter()
+                .map(|row| row.elements
+                    .into_iter()
+                    .map(|element| (
+                        element.duration.map(|duration| duration.value),
+                        element.duration_in_traffic.map(|duration| duration.value),
+                    )) // map
+                    .collect()
+                ) // map
+                .collect(),
+        } // Observation
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Gathers the responses for the individual departures and traffic models
+/// into one profile per origin, destination and traffic model. Results may be
+/// given in any order.
+///
+/// ## Arguments:
+///
+/// * `results` ‧ Each departure and traffic model along with the outcome of
+/// its request.
+
+pub(crate) fn assemble(
+    results: Vec<(DateTime<Utc>, TrafficModel, Result<Observation, Error>)>,
+) -> TrafficProfileResponse {
+
+    let mut profiles: BTreeMap<(usize, usize, TrafficModel), TrafficProfile> = BTreeMap::new();
+    let mut failures = Vec::new();
+
+    for (departure, traffic_model, result) in results {
+        let observation = match result {
+            Ok(observation) => observation,
+            Err(error) => {
+                failures.push(SweepFailure { departure, traffic_model, error });
+                continue;
+            }, // Err
+        }; // match
+
+        for (origin, row) in observation.rows.into_iter().enumerate() {
+            for (destination, (duration, duration_in_traffic)) in row.into_iter().enumerate() {
+                let profile = profiles
+                    .entry((origin, destination, traffic_model.clone()))
+                    .or_insert_with(|| TrafficProfile {
+                        origin,
+                        destination,
+                        origin_address: String::new(),
+                        destination_address: String::new(),
+                        traffic_model: traffic_model.clone(),
+                        samples: Vec::new(),
+                    }); // or_insert_with
+
+                // Keep the first address that Google returned:
+                if profile.origin_address.is_empty() {
+                    if let Some(address) = observation.origin_addresses.get(origin) {
+                        profile.origin_address.clone_from(address);
+                    } // if
+                } // if
+
+                if profile.destination_address.is_empty() {
+                    if let Some(address) = observation.destination_addresses.get(destination) {
+                        profile.destination_address.clone_from(address);
+                    } // if
+                } // if
+
+                profile.samples.push(TrafficSample { departure, duration, duration_in_traffic });
+            } // for
+        } // for
+    } // for
+
+    // Requests finish in any order, so put the samples and failures back in
+    // order of departure:
+    let mut profiles: Vec<TrafficProfile> = profiles.into_values().collect();
+    for profile in &mut profiles {
+        profile.samples.sort_by_key(|sample| sample.departure);
+    } // for
+
+    failures.sort_by(|a, b| (a.departure, &a.traffic_model).cmp(&(b.departure, &b.traffic_model)));
+
+    TrafficProfileResponse { profiles, failures }
+
+} // fn
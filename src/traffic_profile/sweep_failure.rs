@@ -0,0 +1,39 @@
+//! Contains the `SweepFailure` struct, which describes a departure time and
+//! traffic model whose request could not be completed.
+
+use crate::directions::request::traffic_model::TrafficModel;
+use crate::traffic_profile::error::Error;
+use chrono::{DateTime, Utc};
+
+// -----------------------------------------------------------------------------
+
+/// A request of a departure sweep that failed. Its departure time is missing
+/// from the samples of the traffic model's profiles.
+
+#[derive(Debug)]
+pub struct SweepFailure {
+    /// The departure time that could not be retrieved.
+    pub departure: DateTime<Utc>,
+
+    /// The traffic model that could not be retrieved.
+    pub traffic_model: TrafficModel,
+
+    /// The error that the request returned.
+    pub error: Error,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SweepFailure {
+    /// Formats the failed departure and its error in a way that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{departure} ({traffic_model}): {error}",
+            departure=self.departure,
+            traffic_model=self.traffic_model,
+            error=self.error,
+        ) // write!
+    } // fn
+} // impl
@@ -0,0 +1,20 @@
+//! Contains the `AirPressure` struct. It reports the atmospheric pressure at a
+//! location.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the atmospheric [air
+/// pressure](https://developers.google.com/maps/documentation/weather/reference/rest/v1/AirPressure)
+/// conditions.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AirPressure {
+
+    /// The mean sea level air pressure in millibars. This value is reported
+    /// in millibars regardless of the `UnitSystem` requested.
+    #[serde(alias = "meanSeaLevelMillibars")]
+    pub mean_sea_level_millibars: Option<f64>,
+
+} // struct
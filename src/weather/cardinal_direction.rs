@@ -0,0 +1,190 @@
+//! Contains the `CardinalDirection` enum and its associated traits. It is
+//! used to express the direction that the wind is blowing from.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Represents a [cardinal
+/// direction](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Wind#cardinaldirection)
+/// (including ordinal directions) on a 16-point compass rose.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum CardinalDirection {
+    /// North direction.
+    #[serde(alias = "NORTH")]
+    North,
+    /// North-northeast direction.
+    #[serde(alias = "NORTH_NORTHEAST")]
+    NorthNortheast,
+    /// Northeast direction.
+    #[serde(alias = "NORTHEAST")]
+    Northeast,
+    /// East-northeast direction.
+    #[serde(alias = "EAST_NORTHEAST")]
+    EastNortheast,
+    /// East direction.
+    #[serde(alias = "EAST")]
+    East,
+    /// East-southeast direction.
+    #[serde(alias = "EAST_SOUTHEAST")]
+    EastSoutheast,
+    /// Southeast direction.
+    #[serde(alias = "SOUTHEAST")]
+    Southeast,
+    /// South-southeast direction.
+    #[serde(alias = "SOUTH_SOUTHEAST")]
+    SouthSoutheast,
+    /// South direction.
+    #[serde(alias = "SOUTH")]
+    South,
+    /// South-southwest direction.
+    #[serde(alias = "SOUTH_SOUTHWEST")]
+    SouthSouthwest,
+    /// Southwest direction.
+    #[serde(alias = "SOUTHWEST")]
+    Southwest,
+    /// West-southwest direction.
+    #[serde(alias = "WEST_SOUTHWEST")]
+    WestSouthwest,
+    /// West direction.
+    #[serde(alias = "WEST")]
+    West,
+    /// West-northwest direction.
+    #[serde(alias = "WEST_NORTHWEST")]
+    WestNorthwest,
+    /// Northwest direction.
+    #[serde(alias = "NORTHWEST")]
+    Northwest,
+    /// North-northwest direction.
+    #[serde(alias = "NORTH_NORTHWEST")]
+    NorthNorthwest,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for CardinalDirection {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match CardinalDirection::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&CardinalDirection> for String {
+    /// Converts a `CardinalDirection` enum to a `String` that contains a
+    /// cardinal direction code.
+    fn from(cardinal_direction: &CardinalDirection) -> String {
+        match cardinal_direction {
+            CardinalDirection::North => String::from("NORTH"),
+            CardinalDirection::NorthNortheast => String::from("NORTH_NORTHEAST"),
+            CardinalDirection::Northeast => String::from("NORTHEAST"),
+            CardinalDirection::EastNortheast => String::from("EAST_NORTHEAST"),
+            CardinalDirection::East => String::from("EAST"),
+            CardinalDirection::EastSoutheast => String::from("EAST_SOUTHEAST"),
+            CardinalDirection::Southeast => String::from("SOUTHEAST"),
+            CardinalDirection::SouthSoutheast => String::from("SOUTH_SOUTHEAST"),
+            CardinalDirection::South => String::from("SOUTH"),
+            CardinalDirection::SouthSouthwest => String::from("SOUTH_SOUTHWEST"),
+            CardinalDirection::Southwest => String::from("SOUTHWEST"),
+            CardinalDirection::WestSouthwest => String::from("WEST_SOUTHWEST"),
+            CardinalDirection::West => String::from("WEST"),
+            CardinalDirection::WestNorthwest => String::from("WEST_NORTHWEST"),
+            CardinalDirection::Northwest => String::from("NORTHWEST"),
+            CardinalDirection::NorthNorthwest => String::from("NORTH_NORTHWEST"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static CARDINAL_DIRECTIONS_BY_CODE: phf::Map<&'static str, CardinalDirection> = phf_map! {
+    "NORTH" => CardinalDirection::North,
+    "NORTH_NORTHEAST" => CardinalDirection::NorthNortheast,
+    "NORTHEAST" => CardinalDirection::Northeast,
+    "EAST_NORTHEAST" => CardinalDirection::EastNortheast,
+    "EAST" => CardinalDirection::East,
+    "EAST_SOUTHEAST" => CardinalDirection::EastSoutheast,
+    "SOUTHEAST" => CardinalDirection::Southeast,
+    "SOUTH_SOUTHEAST" => CardinalDirection::SouthSoutheast,
+    "SOUTH" => CardinalDirection::South,
+    "SOUTH_SOUTHWEST" => CardinalDirection::SouthSouthwest,
+    "SOUTHWEST" => CardinalDirection::Southwest,
+    "WEST_SOUTHWEST" => CardinalDirection::WestSouthwest,
+    "WEST" => CardinalDirection::West,
+    "WEST_NORTHWEST" => CardinalDirection::WestNorthwest,
+    "NORTHWEST" => CardinalDirection::Northwest,
+    "NORTH_NORTHWEST" => CardinalDirection::NorthNorthwest,
+};
+
+impl std::convert::TryFrom<&str> for CardinalDirection {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `CardinalDirection` enum from a `String` that contains a valid
+    /// cardinal direction code.
+    fn try_from(cardinal_direction_code: &str) -> Result<Self, Self::Error> {
+        CARDINAL_DIRECTIONS_BY_CODE
+            .get(cardinal_direction_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidCardinalDirection(cardinal_direction_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for CardinalDirection {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `CardinalDirection` enum from a `String` that contains a valid
+    /// cardinal direction code.
+    fn from_str(cardinal_direction_code: &str) -> Result<Self, Self::Err> {
+        CARDINAL_DIRECTIONS_BY_CODE
+            .get(cardinal_direction_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidCardinalDirection(cardinal_direction_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for CardinalDirection {
+    /// Returns a reasonable default variant for the `CardinalDirection` enum type.
+    fn default() -> Self {
+        CardinalDirection::North
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for CardinalDirection {
+    /// Formats a `CardinalDirection` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CardinalDirection::North => write!(f, "North"),
+            CardinalDirection::NorthNortheast => write!(f, "North northeast"),
+            CardinalDirection::Northeast => write!(f, "Northeast"),
+            CardinalDirection::EastNortheast => write!(f, "East northeast"),
+            CardinalDirection::East => write!(f, "East"),
+            CardinalDirection::EastSoutheast => write!(f, "East southeast"),
+            CardinalDirection::Southeast => write!(f, "Southeast"),
+            CardinalDirection::SouthSoutheast => write!(f, "South southeast"),
+            CardinalDirection::South => write!(f, "South"),
+            CardinalDirection::SouthSouthwest => write!(f, "South southwest"),
+            CardinalDirection::Southwest => write!(f, "Southwest"),
+            CardinalDirection::WestSouthwest => write!(f, "West southwest"),
+            CardinalDirection::West => write!(f, "West"),
+            CardinalDirection::WestNorthwest => write!(f, "West northwest"),
+            CardinalDirection::Northwest => write!(f, "Northwest"),
+            CardinalDirection::NorthNorthwest => write!(f, "North northwest"),
+        } // match
+    } // fn
+} // impl
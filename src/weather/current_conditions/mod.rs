@@ -0,0 +1,28 @@
+//! The Weather API **Current Conditions** service returns the current weather
+//! conditions at a given location: temperature, feels-like temperature,
+//! precipitation, wind, visibility and more, along with a summary of the
+//! changes over the last 24 hours.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/currentConditions/lookup#query-parameters)
+//!
+//! * `location` - The latitude and longitude of the location to look up.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/currentConditions/lookup#query-parameters)
+//!
+//! * `unitsSystem` - The unit system (metric or imperial) used to express the
+//! response values. Defaults to metric.
+//!
+//! * `languageCode` - The language in which textual descriptions are
+//! returned. Defaults to English.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://weather.googleapis.com/v1/currentConditions:lookup";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::weather::current_conditions::request::Request as CurrentConditionsRequest;
+pub use crate::weather::current_conditions::response::Response as CurrentConditionsResponse;
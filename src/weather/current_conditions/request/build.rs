@@ -0,0 +1,51 @@
+use crate::weather::current_conditions::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Current Conditions request based on
+    /// the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &mut Request<'a> {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&location.latitude={latitude}&location.longitude={longitude}",
+            key=self.client.key,
+            latitude=self.location.lat.normalize(),
+            longitude=self.location.lng.normalize(),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Unit system key/value pair:
+        if let Some(unit_system) = &self.unit_system {
+            query.push_str("&unitsSystem=");
+            query.push_str(&String::from(unit_system).to_uppercase())
+        } // if
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&languageCode=");
+            query.push_str(&String::from(language))
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
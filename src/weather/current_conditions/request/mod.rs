@@ -0,0 +1,63 @@
+//! **Look in this module for documentation on building your _Current Conditions_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod with_language;
+mod with_unit_system;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::unit_system::UnitSystem;
+use crate::language::Language;
+use crate::latlng::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Current Conditions_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The latitude and longitude of the location to look up the weather
+    /// for.
+    location: LatLng,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Determines the unit system used to express the response values:
+    /// temperatures, wind speeds, precipitation amounts and visibility
+    /// distances. Defaults to `UnitSystem::Metric`.
+    unit_system: Option<UnitSystem>,
+
+    /// The language in which textual descriptions (such as the weather
+    /// condition description) are returned. Defaults to English.
+    language: Option<Language>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
@@ -0,0 +1,39 @@
+use crate::client::GoogleMapsClient;
+use crate::latlng::LatLng;
+use crate::weather::current_conditions::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Current Conditions query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The latitude and longitude of the location to look up
+    /// the weather for.
+
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        location: LatLng,
+    ) -> Request<'a> {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            location,
+            // Optional parameters:
+            unit_system: None,
+            language: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
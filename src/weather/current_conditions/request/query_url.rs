@@ -0,0 +1,63 @@
+use crate::weather::current_conditions::{SERVICE_URL, request::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// Returns the query string that will be sent to the Google Maps API. It
+    /// is the result of the builder pattern. This method could be useful for
+    /// records or logging. It could also be used for passing to your HTTP
+    /// client of choice and executing the HTTP GET request yourself.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&mut self) -> String {
+
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.build().query.as_ref().unwrap(),
+        }; // match
+
+        format!("{SERVICE_URL}?{query_string}")
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use crate::directions::request::unit_system::UnitSystem;
+    use crate::language::Language;
+    use crate::latlng::LatLng;
+
+    #[test]
+    fn query_url_has_the_location_and_options() {
+        let client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+        let location = LatLng::try_from_f64(37.4220, -122.0841).unwrap();
+        let mut request = client.current_conditions(location);
+        let url = request
+            .with_unit_system(UnitSystem::Imperial)
+            .with_language(Language::English)
+            .query_url();
+        assert_eq!(
+            url,
+            "https://weather.googleapis.com/v1/currentConditions:lookup\
+            ?key=YOUR_GOOGLE_API_KEY_HERE\
+            &location.latitude=37.422&location.longitude=-122.0841\
+            &unitsSystem=IMPERIAL&languageCode=en"
+        ); // assert_eq!
+    } // fn
+} // mod
@@ -0,0 +1,39 @@
+use crate::language::Language;
+use crate::weather::current_conditions::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specify the language in which to return the weather condition
+    /// descriptions.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `language` ‧ The language that Google's response should be presented
+    /// in. If the language is not supported by the Weather API, English is
+    /// used.
+    ///
+    /// ## Example:
+    ///
+    /// * Force language to French:
+    /// ```rust
+    /// .with_language(Language::French)
+    /// ```
+
+    pub fn with_language(
+        &'a mut self,
+        language: Language
+    ) -> &'a mut Request {
+
+        // Set language in Request struct.
+        self.language = Some(language);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
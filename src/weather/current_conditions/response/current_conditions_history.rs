@@ -0,0 +1,40 @@
+//! Contains the `CurrentConditionsHistory` struct. It summarizes the weather
+//! of the last 24 hours alongside the current conditions.
+
+use crate::weather::{
+    quantitative_precipitation_forecast::QuantitativePrecipitationForecast,
+    temperature::Temperature,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a set of [changes in the current
+/// conditions](https://developers.google.com/maps/documentation/weather/reference/rest/v1/currentConditions/lookup#CurrentConditionsHistory)
+/// over the last 24 hours.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CurrentConditionsHistory {
+
+    /// The current temperature minus the temperature 24 hours ago.
+    #[serde(alias = "temperatureChange")]
+    pub temperature_change: Option<Temperature>,
+
+    /// The maximum (high) temperature in the past 24 hours.
+    #[serde(alias = "maxTemperature")]
+    pub max_temperature: Option<Temperature>,
+
+    /// The minimum (low) temperature in the past 24 hours.
+    #[serde(alias = "minTemperature")]
+    pub min_temperature: Option<Temperature>,
+
+    /// The amount of snow (measured in liquid water equivalent) accumulated in
+    /// the past 24 hours.
+    #[serde(alias = "snowQpf")]
+    pub snow_qpf: Option<QuantitativePrecipitationForecast>,
+
+    /// The amount of precipitation (measured in liquid water equivalent)
+    /// accumulated in the past 24 hours.
+    pub qpf: Option<QuantitativePrecipitationForecast>,
+
+} // struct
@@ -0,0 +1,212 @@
+//! Resources (enums, structs) for processing the _Current Conditions_ response
+//! from the Google Maps Platform. Look in here for more information about the
+//! data returned from Google's server and how to parse it with your program.
+
+pub mod current_conditions_history;
+
+// -----------------------------------------------------------------------------
+
+use crate::weather::{
+    air_pressure::AirPressure,
+    error_response::ErrorResponse,
+    precipitation::Precipitation,
+    temperature::Temperature,
+    time_zone::TimeZone,
+    visibility::Visibility,
+    weather_condition::WeatherCondition,
+    wind::Wind,
+}; // crate::weather
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use crate::weather::current_conditions::response::current_conditions_history::CurrentConditionsHistory;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Current Conditions_ request will be
+/// stored in this structure.
+///
+/// [Current Conditions](https://developers.google.com/maps/documentation/weather/reference/rest/v1/currentConditions/lookup#response-body)
+/// ----------------------------------------------------------------------------------------------------------------------------------
+/// Response message for `Weather.LookupCurrentConditions`. The current
+/// weather conditions at the requested location.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// Current time (UTC) associated with the returned data.
+    #[serde(alias = "currentTime")]
+    pub current_time: Option<DateTime<Utc>>,
+
+    /// The time zone at the requested location. Use it to convert the UTC times
+    /// in this response into local times.
+    #[serde(alias = "timeZone")]
+    pub time_zone: Option<TimeZone>,
+
+    /// Whether the requested location is in daytime (between sunrise and
+    /// sunset).
+    #[serde(alias = "isDaytime")]
+    pub is_daytime: Option<bool>,
+
+    /// The weather condition.
+    #[serde(alias = "weatherCondition")]
+    pub weather_condition: Option<WeatherCondition>,
+
+    /// The temperature.
+    pub temperature: Option<Temperature>,
+
+    /// The measure of how the temperature feels like.
+    #[serde(alias = "feelsLikeTemperature")]
+    pub feels_like_temperature: Option<Temperature>,
+
+    /// The dew point temperature.
+    #[serde(alias = "dewPoint")]
+    pub dew_point: Option<Temperature>,
+
+    /// The heat index temperature.
+    #[serde(alias = "heatIndex")]
+    pub heat_index: Option<Temperature>,
+
+    /// The wind chill, air temperature exposed on the skin.
+    #[serde(alias = "windChill")]
+    pub wind_chill: Option<Temperature>,
+
+    /// The percent of relative humidity (values from 0 to 100).
+    #[serde(alias = "relativeHumidity")]
+    pub relative_humidity: Option<u8>,
+
+    /// The ultraviolet (UV) index.
+    #[serde(alias = "uvIndex")]
+    pub uv_index: Option<u8>,
+
+    /// The precipitation probability and amount of precipitation accumulated.
+    pub precipitation: Option<Precipitation>,
+
+    /// The thunderstorm probability (values from 0 to 100).
+    #[serde(alias = "thunderstormProbability")]
+    pub thunderstorm_probability: Option<u8>,
+
+    /// The air pressure conditions.
+    #[serde(alias = "airPressure")]
+    pub air_pressure: Option<AirPressure>,
+
+    /// The wind conditions.
+    pub wind: Option<Wind>,
+
+    /// The visibility.
+    pub visibility: Option<Visibility>,
+
+    /// Percentage of the sky covered by clouds (values from 0 to 100).
+    #[serde(alias = "cloudCover")]
+    pub cloud_cover: Option<u8>,
+
+    /// The changes in the current conditions over the last 24 hours.
+    #[serde(alias = "currentConditionsHistory")]
+    pub current_conditions_history: Option<CurrentConditionsHistory>,
+
+    /// When the Google Maps Platform encounters an error, it will return an
+    /// error object with more information.
+    pub error: Option<ErrorResponse>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+
+    /// Returns the `current_time` of the conditions converted to the local
+    /// time of the requested location, using the time zone reported in the
+    /// response.
+    ///
+    /// ```rust
+    /// if let Some(local_time) = current_conditions.local_current_time() {
+    ///     println!("Conditions as of {}", local_time.format("%H:%M"));
+    /// }
+    /// ```
+
+    pub fn local_current_time(&self) -> Option<DateTime<Tz>> {
+        let time_zone = self.time_zone.as_ref()?.id?;
+        self.current_time.map(|current_time| current_time.with_timezone(&time_zone))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Weather API JSON `String` response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::{
+        cardinal_direction::CardinalDirection,
+        precipitation_type::PrecipitationType,
+        temperature_unit::TemperatureUnit,
+        unit::Unit,
+        weather_condition_type::WeatherConditionType,
+    }; // crate::weather
+    use chrono::{NaiveDate, TimeZone};
+
+    /// The current conditions in Mountain View, California, based on the
+    /// example in Google's documentation.
+    fn response() -> Response {
+        include_str!("fixtures/current_conditions.json").parse().unwrap()
+    } // fn
+
+    #[test]
+    fn parses_the_current_conditions() {
+        let response = response();
+
+        let condition = response.weather_condition.as_ref().unwrap();
+        assert_eq!(condition.condition_type, Some(WeatherConditionType::Clear));
+        assert_eq!(condition.description.as_ref().unwrap().text.as_deref(), Some("Sunny"));
+        assert_eq!(
+            condition.icon_url().as_deref(),
+            Some("https://maps.gstatic.com/weather/v1/sunny.svg")
+        ); // assert_eq!
+
+        let temperature = response.temperature.as_ref().unwrap();
+        assert_eq!(temperature.degrees, Some(13.7));
+        assert_eq!(temperature.unit, Some(TemperatureUnit::Celsius));
+        assert_eq!(response.relative_humidity, Some(42));
+        assert_eq!(response.is_daytime, Some(true));
+
+        let wind = response.wind.as_ref().unwrap();
+        assert_eq!(wind.direction.as_ref().unwrap().cardinal, Some(CardinalDirection::NorthNorthwest));
+        assert_eq!(wind.gust.as_ref().unwrap().value, Some(18.0));
+
+        let precipitation = response.precipitation.as_ref().unwrap();
+        assert_eq!(
+            precipitation.probability.as_ref().unwrap().precipitation_type,
+            Some(PrecipitationType::Rain)
+        ); // assert_eq!
+        assert_eq!(precipitation.qpf.as_ref().unwrap().unit, Some(Unit::Millimeters));
+
+        let history = response.current_conditions_history.as_ref().unwrap();
+        assert_eq!(history.max_temperature.as_ref().unwrap().degrees, Some(14.3));
+        assert_eq!(history.snow_qpf, None);
+        assert_eq!(response.error, None);
+    } // fn
+
+    #[test]
+    fn converts_the_current_time_to_local_time() {
+        let local = response().local_current_time().unwrap();
+        let expected = chrono_tz::America::Los_Angeles.from_local_datetime(
+            &NaiveDate::from_ymd_opt(2025, 1, 28).unwrap().and_hms_nano_opt(14, 4, 12, 25_273_178).unwrap()
+        ).unwrap();
+        assert_eq!(local, expected);
+
+        let mut response = response();
+        response.time_zone = None;
+        assert_eq!(response.local_current_time(), None);
+    } // fn
+} // mod
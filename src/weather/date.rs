@@ -0,0 +1,51 @@
+//! Contains the `Date` struct. The Weather API reports forecast days as whole
+//! calendar dates in the local time zone of the requested location.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a whole [calendar
+/// date](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#Date).
+/// The time of day and time zone are either specified elsewhere or are
+/// insignificant.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Date {
+
+    /// Year of the date. Must be from 1 to 9999.
+    pub year: u16,
+
+    /// Month of a year. Must be from 1 to 12.
+    pub month: u8,
+
+    /// Day of a month. Must be from 1 to 31 and valid for the year and month.
+    pub day: u8,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Date {
+
+    /// Converts the Weather API `Date` into a `chrono::NaiveDate`. Returns
+    /// `None` if the date is not a valid calendar date.
+
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(
+            self.year.into(),
+            self.month.into(),
+            self.day.into(),
+        ) // NaiveDate
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Date {
+    /// Formats a `Date` struct into an ISO 8601 `YYYY-MM-DD` string.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    } // fn
+} // impl
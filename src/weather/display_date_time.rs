@@ -0,0 +1,77 @@
+//! Contains the `DisplayDateTime` struct. The Weather API reports the local
+//! civil time of each forecast or historical hour with this structure.
+
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents [civil
+/// time](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.hours/lookup#DateTime)
+/// at the requested location, with its offset from UTC.
+///
+/// Fields that are zero (for example, the hours at midnight) may be omitted
+/// by the Weather API, so the time-of-day fields are optional and are treated
+/// as `0` when absent.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct DisplayDateTime {
+
+    /// Year of date. Must be from 1 to 9999.
+    pub year: u16,
+
+    /// Month of year. Must be from 1 to 12.
+    pub month: u8,
+
+    /// Day of month. Must be from 1 to 31 and valid for the year and month.
+    pub day: u8,
+
+    /// Hours of day in 24 hour format. Must be from 0 to 23.
+    pub hours: Option<u8>,
+
+    /// Minutes of hour of day. Must be from 0 to 59.
+    pub minutes: Option<u8>,
+
+    /// Seconds of minutes of the time. Must normally be from 0 to 59.
+    pub seconds: Option<u8>,
+
+    /// Fractions of seconds in nanoseconds. Must be from 0 to 999,999,999.
+    pub nanos: Option<u32>,
+
+    /// UTC offset as a duration in seconds, with up to nine fractional digits
+    /// and ending with `s`. For example: `"-25200s"`.
+    #[serde(alias = "utcOffset")]
+    pub utc_offset: Option<String>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl DisplayDateTime {
+
+    /// Converts the civil time into a `chrono::NaiveDateTime`. Returns `None`
+    /// if the fields do not represent a valid date and time.
+
+    pub fn to_naive_date_time(&self) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(self.year.into(), self.month.into(), self.day.into())?
+            .and_hms_nano_opt(
+                self.hours.unwrap_or_default().into(),
+                self.minutes.unwrap_or_default().into(),
+                self.seconds.unwrap_or_default().into(),
+                self.nanos.unwrap_or_default(),
+            ) // and_hms_nano_opt
+    } // fn
+
+    /// Parses the `utc_offset` duration string into a `chrono::FixedOffset`.
+    /// Returns `None` if no offset was provided or if it could not be parsed.
+
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        let seconds = self.utc_offset
+            .as_ref()?
+            .trim_end_matches('s')
+            .parse::<f64>()
+            .ok()?;
+        FixedOffset::east_opt(seconds.round() as i32)
+    } // fn
+
+} // impl
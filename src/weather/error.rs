@@ -0,0 +1,244 @@
+//! Weather API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::weather::status::Status;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Weather API client.
+
+#[derive(Debug)]
+pub enum Error {
+
+    /// Google Maps Weather API server generated an error. See the `Status`
+    /// enum for more information.
+    GoogleMapsService(Status, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// cardinal direction code. See
+    /// `google_maps\src\weather\cardinal_direction.rs` for more information.
+    InvalidCardinalDirection(String),
+
+    /// The number of forecast days requested must be between 1 and 10.
+    InvalidDays(u8),
+
+    /// The number of hours requested is out of range. Forecasts are available
+    /// for up to 240 hours, and history for up to 24 hours. The second field
+    /// is the maximum for the request.
+    InvalidHours(u8, u8),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// moon phase code. See
+    /// `google_maps\src\weather\forecast_days\response\moon_phase.rs` for more
+    /// information.
+    InvalidMoonPhase(String),
+
+    /// The number of records requested per page is out of range. The second
+    /// field is the maximum for the request.
+    InvalidPageSize(u8, u8),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// precipitation type code. See
+    /// `google_maps\src\weather\precipitation_type.rs` for more information.
+    InvalidPrecipitationType(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// speed unit code. See `google_maps\src\weather\speed_unit.rs` for more
+    /// information.
+    InvalidSpeedUnit(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// status code. See `google_maps\src\weather\status.rs` for more
+    /// information.
+    InvalidStatusCode(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// temperature unit code. See `google_maps\src\weather\temperature_unit.rs`
+    /// for more information.
+    InvalidTemperatureUnit(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// unit code. See `google_maps\src\weather\unit.rs` for more information.
+    InvalidUnit(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// weather condition type code. See
+    /// `google_maps\src\weather\weather_condition_type.rs` for more
+    /// information.
+    InvalidWeatherConditionType(String),
+
+    /// The query string must be built before the request may be sent to the
+    /// Google Maps Weather API server.
+    QueryNotBuilt,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(reqwest::Error),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Weather API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Weather API service: {error_message}"),
+                // If the Google Maps Weather API server did not generate an
+                // error message, return a generic message derived from the
+                // response status:
+                None => match status {
+                    Status::InvalidArgument => write!(f, "Google Maps Weather API service: \
+                        Invalid argument. \
+                        1. Your API key is not valid or was not included in the request. \
+                        or, 2. Your request contained invalid arguments."),
+                    Status::PermissionDenied => write!(f, "Google Maps Weather API service: \
+                        Permission Denied. \
+                        1. API key missing or invalid. \
+                        2. Billing not enabled. \
+                        3. Weather API not enabled for this project. \
+                        or, 4. Self-imposed usage cap exceeded."),
+                    Status::NotFound => write!(f, "Google Maps Weather API service: \
+                        Not found. \
+                        No weather information is available for the requested location."),
+                    Status::ResourceExhausted => write!(f, "Google Maps Weather API service: \
+                        Resource exhausted. \
+                        You have exceeded the request limit that you configured \
+                        in the Google Cloud Platform Console."),
+                    _ => write!(f, "Google Maps Weather API service: {status}."),
+                } // match
+            }, // match
+            Error::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Weather API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Error::InvalidCardinalDirection(direction_code) => write!(f, "Google Maps Weather API client: \
+                `{direction_code}` is not a valid cardinal direction code. \
+                Valid codes are `NORTH`, `NORTH_NORTHEAST`, `NORTHEAST`, \
+                `EAST_NORTHEAST`, `EAST`, `EAST_SOUTHEAST`, `SOUTHEAST`, \
+                `SOUTH_SOUTHEAST`, `SOUTH`, `SOUTH_SOUTHWEST`, `SOUTHWEST`, \
+                `WEST_SOUTHWEST`, `WEST`, `WEST_NORTHWEST`, `NORTHWEST`, and \
+                `NORTH_NORTHWEST`."),
+            Error::InvalidDays(days) => write!(f, "Google Maps Weather API client: \
+                `{days}` is not a valid number of forecast days. \
+                Forecasts are available for 1 to 10 days."),
+            Error::InvalidHours(hours, maximum) => write!(f, "Google Maps Weather API client: \
+                `{hours}` is not a valid number of hours. \
+                This request supports 1 to {maximum} hours."),
+            Error::InvalidMoonPhase(phase_code) => write!(f, "Google Maps Weather API client: \
+                `{phase_code}` is not a valid moon phase code. \
+                Valid codes are `NEW_MOON`, `WAXING_CRESCENT`, `FIRST_QUARTER`, \
+                `WAXING_GIBBOUS`, `FULL_MOON`, `WANING_GIBBOUS`, \
+                `LAST_QUARTER`, and `WANING_CRESCENT`."),
+            Error::InvalidPageSize(page_size, maximum) => write!(f, "Google Maps Weather API client: \
+                `{page_size}` is not a valid page size. \
+                This request supports 1 to {maximum} records per page."),
+            Error::InvalidPrecipitationType(type_code) => write!(f, "Google Maps Weather API client: \
+                `{type_code}` is not a valid precipitation type code. \
+                Valid codes are `NONE`, `SNOW`, `RAIN`, `LIGHT_RAIN`, \
+                `HEAVY_RAIN`, `RAIN_AND_SNOW`, `SLEET`, and `FREEZING_RAIN`."),
+            Error::InvalidSpeedUnit(unit_code) => write!(f, "Google Maps Weather API client: \
+                `{unit_code}` is not a valid speed unit code. \
+                Valid codes are `KILOMETERS_PER_HOUR`, and `MILES_PER_HOUR`."),
+            Error::InvalidStatusCode(status_code) => write!(f, "Google Maps Weather API client: \
+                `{status_code}` is not a valid status code. \
+                Valid codes are `INVALID_ARGUMENT`, `FAILED_PRECONDITION`, \
+                `OUT_OF_RANGE`, `UNAUTHENTICATED`, `PERMISSION_DENIED`, \
+                `NOT_FOUND`, `ABORTED`, `ALREADY_EXISTS`, `RESOURCE_EXHAUSTED`, \
+                `CANCELLED`, `DATA_LOSS`, `UNKNOWN`, `INTERNAL`, \
+                `UNIMPLEMENTED`, `UNAVAILABLE`, and `DEADLINE_EXCEEDED`."),
+            Error::InvalidTemperatureUnit(unit_code) => write!(f, "Google Maps Weather API client: \
+                `{unit_code}` is not a valid temperature unit code. \
+                Valid codes are `CELSIUS`, and `FAHRENHEIT`."),
+            Error::InvalidUnit(unit_code) => write!(f, "Google Maps Weather API client: \
+                `{unit_code}` is not a valid unit code. \
+                Valid codes are `MILLIMETERS`, `INCHES`, `KILOMETERS`, and \
+                `MILES`."),
+            Error::InvalidWeatherConditionType(type_code) => write!(f, "Google Maps Weather API client: \
+                `{type_code}` is not a valid weather condition type code. \
+                See `google_maps\\src\\weather\\weather_condition_type.rs` for \
+                the list of valid codes."),
+            Error::QueryNotBuilt => write!(f, "Google Maps Weather API client library: \
+                The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => write!(f, "Google Maps Weather API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Error::ReqwestMessage(error) => write!(f, "Google Maps Weather API client in the Reqwest library: {error}"),
+            Error::SerdeJson(error) => write!(f, "Google Maps Weather API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Weather API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::GoogleMapsService(_error, _message) => None,
+            Error::HttpUnsuccessful(_status) => None,
+            Error::InvalidCardinalDirection(_direction_code) => None,
+            Error::InvalidDays(_days) => None,
+            Error::InvalidHours(_hours, _maximum) => None,
+            Error::InvalidMoonPhase(_phase_code) => None,
+            Error::InvalidPageSize(_page_size, _maximum) => None,
+            Error::InvalidPrecipitationType(_type_code) => None,
+            Error::InvalidSpeedUnit(_unit_code) => None,
+            Error::InvalidStatusCode(_status_code) => None,
+            Error::InvalidTemperatureUnit(_unit_code) => None,
+            Error::InvalidUnit(_unit_code) => None,
+            Error::InvalidWeatherConditionType(_type_code) => None,
+            Error::QueryNotBuilt => None,
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => Some(error),
+            #[cfg(feature = "enable-reqwest")]
+            Error::ReqwestMessage(_error) => None,
+            Error::SerdeJson(error) => Some(error),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Weather API error type (`google_maps::weather::error::Error`)
+    /// by wrapping it inside. This function is required to use the `?`
+    /// operator.
+    fn from(error: reqwest::Error) -> Error {
+        Error::Reqwest(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Weather API error type
+    /// (`google_maps::weather::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Error {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
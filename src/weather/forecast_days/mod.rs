@@ -0,0 +1,38 @@
+//! The Weather API **Forecast Days** service returns up to 10 days of daily
+//! forecasts for a given location, starting with the current day. Each day
+//! is split into a daytime and a nighttime forecast, and includes sun and
+//! moon events. Records are paginated; use the request's `stream` method to
+//! have the pages fetched for you.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#query-parameters)
+//!
+//! * `location` - The latitude and longitude of the location to look up.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#query-parameters)
+//!
+//! * `days` - Limits the number of days to forecast. Must be from 1 to 10.
+//! Defaults to 10.
+//!
+//! * `pageSize` - The maximum number of daily records to return per page.
+//! Must be from 1 to 10. Defaults to 5.
+//!
+//! * `pageToken` - A page token received from a previous call, used to
+//! retrieve the subsequent page.
+//!
+//! * `unitsSystem` - The unit system (metric or imperial) used to express the
+//! response values. Defaults to metric.
+//!
+//! * `languageCode` - The language in which textual descriptions are
+//! returned. Defaults to English.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://weather.googleapis.com/v1/forecast/days:lookup";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::weather::forecast_days::request::Request as ForecastDaysRequest;
+pub use crate::weather::forecast_days::response::Response as ForecastDaysResponse;
@@ -0,0 +1,27 @@
+use crate::weather::error::Error;
+use crate::weather::forecast_days::request::Request;
+use crate::weather::forecast_days::response::Response;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build().get()` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&mut self) -> Result<Response, Error> {
+        self.validate()?.build().get().await
+    } // fn
+
+} // impl
@@ -0,0 +1,81 @@
+//! **Look in this module for documentation on building your _Forecast Days_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+#[cfg(feature = "enable-reqwest")]
+mod stream;
+mod validate;
+mod with_days;
+mod with_language;
+mod with_page_size;
+mod with_page_token;
+mod with_unit_system;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::unit_system::UnitSystem;
+use crate::language::Language;
+use crate::latlng::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Forecast Days_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The latitude and longitude of the location to look up the weather
+    /// for.
+    location: LatLng,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Limits the number of days to forecast. Must be from 1 to 10.
+    /// Defaults to 10.
+    days: Option<u8>,
+
+    /// The maximum number of daily records to return per page. Must be
+    /// from 1 to 10. Defaults to 5.
+    page_size: Option<u8>,
+
+    /// A page token received from a previous request. It is used to retrieve
+    /// the subsequent page.
+    page_token: Option<String>,
+
+    /// Determines the unit system used to express the response values:
+    /// temperatures, wind speeds, precipitation amounts and visibility
+    /// distances. Defaults to `UnitSystem::Metric`.
+    unit_system: Option<UnitSystem>,
+
+    /// The language in which textual descriptions (such as the weather
+    /// condition description) are returned. Defaults to English.
+    language: Option<Language>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
@@ -0,0 +1,82 @@
+use crate::weather::error::Error;
+use crate::weather::forecast_days::request::Request;
+use crate::weather::forecast_days::response::{Response, forecast_day::ForecastDay};
+use futures::stream::{self, Stream, TryStreamExt};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and returns the daily forecast records as a
+    /// `Stream`.
+    ///
+    /// ## Description:
+    ///
+    /// The Weather API splits its results into pages of `page_size` records.
+    /// This method requests the first page and, as the stream is consumed,
+    /// follows each response's `next_page_token` to request the subsequent
+    /// pages. Pages are only requested when needed, so dropping the stream
+    /// early does not fetch (or bill) the remaining pages.
+    ///
+    /// Time zone information is returned with each page rather than with each
+    /// record. Use the `execute` method if you need it.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut request = google_maps_client.forecast_days(location);
+    /// let records = request.with_page_size(6).stream();
+    /// futures::pin_mut!(records);
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     println!("{:#?}", record.temperature);
+    /// }
+    /// ```
+
+    pub fn stream(&'a mut self) -> impl Stream<Item = Result<ForecastDay, Error>> + 'a {
+        stream::try_unfold(Some(self), next_page)
+            .map_ok(|response| stream::iter(
+                response.forecast_days.unwrap_or_default().into_iter().map(Ok)
+            )) // map_ok
+            .try_flatten()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Requests the page that the given request points to, and returns it along
+/// with the request for the following page. Returns `None` once the last
+/// page has been returned.
+
+async fn next_page<'a>(
+    request: Option<&'a mut Request<'a>>,
+) -> Result<Option<(Response, Option<&'a mut Request<'a>>)>, Error> {
+
+    // If the previous page did not have a next page token, there are no more
+    // pages to request:
+    let request = match request {
+        Some(request) => request,
+        None => return Ok(None),
+    }; // match
+
+    // Request the current page:
+    let response = request.validate()?.build().get().await?;
+
+    // Prepare the request for the next page, if there is one:
+    request.page_token = response.next_page_token
+        .clone()
+        .filter(|page_token| !page_token.is_empty());
+    let next_request = request.page_token.is_some().then_some(request);
+
+    Ok(Some((response, next_request)))
+
+} // fn
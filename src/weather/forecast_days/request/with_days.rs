@@ -0,0 +1,34 @@
+use crate::weather::forecast_days::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Limits the number of days to forecast.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `days` ‧ The number of days, from 1 to 10. Defaults to 10.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_days(5)
+    /// ```
+
+    pub fn with_days(
+        &'a mut self,
+        days: u8
+    ) -> &'a mut Request {
+
+        // Set days in Request struct.
+        self.days = Some(days);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
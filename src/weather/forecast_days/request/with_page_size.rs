@@ -0,0 +1,35 @@
+use crate::weather::forecast_days::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of daily records to return per page.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `page_size` ‧ The number of records per page, from 1 to
+    /// 10. Defaults to 5.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_page_size(10)
+    /// ```
+
+    pub fn with_page_size(
+        &'a mut self,
+        page_size: u8
+    ) -> &'a mut Request {
+
+        // Set page size in Request struct.
+        self.page_size = Some(page_size);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
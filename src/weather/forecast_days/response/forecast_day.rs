@@ -0,0 +1,79 @@
+//! Contains the `ForecastDay` struct. Each day of a forecast is reported with
+//! one of these.
+
+use crate::weather::{
+    date::Date,
+    ice_thickness::IceThickness,
+    interval::Interval,
+    temperature::Temperature,
+}; // crate::weather
+use crate::weather::forecast_days::response::{
+    forecast_day_part::ForecastDayPart,
+    moon_events::MoonEvents,
+    sun_events::SunEvents,
+}; // crate::weather::forecast_days::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a [daily forecast
+/// record](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#ForecastDay)
+/// at a given location.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForecastDay {
+
+    /// The UTC time interval when this forecasted day starts (inclusive) and
+    /// ends (exclusive). A day starts at 7:00 AM local time and ends at 7:00 AM
+    /// local time the next day.
+    pub interval: Option<Interval>,
+
+    /// The local date in the time zone of the location (civil time) which the
+    /// daily forecast data is valid for. This field may be used for display
+    /// purposes on the client side.
+    #[serde(alias = "displayDate")]
+    pub display_date: Option<Date>,
+
+    /// The forecasted weather conditions for the daytime part of the day (7:00
+    /// AM to 7:00 PM local time).
+    #[serde(alias = "daytimeForecast")]
+    pub daytime_forecast: Option<ForecastDayPart>,
+
+    /// The forecasted weather conditions for the nighttime part of the day
+    /// (7:00 PM to 7:00 AM local time).
+    #[serde(alias = "nighttimeForecast")]
+    pub nighttime_forecast: Option<ForecastDayPart>,
+
+    /// The maximum (high) temperature throughout the day.
+    #[serde(alias = "maxTemperature")]
+    pub max_temperature: Option<Temperature>,
+
+    /// The minimum (low) temperature throughout the day.
+    #[serde(alias = "minTemperature")]
+    pub min_temperature: Option<Temperature>,
+
+    /// The maximum (high) feels-like temperature throughout the day.
+    #[serde(alias = "feelsLikeMaxTemperature")]
+    pub feels_like_max_temperature: Option<Temperature>,
+
+    /// The minimum (low) feels-like temperature throughout the day.
+    #[serde(alias = "feelsLikeMinTemperature")]
+    pub feels_like_min_temperature: Option<Temperature>,
+
+    /// The maximum heat index temperature throughout the day.
+    #[serde(alias = "maxHeatIndex")]
+    pub max_heat_index: Option<Temperature>,
+
+    /// The events related to the sun (e.g. sunrise, sunset).
+    #[serde(alias = "sunEvents")]
+    pub sun_events: Option<SunEvents>,
+
+    /// The events related to the moon (e.g. moonrise, moonset).
+    #[serde(alias = "moonEvents")]
+    pub moon_events: Option<MoonEvents>,
+
+    /// The amount of ice accumulated.
+    #[serde(alias = "iceThickness")]
+    pub ice_thickness: Option<IceThickness>,
+
+} // struct
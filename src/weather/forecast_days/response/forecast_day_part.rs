@@ -0,0 +1,55 @@
+//! Contains the `ForecastDayPart` struct. A forecast day is split into a
+//! daytime and a nighttime part.
+
+use crate::weather::{
+    ice_thickness::IceThickness,
+    interval::Interval,
+    precipitation::Precipitation,
+    weather_condition::WeatherCondition,
+    wind::Wind,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a [forecast
+/// record](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#ForecastDayPart)
+/// for a part of the day (daytime or nighttime).
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForecastDayPart {
+
+    /// The UTC date and time when this part of the day starts and ends.
+    pub interval: Option<Interval>,
+
+    /// The weather condition.
+    #[serde(alias = "weatherCondition")]
+    pub weather_condition: Option<WeatherCondition>,
+
+    /// The percent of relative humidity (values from 0 to 100).
+    #[serde(alias = "relativeHumidity")]
+    pub relative_humidity: Option<u8>,
+
+    /// The maximum ultraviolet (UV) index.
+    #[serde(alias = "uvIndex")]
+    pub uv_index: Option<u8>,
+
+    /// The precipitation probability and amount of precipitation accumulated.
+    pub precipitation: Option<Precipitation>,
+
+    /// The thunderstorm probability (values from 0 to 100).
+    #[serde(alias = "thunderstormProbability")]
+    pub thunderstorm_probability: Option<u8>,
+
+    /// The wind conditions.
+    pub wind: Option<Wind>,
+
+    /// Percentage of the sky covered by clouds (values from 0 to 100).
+    #[serde(alias = "cloudCover")]
+    pub cloud_cover: Option<u8>,
+
+    /// The amount of ice accumulated.
+    #[serde(alias = "iceThickness")]
+    pub ice_thickness: Option<IceThickness>,
+
+} // struct
@@ -0,0 +1,64 @@
+//! Resources (enums, structs) for processing the _Forecast Days_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+pub mod forecast_day;
+pub mod forecast_day_part;
+pub mod moon_events;
+pub mod moon_phase;
+pub mod sun_events;
+
+// -----------------------------------------------------------------------------
+
+use crate::weather::{
+    error_response::ErrorResponse,
+    time_zone::TimeZone,
+}; // crate::weather
+use crate::weather::forecast_days::response::forecast_day::ForecastDay;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Forecast Days_ request will be stored in
+/// this structure.
+///
+/// [Forecast Days](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#response-body)
+/// ------------------------------------------------------------------------------------------------------------------------------
+/// One page of forecast day records at the requested location. Further
+/// pages may be requested with `next_page_token`, or by consuming the
+/// request's `stream` method.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// The daily forecast records, according to the number of days and page
+    /// size specified in the request.
+    #[serde(alias = "forecastDays")]
+    pub forecast_days: Option<Vec<ForecastDay>>,
+
+    /// The time zone at the requested location. Use it to convert the UTC times
+    /// in this response into local times.
+    #[serde(alias = "timeZone")]
+    pub time_zone: Option<TimeZone>,
+
+    /// The token to retrieve the next page. If this field is omitted, there are
+    /// no subsequent pages.
+    #[serde(alias = "nextPageToken")]
+    pub next_page_token: Option<String>,
+
+    /// When the Google Maps Platform encounters an error, it will return an
+    /// error object with more information.
+    pub error: Option<ErrorResponse>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Weather API JSON `String` response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+//! Contains the `MoonEvents` struct. It reports the moon phase, moonrise and
+//! moonset times for a forecast day.
+
+use chrono::{DateTime, Utc};
+use crate::weather::forecast_days::response::moon_phase::MoonPhase;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the events related to the
+/// [moon](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#MoonEvents)
+/// (e.g. moonrise, moonset).
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct MoonEvents {
+
+    /// The moon phase (a.k.a. lunar phase).
+    #[serde(alias = "moonPhase")]
+    pub moon_phase: Option<MoonPhase>,
+
+    /// The time when the upper limb of the moon appears above the horizon.
+    /// In rare cases there can be no moonrise, or two moonrises, in a day.
+    #[serde(alias = "moonriseTimes")]
+    pub moonrise_times: Option<Vec<DateTime<Utc>>>,
+
+    /// The time when the upper limb of the moon disappears below the
+    /// horizon. In rare cases there can be no moonset, or two moonsets, in a
+    /// day.
+    #[serde(alias = "moonsetTimes")]
+    pub moonset_times: Option<Vec<DateTime<Utc>>>,
+
+} // struct
@@ -0,0 +1,146 @@
+//! Contains the `MoonPhase` enum and its associated traits. It describes the
+//! illuminated portion of the moon on a forecast day.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Marks the [moon
+/// phase](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#moonphase)
+/// (a.k.a. lunar phase).
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum MoonPhase {
+    /// No illuminated portion of the moon is visible.
+    #[serde(alias = "NEW_MOON")]
+    NewMoon,
+    /// 1-49% of the moon is illuminated and the illuminated portion is growing.
+    #[serde(alias = "WAXING_CRESCENT")]
+    WaxingCrescent,
+    /// Half of the moon is illuminated and the illuminated portion is growing.
+    #[serde(alias = "FIRST_QUARTER")]
+    FirstQuarter,
+    /// 51-99% of the moon is illuminated and the illuminated portion is
+    /// growing.
+    #[serde(alias = "WAXING_GIBBOUS")]
+    WaxingGibbous,
+    /// The moon is fully illuminated.
+    #[serde(alias = "FULL_MOON")]
+    FullMoon,
+    /// 51-99% of the moon is illuminated and the illuminated portion is
+    /// shrinking.
+    #[serde(alias = "WANING_GIBBOUS")]
+    WaningGibbous,
+    /// Half of the moon is illuminated and the illuminated portion is
+    /// shrinking.
+    #[serde(alias = "LAST_QUARTER")]
+    LastQuarter,
+    /// 1-49% of the moon is illuminated and the illuminated portion is
+    /// shrinking.
+    #[serde(alias = "WANING_CRESCENT")]
+    WaningCrescent,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for MoonPhase {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match MoonPhase::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MoonPhase> for String {
+    /// Converts a `MoonPhase` enum to a `String` that contains a moon phase
+    /// code.
+    fn from(moon_phase: &MoonPhase) -> String {
+        match moon_phase {
+            MoonPhase::NewMoon => String::from("NEW_MOON"),
+            MoonPhase::WaxingCrescent => String::from("WAXING_CRESCENT"),
+            MoonPhase::FirstQuarter => String::from("FIRST_QUARTER"),
+            MoonPhase::WaxingGibbous => String::from("WAXING_GIBBOUS"),
+            MoonPhase::FullMoon => String::from("FULL_MOON"),
+            MoonPhase::WaningGibbous => String::from("WANING_GIBBOUS"),
+            MoonPhase::LastQuarter => String::from("LAST_QUARTER"),
+            MoonPhase::WaningCrescent => String::from("WANING_CRESCENT"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static MOON_PHASES_BY_CODE: phf::Map<&'static str, MoonPhase> = phf_map! {
+    "NEW_MOON" => MoonPhase::NewMoon,
+    "WAXING_CRESCENT" => MoonPhase::WaxingCrescent,
+    "FIRST_QUARTER" => MoonPhase::FirstQuarter,
+    "WAXING_GIBBOUS" => MoonPhase::WaxingGibbous,
+    "FULL_MOON" => MoonPhase::FullMoon,
+    "WANING_GIBBOUS" => MoonPhase::WaningGibbous,
+    "LAST_QUARTER" => MoonPhase::LastQuarter,
+    "WANING_CRESCENT" => MoonPhase::WaningCrescent,
+};
+
+impl std::convert::TryFrom<&str> for MoonPhase {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `MoonPhase` enum from a `String` that contains a valid moon phase
+    /// code.
+    fn try_from(moon_phase_code: &str) -> Result<Self, Self::Error> {
+        MOON_PHASES_BY_CODE
+            .get(moon_phase_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidMoonPhase(moon_phase_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for MoonPhase {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `MoonPhase` enum from a `String` that contains a valid moon phase
+    /// code.
+    fn from_str(moon_phase_code: &str) -> Result<Self, Self::Err> {
+        MOON_PHASES_BY_CODE
+            .get(moon_phase_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidMoonPhase(moon_phase_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for MoonPhase {
+    /// Returns a reasonable default variant for the `MoonPhase` enum type.
+    fn default() -> Self {
+        MoonPhase::NewMoon
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MoonPhase {
+    /// Formats a `MoonPhase` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MoonPhase::NewMoon => write!(f, "New moon"),
+            MoonPhase::WaxingCrescent => write!(f, "Waxing crescent"),
+            MoonPhase::FirstQuarter => write!(f, "First quarter"),
+            MoonPhase::WaxingGibbous => write!(f, "Waxing gibbous"),
+            MoonPhase::FullMoon => write!(f, "Full moon"),
+            MoonPhase::WaningGibbous => write!(f, "Waning gibbous"),
+            MoonPhase::LastQuarter => write!(f, "Last quarter"),
+            MoonPhase::WaningCrescent => write!(f, "Waning crescent"),
+        } // match
+    } // fn
+} // impl
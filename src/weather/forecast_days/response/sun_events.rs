@@ -0,0 +1,26 @@
+//! Contains the `SunEvents` struct. It reports sunrise and sunset times for a
+//! forecast day.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the events related to the
+/// [sun](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.days/lookup#SunEvents)
+/// (e.g. sunrise, sunset).
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SunEvents {
+
+    /// The time when the sun rises. This field is not populated in polar
+    /// regions where the sun does not rise on the given day.
+    #[serde(alias = "sunriseTime")]
+    pub sunrise_time: Option<DateTime<Utc>>,
+
+    /// The time when the sun sets. This field is not populated in polar
+    /// regions where the sun does not set on the given day.
+    #[serde(alias = "sunsetTime")]
+    pub sunset_time: Option<DateTime<Utc>>,
+
+} // struct
@@ -0,0 +1,37 @@
+//! The Weather API **Forecast Hours** service returns up to 240 hours of
+//! hourly forecasts for a given location, starting at the current hour.
+//! Records are paginated; use the request's `stream` method to have the
+//! pages fetched for you.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.hours/lookup#query-parameters)
+//!
+//! * `location` - The latitude and longitude of the location to look up.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/forecast.hours/lookup#query-parameters)
+//!
+//! * `hours` - Limits the number of hours to forecast. Must be from 1 to 240.
+//! Defaults to 240.
+//!
+//! * `pageSize` - The maximum number of hourly records to return per page.
+//! Must be from 1 to 24. Defaults to 24.
+//!
+//! * `pageToken` - A page token received from a previous call, used to
+//! retrieve the subsequent page.
+//!
+//! * `unitsSystem` - The unit system (metric or imperial) used to express the
+//! response values. Defaults to metric.
+//!
+//! * `languageCode` - The language in which textual descriptions are
+//! returned. Defaults to English.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://weather.googleapis.com/v1/forecast/hours:lookup";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::weather::forecast_hours::request::Request as ForecastHoursRequest;
+pub use crate::weather::forecast_hours::response::Response as ForecastHoursResponse;
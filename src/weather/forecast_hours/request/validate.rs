@@ -0,0 +1,38 @@
+use crate::weather::error::Error;
+use crate::weather::forecast_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures the built query is valid. This function checks that the
+    /// number of hours and the page size are within the range supported by
+    /// the Weather API _Forecast Hours_ service, so that an out-of-range request is
+    /// not sent to (and billed by) Google.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&mut Request<'a>, Error> {
+
+        if let Some(hours) = self.hours {
+            if !(1..=240).contains(&hours) {
+                return Err(Error::InvalidHours(hours, 240));
+            } // if
+        } // if
+
+        if let Some(page_size) = self.page_size {
+            if !(1..=24).contains(&page_size) {
+                return Err(Error::InvalidPageSize(page_size, 24));
+            } // if
+        } // if
+
+        // If no invalid parameters were found, return the request:
+        Ok(self)
+
+    } // fn
+
+} // impl
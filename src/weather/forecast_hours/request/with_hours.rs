@@ -0,0 +1,34 @@
+use crate::weather::forecast_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Limits the number of hours to forecast.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `hours` ‧ The number of hours, from 1 to 240. Defaults to 240.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_hours(48)
+    /// ```
+
+    pub fn with_hours(
+        &'a mut self,
+        hours: u8
+    ) -> &'a mut Request {
+
+        // Set hours in Request struct.
+        self.hours = Some(hours);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
@@ -0,0 +1,36 @@
+use crate::weather::forecast_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Continues a previous request from the given page.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `page_token` ‧ The `next_page_token` received in a previous response.
+    /// All other request parameters must match the request that returned the
+    /// token.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_page_token(response.next_page_token.unwrap())
+    /// ```
+
+    pub fn with_page_token(
+        &'a mut self,
+        page_token: String
+    ) -> &'a mut Request {
+
+        // Set page token in Request struct.
+        self.page_token = Some(page_token);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
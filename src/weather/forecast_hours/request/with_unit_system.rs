@@ -0,0 +1,39 @@
+use crate::directions::request::unit_system::UnitSystem;
+use crate::weather::forecast_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the unit system used to express the response values.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `unit_system` ‧ `UnitSystem::Metric` (the default) returns
+    /// temperatures in Celsius, speeds in kilometers per hour, precipitation
+    /// in millimeters and distances in kilometers. `UnitSystem::Imperial`
+    /// returns temperatures in Fahrenheit, speeds in miles per hour,
+    /// precipitation in inches and distances in miles.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_unit_system(UnitSystem::Imperial)
+    /// ```
+
+    pub fn with_unit_system(
+        &'a mut self,
+        unit_system: UnitSystem
+    ) -> &'a mut Request {
+
+        // Set unit system in Request struct.
+        self.unit_system = Some(unit_system);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
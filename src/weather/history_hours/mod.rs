@@ -0,0 +1,37 @@
+//! The Weather API **History Hours** service returns up to 24 hours of cached
+//! hourly weather history for a given location, starting with the most
+//! recent hour. Records are paginated; use the request's `stream` method to
+//! have the pages fetched for you.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/history.hours/lookup#query-parameters)
+//!
+//! * `location` - The latitude and longitude of the location to look up.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/weather/reference/rest/v1/history.hours/lookup#query-parameters)
+//!
+//! * `hours` - Limits the number of hours of history to return. Must be from
+//! 1 to 24. Defaults to 24.
+//!
+//! * `pageSize` - The maximum number of hourly records to return per page.
+//! Must be from 1 to 24. Defaults to 24.
+//!
+//! * `pageToken` - A page token received from a previous call, used to
+//! retrieve the subsequent page.
+//!
+//! * `unitsSystem` - The unit system (metric or imperial) used to express the
+//! response values. Defaults to metric.
+//!
+//! * `languageCode` - The language in which textual descriptions are
+//! returned. Defaults to English.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://weather.googleapis.com/v1/history/hours:lookup";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::weather::history_hours::request::Request as HistoryHoursRequest;
+pub use crate::weather::history_hours::response::Response as HistoryHoursResponse;
@@ -0,0 +1,70 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use crate::weather::history_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps History Hours request based on
+    /// the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &mut Request<'a> {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&location.latitude={latitude}&location.longitude={longitude}",
+            key=self.client.key,
+            latitude=self.location.lat.normalize(),
+            longitude=self.location.lng.normalize(),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Hours key/value pair:
+        if let Some(hours) = &self.hours {
+            query.push_str("&hours=");
+            query.push_str(&hours.to_string())
+        } // if
+
+        // Page size key/value pair:
+        if let Some(page_size) = &self.page_size {
+            query.push_str("&pageSize=");
+            query.push_str(&page_size.to_string())
+        } // if
+
+        // Page token key/value pair:
+        if let Some(page_token) = &self.page_token {
+            query.push_str("&pageToken=");
+            query.push_str(&utf8_percent_encode(page_token, NON_ALPHANUMERIC).to_string())
+        } // if
+
+        // Unit system key/value pair:
+        if let Some(unit_system) = &self.unit_system {
+            query.push_str("&unitsSystem=");
+            query.push_str(&String::from(unit_system).to_uppercase())
+        } // if
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&languageCode=");
+            query.push_str(&String::from(language))
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
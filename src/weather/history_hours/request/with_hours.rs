@@ -0,0 +1,34 @@
+use crate::weather::history_hours::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Limits the number of hours to look back.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `hours` ‧ The number of hours, from 1 to 24. Defaults to 24.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_hours(24)
+    /// ```
+
+    pub fn with_hours(
+        &'a mut self,
+        hours: u8
+    ) -> &'a mut Request {
+
+        // Set hours in Request struct.
+        self.hours = Some(hours);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
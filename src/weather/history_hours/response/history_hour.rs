@@ -0,0 +1,103 @@
+//! Contains the `HistoryHour` struct. Each past hour of a location's weather
+//! history is reported with one of these.
+
+use crate::weather::{
+    air_pressure::AirPressure,
+    display_date_time::DisplayDateTime,
+    ice_thickness::IceThickness,
+    interval::Interval,
+    precipitation::Precipitation,
+    temperature::Temperature,
+    visibility::Visibility,
+    weather_condition::WeatherCondition,
+    wind::Wind,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents an [hourly history
+/// record](https://developers.google.com/maps/documentation/weather/reference/rest/v1/history.hours/lookup#HistoryHour)
+/// at a given location.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryHour {
+
+    /// The one hour interval (in UTC time) this historical data is valid for
+    /// (the timestamps are rounded down to the closest hour).
+    pub interval: Option<Interval>,
+
+    /// The local date and time in the time zone of the location (civil time)
+    /// which this hourly historical data is valid for. This field may be used
+    /// for display purposes on the client side.
+    #[serde(alias = "displayDateTime")]
+    pub display_date_time: Option<DisplayDateTime>,
+
+    /// True if this hour is between the local sunrise (inclusive) and sunset
+    /// (exclusive) times. Otherwise, it is nighttime (between the sunset and
+    /// the next sunrise).
+    #[serde(alias = "isDaytime")]
+    pub is_daytime: Option<bool>,
+
+    /// The weather condition.
+    #[serde(alias = "weatherCondition")]
+    pub weather_condition: Option<WeatherCondition>,
+
+    /// The temperature.
+    pub temperature: Option<Temperature>,
+
+    /// The measure of how the temperature feels like.
+    #[serde(alias = "feelsLikeTemperature")]
+    pub feels_like_temperature: Option<Temperature>,
+
+    /// The dew point temperature.
+    #[serde(alias = "dewPoint")]
+    pub dew_point: Option<Temperature>,
+
+    /// The heat index temperature.
+    #[serde(alias = "heatIndex")]
+    pub heat_index: Option<Temperature>,
+
+    /// The wind chill, air temperature exposed on the skin.
+    #[serde(alias = "windChill")]
+    pub wind_chill: Option<Temperature>,
+
+    /// The wet bulb temperature, the lowest temperature achievable by
+    /// evaporating water.
+    #[serde(alias = "wetBulbTemperature")]
+    pub wet_bulb_temperature: Option<Temperature>,
+
+    /// The percent of relative humidity (values from 0 to 100).
+    #[serde(alias = "relativeHumidity")]
+    pub relative_humidity: Option<u8>,
+
+    /// The maximum ultraviolet (UV) index.
+    #[serde(alias = "uvIndex")]
+    pub uv_index: Option<u8>,
+
+    /// The precipitation probability and amount of precipitation accumulated.
+    pub precipitation: Option<Precipitation>,
+
+    /// The thunderstorm probability (values from 0 to 100).
+    #[serde(alias = "thunderstormProbability")]
+    pub thunderstorm_probability: Option<u8>,
+
+    /// The air pressure conditions.
+    #[serde(alias = "airPressure")]
+    pub air_pressure: Option<AirPressure>,
+
+    /// The wind conditions.
+    pub wind: Option<Wind>,
+
+    /// The visibility.
+    pub visibility: Option<Visibility>,
+
+    /// Percentage of the sky covered by clouds (values from 0 to 100).
+    #[serde(alias = "cloudCover")]
+    pub cloud_cover: Option<u8>,
+
+    /// The amount of ice accumulated.
+    #[serde(alias = "iceThickness")]
+    pub ice_thickness: Option<IceThickness>,
+
+} // struct
@@ -0,0 +1,22 @@
+//! Contains the `IceThickness` struct. It reports the expected accumulation
+//! of ice from freezing rain.
+
+use crate::weather::unit::Unit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents [ice
+/// thickness](https://developers.google.com/maps/documentation/weather/reference/rest/v1/IceThickness)
+/// conditions.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct IceThickness {
+
+    /// The ice thickness value.
+    pub thickness: Option<f64>,
+
+    /// The code that represents the unit used to measure the ice thickness.
+    pub unit: Option<Unit>,
+
+} // struct
@@ -0,0 +1,67 @@
+//! Contains the `Interval` struct. It is the span of time that a forecast or
+//! historical record covers.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a time
+/// [interval](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Interval),
+/// encoded as a start time (inclusive) and an end time (exclusive). Both
+/// times are reported in UTC. Use the `local_start_time` and
+/// `local_end_time` methods with the response's time zone to get the times
+/// at the requested location.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Interval {
+
+    /// Inclusive start of the interval. If specified, a timestamp matching
+    /// this interval will have to be the same or after the start.
+    #[serde(alias = "startTime")]
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// Exclusive end of the interval. If specified, a timestamp matching this
+    /// interval will have to be before the end.
+    #[serde(alias = "endTime")]
+    pub end_time: Option<DateTime<Utc>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Interval {
+
+    /// Returns whether the given time falls within this interval. An
+    /// unspecified start or end time leaves that side of the interval
+    /// unbounded.
+
+    pub fn contains(&self, time: &DateTime<Utc>) -> bool {
+        self.start_time.as_ref().is_none_or(|start_time| start_time <= time) &&
+            self.end_time.as_ref().is_none_or(|end_time| time < end_time)
+    } // fn
+
+    /// Returns the start of the interval converted to the given time zone.
+    /// The time zone is typically the `time_zone` field of a Weather API
+    /// response.
+    ///
+    /// ```rust
+    /// if let Some(time_zone) = forecast.time_zone.as_ref().and_then(|tz| tz.id) {
+    ///     let start = forecast_hour.interval.as_ref().and_then(|i| i.local_start_time(&time_zone));
+    /// }
+    /// ```
+
+    pub fn local_start_time(&self, time_zone: &Tz) -> Option<DateTime<Tz>> {
+        self.start_time.map(|start_time| start_time.with_timezone(time_zone))
+    } // fn
+
+    /// Returns the end of the interval converted to the given time zone.
+    /// The time zone is typically the `time_zone` field of a Weather API
+    /// response.
+
+    pub fn local_end_time(&self, time_zone: &Tz) -> Option<DateTime<Tz>> {
+        self.end_time.map(|end_time| end_time.with_timezone(time_zone))
+    } // fn
+
+} // impl
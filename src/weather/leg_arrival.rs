@@ -0,0 +1,186 @@
+//! Looks up the forecast weather at the end of each leg of a Directions API
+//! route, at the time the traveller is expected to arrive there.
+
+use chrono::{DateTime, Duration, Utc};
+use crate::client::GoogleMapsClient;
+use crate::directions::request::unit_system::UnitSystem;
+use crate::directions::response::{leg::Leg, route::Route};
+use crate::latlng::LatLng;
+use crate::weather::error::Error;
+use crate::weather::forecast_hours::response::forecast_hour::ForecastHour;
+use futures::TryStreamExt;
+
+// -----------------------------------------------------------------------------
+
+/// The Weather API forecasts up to 240 hours ahead, starting at the current
+/// hour.
+const MAXIMUM_FORECAST_HOURS: i64 = 240;
+
+// =============================================================================
+
+impl Leg {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the time the traveller is expected to arrive at the end of this
+    /// leg.
+    ///
+    /// Transit legs report their own arrival time, which is used as-is. For
+    /// other travel modes, the leg's duration (in traffic, when available) is
+    /// added to the given departure time.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `departure_time` ‧ The time the traveller leaves the start of this
+    /// leg.
+
+    pub fn estimated_arrival_time(&self, departure_time: DateTime<Utc>) -> DateTime<Utc> {
+        match &self.arrival_time {
+            Some(arrival_time) => arrival_time.value.and_utc(),
+            None => departure_time + self.duration_in_traffic
+                .as_ref()
+                .unwrap_or(&self.duration)
+                .value,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the hourly forecast at this leg's `end_location` for the hour
+    /// in which the traveller is expected to arrive.
+    ///
+    /// Returns `None` if the arrival time is in the past, or further out than
+    /// the 240 hours the Weather API forecasts.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `departure_time` ‧ The time the traveller leaves the start of this
+    /// leg. See `estimated_arrival_time` for how the arrival time is found.
+    /// * `unit_system` ‧ The unit system the forecast should be expressed in.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let leg = &directions.routes[0].legs[0];
+    /// if let Some(forecast) = leg.arrival_weather(&google_maps_client, Utc::now(), UnitSystem::Metric).await? {
+    ///     println!("{:#?}", forecast.weather_condition);
+    /// }
+    /// ```
+
+    pub async fn arrival_weather(
+        &self,
+        client: &GoogleMapsClient,
+        departure_time: DateTime<Utc>,
+        unit_system: UnitSystem,
+    ) -> Result<Option<ForecastHour>, Error> {
+        forecast_hour_at(
+            client,
+            &self.end_location,
+            self.estimated_arrival_time(departure_time),
+            unit_system,
+        ).await
+    } // fn
+
+} // impl
+
+// =============================================================================
+
+impl Route {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the hourly forecast at the end of each leg of this route, for
+    /// the hour in which the traveller is expected to arrive there.
+    ///
+    /// Each leg is assumed to start as soon as the previous one ends. The
+    /// returned `Vec` has one entry per leg, in order. An entry is `None` if
+    /// that leg's arrival time is outside of the Weather API's forecast
+    /// range.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `departure_time` ‧ The time the traveller leaves the route's origin.
+    /// * `unit_system` ‧ The unit system the forecasts should be expressed in.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let route = &directions.routes[0];
+    /// let forecasts = route.arrival_weather(&google_maps_client, Utc::now(), UnitSystem::Imperial).await?;
+    ///
+    /// for (leg, forecast) in route.legs.iter().zip(forecasts) {
+    ///     println!("{}: {:#?}", leg.end_address, forecast.and_then(|f| f.temperature));
+    /// }
+    /// ```
+
+    pub async fn arrival_weather(
+        &self,
+        client: &GoogleMapsClient,
+        departure_time: DateTime<Utc>,
+        unit_system: UnitSystem,
+    ) -> Result<Vec<Option<ForecastHour>>, Error> {
+
+        let mut departure_time = departure_time;
+        let mut forecasts = Vec::with_capacity(self.legs.len());
+
+        for leg in &self.legs {
+            let arrival_time = leg.estimated_arrival_time(departure_time);
+            forecasts.push(forecast_hour_at(
+                client,
+                &leg.end_location,
+                arrival_time,
+                unit_system.clone(),
+            ).await?);
+            departure_time = arrival_time;
+        } // for
+
+        Ok(forecasts)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the hourly forecast record, at the given location, whose interval
+/// contains the given time. Only as many hours as needed to reach that time
+/// are requested.
+
+async fn forecast_hour_at(
+    client: &GoogleMapsClient,
+    location: &LatLng,
+    time: DateTime<Utc>,
+    unit_system: UnitSystem,
+) -> Result<Option<ForecastHour>, Error> {
+
+    // The forecast starts with the current hour. Times before it, or past the
+    // end of the forecast range, can not be looked up:
+    let hours_ahead = (time - Utc::now()).num_hours();
+    if time < Utc::now() - Duration::hours(1) || hours_ahead >= MAXIMUM_FORECAST_HOURS {
+        return Ok(None);
+    } // if
+
+    // Request one extra hour to account for the current, partial hour:
+    let hours = u8::try_from((hours_ahead + 2).clamp(1, MAXIMUM_FORECAST_HOURS))
+        .unwrap_or(u8::MAX);
+
+    let mut request = client.forecast_hours(location.clone());
+    let forecast_hours = request
+        .with_hours(hours)
+        .with_unit_system(unit_system)
+        .stream();
+    futures::pin_mut!(forecast_hours);
+
+    while let Some(forecast_hour) = forecast_hours.try_next().await? {
+        if let Some(interval) = &forecast_hour.interval {
+            if interval.contains(&time) {
+                return Ok(Some(forecast_hour));
+            } // if
+        } // if
+    } // while
+
+    Ok(None)
+
+} // fn
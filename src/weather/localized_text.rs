@@ -0,0 +1,21 @@
+//! Contains the `LocalizedText` struct. It holds human-readable text along
+//! with the language that it is written in.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// [Localized variant](https://developers.google.com/maps/documentation/weather/reference/rest/v1/LocalizedText)
+/// of a text in a particular language.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct LocalizedText {
+
+    /// Localized string in the language corresponding to `language_code`.
+    pub text: Option<String>,
+
+    /// The text's BCP-47 language code, such as "en-US" or "sr-Latn".
+    #[serde(alias = "languageCode")]
+    pub language_code: Option<String>,
+
+} // struct
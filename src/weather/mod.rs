@@ -0,0 +1,140 @@
+//! **Weather API** - Get current conditions, hourly & daily forecasts and
+//! hourly history for a location.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/weather/overview)
+//!
+//! * **Before you begin**: Before you start using the Weather API, you need a
+//! project with a billing account and the Weather API enabled. To learn more,
+//! see [Set up in Cloud Console](https://developers.google.com/maps/documentation/weather/get-api-key).
+//!
+//! The Weather API lets you request weather data for a specific location,
+//! expressed as a latitude & longitude. Values are expressed in metric units
+//! by default, or in imperial units when `UnitSystem::Imperial` is requested.
+//! All times are reported in UTC, and each response includes the time zone of
+//! the requested location so that they may be converted to local time with
+//! [chrono-tz](https://crates.io/crates/chrono-tz).
+//!
+//! # [Introduction](https://developers.google.com/maps/documentation/weather/overview#how-the-weather-api-works)
+//!
+//! The Weather API is available via a simple HTTPS interface, and exposes the
+//! following services:
+//!
+//! * [Current conditions](https://developers.google.com/maps/documentation/weather/current-conditions)
+//! This service returns the current weather conditions at a location, and a
+//! summary of the changes over the last 24 hours.
+//!
+//! * [Hourly forecast](https://developers.google.com/maps/documentation/weather/hourly-forecast)
+//! This service returns up to 240 hours of hourly forecasts.
+//!
+//! * [Daily forecast](https://developers.google.com/maps/documentation/weather/daily-forecast)
+//! This service returns up to 10 days of daily forecasts, split into daytime
+//! and nighttime parts, along with sun & moon events.
+//!
+//! * [Hourly history](https://developers.google.com/maps/documentation/weather/hourly-history)
+//! This service returns up to 24 hours of cached hourly weather history.
+//!
+//! The forecast & history services are paginated. Their requests have a
+//! `stream` method that returns every record as a `futures::Stream`,
+//! requesting subsequent pages as it is consumed.
+//!
+//! The `arrival_weather` methods on the Directions API `Leg` and `Route`
+//! structs look up the forecast at the end of each leg, for the time the
+//! traveller is expected to arrive there.
+
+pub mod air_pressure;
+pub mod cardinal_direction;
+pub mod current_conditions;
+pub mod date;
+pub mod display_date_time;
+pub mod error;
+pub mod error_response;
+pub mod forecast_days;
+pub mod forecast_hours;
+pub mod history_hours;
+pub mod ice_thickness;
+pub mod interval;
+#[cfg(feature = "enable-reqwest")]
+mod leg_arrival;
+pub mod localized_text;
+pub mod precipitation;
+pub mod precipitation_probability;
+pub mod precipitation_type;
+pub mod quantitative_precipitation_forecast;
+pub mod speed_unit;
+pub mod status;
+pub mod temperature;
+pub mod temperature_unit;
+pub mod time_zone;
+pub mod unit;
+pub mod visibility;
+pub mod weather_condition;
+pub mod weather_condition_type;
+pub mod wind;
+pub mod wind_direction;
+pub mod wind_speed;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::weather::{
+    air_pressure::AirPressure,
+    cardinal_direction::CardinalDirection,
+    date::Date as WeatherDate,
+    display_date_time::DisplayDateTime,
+    error::Error as WeatherError,
+    error_response::ErrorResponse as WeatherErrorResponse,
+    ice_thickness::IceThickness,
+    interval::Interval,
+    localized_text::LocalizedText,
+    precipitation::Precipitation,
+    precipitation_probability::PrecipitationProbability,
+    precipitation_type::PrecipitationType,
+    quantitative_precipitation_forecast::QuantitativePrecipitationForecast,
+    speed_unit::SpeedUnit,
+    status::Status as WeatherStatus,
+    temperature::Temperature,
+    temperature_unit::TemperatureUnit,
+    time_zone::TimeZone as WeatherTimeZone,
+    unit::Unit as WeatherUnit,
+    visibility::Visibility,
+    weather_condition::WeatherCondition,
+    weather_condition_type::WeatherConditionType,
+    wind::Wind,
+    wind_direction::WindDirection,
+    wind_speed::WindSpeed,
+}; // crate::weather
+
+pub use crate::weather::current_conditions::{
+    request::Request as CurrentConditionsRequest,
+    response::{
+        current_conditions_history::CurrentConditionsHistory,
+        Response as CurrentConditionsResponse,
+    }, // response
+}; // crate::weather::current_conditions
+
+pub use crate::weather::forecast_days::{
+    request::Request as ForecastDaysRequest,
+    response::{
+        forecast_day::ForecastDay,
+        forecast_day_part::ForecastDayPart,
+        moon_events::MoonEvents,
+        moon_phase::MoonPhase,
+        Response as ForecastDaysResponse,
+        sun_events::SunEvents,
+    }, // response
+}; // crate::weather::forecast_days
+
+pub use crate::weather::forecast_hours::{
+    request::Request as ForecastHoursRequest,
+    response::{
+        forecast_hour::ForecastHour,
+        Response as ForecastHoursResponse,
+    }, // response
+}; // crate::weather::forecast_hours
+
+pub use crate::weather::history_hours::{
+    request::Request as HistoryHoursRequest,
+    response::{
+        history_hour::HistoryHour,
+        Response as HistoryHoursResponse,
+    }, // response
+}; // crate::weather::history_hours
@@ -0,0 +1,32 @@
+//! Contains the `Precipitation` struct. It groups the probability and amount
+//! of expected or recorded precipitation.
+
+use crate::weather::{
+    precipitation_probability::PrecipitationProbability,
+    quantitative_precipitation_forecast::QuantitativePrecipitationForecast,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a set of
+/// [precipitation](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Precipitation)
+/// values at a given location.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Precipitation {
+
+    /// The probability of precipitation (values from 0 to 100).
+    pub probability: Option<PrecipitationProbability>,
+
+    /// The amount of precipitation (rain or snow), measured as liquid water
+    /// equivalent, that has accumulated over a period of time. Note: QPF is an
+    /// abbreviation for Quantitative Precipitation Forecast.
+    pub qpf: Option<QuantitativePrecipitationForecast>,
+
+    /// The amount of snow, measured as liquid water equivalent, that has
+    /// accumulated over a period of time.
+    #[serde(alias = "snowQpf")]
+    pub snow_qpf: Option<QuantitativePrecipitationForecast>,
+
+} // struct
@@ -0,0 +1,23 @@
+//! Contains the `PrecipitationProbability` struct. It holds the chance of
+//! precipitation and the kind of precipitation expected.
+
+use crate::weather::precipitation_type::PrecipitationType;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// [Probability](https://developers.google.com/maps/documentation/weather/reference/rest/v1/PrecipitationProbability)
+/// of precipitation of a specific type.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PrecipitationProbability {
+
+    /// A percentage from 0 to 100 that indicates the chances of
+    /// precipitation.
+    pub percent: Option<u8>,
+
+    /// A code that indicates the type of precipitation.
+    #[serde(alias = "type")]
+    pub precipitation_type: Option<PrecipitationType>,
+
+} // struct
@@ -0,0 +1,142 @@
+//! Contains the `PrecipitationType` enum and its associated traits. It
+//! describes the kind of precipitation that is expected or that fell.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// A set of values that describe the [type of
+/// precipitation](https://developers.google.com/maps/documentation/weather/reference/rest/v1/PrecipitationProbability#precipitationtype)
+/// at a given location.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum PrecipitationType {
+    /// No precipitation.
+    #[serde(alias = "NONE")]
+    None,
+    /// Snow precipitation.
+    #[serde(alias = "SNOW")]
+    Snow,
+    /// Rain precipitation.
+    #[serde(alias = "RAIN")]
+    Rain,
+    /// Light rain precipitation.
+    #[serde(alias = "LIGHT_RAIN")]
+    LightRain,
+    /// Heavy rain precipitation.
+    #[serde(alias = "HEAVY_RAIN")]
+    HeavyRain,
+    /// Both rain and snow precipitations.
+    #[serde(alias = "RAIN_AND_SNOW")]
+    RainAndSnow,
+    /// Sleet precipitation.
+    #[serde(alias = "SLEET")]
+    Sleet,
+    /// Freezing rain precipitation.
+    #[serde(alias = "FREEZING_RAIN")]
+    FreezingRain,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for PrecipitationType {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match PrecipitationType::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PrecipitationType> for String {
+    /// Converts a `PrecipitationType` enum to a `String` that contains a
+    /// precipitation type code.
+    fn from(precipitation_type: &PrecipitationType) -> String {
+        match precipitation_type {
+            PrecipitationType::None => String::from("NONE"),
+            PrecipitationType::Snow => String::from("SNOW"),
+            PrecipitationType::Rain => String::from("RAIN"),
+            PrecipitationType::LightRain => String::from("LIGHT_RAIN"),
+            PrecipitationType::HeavyRain => String::from("HEAVY_RAIN"),
+            PrecipitationType::RainAndSnow => String::from("RAIN_AND_SNOW"),
+            PrecipitationType::Sleet => String::from("SLEET"),
+            PrecipitationType::FreezingRain => String::from("FREEZING_RAIN"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static PRECIPITATION_TYPES_BY_CODE: phf::Map<&'static str, PrecipitationType> = phf_map! {
+    "NONE" => PrecipitationType::None,
+    "SNOW" => PrecipitationType::Snow,
+    "RAIN" => PrecipitationType::Rain,
+    "LIGHT_RAIN" => PrecipitationType::LightRain,
+    "HEAVY_RAIN" => PrecipitationType::HeavyRain,
+    "RAIN_AND_SNOW" => PrecipitationType::RainAndSnow,
+    "SLEET" => PrecipitationType::Sleet,
+    "FREEZING_RAIN" => PrecipitationType::FreezingRain,
+};
+
+impl std::convert::TryFrom<&str> for PrecipitationType {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `PrecipitationType` enum from a `String` that contains a valid
+    /// precipitation type code.
+    fn try_from(precipitation_type_code: &str) -> Result<Self, Self::Error> {
+        PRECIPITATION_TYPES_BY_CODE
+            .get(precipitation_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidPrecipitationType(precipitation_type_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for PrecipitationType {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `PrecipitationType` enum from a `String` that contains a valid
+    /// precipitation type code.
+    fn from_str(precipitation_type_code: &str) -> Result<Self, Self::Err> {
+        PRECIPITATION_TYPES_BY_CODE
+            .get(precipitation_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidPrecipitationType(precipitation_type_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for PrecipitationType {
+    /// Returns a reasonable default variant for the `PrecipitationType` enum type.
+    fn default() -> Self {
+        PrecipitationType::None
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PrecipitationType {
+    /// Formats a `PrecipitationType` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PrecipitationType::None => write!(f, "None"),
+            PrecipitationType::Snow => write!(f, "Snow"),
+            PrecipitationType::Rain => write!(f, "Rain"),
+            PrecipitationType::LightRain => write!(f, "Light rain"),
+            PrecipitationType::HeavyRain => write!(f, "Heavy rain"),
+            PrecipitationType::RainAndSnow => write!(f, "Rain and snow"),
+            PrecipitationType::Sleet => write!(f, "Sleet"),
+            PrecipitationType::FreezingRain => write!(f, "Freezing rain"),
+        } // match
+    } // fn
+} // impl
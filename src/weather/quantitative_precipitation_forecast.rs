@@ -0,0 +1,25 @@
+//! Contains the `QuantitativePrecipitationForecast` struct. It expresses an
+//! amount of liquid (or liquid-equivalent) precipitation.
+
+use crate::weather::unit::Unit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the expected amount of melted precipitation accumulated over a
+/// specified time period over a specified area. This is known as
+/// [QPF](https://developers.google.com/maps/documentation/weather/reference/rest/v1/QuantitativePrecipitationForecast).
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct QuantitativePrecipitationForecast {
+
+    /// The amount of precipitation, measured as liquid water equivalent, that
+    /// has accumulated on a surface. The unit follows the `UnitSystem` set in
+    /// the request: millimeters for `Metric`, and inches for `Imperial`.
+    pub quantity: Option<f64>,
+
+    /// The code of the unit used to measure the amount of accumulated
+    /// precipitation.
+    pub unit: Option<Unit>,
+
+} // struct
@@ -0,0 +1,106 @@
+//! Contains the `SpeedUnit` enum and its associated traits. It identifies
+//! the unit that a wind speed is expressed in.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Represents the [unit](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Wind#speedunit)
+/// used to express a wind speed. The unit depends on the `UnitSystem`
+/// requested.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum SpeedUnit {
+    /// The speed is measured in kilometers per hour.
+    #[serde(alias = "KILOMETERS_PER_HOUR")]
+    KilometersPerHour,
+    /// The speed is measured in miles per hour.
+    #[serde(alias = "MILES_PER_HOUR")]
+    MilesPerHour,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for SpeedUnit {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match SpeedUnit::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SpeedUnit> for String {
+    /// Converts a `SpeedUnit` enum to a `String` that contains a speed unit
+    /// code.
+    fn from(speed_unit: &SpeedUnit) -> String {
+        match speed_unit {
+            SpeedUnit::KilometersPerHour => String::from("KILOMETERS_PER_HOUR"),
+            SpeedUnit::MilesPerHour => String::from("MILES_PER_HOUR"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static SPEED_UNITS_BY_CODE: phf::Map<&'static str, SpeedUnit> = phf_map! {
+    "KILOMETERS_PER_HOUR" => SpeedUnit::KilometersPerHour,
+    "MILES_PER_HOUR" => SpeedUnit::MilesPerHour,
+};
+
+impl std::convert::TryFrom<&str> for SpeedUnit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `SpeedUnit` enum from a `String` that contains a valid speed unit
+    /// code.
+    fn try_from(speed_unit_code: &str) -> Result<Self, Self::Error> {
+        SPEED_UNITS_BY_CODE
+            .get(speed_unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidSpeedUnit(speed_unit_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for SpeedUnit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `SpeedUnit` enum from a `String` that contains a valid speed unit
+    /// code.
+    fn from_str(speed_unit_code: &str) -> Result<Self, Self::Err> {
+        SPEED_UNITS_BY_CODE
+            .get(speed_unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidSpeedUnit(speed_unit_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for SpeedUnit {
+    /// Returns a reasonable default variant for the `SpeedUnit` enum type.
+    fn default() -> Self {
+        SpeedUnit::KilometersPerHour
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SpeedUnit {
+    /// Formats a `SpeedUnit` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SpeedUnit::KilometersPerHour => write!(f, "Kilometers per hour"),
+            SpeedUnit::MilesPerHour => write!(f, "Miles per hour"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,217 @@
+//! The `"status"` field within the Weather API error response object contains
+//! the status of the request, and may contain debugging information to help
+//! you track down why the Weather API is not working.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Indicates the status of the response. The Weather API uses the canonical
+/// [Google RPC error codes](https://cloud.google.com/apis/design/errors#handling_errors).
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Status {
+
+    /// The client specified an invalid argument. For example, the latitude
+    /// or longitude is out of range, or the number of requested `hours` or
+    /// `days` is not supported.
+    #[serde(alias = "INVALID_ARGUMENT")]
+    InvalidArgument,
+
+    /// The request can not be executed in the current system state.
+    #[serde(alias = "FAILED_PRECONDITION")]
+    FailedPrecondition,
+
+    /// The client specified an invalid range.
+    #[serde(alias = "OUT_OF_RANGE")]
+    OutOfRange,
+
+    /// Request not authenticated due to a missing, invalid, or expired API
+    /// key.
+    #[serde(alias = "UNAUTHENTICATED")]
+    Unauthenticated,
+
+    /// The request was denied for one or more of the following reasons:
+    ///
+    /// * The API key is missing or invalid.
+    /// * Billing has not been enabled on your account.
+    /// * The Weather API has not been enabled for this project.
+    /// * A self-imposed usage cap has been exceeded.
+    #[serde(alias = "PERMISSION_DENIED")]
+    PermissionDenied,
+
+    /// No weather information is available for the requested location.
+    #[serde(alias = "NOT_FOUND")]
+    NotFound,
+
+    /// Concurrency conflict, such as a read-modify-write conflict.
+    #[serde(alias = "ABORTED")]
+    Aborted,
+
+    /// The resource that a client tried to create already exists.
+    #[serde(alias = "ALREADY_EXISTS")]
+    AlreadyExists,
+
+    /// You have exceeded the request limit that you configured in the Google
+    /// Cloud Platform Console, or the project has run out of quota.
+    #[serde(alias = "RESOURCE_EXHAUSTED")]
+    ResourceExhausted,
+
+    /// Request cancelled by the client.
+    #[serde(alias = "CANCELLED")]
+    Cancelled,
+
+    /// Unrecoverable data loss or data corruption.
+    #[serde(alias = "DATA_LOSS")]
+    DataLoss,
+
+    /// Unknown server error. Typically a server bug.
+    #[serde(alias = "UNKNOWN")]
+    Unknown,
+
+    /// Internal server error. Typically a server bug.
+    #[serde(alias = "INTERNAL")]
+    Internal,
+
+    /// API method not implemented by the server.
+    #[serde(alias = "UNIMPLEMENTED")]
+    Unimplemented,
+
+    /// Service unavailable. Typically the server is down.
+    #[serde(alias = "UNAVAILABLE")]
+    Unavailable,
+
+    /// Request deadline exceeded. This will happen only if the caller sets a
+    /// deadline that is shorter than the method's default deadline.
+    #[serde(alias = "DEADLINE_EXCEEDED")]
+    DeadlineExceeded,
+
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Status {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Status::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Status> for String {
+    /// Converts a `Status` enum to a `String` that contains a status
+    /// code.
+    fn from(status: &Status) -> String {
+        match status {
+            Status::InvalidArgument => String::from("INVALID_ARGUMENT"),
+            Status::FailedPrecondition => String::from("FAILED_PRECONDITION"),
+            Status::OutOfRange => String::from("OUT_OF_RANGE"),
+            Status::Unauthenticated => String::from("UNAUTHENTICATED"),
+            Status::PermissionDenied => String::from("PERMISSION_DENIED"),
+            Status::NotFound => String::from("NOT_FOUND"),
+            Status::Aborted => String::from("ABORTED"),
+            Status::AlreadyExists => String::from("ALREADY_EXISTS"),
+            Status::ResourceExhausted => String::from("RESOURCE_EXHAUSTED"),
+            Status::Cancelled => String::from("CANCELLED"),
+            Status::DataLoss => String::from("DATA_LOSS"),
+            Status::Unknown => String::from("UNKNOWN"),
+            Status::Internal => String::from("INTERNAL"),
+            Status::Unimplemented => String::from("UNIMPLEMENTED"),
+            Status::Unavailable => String::from("UNAVAILABLE"),
+            Status::DeadlineExceeded => String::from("DEADLINE_EXCEEDED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static STATUSES_BY_CODE: phf::Map<&'static str, Status> = phf_map! {
+    "INVALID_ARGUMENT" => Status::InvalidArgument,
+    "FAILED_PRECONDITION" => Status::FailedPrecondition,
+    "OUT_OF_RANGE" => Status::OutOfRange,
+    "UNAUTHENTICATED" => Status::Unauthenticated,
+    "PERMISSION_DENIED" => Status::PermissionDenied,
+    "NOT_FOUND" => Status::NotFound,
+    "ABORTED" => Status::Aborted,
+    "ALREADY_EXISTS" => Status::AlreadyExists,
+    "RESOURCE_EXHAUSTED" => Status::ResourceExhausted,
+    "CANCELLED" => Status::Cancelled,
+    "DATA_LOSS" => Status::DataLoss,
+    "UNKNOWN" => Status::Unknown,
+    "INTERNAL" => Status::Internal,
+    "UNIMPLEMENTED" => Status::Unimplemented,
+    "UNAVAILABLE" => Status::Unavailable,
+    "DEADLINE_EXCEEDED" => Status::DeadlineExceeded,
+};
+
+impl std::convert::TryFrom<&str> for Status {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `Status` enum from a `String` that contains a valid status
+    /// code.
+    fn try_from(status_code: &str) -> Result<Self, Self::Error> {
+        STATUSES_BY_CODE
+            .get(status_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidStatusCode(status_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Status {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `Status` enum from a `String` that contains a valid status
+    /// code.
+    fn from_str(status_code: &str) -> Result<Self, Self::Err> {
+        STATUSES_BY_CODE
+            .get(status_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidStatusCode(status_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Status {
+    /// Returns a reasonable default variant for the `Status` enum type.
+    fn default() -> Self {
+        Status::InvalidArgument
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Status {
+    /// Formats a `Status` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Status::InvalidArgument => write!(f, "Invalid Argument"),
+            Status::FailedPrecondition => write!(f, "Failed Precondition"),
+            Status::OutOfRange => write!(f, "Out of Range"),
+            Status::Unauthenticated => write!(f, "Unauthenticated"),
+            Status::PermissionDenied => write!(f, "Permission Denied"),
+            Status::NotFound => write!(f, "Not Found"),
+            Status::Aborted => write!(f, "Aborted"),
+            Status::AlreadyExists => write!(f, "Already Exists"),
+            Status::ResourceExhausted => write!(f, "Resource Exhausted"),
+            Status::Cancelled => write!(f, "Cancelled"),
+            Status::DataLoss => write!(f, "Data Loss"),
+            Status::Unknown => write!(f, "Unknown"),
+            Status::Internal => write!(f, "Internal"),
+            Status::Unimplemented => write!(f, "Unimplemented"),
+            Status::Unavailable => write!(f, "Unavailable"),
+            Status::DeadlineExceeded => write!(f, "Deadline Exceeded"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,23 @@
+//! Contains the `Temperature` struct. It is used for air, dew point, heat
+//! index, wind chill and other temperatures reported by the Weather API.
+
+use crate::weather::temperature_unit::TemperatureUnit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a
+/// [temperature](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Temperature)
+/// value. The unit follows the `UnitSystem` set in the request: Celsius for
+/// `Metric`, and Fahrenheit for `Imperial`.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Temperature {
+
+    /// The temperature value (in degrees) in the specified unit.
+    pub degrees: Option<f64>,
+
+    /// The code for the unit used to measure the temperature value.
+    pub unit: Option<TemperatureUnit>,
+
+} // struct
@@ -0,0 +1,106 @@
+//! Contains the `TemperatureUnit` enum and its associated traits. It
+//! identifies the scale that a `Temperature` is expressed in.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Represents the [unit](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Temperature#temperatureunit)
+/// used to express a temperature value. The unit depends on the `UnitSystem`
+/// requested.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum TemperatureUnit {
+    /// The temperature is measured in Celsius.
+    #[serde(alias = "CELSIUS")]
+    Celsius,
+    /// The temperature is measured in Fahrenheit.
+    #[serde(alias = "FAHRENHEIT")]
+    Fahrenheit,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for TemperatureUnit {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match TemperatureUnit::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&TemperatureUnit> for String {
+    /// Converts a `TemperatureUnit` enum to a `String` that contains a
+    /// temperature unit code.
+    fn from(temperature_unit: &TemperatureUnit) -> String {
+        match temperature_unit {
+            TemperatureUnit::Celsius => String::from("CELSIUS"),
+            TemperatureUnit::Fahrenheit => String::from("FAHRENHEIT"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static TEMPERATURE_UNITS_BY_CODE: phf::Map<&'static str, TemperatureUnit> = phf_map! {
+    "CELSIUS" => TemperatureUnit::Celsius,
+    "FAHRENHEIT" => TemperatureUnit::Fahrenheit,
+};
+
+impl std::convert::TryFrom<&str> for TemperatureUnit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `TemperatureUnit` enum from a `String` that contains a valid
+    /// temperature unit code.
+    fn try_from(temperature_unit_code: &str) -> Result<Self, Self::Error> {
+        TEMPERATURE_UNITS_BY_CODE
+            .get(temperature_unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidTemperatureUnit(temperature_unit_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for TemperatureUnit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `TemperatureUnit` enum from a `String` that contains a valid
+    /// temperature unit code.
+    fn from_str(temperature_unit_code: &str) -> Result<Self, Self::Err> {
+        TEMPERATURE_UNITS_BY_CODE
+            .get(temperature_unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidTemperatureUnit(temperature_unit_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for TemperatureUnit {
+    /// Returns a reasonable default variant for the `TemperatureUnit` enum type.
+    fn default() -> Self {
+        TemperatureUnit::Celsius
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for TemperatureUnit {
+    /// Formats a `TemperatureUnit` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TemperatureUnit::Celsius => write!(f, "Celsius"),
+            TemperatureUnit::Fahrenheit => write!(f, "Fahrenheit"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,22 @@
+//! Contains the `TimeZone` struct. The Weather API reports the time zone of
+//! the requested location so that UTC times may be converted to local time.
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a [time
+/// zone](https://developers.google.com/maps/documentation/weather/reference/rest/v1/TimeZone)
+/// from the [IANA Time Zone Database](https://www.iana.org/time-zones).
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TimeZone {
+
+    /// IANA Time Zone Database time zone, e.g. "America/New_York".
+    pub id: Option<Tz>,
+
+    /// IANA Time Zone Database version number, e.g. "2019a".
+    pub version: Option<String>,
+
+} // struct
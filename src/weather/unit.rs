@@ -0,0 +1,116 @@
+//! Contains the `Unit` enum and its associated traits. It identifies the
+//! unit that a precipitation amount, ice thickness or visibility distance is
+//! expressed in.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Represents the [unit](https://developers.google.com/maps/documentation/weather/reference/rest/v1/QuantitativePrecipitationForecast#unit)
+/// used to express a length. Precipitation & ice amounts are reported in
+/// millimeters or inches, while visibility is reported in kilometers or
+/// miles, depending on the `UnitSystem` requested.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Unit {
+    /// The amount is measured in millimeters.
+    #[serde(alias = "MILLIMETERS")]
+    Millimeters,
+    /// The amount is measured in inches.
+    #[serde(alias = "INCHES")]
+    Inches,
+    /// The distance is measured in kilometers.
+    #[serde(alias = "KILOMETERS")]
+    Kilometers,
+    /// The distance is measured in miles.
+    #[serde(alias = "MILES")]
+    Miles,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Unit {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Unit::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Unit> for String {
+    /// Converts a `Unit` enum to a `String` that contains a unit code.
+    fn from(unit: &Unit) -> String {
+        match unit {
+            Unit::Millimeters => String::from("MILLIMETERS"),
+            Unit::Inches => String::from("INCHES"),
+            Unit::Kilometers => String::from("KILOMETERS"),
+            Unit::Miles => String::from("MILES"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static UNITS_BY_CODE: phf::Map<&'static str, Unit> = phf_map! {
+    "MILLIMETERS" => Unit::Millimeters,
+    "INCHES" => Unit::Inches,
+    "KILOMETERS" => Unit::Kilometers,
+    "MILES" => Unit::Miles,
+};
+
+impl std::convert::TryFrom<&str> for Unit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `Unit` enum from a `String` that contains a valid unit code.
+    fn try_from(unit_code: &str) -> Result<Self, Self::Error> {
+        UNITS_BY_CODE
+            .get(unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidUnit(unit_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Unit {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `Unit` enum from a `String` that contains a valid unit code.
+    fn from_str(unit_code: &str) -> Result<Self, Self::Err> {
+        UNITS_BY_CODE
+            .get(unit_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidUnit(unit_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Unit {
+    /// Returns a reasonable default variant for the `Unit` enum type.
+    fn default() -> Self {
+        Unit::Millimeters
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Unit {
+    /// Formats a `Unit` enum into a string that is presentable to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Unit::Millimeters => write!(f, "Millimeters"),
+            Unit::Inches => write!(f, "Inches"),
+            Unit::Kilometers => write!(f, "Kilometers"),
+            Unit::Miles => write!(f, "Miles"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+//! Contains the `Visibility` struct. It reports how far objects can be
+//! clearly seen.
+
+use crate::weather::unit::Unit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents
+/// [visibility](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Visibility)
+/// conditions, the distance at which objects can be discerned. The unit
+/// follows the `UnitSystem` set in the request: kilometers for `Metric`, and
+/// miles for `Imperial`.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Visibility {
+
+    /// The visibility distance in the specified unit.
+    pub distance: Option<f64>,
+
+    /// The code that represents the unit used to measure the distance.
+    pub unit: Option<Unit>,
+
+} // struct
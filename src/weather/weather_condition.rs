@@ -0,0 +1,54 @@
+//! Contains the `WeatherCondition` struct. It summarizes the weather at a
+//! location with an icon, a description and a condition type.
+
+use crate::weather::{
+    localized_text::LocalizedText,
+    weather_condition_type::WeatherConditionType,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a [weather
+/// condition](https://developers.google.com/maps/documentation/weather/reference/rest/v1/WeatherCondition)
+/// for a given location at a given period of time.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WeatherCondition {
+
+    /// The base URI for the icon not including the file type extension. To
+    /// display the icon, append a theme if desired and the file type
+    /// extension (`.png` or `.svg`) to this URI. By default, the icon is
+    /// light themed, but `_dark` can be appended for dark mode.
+    #[serde(alias = "iconBaseUri")]
+    pub icon_base_uri: Option<String>,
+
+    /// The textual description for this weather condition (localized).
+    pub description: Option<LocalizedText>,
+
+    /// The type of weather condition.
+    #[serde(alias = "type")]
+    pub condition_type: Option<WeatherConditionType>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl WeatherCondition {
+
+    /// Returns the URL of the light-themed SVG icon for this weather
+    /// condition, if Google provided an icon base URI.
+    ///
+    /// ```rust
+    /// if let Some(icon_url) = weather_condition.icon_url() {
+    ///     println!("<img src=\"{icon_url}\">");
+    /// }
+    /// ```
+
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon_base_uri
+            .as_ref()
+            .map(|icon_base_uri| format!("{icon_base_uri}.svg"))
+    } // fn
+
+} // impl
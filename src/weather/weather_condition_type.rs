@@ -0,0 +1,338 @@
+//! Contains the `WeatherConditionType` enum and its associated traits. It
+//! describes the weather at a location, such as clear skies or heavy rain.
+
+use crate::weather::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Serialize, Deserializer};
+
+// -----------------------------------------------------------------------------
+
+/// Marks the [weather
+/// condition](https://developers.google.com/maps/documentation/weather/reference/rest/v1/WeatherCondition#type)
+/// type. The Weather API's icon & description are derived from this value.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum WeatherConditionType {
+    /// No clouds.
+    #[serde(alias = "CLEAR")]
+    Clear,
+    /// Periodic clouds.
+    #[serde(alias = "MOSTLY_CLEAR")]
+    MostlyClear,
+    /// Partly cloudy (some clouds).
+    #[serde(alias = "PARTLY_CLOUDY")]
+    PartlyCloudy,
+    /// Mostly cloudy (more clouds than sun).
+    #[serde(alias = "MOSTLY_CLOUDY")]
+    MostlyCloudy,
+    /// Cloudy (all clouds, no sun).
+    #[serde(alias = "CLOUDY")]
+    Cloudy,
+    /// High wind.
+    #[serde(alias = "WINDY")]
+    Windy,
+    /// High wind with precipitation.
+    #[serde(alias = "WIND_AND_RAIN")]
+    WindAndRain,
+    /// Light intermittent rain.
+    #[serde(alias = "LIGHT_RAIN_SHOWERS")]
+    LightRainShowers,
+    /// Chance of intermittent rain.
+    #[serde(alias = "CHANCE_OF_SHOWERS")]
+    ChanceOfShowers,
+    /// Intermittent rain.
+    #[serde(alias = "SCATTERED_SHOWERS")]
+    ScatteredShowers,
+    /// Showers are considered to be rainfall that has a shorter duration than
+    /// rain, and is characterized by suddenness in terms of start and stop
+    /// times, and rapid changes in intensity.
+    #[serde(alias = "RAIN_SHOWERS")]
+    RainShowers,
+    /// Intense showers.
+    #[serde(alias = "HEAVY_RAIN_SHOWERS")]
+    HeavyRainShowers,
+    /// Rain (light to moderate in quantity).
+    #[serde(alias = "LIGHT_TO_MODERATE_RAIN")]
+    LightToModerateRain,
+    /// Rain (moderate to heavy in quantity).
+    #[serde(alias = "MODERATE_TO_HEAVY_RAIN")]
+    ModerateToHeavyRain,
+    /// Moderate rain.
+    #[serde(alias = "RAIN")]
+    Rain,
+    /// Light rain.
+    #[serde(alias = "LIGHT_RAIN")]
+    LightRain,
+    /// Heavy rain.
+    #[serde(alias = "HEAVY_RAIN")]
+    HeavyRain,
+    /// Rain periodically heavy.
+    #[serde(alias = "RAIN_PERIODICALLY_HEAVY")]
+    RainPeriodicallyHeavy,
+    /// Light snow that is falling at varying intensities for brief periods of
+    /// time.
+    #[serde(alias = "LIGHT_SNOW_SHOWERS")]
+    LightSnowShowers,
+    /// Chance of snow showers.
+    #[serde(alias = "CHANCE_OF_SNOW_SHOWERS")]
+    ChanceOfSnowShowers,
+    /// Snow that is falling at varying intensities for brief periods of time.
+    #[serde(alias = "SCATTERED_SNOW_SHOWERS")]
+    ScatteredSnowShowers,
+    /// Snow showers.
+    #[serde(alias = "SNOW_SHOWERS")]
+    SnowShowers,
+    /// Heavy snow showers.
+    #[serde(alias = "HEAVY_SNOW_SHOWERS")]
+    HeavySnowShowers,
+    /// Light to moderate snow.
+    #[serde(alias = "LIGHT_TO_MODERATE_SNOW")]
+    LightToModerateSnow,
+    /// Moderate to heavy snow.
+    #[serde(alias = "MODERATE_TO_HEAVY_SNOW")]
+    ModerateToHeavySnow,
+    /// Snow.
+    #[serde(alias = "SNOW")]
+    Snow,
+    /// Light snow.
+    #[serde(alias = "LIGHT_SNOW")]
+    LightSnow,
+    /// Heavy snow.
+    #[serde(alias = "HEAVY_SNOW")]
+    HeavySnow,
+    /// Snow with possible thunder and lightning.
+    #[serde(alias = "SNOWSTORM")]
+    Snowstorm,
+    /// Snow, at times heavy.
+    #[serde(alias = "SNOW_PERIODICALLY_HEAVY")]
+    SnowPeriodicallyHeavy,
+    /// Heavy snow with possible thunder and lightning.
+    #[serde(alias = "HEAVY_SNOW_STORM")]
+    HeavySnowStorm,
+    /// Snow with intense wind.
+    #[serde(alias = "BLOWING_SNOW")]
+    BlowingSnow,
+    /// Rain and snow mix.
+    #[serde(alias = "RAIN_AND_SNOW")]
+    RainAndSnow,
+    /// Hail.
+    #[serde(alias = "HAIL")]
+    Hail,
+    /// Hail that is falling at varying intensities for brief periods of time.
+    #[serde(alias = "HAIL_SHOWERS")]
+    HailShowers,
+    /// Thunderstorm.
+    #[serde(alias = "THUNDERSTORM")]
+    Thunderstorm,
+    /// A shower of rain accompanied by thunder and lightning.
+    #[serde(alias = "THUNDERSHOWER")]
+    Thundershower,
+    /// Light thunderstorm rain.
+    #[serde(alias = "LIGHT_THUNDERSTORM_RAIN")]
+    LightThunderstormRain,
+    /// Thunderstorms that has rain in various intensities for brief periods of
+    /// time.
+    #[serde(alias = "SCATTERED_THUNDERSTORMS")]
+    ScatteredThunderstorms,
+    /// Heavy thunderstorm.
+    #[serde(alias = "HEAVY_THUNDERSTORM")]
+    HeavyThunderstorm,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for WeatherConditionType {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match WeatherConditionType::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&WeatherConditionType> for String {
+    /// Converts a `WeatherConditionType` enum to a `String` that contains a
+    /// weather condition type code.
+    fn from(weather_condition_type: &WeatherConditionType) -> String {
+        match weather_condition_type {
+            WeatherConditionType::Clear => String::from("CLEAR"),
+            WeatherConditionType::MostlyClear => String::from("MOSTLY_CLEAR"),
+            WeatherConditionType::PartlyCloudy => String::from("PARTLY_CLOUDY"),
+            WeatherConditionType::MostlyCloudy => String::from("MOSTLY_CLOUDY"),
+            WeatherConditionType::Cloudy => String::from("CLOUDY"),
+            WeatherConditionType::Windy => String::from("WINDY"),
+            WeatherConditionType::WindAndRain => String::from("WIND_AND_RAIN"),
+            WeatherConditionType::LightRainShowers => String::from("LIGHT_RAIN_SHOWERS"),
+            WeatherConditionType::ChanceOfShowers => String::from("CHANCE_OF_SHOWERS"),
+            WeatherConditionType::ScatteredShowers => String::from("SCATTERED_SHOWERS"),
+            WeatherConditionType::RainShowers => String::from("RAIN_SHOWERS"),
+            WeatherConditionType::HeavyRainShowers => String::from("HEAVY_RAIN_SHOWERS"),
+            WeatherConditionType::LightToModerateRain => String::from("LIGHT_TO_MODERATE_RAIN"),
+            WeatherConditionType::ModerateToHeavyRain => String::from("MODERATE_TO_HEAVY_RAIN"),
+            WeatherConditionType::Rain => String::from("RAIN"),
+            WeatherConditionType::LightRain => String::from("LIGHT_RAIN"),
+            WeatherConditionType::HeavyRain => String::from("HEAVY_RAIN"),
+            WeatherConditionType::RainPeriodicallyHeavy => String::from("RAIN_PERIODICALLY_HEAVY"),
+            WeatherConditionType::LightSnowShowers => String::from("LIGHT_SNOW_SHOWERS"),
+            WeatherConditionType::ChanceOfSnowShowers => String::from("CHANCE_OF_SNOW_SHOWERS"),
+            WeatherConditionType::ScatteredSnowShowers => String::from("SCATTERED_SNOW_SHOWERS"),
+            WeatherConditionType::SnowShowers => String::from("SNOW_SHOWERS"),
+            WeatherConditionType::HeavySnowShowers => String::from("HEAVY_SNOW_SHOWERS"),
+            WeatherConditionType::LightToModerateSnow => String::from("LIGHT_TO_MODERATE_SNOW"),
+            WeatherConditionType::ModerateToHeavySnow => String::from("MODERATE_TO_HEAVY_SNOW"),
+            WeatherConditionType::Snow => String::from("SNOW"),
+            WeatherConditionType::LightSnow => String::from("LIGHT_SNOW"),
+            WeatherConditionType::HeavySnow => String::from("HEAVY_SNOW"),
+            WeatherConditionType::Snowstorm => String::from("SNOWSTORM"),
+            WeatherConditionType::SnowPeriodicallyHeavy => String::from("SNOW_PERIODICALLY_HEAVY"),
+            WeatherConditionType::HeavySnowStorm => String::from("HEAVY_SNOW_STORM"),
+            WeatherConditionType::BlowingSnow => String::from("BLOWING_SNOW"),
+            WeatherConditionType::RainAndSnow => String::from("RAIN_AND_SNOW"),
+            WeatherConditionType::Hail => String::from("HAIL"),
+            WeatherConditionType::HailShowers => String::from("HAIL_SHOWERS"),
+            WeatherConditionType::Thunderstorm => String::from("THUNDERSTORM"),
+            WeatherConditionType::Thundershower => String::from("THUNDERSHOWER"),
+            WeatherConditionType::LightThunderstormRain => String::from("LIGHT_THUNDERSTORM_RAIN"),
+            WeatherConditionType::ScatteredThunderstorms => String::from("SCATTERED_THUNDERSTORMS"),
+            WeatherConditionType::HeavyThunderstorm => String::from("HEAVY_THUNDERSTORM"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static WEATHER_CONDITION_TYPES_BY_CODE: phf::Map<&'static str, WeatherConditionType> = phf_map! {
+    "CLEAR" => WeatherConditionType::Clear,
+    "MOSTLY_CLEAR" => WeatherConditionType::MostlyClear,
+    "PARTLY_CLOUDY" => WeatherConditionType::PartlyCloudy,
+    "MOSTLY_CLOUDY" => WeatherConditionType::MostlyCloudy,
+    "CLOUDY" => WeatherConditionType::Cloudy,
+    "WINDY" => WeatherConditionType::Windy,
+    "WIND_AND_RAIN" => WeatherConditionType::WindAndRain,
+    "LIGHT_RAIN_SHOWERS" => WeatherConditionType::LightRainShowers,
+    "CHANCE_OF_SHOWERS" => WeatherConditionType::ChanceOfShowers,
+    "SCATTERED_SHOWERS" => WeatherConditionType::ScatteredShowers,
+    "RAIN_SHOWERS" => WeatherConditionType::RainShowers,
+    "HEAVY_RAIN_SHOWERS" => WeatherConditionType::HeavyRainShowers,
+    "LIGHT_TO_MODERATE_RAIN" => WeatherConditionType::LightToModerateRain,
+    "MODERATE_TO_HEAVY_RAIN" => WeatherConditionType::ModerateToHeavyRain,
+    "RAIN" => WeatherConditionType::Rain,
+    "LIGHT_RAIN" => WeatherConditionType::LightRain,
+    "HEAVY_RAIN" => WeatherConditionType::HeavyRain,
+    "RAIN_PERIODICALLY_HEAVY" => WeatherConditionType::RainPeriodicallyHeavy,
+    "LIGHT_SNOW_SHOWERS" => WeatherConditionType::LightSnowShowers,
+    "CHANCE_OF_SNOW_SHOWERS" => WeatherConditionType::ChanceOfSnowShowers,
+    "SCATTERED_SNOW_SHOWERS" => WeatherConditionType::ScatteredSnowShowers,
+    "SNOW_SHOWERS" => WeatherConditionType::SnowShowers,
+    "HEAVY_SNOW_SHOWERS" => WeatherConditionType::HeavySnowShowers,
+    "LIGHT_TO_MODERATE_SNOW" => WeatherConditionType::LightToModerateSnow,
+    "MODERATE_TO_HEAVY_SNOW" => WeatherConditionType::ModerateToHeavySnow,
+    "SNOW" => WeatherConditionType::Snow,
+    "LIGHT_SNOW" => WeatherConditionType::LightSnow,
+    "HEAVY_SNOW" => WeatherConditionType::HeavySnow,
+    "SNOWSTORM" => WeatherConditionType::Snowstorm,
+    "SNOW_PERIODICALLY_HEAVY" => WeatherConditionType::SnowPeriodicallyHeavy,
+    "HEAVY_SNOW_STORM" => WeatherConditionType::HeavySnowStorm,
+    "BLOWING_SNOW" => WeatherConditionType::BlowingSnow,
+    "RAIN_AND_SNOW" => WeatherConditionType::RainAndSnow,
+    "HAIL" => WeatherConditionType::Hail,
+    "HAIL_SHOWERS" => WeatherConditionType::HailShowers,
+    "THUNDERSTORM" => WeatherConditionType::Thunderstorm,
+    "THUNDERSHOWER" => WeatherConditionType::Thundershower,
+    "LIGHT_THUNDERSTORM_RAIN" => WeatherConditionType::LightThunderstormRain,
+    "SCATTERED_THUNDERSTORMS" => WeatherConditionType::ScatteredThunderstorms,
+    "HEAVY_THUNDERSTORM" => WeatherConditionType::HeavyThunderstorm,
+};
+
+impl std::convert::TryFrom<&str> for WeatherConditionType {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Error = crate::weather::error::Error;
+    /// Gets a `WeatherConditionType` enum from a `String` that contains a valid
+    /// weather condition type code.
+    fn try_from(weather_condition_type_code: &str) -> Result<Self, Self::Error> {
+        WEATHER_CONDITION_TYPES_BY_CODE
+            .get(weather_condition_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidWeatherConditionType(weather_condition_type_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for WeatherConditionType {
+    // Error definitions are contained in the
+    // `google_maps\src\weather\error.rs` module.
+    type Err = crate::weather::error::Error;
+    /// Gets a `WeatherConditionType` enum from a `String` that contains a valid
+    /// weather condition type code.
+    fn from_str(weather_condition_type_code: &str) -> Result<Self, Self::Err> {
+        WEATHER_CONDITION_TYPES_BY_CODE
+            .get(weather_condition_type_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidWeatherConditionType(weather_condition_type_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for WeatherConditionType {
+    /// Returns a reasonable default variant for the `WeatherConditionType` enum type.
+    fn default() -> Self {
+        WeatherConditionType::Clear
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for WeatherConditionType {
+    /// Formats a `WeatherConditionType` enum into a string that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            WeatherConditionType::Clear => write!(f, "Clear"),
+            WeatherConditionType::MostlyClear => write!(f, "Mostly clear"),
+            WeatherConditionType::PartlyCloudy => write!(f, "Partly cloudy"),
+            WeatherConditionType::MostlyCloudy => write!(f, "Mostly cloudy"),
+            WeatherConditionType::Cloudy => write!(f, "Cloudy"),
+            WeatherConditionType::Windy => write!(f, "Windy"),
+            WeatherConditionType::WindAndRain => write!(f, "Wind and rain"),
+            WeatherConditionType::LightRainShowers => write!(f, "Light rain showers"),
+            WeatherConditionType::ChanceOfShowers => write!(f, "Chance of showers"),
+            WeatherConditionType::ScatteredShowers => write!(f, "Scattered showers"),
+            WeatherConditionType::RainShowers => write!(f, "Rain showers"),
+            WeatherConditionType::HeavyRainShowers => write!(f, "Heavy rain showers"),
+            WeatherConditionType::LightToModerateRain => write!(f, "Light to moderate rain"),
+            WeatherConditionType::ModerateToHeavyRain => write!(f, "Moderate to heavy rain"),
+            WeatherConditionType::Rain => write!(f, "Rain"),
+            WeatherConditionType::LightRain => write!(f, "Light rain"),
+            WeatherConditionType::HeavyRain => write!(f, "Heavy rain"),
+            WeatherConditionType::RainPeriodicallyHeavy => write!(f, "Rain periodically heavy"),
+            WeatherConditionType::LightSnowShowers => write!(f, "Light snow showers"),
+            WeatherConditionType::ChanceOfSnowShowers => write!(f, "Chance of snow showers"),
+            WeatherConditionType::ScatteredSnowShowers => write!(f, "Scattered snow showers"),
+            WeatherConditionType::SnowShowers => write!(f, "Snow showers"),
+            WeatherConditionType::HeavySnowShowers => write!(f, "Heavy snow showers"),
+            WeatherConditionType::LightToModerateSnow => write!(f, "Light to moderate snow"),
+            WeatherConditionType::ModerateToHeavySnow => write!(f, "Moderate to heavy snow"),
+            WeatherConditionType::Snow => write!(f, "Snow"),
+            WeatherConditionType::LightSnow => write!(f, "Light snow"),
+            WeatherConditionType::HeavySnow => write!(f, "Heavy snow"),
+            WeatherConditionType::Snowstorm => write!(f, "Snowstorm"),
+            WeatherConditionType::SnowPeriodicallyHeavy => write!(f, "Snow periodically heavy"),
+            WeatherConditionType::HeavySnowStorm => write!(f, "Heavy snow storm"),
+            WeatherConditionType::BlowingSnow => write!(f, "Blowing snow"),
+            WeatherConditionType::RainAndSnow => write!(f, "Rain and snow"),
+            WeatherConditionType::Hail => write!(f, "Hail"),
+            WeatherConditionType::HailShowers => write!(f, "Hail showers"),
+            WeatherConditionType::Thunderstorm => write!(f, "Thunderstorm"),
+            WeatherConditionType::Thundershower => write!(f, "Thundershower"),
+            WeatherConditionType::LightThunderstormRain => write!(f, "Light thunderstorm rain"),
+            WeatherConditionType::ScatteredThunderstorms => write!(f, "Scattered thunderstorms"),
+            WeatherConditionType::HeavyThunderstorm => write!(f, "Heavy thunderstorm"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+//! Contains the `Wind` struct. It groups the direction, speed and gust of the
+//! wind at a location.
+
+use crate::weather::{
+    wind_direction::WindDirection,
+    wind_speed::WindSpeed,
+}; // crate::weather
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a set of
+/// [wind](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Wind)
+/// properties.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Wind {
+
+    /// The direction of the wind, the angle it is coming from.
+    pub direction: Option<WindDirection>,
+
+    /// The speed of the wind.
+    pub speed: Option<WindSpeed>,
+
+    /// The wind gust (sudden increase in the wind speed).
+    pub gust: Option<WindSpeed>,
+
+} // struct
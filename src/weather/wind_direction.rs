@@ -0,0 +1,22 @@
+//! Contains the `WindDirection` struct. It reports the direction that the wind
+//! is blowing from.
+
+use crate::weather::cardinal_direction::CardinalDirection;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the [direction from which the wind
+/// originates](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Wind#winddirection).
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WindDirection {
+
+    /// The direction of the wind in degrees (values from 0 to 360).
+    pub degrees: Option<u16>,
+
+    /// The code that represents the cardinal direction from which the wind is
+    /// blowing.
+    pub cardinal: Option<CardinalDirection>,
+
+} // struct
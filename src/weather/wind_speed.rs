@@ -0,0 +1,23 @@
+//! Contains the `WindSpeed` struct. It is used for both sustained wind speed
+//! and wind gusts.
+
+use crate::weather::speed_unit::SpeedUnit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents the [speed of the
+/// wind](https://developers.google.com/maps/documentation/weather/reference/rest/v1/Wind#windspeed).
+/// The unit follows the `UnitSystem` set in the request: kilometers per hour
+/// for `Metric`, and miles per hour for `Imperial`.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct WindSpeed {
+
+    /// The value of the wind speed.
+    pub value: Option<f64>,
+
+    /// The code that represents the unit used to measure the wind speed.
+    pub unit: Option<SpeedUnit>,
+
+} // struct
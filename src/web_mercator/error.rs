@@ -0,0 +1,73 @@
+//! Web Mercator error types and error messages.
+
+use crate::web_mercator::MAX_ZOOM;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when converting between coordinates, tiles and
+/// quadkeys.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// A quadkey may only contain the digits `0`, `1`, `2` and `3`.
+    InvalidQuadkey(String),
+    /// The result could not be converted into a `LatLng`.
+    InvalidCoordinate(crate::error::Error),
+    /// The tile's coordinates are outside of the map at its zoom level.
+    TileOutOfRange(u32, u32, u8),
+    /// The zoom level is higher than this library supports.
+    ZoomTooLarge(u8),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidQuadkey(quadkey) => write!(f,
+                "Google Maps Platform API client: \
+                `{quadkey}` is not a valid quadkey. \
+                Quadkeys may only contain the digits 0, 1, 2 and 3, and may be \
+                at most {MAX_ZOOM} digits long."),
+            Error::InvalidCoordinate(error) => write!(f,
+                "Google Maps Platform API client: \
+                could not convert the map coordinate into a latitude & longitude: {error}"),
+            Error::TileOutOfRange(x, y, zoom) => write!(f,
+                "Google Maps Platform API client: \
+                tile ({x}, {y}) is outside of the map at zoom level {zoom}."),
+            Error::ZoomTooLarge(zoom) => write!(f,
+                "Google Maps Platform API client: \
+                zoom level `{zoom}` is too large. \
+                The highest supported zoom level is {MAX_ZOOM}."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for Error {
+    /// If the cause for the error is in an underlying library (not this
+    /// library but a library this one depends on), this trait unwraps the
+    /// original source error. This trait converts a Google Maps Platform API
+    /// error type into the native error type of the underlying library.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidCoordinate(error) => Some(error),
+            Error::InvalidQuadkey(_quadkey) => None,
+            Error::TileOutOfRange(_x, _y, _zoom) => None,
+            Error::ZoomTooLarge(_zoom) => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<crate::error::Error> for Error {
+    /// This trait converts a general `google_maps` error into a Web Mercator
+    /// error.
+    fn from(error: crate::error::Error) -> Error {
+        Error::InvalidCoordinate(error)
+    } // fn
+} // impl
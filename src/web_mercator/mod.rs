@@ -0,0 +1,86 @@
+//! [Web Mercator](https://en.wikipedia.org/wiki/Web_Mercator_projection)
+//! coordinate math, as used by Google Maps, OpenStreetMap and Bing Maps tiles.
+//!
+//! Google Maps describes positions on the map using three coordinate systems,
+//! explained in the [Map and Tile
+//! Coordinates](https://developers.google.com/maps/documentation/javascript/coordinates)
+//! guide:
+//!
+//! * `WorldCoordinate` ‧ A position on a single 256 × 256 tile that covers the
+//! whole world. This does not depend on the zoom level.
+//!
+//! * `PixelCoordinate` ‧ A position, in pixels, on the map at a specific zoom
+//! level. At zoom level `z` the world is `256 × 2ᶻ` pixels wide.
+//!
+//! * `Tile` ‧ One of the 256 × 256 pixel tiles that the map is cut into at a
+//! specific zoom level. Tiles may also be identified by a
+//! [quadkey](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system).
+//!
+//! The projection can not show the poles. Latitudes beyond about ±85.0511° are
+//! clamped to the top or bottom edge of the map.
+//!
+//! # Example:
+//!
+//! ```rust
+//! // Pick the zoom level for a 640 × 480 Static Maps thumbnail of a route:
+//! let zoom = route.bounds.zoom_to_fit(640, 480);
+//!
+//! // Bucket places into tiles for clustering:
+//! let tile = place.geometry.location.to_tile(12)?;
+//! let quadkey = tile.to_quadkey();
+//! ```
+
+pub mod error;
+pub mod pixel_coordinate;
+pub mod tile;
+pub mod tile_range;
+pub mod world_coordinate;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::web_mercator::{
+    error::Error,
+    pixel_coordinate::PixelCoordinate,
+    tile::Tile,
+    tile_range::TileRange,
+    world_coordinate::WorldCoordinate,
+}; // crate
+
+// -----------------------------------------------------------------------------
+
+/// The width and height, in pixels, of a map tile.
+pub const TILE_SIZE: u32 = 256;
+
+/// The highest zoom level supported by the tile math in this module. Tile
+/// coordinates at this zoom level still fit into a `u32`.
+pub const MAX_ZOOM: u8 = 30;
+
+/// The highest zoom level that Google Maps generally provides imagery for.
+/// `Bounds::zoom_to_fit` will not return a zoom level higher than this.
+pub const MAX_MAP_ZOOM: u8 = 21;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the scale factor, `2ᶻ`, between world coordinates and pixel
+/// coordinates at a zoom level.
+
+fn scale(zoom: u8) -> f64 {
+    f64::from(zoom).exp2()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the number of tiles along each edge of the map at a zoom level.
+///
+/// ## Errors:
+///
+/// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+/// `MAX_ZOOM`.
+
+fn tiles_per_side(zoom: u8) -> Result<u32, Error> {
+    if zoom > MAX_ZOOM {
+        Err(Error::ZoomTooLarge(zoom))
+    } else {
+        Ok(1_u32 << zoom)
+    } // if
+} // fn
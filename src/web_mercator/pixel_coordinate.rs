@@ -0,0 +1,89 @@
+//! Contains the `PixelCoordinate` struct and its associated methods.
+
+use crate::latlng::LatLng;
+use crate::web_mercator::{
+    error::Error, scale, tile::Tile, tiles_per_side, world_coordinate::WorldCoordinate, TILE_SIZE,
+}; // crate::web_mercator
+
+// -----------------------------------------------------------------------------
+//
+/// A position, in pixels, on the Web Mercator map at a specific zoom level.
+/// At zoom level `z` the map is `256 × 2ᶻ` pixels wide and tall, with the
+/// origin at its top-left (north-west) corner.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct PixelCoordinate {
+    /// The horizontal position in pixels, increasing eastward.
+    pub x: f64,
+    /// The vertical position in pixels, increasing southward.
+    pub y: f64,
+    /// The zoom level that the pixel coordinate is relative to.
+    pub zoom: u8,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PixelCoordinate {
+    /// Returns the zoom-independent world coordinate of this position.
+    pub fn to_world_coordinate(&self) -> WorldCoordinate {
+        WorldCoordinate {
+            x: self.x / scale(self.zoom),
+            y: self.y / scale(self.zoom),
+        } // WorldCoordinate
+    } // fn
+
+    /// Returns the latitude & longitude of this position.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidCoordinate` if either coordinate is not a
+    /// finite number.
+    pub fn to_latlng(&self) -> Result<LatLng, Error> {
+        self.to_world_coordinate().to_latlng()
+    } // fn
+
+    /// Returns the tile that contains this position. Positions beyond the
+    /// edges of the map are clamped to the nearest tile.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+    /// `MAX_ZOOM`.
+    pub fn to_tile(&self) -> Result<Tile, Error> {
+        let max = f64::from(tiles_per_side(self.zoom)? - 1);
+        let size = f64::from(TILE_SIZE);
+        // The clamped values are whole numbers within `u32` range, so the
+        // conversions can not truncate:
+        let (x, y) = (
+            (self.x / size).floor().clamp(0.0, max) as u32,
+            (self.y / size).floor().clamp(0.0, max) as u32,
+        ); // let
+        Ok(Tile { x, y, zoom: self.zoom })
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the map tile that contains this point at a zoom level.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+    /// `MAX_ZOOM`.
+    pub fn to_tile(&self, zoom: u8) -> Result<Tile, Error> {
+        self.to_pixel_coordinate(zoom).to_tile()
+    } // fn
+
+    /// Returns the quadkey of the map tile that contains this point at a zoom
+    /// level. Places that share a quadkey prefix are near each other, which
+    /// makes quadkeys convenient keys for spatial indexes and clustering.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+    /// `MAX_ZOOM`.
+    pub fn to_quadkey(&self, zoom: u8) -> Result<String, Error> {
+        self.to_tile(zoom).map(|tile| tile.to_quadkey())
+    } // fn
+} // impl
@@ -0,0 +1,209 @@
+//! Contains the `Tile` struct and its associated methods, including
+//! conversion to and from quadkeys.
+
+use crate::bounds::Bounds;
+use crate::latlng::LatLng;
+use crate::web_mercator::{
+    error::Error, pixel_coordinate::PixelCoordinate, tiles_per_side, MAX_ZOOM, TILE_SIZE,
+}; // crate::web_mercator
+
+// -----------------------------------------------------------------------------
+//
+/// One of the 256 × 256 pixel tiles that the Web Mercator map is cut into at a
+/// zoom level. At zoom level `z` there are `2ᶻ × 2ᶻ` tiles, numbered from the
+/// top-left (north-west) corner of the map.
+///
+/// This is the same numbering used by Google Maps, OpenStreetMap ("slippy
+/// map" tiles) and Bing Maps.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tile {
+    /// The tile's column, increasing eastward from the antimeridian.
+    pub x: u32,
+    /// The tile's row, increasing southward from the top of the map.
+    pub y: u32,
+    /// The tile's zoom level.
+    pub zoom: u8,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Tile {
+    /// Builds a tile from its column, row and zoom level.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+    /// `MAX_ZOOM`.
+    /// * Returns `Error::TileOutOfRange` if the column or row is outside of the
+    /// map at that zoom level.
+    pub fn try_new(x: u32, y: u32, zoom: u8) -> Result<Tile, Error> {
+        let tiles = tiles_per_side(zoom)?;
+        if x >= tiles || y >= tiles {
+            return Err(Error::TileOutOfRange(x, y, zoom));
+        } // if
+        Ok(Tile { x, y, zoom })
+    } // fn
+
+    /// Returns the tile identified by a
+    /// [quadkey](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system).
+    /// The number of digits in the quadkey is the tile's zoom level, so an
+    /// empty quadkey is the single tile at zoom level 0.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidQuadkey` if the quadkey contains anything
+    /// other than the digits `0` to `3`, or is longer than `MAX_ZOOM` digits.
+    pub fn from_quadkey(quadkey: &str) -> Result<Tile, Error> {
+        let zoom = u8::try_from(quadkey.len())
+            .ok()
+            .filter(|zoom| *zoom <= MAX_ZOOM)
+            .ok_or_else(|| Error::InvalidQuadkey(quadkey.to_string()))?;
+
+        let (mut x, mut y) = (0_u32, 0_u32);
+
+        for digit in quadkey.chars() {
+            let digit = digit
+                .to_digit(4)
+                .ok_or_else(|| Error::InvalidQuadkey(quadkey.to_string()))?;
+            x = (x << 1) | (digit & 1);
+            y = (y << 1) | (digit >> 1);
+        } // for
+
+        Ok(Tile { x, y, zoom })
+    } // fn
+
+    /// Returns the tile's quadkey. Each digit selects one quarter of the tile
+    /// before it, so a tile's quadkey starts with the quadkeys of all of the
+    /// larger tiles that contain it.
+    ///
+    /// Zoom levels beyond the width of the `x` and `y` fields produce leading
+    /// `0` digits rather than overflowing.
+    pub fn to_quadkey(&self) -> String {
+        (1..=self.zoom)
+            .rev()
+            .map(|level| {
+                let mask = 1_u32.checked_shl(u32::from(level - 1)).unwrap_or(0);
+                let digit = u32::from(self.x & mask != 0) + 2 * u32::from(self.y & mask != 0);
+                char::from_digit(digit, 4).unwrap_or('0')
+            }) // map
+            .collect()
+    } // fn
+
+    /// Returns the geographic area covered by the tile.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// tile's fields were set to a position that isn't on the map.
+    pub fn bounds(&self) -> Result<Bounds, Error> {
+        self.validate()?;
+        let size = f64::from(TILE_SIZE);
+        let corner = |x: u32, y: u32| PixelCoordinate {
+            x: f64::from(x) * size,
+            y: f64::from(y) * size,
+            zoom: self.zoom,
+        }; // corner
+
+        let north_west = corner(self.x, self.y).to_latlng()?;
+        let south_east = corner(self.x + 1, self.y + 1).to_latlng()?;
+
+        // The eastern edge of the last column is 180° E, not 180° W:
+        let east = if south_east.lng <= north_west.lng { -south_east.lng } else { south_east.lng };
+
+        Ok(Bounds {
+            southwest: LatLng { lat: south_east.lat, lng: north_west.lng },
+            northeast: LatLng { lat: north_west.lat, lng: east },
+        }) // Bounds
+    } // fn
+
+    /// Returns the center of the tile.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// tile's fields were set to a position that isn't on the map.
+    pub fn center(&self) -> Result<LatLng, Error> {
+        self.validate()?;
+        let size = f64::from(TILE_SIZE);
+        PixelCoordinate {
+            x: (f64::from(self.x) + 0.5) * size,
+            y: (f64::from(self.y) + 0.5) * size,
+            zoom: self.zoom,
+        }.to_latlng()
+    } // fn
+
+    /// Returns the tile at the next lower zoom level that contains this tile,
+    /// or `None` if this tile is at zoom level 0.
+    pub fn parent(&self) -> Option<Tile> {
+        (self.zoom > 0).then(|| Tile { x: self.x >> 1, y: self.y >> 1, zoom: self.zoom - 1 })
+    } // fn
+
+    /// Returns the four tiles at the next higher zoom level that make up this
+    /// tile, in quadkey order: north-west, north-east, south-west, south-east.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if this tile is already at `MAX_ZOOM`.
+    /// * Returns `Error::TileOutOfRange` if the tile's fields were set to a
+    /// position that isn't on the map.
+    pub fn children(&self) -> Result<[Tile; 4], Error> {
+        self.validate()?;
+        let zoom = self.zoom + 1;
+        tiles_per_side(zoom)?;
+        let (x, y) = (self.x << 1, self.y << 1);
+        Ok([
+            Tile { x, y, zoom },
+            Tile { x: x + 1, y, zoom },
+            Tile { x, y: y + 1, zoom },
+            Tile { x: x + 1, y: y + 1, zoom },
+        ]) // Ok
+    } // fn
+
+    /// Checks a tile whose public fields may have been set directly, so that
+    /// the arithmetic in the other methods can not overflow.
+    fn validate(&self) -> Result<(), Error> {
+        Tile::try_new(self.x, self.y, self.zoom).map(|_tile| ())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Tile {
+    /// Formats the tile as `zoom/x/y`, the path used by most tile servers.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.zoom, self.x, self.y)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_stop_at_the_highest_zoom_level() {
+        let tile = Tile { x: 0, y: 0, zoom: MAX_ZOOM };
+        assert_eq!(tile.children(), Err(Error::ZoomTooLarge(MAX_ZOOM + 1)));
+
+        let tile = Tile { x: 0, y: 0, zoom: u8::MAX };
+        assert_eq!(tile.children(), Err(Error::ZoomTooLarge(u8::MAX)));
+    } // fn
+
+    #[test]
+    fn rejects_tiles_that_are_not_on_the_map() {
+        let tile = Tile { x: u32::MAX, y: 0, zoom: 2 };
+        assert_eq!(tile.children(), Err(Error::TileOutOfRange(u32::MAX, 0, 2)));
+        assert_eq!(tile.bounds(), Err(Error::TileOutOfRange(u32::MAX, 0, 2)));
+        assert_eq!(tile.center(), Err(Error::TileOutOfRange(u32::MAX, 0, 2)));
+    } // fn
+
+    #[test]
+    fn quadkey_does_not_overflow_at_large_zoom_levels() {
+        let tile = Tile { x: 1, y: 0, zoom: 40 };
+        let quadkey = tile.to_quadkey();
+        assert_eq!(quadkey.len(), 40);
+        assert!(quadkey.ends_with("01"));
+    } // fn
+} // mod
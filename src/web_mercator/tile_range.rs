@@ -0,0 +1,240 @@
+//! Contains the `TileRange` struct and its associated methods, and the
+//! `Bounds` methods that map a bounding box onto tiles and zoom levels.
+
+use crate::bounds::Bounds;
+use crate::web_mercator::{
+    error::Error, tile::Tile, tiles_per_side, MAX_MAP_ZOOM, TILE_SIZE,
+}; // crate::web_mercator
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+//
+/// A rectangular block of tiles at one zoom level, such as the tiles needed
+/// to cover a `Bounds`.
+///
+/// If `min_x` is greater than `max_x` the range crosses the antimeridian: it
+/// runs from `min_x` east to the last column of the map, and continues from
+/// column `0` to `max_x`.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TileRange {
+    /// The western-most column.
+    pub min_x: u32,
+    /// The northern-most row.
+    pub min_y: u32,
+    /// The eastern-most column.
+    pub max_x: u32,
+    /// The southern-most row.
+    pub max_y: u32,
+    /// The zoom level of the tiles.
+    pub zoom: u8,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TileRange {
+    /// Returns the number of columns in the range.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// range's fields were set to tiles that aren't on the map.
+    pub fn width(&self) -> Result<u32, Error> {
+        let columns = self.validate()?;
+        if self.min_x > self.max_x {
+            Ok(columns - self.min_x + self.max_x + 1)
+        } else {
+            Ok(self.max_x - self.min_x + 1)
+        } // if
+    } // fn
+
+    /// Returns the number of rows in the range. A range whose `min_y` is
+    /// greater than its `max_y` has no rows.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// range's fields were set to tiles that aren't on the map.
+    pub fn height(&self) -> Result<u32, Error> {
+        self.validate()?;
+        Ok((self.max_y + 1).saturating_sub(self.min_y))
+    } // fn
+
+    /// Returns the number of tiles in the range. This can be very large at
+    /// high zoom levels, so check it before iterating over `tiles`.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// range's fields were set to tiles that aren't on the map.
+    pub fn tile_count(&self) -> Result<u64, Error> {
+        Ok(u64::from(self.width()?) * u64::from(self.height()?))
+    } // fn
+
+    /// Returns `true` if the tile is at the range's zoom level and within its
+    /// columns and rows.
+    pub fn contains(&self, tile: &Tile) -> bool {
+        let in_columns = if self.min_x > self.max_x {
+            tile.x >= self.min_x || tile.x <= self.max_x
+        } else {
+            tile.x >= self.min_x && tile.x <= self.max_x
+        }; // if
+        tile.zoom == self.zoom && in_columns && tile.y >= self.min_y && tile.y <= self.max_y
+    } // fn
+
+    /// Returns an iterator over every tile in the range, row by row from the
+    /// north-west corner.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` or `Error::TileOutOfRange` if the
+    /// range's fields were set to tiles that aren't on the map.
+    pub fn tiles(&self) -> Result<impl Iterator<Item = Tile>, Error> {
+        let range = *self;
+        let columns = range.validate()?;
+        let width = range.width()?;
+        Ok((range.min_y..=range.max_y).flat_map(move |y| {
+            (0..width).map(move |offset| Tile {
+                x: (range.min_x + offset) % columns,
+                y,
+                zoom: range.zoom,
+            }) // map
+        })) // Ok
+    } // fn
+
+    /// Checks a range whose public fields may have been set directly, so that
+    /// the arithmetic in the other methods can not overflow. Returns the
+    /// number of columns in the map at the range's zoom level.
+    fn validate(&self) -> Result<u32, Error> {
+        Tile::try_new(self.min_x, self.min_y, self.zoom)?;
+        Tile::try_new(self.max_x, self.max_y, self.zoom)?;
+        tiles_per_side(self.zoom)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns the block of tiles at a zoom level that covers the bounding
+    /// box. Boxes that cross the antimeridian produce a `TileRange` that wraps
+    /// around the edge of the map.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::ZoomTooLarge` if the zoom level is greater than
+    /// `MAX_ZOOM`.
+    pub fn tile_range(&self, zoom: u8) -> Result<TileRange, Error> {
+        let south_west = self.southwest.to_tile(zoom)?;
+        let north_east = self.northeast.to_tile(zoom)?;
+
+        // `to_tile` clamps 180° E onto the last column, so only boxes that
+        // cross the antimeridian wrap. A crossing box whose corners fall in
+        // the same column wraps all the way around, and covers every column:
+        let (min_x, max_x) = if self.crosses_antimeridian() && south_west.x == north_east.x {
+            (0, tiles_per_side(zoom)? - 1)
+        } else {
+            (south_west.x, north_east.x)
+        }; // if
+
+        Ok(TileRange {
+            min_x,
+            min_y: north_east.y,
+            max_x,
+            max_y: south_west.y,
+            zoom,
+        }) // TileRange
+    } // fn
+
+    /// Returns the highest zoom level at which the whole bounding box fits
+    /// into a map viewport of `width` × `height` pixels, for example a Static
+    /// Maps image. The result is never higher than `MAX_MAP_ZOOM`, which is
+    /// also returned for a box with no area.
+    ///
+    /// Remember to leave room for markers and other overlays by passing a
+    /// viewport that is slightly smaller than the image.
+    pub fn zoom_to_fit(&self, width: u32, height: u32) -> u8 {
+        let size = f64::from(TILE_SIZE);
+
+        // The fraction of the world's height and width that the box covers:
+        let north = self.northeast.to_world_coordinate().y;
+        let south = self.southwest.to_world_coordinate().y;
+        let lat_fraction = (south - north) / size;
+        let lng_fraction = self.span().1.to_f64().unwrap_or(360.0) / 360.0;
+
+        let fit = |pixels: u32, fraction: f64| -> f64 {
+            if fraction > 0.0 {
+                (f64::from(pixels) / size / fraction).log2().floor()
+            } else {
+                f64::from(MAX_MAP_ZOOM)
+            } // if
+        }; // fit
+
+        // The clamped value is a whole number from 0 to 21, so the conversion
+        // can not truncate:
+        fit(height, lat_fraction)
+            .min(fit(width, lng_fraction))
+            .clamp(0.0, f64::from(MAX_MAP_ZOOM)) as u8
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latlng::LatLng;
+
+    fn bounds(south: f64, west: f64, north: f64, east: f64) -> Bounds {
+        Bounds {
+            southwest: LatLng::try_from_f64(south, west).unwrap(),
+            northeast: LatLng::try_from_f64(north, east).unwrap(),
+        } // Bounds
+    } // fn
+
+    #[test]
+    fn range_crossing_antimeridian_wraps() {
+        let range = bounds(-10.0, 170.0, 10.0, -170.0).tile_range(2).unwrap();
+        assert_eq!(range, TileRange { min_x: 3, min_y: 1, max_x: 0, max_y: 2, zoom: 2 });
+        assert_eq!(range.width(), Ok(2));
+        assert_eq!(range.tile_count(), Ok(4));
+
+        assert!(range.contains(&Tile { x: 3, y: 1, zoom: 2 }));
+        assert!(range.contains(&Tile { x: 0, y: 2, zoom: 2 }));
+        assert!(!range.contains(&Tile { x: 1, y: 1, zoom: 2 }));
+
+        let columns: Vec<u32> = range.tiles().unwrap().filter(|tile| tile.y == 1).map(|tile| tile.x).collect();
+        assert_eq!(columns, vec![3, 0]);
+    } // fn
+
+    #[test]
+    fn range_ending_at_180_east_does_not_wrap() {
+        let range = bounds(0.0, 90.0, 10.0, 180.0).tile_range(1).unwrap();
+        assert_eq!((range.min_x, range.max_x), (1, 1));
+        assert_eq!(range.width(), Ok(1));
+    } // fn
+
+    #[test]
+    fn range_crossing_antimeridian_within_one_column_covers_world() {
+        let range = bounds(0.0, 10.0, 1.0, 5.0).tile_range(2).unwrap();
+        assert_eq!((range.min_x, range.max_x), (0, 3));
+        assert_eq!(range.width(), Ok(4));
+    } // fn
+
+    #[test]
+    fn rejects_ranges_that_are_not_on_the_map() {
+        let range = TileRange { min_x: 0, min_y: 0, max_x: 1, max_y: 1, zoom: 31 };
+        assert_eq!(range.width(), Err(Error::ZoomTooLarge(31)));
+        assert!(range.tiles().is_err());
+
+        let range = TileRange { min_x: 0, min_y: 0, max_x: 4, max_y: 1, zoom: 2 };
+        assert_eq!(range.tile_count(), Err(Error::TileOutOfRange(4, 1, 2)));
+    } // fn
+
+    #[test]
+    fn range_with_rows_reversed_is_empty() {
+        let range = TileRange { min_x: 0, min_y: 2, max_x: 1, max_y: 1, zoom: 2 };
+        assert_eq!(range.height(), Ok(0));
+        assert_eq!(range.tile_count(), Ok(0));
+        assert_eq!(range.tiles().unwrap().count(), 0);
+    } // fn
+} // mod
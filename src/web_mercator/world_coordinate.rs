@@ -0,0 +1,73 @@
+//! Contains the `WorldCoordinate` struct and its associated methods.
+
+use crate::latlng::LatLng;
+use crate::web_mercator::{error::Error, pixel_coordinate::PixelCoordinate, scale, TILE_SIZE};
+use std::f64::consts::PI;
+
+// -----------------------------------------------------------------------------
+//
+/// A position on the Web Mercator projection of the whole world onto a single
+/// 256 × 256 tile. `x` increases eastward from the antimeridian and `y`
+/// increases southward from the top of the map. World coordinates do not
+/// depend on the zoom level.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct WorldCoordinate {
+    /// The horizontal position, from `0.0` at 180° W to `256.0` at 180° E.
+    pub x: f64,
+    /// The vertical position, from `0.0` at about 85.0511° N to `256.0` at
+    /// about 85.0511° S.
+    pub y: f64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl WorldCoordinate {
+    /// Returns the pixel coordinate of this position at a zoom level.
+    pub fn to_pixel_coordinate(&self, zoom: u8) -> PixelCoordinate {
+        PixelCoordinate {
+            x: self.x * scale(zoom),
+            y: self.y * scale(zoom),
+            zoom,
+        } // PixelCoordinate
+    } // fn
+
+    /// Returns the latitude & longitude of this position. `x` values outside
+    /// of the map wrap around the world, and `y` values are clamped to the top
+    /// and bottom of the map.
+    ///
+    /// ## Errors:
+    ///
+    /// * Returns `Error::InvalidCoordinate` if either coordinate is not a
+    /// finite number.
+    pub fn to_latlng(&self) -> Result<LatLng, Error> {
+        let size = f64::from(TILE_SIZE);
+        let lng = self.x / size * 2.0 * PI - PI;
+        let n = PI - 2.0 * PI * self.y.clamp(0.0, size) / size;
+        Ok(LatLng::try_from_radians(n.sinh().atan(), lng)?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Projects this point onto the Web Mercator world coordinate space.
+    /// Latitudes beyond about ±85.0511° are clamped to the top or bottom edge
+    /// of the map.
+    pub fn to_world_coordinate(&self) -> WorldCoordinate {
+        let size = f64::from(TILE_SIZE);
+        // Clamping the sine keeps the poles, which are infinitely far away on
+        // the projection, from producing infinite coordinates:
+        let sin_lat = self.lat_radians().sin().clamp(-0.999_999_9, 0.999_999_9);
+        WorldCoordinate {
+            x: size * (0.5 + self.lng_radians() / (2.0 * PI)),
+            y: (size * (0.5 - ((1.0 + sin_lat) / (1.0 - sin_lat)).ln() / (4.0 * PI)))
+                .clamp(0.0, size),
+        } // WorldCoordinate
+    } // fn
+
+    /// Returns the pixel coordinate of this point on the map at a zoom level.
+    pub fn to_pixel_coordinate(&self, zoom: u8) -> PixelCoordinate {
+        self.to_world_coordinate().to_pixel_coordinate(zoom)
+    } // fn
+} // impl